@@ -86,9 +86,14 @@ class CreateWallet : CliktCommand(help = "Creates a new wallet") {
             mutableTokenList,
             mutableActivityList,
             mutableCoinList,
-            settings
+            settings,
+            null,
+            false,
+            null
         )
 
+        wallet.masterFingerprint = getMasterFingerprint(wallet, "")
+
         sqliteManager.insertWallet(wallet);
 
         val json = buildJsonObject {
@@ -240,15 +240,26 @@ class TransferReceive: CliktCommand(help = "Retrieve coins from server") {
         return transferMsg.statechainId
     }
 
-    private suspend fun getMsgAddr(authPubkey: String) : List<String> {
-        val url = "${appContext.clientConfig.statechainEntity}/transfer/get_msg_addr/${authPubkey}"
-
+    private suspend fun getMsgAddr(coin: Coin) : List<String> {
         val httpClient = HttpClient(CIO) {
             install(ContentNegotiation) {
                 json()
             }
         }
 
+        val challengeUrl = "${appContext.clientConfig.statechainEntity}/transfer/challenge"
+
+        val challengeResponse = httpClient.post(challengeUrl) {
+            contentType(ContentType.Application.Json)
+            setBody(buildJsonObject { putJsonArray("auth_pub_keys") { add(coin.authPubkey) } })
+        }
+
+        val challenge = Json.parseToJsonElement(challengeResponse.bodyAsText()).jsonObject["challenge"]!!.jsonPrimitive.content
+
+        val authSig = signAuthChallenge(challenge, coin)
+
+        val url = "${appContext.clientConfig.statechainEntity}/transfer/get_msg_addr/${coin.authPubkey}?challenge=${challenge}&auth_sig=${authSig}"
+
         val response: GetMsgAddrResponsePayload = httpClient.get(url).body();
 
         httpClient.close()
@@ -266,17 +277,17 @@ class TransferReceive: CliktCommand(help = "Retrieve coins from server") {
 
         val infoConfig = getInfoConfig(appContext.clientConfig)
 
-        val uniqueAuthPubkeys = mutableSetOf<String>()
+        val uniqueAuthPubkeys = mutableMapOf<String, Coin>()
 
         wallet.coins.forEach { coin ->
-            uniqueAuthPubkeys.add(coin.authPubkey)
+            uniqueAuthPubkeys[coin.authPubkey] = coin
         }
 
         val encMsgsPerAuthPubkey = mutableMapOf<String, List<String>>()
 
-        for (authPubkey in uniqueAuthPubkeys) {
+        for ((authPubkey, authCoin) in uniqueAuthPubkeys) {
             try {
-                val encMessages = getMsgAddr(authPubkey)
+                val encMessages = getMsgAddr(authCoin)
                 if (encMessages.isEmpty()) {
                     println("No messages")
                     continue
@@ -16,11 +16,12 @@
 // compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
 // helpers directly inline like we're doing here.
 
-import com.sun.jna.Callback
 import com.sun.jna.Library
+import com.sun.jna.IntegerType
 import com.sun.jna.Native
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
+import com.sun.jna.Callback
 import com.sun.jna.ptr.*
 import kotlinx.serialization.Serializable
 import kotlinx.serialization.SerialName
@@ -28,8 +29,8 @@ import java.nio.ByteBuffer
 import java.nio.ByteOrder
 import java.nio.CharBuffer
 import java.nio.charset.CodingErrorAction
-import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicLong
+import java.util.concurrent.ConcurrentHashMap
 
 // This is a helper for safely working with byte buffers returned from the Rust code.
 // A rust-owned buffer is represented by its capacity, its current length, and a
@@ -40,37 +41,29 @@ open class RustBuffer : Structure() {
     // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
     // When dealing with these fields, make sure to call `toULong()`.
     @JvmField var capacity: Long = 0
-
     @JvmField var len: Long = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue : RustBuffer(), Structure.ByValue
+    class ByValue: RustBuffer(), Structure.ByValue
+    class ByReference: RustBuffer(), Structure.ByReference
 
-    class ByReference : RustBuffer(), Structure.ByReference
-
-    internal fun setValue(other: RustBuffer) {
+   internal fun setValue(other: RustBuffer) {
         capacity = other.capacity
         len = other.len
         data = other.data
     }
 
     companion object {
-        internal fun alloc(size: ULong = 0UL) =
-            uniffiRustCall { status ->
-                // Note: need to convert the size to a `Long` value to make this work with JVM.
-                UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_alloc(size.toLong(), status)
-            }.also {
-                if (it.data == null) {
-                    throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=$size)")
-                }
-            }
+        internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
+            // Note: need to convert the size to a `Long` value to make this work with JVM.
+            UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_alloc(size.toLong(), status)
+        }.also {
+            if(it.data == null) {
+               throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
+           }
+        }
 
-        internal fun create(
-            capacity: ULong,
-            len: ULong,
-            data: Pointer?,
-        ): RustBuffer.ByValue {
+        internal fun create(capacity: ULong, len: ULong, data: Pointer?): RustBuffer.ByValue {
             var buf = RustBuffer.ByValue()
             buf.capacity = capacity.toLong()
             buf.len = len.toLong()
@@ -78,10 +71,9 @@ open class RustBuffer : Structure() {
             return buf
         }
 
-        internal fun free(buf: RustBuffer.ByValue) =
-            uniffiRustCall { status ->
-                UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_free(buf, status)
-            }
+        internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
+            UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_free(buf, status)
+        }
     }
 
     @Suppress("TooGenericExceptionThrown")
@@ -132,12 +124,10 @@ class RustBufferByReference : ByReference(16) {
 @Structure.FieldOrder("len", "data")
 open class ForeignBytes : Structure() {
     @JvmField var len: Int = 0
-
     @JvmField var data: Pointer? = null
 
     class ByValue : ForeignBytes(), Structure.ByValue
 }
-
 // The FfiConverter interface handles converter types to and from the FFI
 //
 // All implementing objects should be public to support external types.  When a
@@ -163,10 +153,7 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun allocationSize(value: KotlinType): ULong
 
     // Write a Kotlin type to a `ByteBuffer`
-    fun write(
-        value: KotlinType,
-        buf: ByteBuffer,
-    )
+    fun write(value: KotlinType, buf: ByteBuffer)
 
     // Lower a value into a `RustBuffer`
     //
@@ -177,10 +164,9 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun lowerIntoRustBuffer(value: KotlinType): RustBuffer.ByValue {
         val rbuf = RustBuffer.alloc(allocationSize(value))
         try {
-            val bbuf =
-                rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
-                    it.order(ByteOrder.BIG_ENDIAN)
-                }
+            val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+                it.order(ByteOrder.BIG_ENDIAN)
+            }
             write(value, bbuf)
             rbuf.writeField("len", bbuf.position().toLong())
             return rbuf
@@ -197,11 +183,11 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun liftFromRustBuffer(rbuf: RustBuffer.ByValue): KotlinType {
         val byteBuf = rbuf.asByteBuffer()!!
         try {
-            val item = read(byteBuf)
-            if (byteBuf.hasRemaining()) {
-                throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
-            }
-            return item
+           val item = read(byteBuf)
+           if (byteBuf.hasRemaining()) {
+               throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
+           }
+           return item
         } finally {
             RustBuffer.free(rbuf)
         }
@@ -209,9 +195,8 @@ public interface FfiConverter<KotlinType, FfiType> {
 }
 
 // FfiConverter that uses `RustBuffer` as the FfiType
-public interface FfiConverterRustBuffer<KotlinType> : FfiConverter<KotlinType, RustBuffer.ByValue> {
+public interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBuffer.ByValue> {
     override fun lift(value: RustBuffer.ByValue) = liftFromRustBuffer(value)
-
     override fun lower(value: KotlinType) = lowerIntoRustBuffer(value)
 }
 // A handful of classes and functions to support the generated data structures.
@@ -224,10 +209,9 @@ internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
     @JvmField var code: Byte = 0
-
     @JvmField var error_buf: RustBuffer.ByValue = RustBuffer.ByValue()
 
-    class ByValue : UniffiRustCallStatus(), Structure.ByValue
+    class ByValue: UniffiRustCallStatus(), Structure.ByValue
 
     fun isSuccess(): Boolean {
         return code == UNIFFI_CALL_SUCCESS
@@ -242,10 +226,7 @@ internal open class UniffiRustCallStatus : Structure() {
     }
 
     companion object {
-        fun create(
-            code: Byte,
-            errorBuf: RustBuffer.ByValue,
-        ): UniffiRustCallStatus.ByValue {
+        fun create(code: Byte, errorBuf: RustBuffer.ByValue): UniffiRustCallStatus.ByValue {
             val callStatus = UniffiRustCallStatus.ByValue()
             callStatus.code = code
             callStatus.error_buf = errorBuf
@@ -258,7 +239,7 @@ class InternalException(message: String) : Exception(message)
 
 // Each top-level error class has a companion object that can lift the error from the call status's rust buffer
 interface UniffiRustCallStatusErrorHandler<E> {
-    fun lift(error_buf: RustBuffer.ByValue): E
+    fun lift(error_buf: RustBuffer.ByValue): E;
 }
 
 // Helpers for calling Rust
@@ -266,21 +247,15 @@ interface UniffiRustCallStatusErrorHandler<E> {
 // synchronize itself
 
 // Call a rust function that returns a Result<>.  Pass in the Error class companion that corresponds to the Err
-private inline fun <U, E : Exception> uniffiRustCallWithError(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    callback: (UniffiRustCallStatus) -> U,
-): U {
-    var status = UniffiRustCallStatus()
+private inline fun <U, E: Exception> uniffiRustCallWithError(errorHandler: UniffiRustCallStatusErrorHandler<E>, callback: (UniffiRustCallStatus) -> U): U {
+    var status = UniffiRustCallStatus();
     val return_value = callback(status)
     uniffiCheckCallStatus(errorHandler, status)
     return return_value
 }
 
 // Check UniffiRustCallStatus and throw an error if the call wasn't successful
-private fun <E : Exception> uniffiCheckCallStatus(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    status: UniffiRustCallStatus,
-) {
+private fun<E: Exception> uniffiCheckCallStatus(errorHandler: UniffiRustCallStatusErrorHandler<E>, status: UniffiRustCallStatus) {
     if (status.isSuccess()) {
         return
     } else if (status.isError()) {
@@ -300,7 +275,7 @@ private fun <E : Exception> uniffiCheckCallStatus(
 }
 
 // UniffiRustCallStatusErrorHandler implementation for times when we don't expect a CALL_ERROR
-object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<InternalException> {
+object UniffiNullRustCallStatusErrorHandler: UniffiRustCallStatusErrorHandler<InternalException> {
     override fun lift(error_buf: RustBuffer.ByValue): InternalException {
         RustBuffer.free(error_buf)
         return InternalException("Unexpected CALL_ERROR")
@@ -309,31 +284,31 @@ object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<I
 
 // Call a rust function that returns a plain value
 private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U {
-    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback);
 }
 
-internal inline fun <T> uniffiTraitInterfaceCall(
+internal inline fun<T> uniffiTraitInterfaceCall(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: Exception) {
+    } catch(e: Exception) {
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
         callStatus.error_buf = FfiConverterString.lower(e.toString())
     }
 }
 
-internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError(
+internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
-    lowerError: (E) -> RustBuffer.ByValue,
+    lowerError: (E) -> RustBuffer.ByValue
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: Exception) {
+    } catch(e: Exception) {
         if (e is E) {
             callStatus.code = UNIFFI_CALL_ERROR
             callStatus.error_buf = lowerError(e)
@@ -343,11 +318,10 @@ internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError
         }
     }
 }
-
 // Map handles to objects
 //
 // This is used pass an opaque 64-bit handle representing a foreign object to the Rust code.
-internal class UniffiHandleMap<T : Any> {
+internal class UniffiHandleMap<T: Any> {
     private val map = ConcurrentHashMap<Long, T>()
     private val counter = java.util.concurrent.atomic.AtomicLong(0)
 
@@ -383,26 +357,22 @@ private fun findLibraryName(componentName: String): String {
     return "mercurylib"
 }
 
-private inline fun <reified Lib : Library> loadIndirect(componentName: String): Lib {
+private inline fun <reified Lib : Library> loadIndirect(
+    componentName: String
+): Lib {
     return Native.load<Lib>(findLibraryName(componentName), Lib::class.java)
 }
 
 // Define FFI callback types
 internal interface UniffiRustFutureContinuationCallback : com.sun.jna.Callback {
-    fun callback(
-        `data`: Long,
-        `pollResult`: Byte,
-    )
+    fun callback(`data`: Long,`pollResult`: Byte,)
 }
-
 internal interface UniffiForeignFutureFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 internal interface UniffiCallbackInterfaceFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 @Structure.FieldOrder("handle", "free")
 internal open class UniffiForeignFuture(
     @JvmField internal var `handle`: Long = 0.toLong(),
@@ -411,14 +381,14 @@ internal open class UniffiForeignFuture(
     class UniffiByValue(
         `handle`: Long = 0.toLong(),
         `free`: UniffiForeignFutureFree? = null,
-    ) : UniffiForeignFuture(`handle`, `free`), Structure.ByValue
+    ): UniffiForeignFuture(`handle`,`free`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFuture) {
+   internal fun uniffiSetValue(other: UniffiForeignFuture) {
         `handle` = other.`handle`
         `free` = other.`free`
     }
-}
 
+}
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -427,21 +397,17 @@ internal open class UniffiForeignFutureStructU8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU8(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -450,21 +416,17 @@ internal open class UniffiForeignFutureStructI8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI8(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -473,21 +435,17 @@ internal open class UniffiForeignFutureStructU16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU16(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -496,21 +454,17 @@ internal open class UniffiForeignFutureStructI16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI16(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -519,21 +473,17 @@ internal open class UniffiForeignFutureStructU32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU32(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -542,21 +492,17 @@ internal open class UniffiForeignFutureStructI32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI32(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -565,21 +511,17 @@ internal open class UniffiForeignFutureStructU64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU64(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -588,21 +530,17 @@ internal open class UniffiForeignFutureStructI64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI64(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructF32(
     @JvmField internal var `returnValue`: Float = 0.0f,
@@ -611,21 +549,17 @@ internal open class UniffiForeignFutureStructF32(
     class UniffiByValue(
         `returnValue`: Float = 0.0f,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructF32(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructF32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructF32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructF64(
     @JvmField internal var `returnValue`: Double = 0.0,
@@ -634,21 +568,17 @@ internal open class UniffiForeignFutureStructF64(
     class UniffiByValue(
         `returnValue`: Double = 0.0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructF64(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructF64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructF64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructPointer(
     @JvmField internal var `returnValue`: Pointer = Pointer.NULL,
@@ -657,21 +587,17 @@ internal open class UniffiForeignFutureStructPointer(
     class UniffiByValue(
         `returnValue`: Pointer = Pointer.NULL,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructPointer(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructPointer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompletePointer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructPointer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructPointer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructRustBuffer(
     @JvmField internal var `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
@@ -680,2067 +606,3115 @@ internal open class UniffiForeignFutureStructRustBuffer(
     class UniffiByValue(
         `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructRustBuffer(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructRustBuffer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteRustBuffer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("callStatus")
 internal open class UniffiForeignFutureStructVoid(
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructVoid(`callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructVoid(`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructVoid.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructVoid.UniffiByValue,)
 }
 
-// A JNA Library to expose the extern-C FFI definitions.
-// This is an implementation detail which will be called internally by the public API.
 
-internal interface UniffiLib : Library {
-    companion object {
-        internal val INSTANCE: UniffiLib by lazy {
-            loadIndirect<UniffiLib>(componentName = "mercurylib")
-                .also { lib: UniffiLib ->
-                    uniffiCheckContractApiVersion(lib)
-                    uniffiCheckApiChecksums(lib)
-                }
-        }
-    }
 
-    fun uniffi_mercurylib_fn_func_create_aggregated_address(
-        `coin`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_and_commit_nonces(
-        `coin`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_cpfp_tx(
-        `backupTx`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `toAddress`: RustBuffer.ByValue,
-        `feeRateSatsPerByte`: Long,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_deposit_msg1(
-        `coin`: RustBuffer.ByValue,
-        `tokenId`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_signature(
-        `msg`: RustBuffer.ByValue,
-        `clientPartialSigHex`: RustBuffer.ByValue,
-        `serverPartialSigHex`: RustBuffer.ByValue,
-        `sessionHex`: RustBuffer.ByValue,
-        `outputPubkeyHex`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_transfer_signature(
-        `recipientAddress`: RustBuffer.ByValue,
-        `inputTxid`: RustBuffer.ByValue,
-        `inputVout`: Int,
-        `clientSeckey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_transfer_update_msg(
-        `x1`: RustBuffer.ByValue,
-        `recipientAddress`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `transferSignature`: RustBuffer.ByValue,
-        `backupTransactions`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_decode_statechain_address(
-        `scAddress`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(
-        `wallet`: RustBuffer.ByValue,
-        `authPubkey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_ffi_validate_signature_scheme(
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `statechainInfo`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `feeRateTolerance`: Int,
-        `currentFeeRateSatsPerByte`: Int,
-        `interval`: Int,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(
-        `newUserPubkey`: RustBuffer.ByValue,
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(
-        `statechainInfo`: RustBuffer.ByValue,
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(
-        `encryptedMessage`: RustBuffer.ByValue,
-        `privateKeyWif`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(
-        `enclavePublicKey`: RustBuffer.ByValue,
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `clientPubkeyShare`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_generate_mnemonic(uniffi_out_err: UniffiRustCallStatus): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_blockheight(
-        `bkpTx`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun uniffi_mercurylib_fn_func_get_new_coin(
-        `wallet`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_new_key_info(
-        `serverPublicKeyHex`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `statechainId`: RustBuffer.ByValue,
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_output_address_from_tx0(
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_partial_sig_request(
-        `coin`: RustBuffer.ByValue,
-        `blockHeight`: Int,
-        `initlock`: Int,
-        `interval`: Int,
-        `feeRateSatsPerByte`: Int,
-        `qtBackupTx`: Int,
-        `toAddress`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        `isWithdrawal`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_tx0_outpoint(
-        `backupTransactions`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_user_backup_address(
-        `coin`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(
-        `coin`: RustBuffer.ByValue,
-        `depositMsg1Response`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(
-        `coin`: RustBuffer.ByValue,
-        `enclavePubkey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(
-        `backupTxs`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_new_backup_transaction(
-        `encodedUnsignedTx`: RustBuffer.ByValue,
-        `signatureHex`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_sign_message(
-        `message`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_validate_address(
-        `address`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_verify_blinded_musig_scheme(
-        `backupTx`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `statechainInfo`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun uniffi_mercurylib_fn_func_verify_transaction_signature(
-        `txNHex`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `feeRateTolerance`: Int,
-        `currentFeeRateSatsPerByte`: Int,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun ffi_mercurylib_rustbuffer_alloc(
-        `size`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rustbuffer_from_bytes(
-        `bytes`: ForeignBytes.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rustbuffer_free(
-        `buf`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun ffi_mercurylib_rustbuffer_reserve(
-        `buf`: RustBuffer.ByValue,
-        `additional`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rust_future_poll_u8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun ffi_mercurylib_rust_future_poll_i8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun ffi_mercurylib_rust_future_poll_u16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
 
-    fun ffi_mercurylib_rust_future_poll_i16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
 
-    fun ffi_mercurylib_rust_future_poll_u32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun ffi_mercurylib_rust_future_poll_i32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun ffi_mercurylib_rust_future_poll_u64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    fun ffi_mercurylib_rust_future_poll_i64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    fun ffi_mercurylib_rust_future_poll_f32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_f32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_f32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_f32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Float
 
-    fun ffi_mercurylib_rust_future_poll_f64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_f64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_f64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_f64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Double
 
-    fun ffi_mercurylib_rust_future_poll_pointer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_pointer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_pointer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_pointer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Pointer
 
-    fun ffi_mercurylib_rust_future_poll_rust_buffer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_rust_buffer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_rust_buffer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_rust_buffer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rust_future_poll_void(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_void(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_void(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_void(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun uniffi_mercurylib_checksum_func_create_aggregated_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_and_commit_nonces(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_cpfp_tx(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_deposit_msg1(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_signature(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_transfer_signature(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_transfer_update_msg(): Short
 
-    fun uniffi_mercurylib_checksum_func_decode_statechain_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state(): Short
 
-    fun uniffi_mercurylib_checksum_func_ffi_validate_signature_scheme(): Short
 
-    fun uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(): Short
 
-    fun uniffi_mercurylib_checksum_func_generate_mnemonic(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_blockheight(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_new_coin(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_new_key_info(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_output_address_from_tx0(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_partial_sig_request(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_tx0_outpoint(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_user_backup_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response(): Short
 
-    fun uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin(): Short
 
-    fun uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey(): Short
 
-    fun uniffi_mercurylib_checksum_func_new_backup_transaction(): Short
 
-    fun uniffi_mercurylib_checksum_func_sign_message(): Short
 
-    fun uniffi_mercurylib_checksum_func_validate_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme(): Short
 
-    fun uniffi_mercurylib_checksum_func_verify_transaction_signature(): Short
 
-    fun ffi_mercurylib_uniffi_contract_version(): Int
-}
 
-private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
-    // Get the bindings contract version from our ComponentInterface
-    val bindings_contract_version = 26
-    // Get the scaffolding contract version by calling the into the dylib
-    val scaffolding_contract_version = lib.ffi_mercurylib_uniffi_contract_version()
-    if (bindings_contract_version != scaffolding_contract_version) {
-        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
-    }
-}
 
-@Suppress("UNUSED_PARAMETER")
-private fun uniffiCheckApiChecksums(lib: UniffiLib) {
-    if (lib.uniffi_mercurylib_checksum_func_create_aggregated_address() != 44269.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_and_commit_nonces() != 16584.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_cpfp_tx() != 63811.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_deposit_msg1() != 9767.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_signature() != 53021.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_transfer_signature() != 61677.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_transfer_update_msg() != 6918.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_decode_statechain_address() != 7125.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state() != 30591.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_ffi_validate_signature_scheme() != 26248.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature() != 18534.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload() != 58308.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg() != 44515.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey() != 51706.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey() != 46083.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_generate_mnemonic() != 62910.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_blockheight() != 5222.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_new_coin() != 45841.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_new_key_info() != 64987.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_output_address_from_tx0() != 62309.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_partial_sig_request() != 13111.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_tx0_outpoint() != 21467.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_user_backup_address() != 29075.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response() != 64110.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin() != 37041.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey() != 19689.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_new_backup_transaction() != 56642.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_sign_message() != 9994.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_validate_address() != 16334.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme() != 42963.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_verify_transaction_signature() != 32006.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-}
 
-// Async support
 
-// Public interface members begin here.
 
-// Interface implemented by anything that can contain an object reference.
-//
-// Such types expose a `destroy()` method that must be called to cleanly
-// dispose of the contained objects. Failure to call this method may result
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+// A JNA Library to expose the extern-C FFI definitions.
+// This is an implementation detail which will be called internally by the public API.
+
+internal interface UniffiLib : Library {
+    companion object {
+        internal val INSTANCE: UniffiLib by lazy {
+            loadIndirect<UniffiLib>(componentName = "mercurylib")
+            .also { lib: UniffiLib ->
+                uniffiCheckContractApiVersion(lib)
+                uniffiCheckApiChecksums(lib)
+                }
+        }
+        
+    }
+
+    fun uniffi_mercurylib_fn_func_blind_token(`signerPubkey`: RustBuffer.ByValue,`publicNonce`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_change_wallet_password(`wallet`: RustBuffer.ByValue,`oldPassword`: RustBuffer.ByValue,`newPassword`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_aggregated_address(`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_and_commit_nonces(`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_auth_key_proof(`coin`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_blind_deposit_msg1(`coin`: RustBuffer.ByValue,`tokenId`: RustBuffer.ByValue,`blindSignature`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_cpfp_psbt(`backupTx`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`toAddress`: RustBuffer.ByValue,`feeRateSatsPerByte`: Double,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_cpfp_tx(`backupTx`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`toAddress`: RustBuffer.ByValue,`feeRateSatsPerByte`: Double,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_deposit_msg1(`coin`: RustBuffer.ByValue,`tokenId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_get_msg_addr_bulk_request_payload(`coins`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_recover_statecoins_request_payload(`coins`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_signature(`msg`: RustBuffer.ByValue,`clientPartialSigHex`: RustBuffer.ByValue,`serverPartialSigHex`: RustBuffer.ByValue,`sessionHex`: RustBuffer.ByValue,`outputPubkeyHex`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_transfer_notification_request_payload(`coins`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_transfer_signature(`recipientAddress`: RustBuffer.ByValue,`inputTxid`: RustBuffer.ByValue,`inputVout`: Int,`clientSeckey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_transfer_update_msg(`x1`: RustBuffer.ByValue,`recipientAddress`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`transferSignature`: RustBuffer.ByValue,`backupTransactions`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_decode_statechain_address(`scAddress`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_derive_recovery_coins(`wallet`: RustBuffer.ByValue,`startIndex`: Int,`count`: Int,`passphrase`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(`wallet`: RustBuffer.ByValue,`authPubkey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_export_wallet_backup(`wallet`: RustBuffer.ByValue,`backupTxs`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_ffi_validate_backup_chain(`ffiTransferMsg`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`feeRateTolerance`: Double,`currentFeeRateSatsPerByte`: Double,`interval`: Int,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_ffi_validate_signature_scheme(`ffiTransferMsg`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`feeRateTolerance`: Double,`currentFeeRateSatsPerByte`: Double,`interval`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(`newUserPubkey`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,`ffiTransferMsg`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(`statechainInfo`: RustBuffer.ByValue,`ffiTransferMsg`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(`encryptedMessage`: RustBuffer.ByValue,`privateKeyWif`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(`enclavePublicKey`: RustBuffer.ByValue,`ffiTransferMsg`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(`ffiTransferMsg`: RustBuffer.ByValue,`clientPubkeyShare`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_generate_mnemonic(uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_generate_mnemonic_with_word_count(`wordCount`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_blockheight(`bkpTx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun uniffi_mercurylib_fn_func_get_master_fingerprint(`wallet`: RustBuffer.ByValue,`passphrase`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_new_coin(`wallet`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_new_coin_with_passphrase(`wallet`: RustBuffer.ByValue,`passphrase`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_new_key_info(`serverPublicKeyHex`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`statechainId`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_output_address_from_tx0(`tx0Outpoint`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_partial_sig_request(`coin`: RustBuffer.ByValue,`blockHeight`: Int,`initlock`: Int,`interval`: Int,`feeRateSatsPerByte`: Double,`qtBackupTx`: Int,`toAddress`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,`isWithdrawal`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_tx0_outpoint(`backupTransactions`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_user_backup_address(`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(`coin`: RustBuffer.ByValue,`depositMsg1Response`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_import_signed_psbt(`psbtBase64`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_import_wallet_backup(`backupFile`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(`coin`: RustBuffer.ByValue,`enclavePubkey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_is_wallet_locked(`wallet`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(`backupTxs`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_lock_wallet(`wallet`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_new_backup_transaction(`encodedUnsignedTx`: RustBuffer.ByValue,`signatureHex`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_recover_coin(`coin`: RustBuffer.ByValue,`recoveredStatecoin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_sign_auth_challenge(`challenge`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_sign_message(`message`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_sign_oracle_attestation(`eventId`: RustBuffer.ByValue,`outcome`: RustBuffer.ByValue,`oracleSeckey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_unblind_token_signature(`blindedToken`: RustBuffer.ByValue,`blindSignature`: RustBuffer.ByValue,`signerPubkey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_unlock_wallet(`wallet`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_validate_address(`address`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_verify_backup_chain(`backupTransactions`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_blind_token(`signerPubkey`: RustBuffer.ByValue,`tokenId`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_verify_blinded_musig_scheme(`backupTx`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_oracle_attestation(`oraclePubKey`: RustBuffer.ByValue,`eventId`: RustBuffer.ByValue,`outcome`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_verify_transaction_signature(`txNHex`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`feeRateTolerance`: Double,`currentFeeRateSatsPerByte`: Double,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun ffi_mercurylib_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun ffi_mercurylib_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun ffi_mercurylib_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun ffi_mercurylib_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Short
+    fun ffi_mercurylib_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Short
+    fun ffi_mercurylib_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun ffi_mercurylib_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun ffi_mercurylib_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
+    fun ffi_mercurylib_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
+    fun ffi_mercurylib_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_f32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_f32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Float
+    fun ffi_mercurylib_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_f64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_f64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Double
+    fun ffi_mercurylib_rust_future_poll_pointer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_pointer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_pointer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_pointer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Pointer
+    fun ffi_mercurylib_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_rust_buffer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_rust_buffer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_void(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_void(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_checksum_func_blind_token(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_change_wallet_password(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_aggregated_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_and_commit_nonces(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_auth_key_proof(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_blind_deposit_msg1(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_cpfp_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_cpfp_tx(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_deposit_msg1(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_get_msg_addr_bulk_request_payload(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_recover_statecoins_request_payload(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_transfer_notification_request_payload(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_transfer_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_transfer_update_msg(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_decode_statechain_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_derive_recovery_coins(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_export_wallet_backup(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_ffi_validate_backup_chain(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_ffi_validate_signature_scheme(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_generate_mnemonic(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_generate_mnemonic_with_word_count(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_blockheight(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_master_fingerprint(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_new_coin(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_new_coin_with_passphrase(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_new_key_info(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_output_address_from_tx0(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_partial_sig_request(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_tx0_outpoint(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_user_backup_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_import_signed_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_import_wallet_backup(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_is_wallet_locked(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_lock_wallet(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_new_backup_transaction(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_recover_coin(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_sign_auth_challenge(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_sign_message(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_sign_oracle_attestation(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_unblind_token_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_unlock_wallet(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_validate_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_backup_chain(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_blind_token(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_oracle_attestation(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_transaction_signature(
+    ): Short
+    fun ffi_mercurylib_uniffi_contract_version(
+    ): Int
+    
+}
+
+private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
+    // Get the bindings contract version from our ComponentInterface
+    val bindings_contract_version = 26
+    // Get the scaffolding contract version by calling the into the dylib
+    val scaffolding_contract_version = lib.ffi_mercurylib_uniffi_contract_version()
+    if (bindings_contract_version != scaffolding_contract_version) {
+        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+@Suppress("UNUSED_PARAMETER")
+private fun uniffiCheckApiChecksums(lib: UniffiLib) {
+    if (lib.uniffi_mercurylib_checksum_func_blind_token() != 6046.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_change_wallet_password() != 13679.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_aggregated_address() != 44269.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_and_commit_nonces() != 16584.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_auth_key_proof() != 5492.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_blind_deposit_msg1() != 8306.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_cpfp_psbt() != 29254.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_cpfp_tx() != 38451.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_deposit_msg1() != 9767.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_get_msg_addr_bulk_request_payload() != 24034.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_recover_statecoins_request_payload() != 50510.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_signature() != 53021.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_transfer_notification_request_payload() != 61667.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_transfer_signature() != 61677.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_transfer_update_msg() != 6918.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_decode_statechain_address() != 7125.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_derive_recovery_coins() != 18032.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state() != 30591.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_export_wallet_backup() != 58779.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_ffi_validate_backup_chain() != 17719.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_ffi_validate_signature_scheme() != 15679.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature() != 18534.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload() != 58308.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg() != 44515.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey() != 51706.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey() != 46083.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_generate_mnemonic() != 62910.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_generate_mnemonic_with_word_count() != 39962.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_blockheight() != 5222.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_master_fingerprint() != 36195.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_new_coin() != 45841.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_new_coin_with_passphrase() != 35789.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_new_key_info() != 64987.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_output_address_from_tx0() != 62309.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_partial_sig_request() != 1183.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_tx0_outpoint() != 21467.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_user_backup_address() != 29075.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response() != 64110.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_import_signed_psbt() != 17977.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_import_wallet_backup() != 57225.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin() != 37041.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_is_wallet_locked() != 46264.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey() != 19689.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_lock_wallet() != 51215.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_new_backup_transaction() != 56642.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_recover_coin() != 60594.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_sign_auth_challenge() != 19210.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_sign_message() != 9994.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_sign_oracle_attestation() != 63751.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_unblind_token_signature() != 49285.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_unlock_wallet() != 917.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_validate_address() != 16334.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_backup_chain() != 11586.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_blind_token() != 24320.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme() != 42963.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_oracle_attestation() != 57914.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_transaction_signature() != 50583.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+// Async support
+
+// Public interface members begin here.
+
+
+// Interface implemented by anything that can contain an object reference.
+//
+// Such types expose a `destroy()` method that must be called to cleanly
+// dispose of the contained objects. Failure to call this method may result
 // in memory leaks.
 //
 // The easiest way to ensure this method is called is to use the `.use`
 // helper method to execute a block and destroy the object at the end.
 interface Disposable {
     fun destroy()
+    companion object {
+        fun destroy(vararg args: Any?) {
+            args.filterIsInstance<Disposable>()
+                .forEach(Disposable::destroy)
+        }
+    }
+}
+
+inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
+    try {
+        block(this)
+    } finally {
+        try {
+            // N.B. our implementation is on the nullable type `Disposable?`.
+            this?.destroy()
+        } catch (e: Throwable) {
+            // swallow
+        }
+    }
+
+/** Used to instantiate an interface without an actual pointer, for fakes in tests, mostly. */
+object NoPointer
+
+public object FfiConverterUByte: FfiConverter<UByte, Byte> {
+    override fun lift(value: Byte): UByte {
+        return value.toUByte()
+    }
+
+    override fun read(buf: ByteBuffer): UByte {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: UByte): Byte {
+        return value.toByte()
+    }
+
+    override fun allocationSize(value: UByte) = 1UL
+
+    override fun write(value: UByte, buf: ByteBuffer) {
+        buf.put(value.toByte())
+    }
+}
+
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
+public object FfiConverterDouble: FfiConverter<Double, Double> {
+    override fun lift(value: Double): Double {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Double {
+        return buf.getDouble()
+    }
+
+    override fun lower(value: Double): Double {
+        return value
+    }
+
+    override fun allocationSize(value: Double) = 8UL
+
+    override fun write(value: Double, buf: ByteBuffer) {
+        buf.putDouble(value)
+    }
+}
+
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
+
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
+
+    override fun allocationSize(value: Boolean) = 1UL
+
+    override fun write(value: Boolean, buf: ByteBuffer) {
+        buf.put(lower(value))
+    }
+}
+
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
+    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
+    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
+    // store our length and avoid writing it out to the buffer.
+    override fun lift(value: RustBuffer.ByValue): String {
+        try {
+            val byteArr = ByteArray(value.len.toInt())
+            value.asByteBuffer()!!.get(byteArr)
+            return byteArr.toString(Charsets.UTF_8)
+        } finally {
+            RustBuffer.free(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr.toString(Charsets.UTF_8)
+    }
+
+    fun toUtf8(value: String): ByteBuffer {
+        // Make sure we don't have invalid UTF-16, check for lone surrogates.
+        return Charsets.UTF_8.newEncoder().run {
+            onMalformedInput(CodingErrorAction.REPORT)
+            encode(CharBuffer.wrap(value))
+        }
+    }
+
+    override fun lower(value: String): RustBuffer.ByValue {
+        val byteBuf = toUtf8(value)
+        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
+        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
+        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
+        rbuf.asByteBuffer()!!.put(byteBuf)
+        return rbuf
+    }
+
+    // We aren't sure exactly how many bytes our string will be once it's UTF-8
+    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
+    // enough.
+    override fun allocationSize(value: String): ULong {
+        val sizeForLength = 4UL
+        val sizeForString = value.length.toULong() * 3UL
+        return sizeForLength + sizeForString
+    }
+
+    override fun write(value: String, buf: ByteBuffer) {
+        val byteBuf = toUtf8(value)
+        buf.putInt(byteBuf.limit())
+        buf.put(byteBuf)
+    }
+}
+
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
+
+
+
+@Serializable
+data class Activity(
+    var `utxo`: kotlin.String, 
+    var `amount`: kotlin.UInt, 
+    var `action`: kotlin.String, 
+    var `date`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeActivity: FfiConverterRustBuffer<Activity> {
+    override fun read(buf: ByteBuffer): Activity {
+        return Activity(
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Activity) = (
+            FfiConverterString.allocationSize(value.`utxo`) +
+            FfiConverterUInt.allocationSize(value.`amount`) +
+            FfiConverterString.allocationSize(value.`action`) +
+            FfiConverterString.allocationSize(value.`date`)
+    )
+
+    override fun write(value: Activity, buf: ByteBuffer) {
+            FfiConverterString.write(value.`utxo`, buf)
+            FfiConverterUInt.write(value.`amount`, buf)
+            FfiConverterString.write(value.`action`, buf)
+            FfiConverterString.write(value.`date`, buf)
+    }
+}
+
+
+
+@Serializable
+data class AggregatedPublicKey(
+    var `aggregatePubkey`: kotlin.String, 
+    var `aggregateAddress`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeAggregatedPublicKey: FfiConverterRustBuffer<AggregatedPublicKey> {
+    override fun read(buf: ByteBuffer): AggregatedPublicKey {
+        return AggregatedPublicKey(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AggregatedPublicKey) = (
+            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
+            FfiConverterString.allocationSize(value.`aggregateAddress`)
+    )
+
+    override fun write(value: AggregatedPublicKey, buf: ByteBuffer) {
+            FfiConverterString.write(value.`aggregatePubkey`, buf)
+            FfiConverterString.write(value.`aggregateAddress`, buf)
+    }
+}
+
+
+
+data class AuthChallengeRequestPayload(
+    var `authPubKeys`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeAuthChallengeRequestPayload: FfiConverterRustBuffer<AuthChallengeRequestPayload> {
+    override fun read(buf: ByteBuffer): AuthChallengeRequestPayload {
+        return AuthChallengeRequestPayload(
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AuthChallengeRequestPayload) = (
+            FfiConverterSequenceString.allocationSize(value.`authPubKeys`)
+    )
+
+    override fun write(value: AuthChallengeRequestPayload, buf: ByteBuffer) {
+            FfiConverterSequenceString.write(value.`authPubKeys`, buf)
+    }
+}
+
+
+
+data class AuthChallengeResponsePayload(
+    var `challenge`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeAuthChallengeResponsePayload: FfiConverterRustBuffer<AuthChallengeResponsePayload> {
+    override fun read(buf: ByteBuffer): AuthChallengeResponsePayload {
+        return AuthChallengeResponsePayload(
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AuthChallengeResponsePayload) = (
+            FfiConverterString.allocationSize(value.`challenge`)
+    )
+
+    override fun write(value: AuthChallengeResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`challenge`, buf)
+    }
+}
+
+
+
+data class AuthKeyProof(
+    var `authPubKey`: kotlin.String, 
+    var `authSig`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeAuthKeyProof: FfiConverterRustBuffer<AuthKeyProof> {
+    override fun read(buf: ByteBuffer): AuthKeyProof {
+        return AuthKeyProof(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AuthKeyProof) = (
+            FfiConverterString.allocationSize(value.`authPubKey`) +
+            FfiConverterString.allocationSize(value.`authSig`)
+    )
+
+    override fun write(value: AuthKeyProof, buf: ByteBuffer) {
+            FfiConverterString.write(value.`authPubKey`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+    }
+}
+
+
+
+data class BackupChainReport(
+    /**
+     * The chain is complete and all the checks passed for every backup transaction
+     */
+    var `valid`: kotlin.Boolean, 
+    /**
+     * Why the chain is incomplete, see `verify_backup_chain`
+     */
+    var `chainError`: kotlin.String?, 
+    var `backupTxs`: List<BackupTxReport>, 
+    /**
+     * Locktime of the latest backup transaction, None if it cannot be read
+     */
+    var `previousLockTime`: kotlin.UInt?
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBackupChainReport: FfiConverterRustBuffer<BackupChainReport> {
+    override fun read(buf: ByteBuffer): BackupChainReport {
+        return BackupChainReport(
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterSequenceTypeBackupTxReport.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupChainReport) = (
+            FfiConverterBoolean.allocationSize(value.`valid`) +
+            FfiConverterOptionalString.allocationSize(value.`chainError`) +
+            FfiConverterSequenceTypeBackupTxReport.allocationSize(value.`backupTxs`) +
+            FfiConverterOptionalUInt.allocationSize(value.`previousLockTime`)
+    )
+
+    override fun write(value: BackupChainReport, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`valid`, buf)
+            FfiConverterOptionalString.write(value.`chainError`, buf)
+            FfiConverterSequenceTypeBackupTxReport.write(value.`backupTxs`, buf)
+            FfiConverterOptionalUInt.write(value.`previousLockTime`, buf)
+    }
+}
+
+
+
+@Serializable
+data class BackupTx(
+    var `txN`: kotlin.UInt, 
+    var `tx`: kotlin.String, 
+    var `clientPublicNonce`: kotlin.String, 
+    var `serverPublicNonce`: kotlin.String, 
+    var `clientPublicKey`: kotlin.String, 
+    var `serverPublicKey`: kotlin.String, 
+    var `blindingFactor`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBackupTx: FfiConverterRustBuffer<BackupTx> {
+    override fun read(buf: ByteBuffer): BackupTx {
+        return BackupTx(
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupTx) = (
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterString.allocationSize(value.`tx`) +
+            FfiConverterString.allocationSize(value.`clientPublicNonce`) +
+            FfiConverterString.allocationSize(value.`serverPublicNonce`) +
+            FfiConverterString.allocationSize(value.`clientPublicKey`) +
+            FfiConverterString.allocationSize(value.`serverPublicKey`) +
+            FfiConverterString.allocationSize(value.`blindingFactor`)
+    )
+
+    override fun write(value: BackupTx, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterString.write(value.`tx`, buf)
+            FfiConverterString.write(value.`clientPublicNonce`, buf)
+            FfiConverterString.write(value.`serverPublicNonce`, buf)
+            FfiConverterString.write(value.`clientPublicKey`, buf)
+            FfiConverterString.write(value.`serverPublicKey`, buf)
+            FfiConverterString.write(value.`blindingFactor`, buf)
+    }
+}
+
+
+
+/**
+ * Result of the checks of a single backup transaction
+ */
+data class BackupTxReport(
+    var `txN`: kotlin.UInt, 
+    var `locktime`: kotlin.UInt?, 
+    var `feeRate`: kotlin.Double?, 
+    var `feeRateValid`: kotlin.Boolean, 
+    var `outputAddress`: kotlin.String?, 
+    var `signatureValid`: kotlin.Boolean, 
+    var `blindedMusigValid`: kotlin.Boolean, 
+    /**
+     * The locktime is `interval` blocks lower than the previous backup transaction (always true for the first one)
+     */
+    var `intervalValid`: kotlin.Boolean, 
+    /**
+     * The output pays to the public key of the sender of the transfer message. It is reported for the UI and is not
+     * part of `BackupChainReport::valid`, as the latest backup transaction pays the receiver.
+     */
+    var `paysSender`: kotlin.Boolean, 
+    var `errors`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBackupTxReport: FfiConverterRustBuffer<BackupTxReport> {
+    override fun read(buf: ByteBuffer): BackupTxReport {
+        return BackupTxReport(
+            FfiConverterUInt.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalDouble.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupTxReport) = (
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterOptionalUInt.allocationSize(value.`locktime`) +
+            FfiConverterOptionalDouble.allocationSize(value.`feeRate`) +
+            FfiConverterBoolean.allocationSize(value.`feeRateValid`) +
+            FfiConverterOptionalString.allocationSize(value.`outputAddress`) +
+            FfiConverterBoolean.allocationSize(value.`signatureValid`) +
+            FfiConverterBoolean.allocationSize(value.`blindedMusigValid`) +
+            FfiConverterBoolean.allocationSize(value.`intervalValid`) +
+            FfiConverterBoolean.allocationSize(value.`paysSender`) +
+            FfiConverterSequenceString.allocationSize(value.`errors`)
+    )
+
+    override fun write(value: BackupTxReport, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterOptionalUInt.write(value.`locktime`, buf)
+            FfiConverterOptionalDouble.write(value.`feeRate`, buf)
+            FfiConverterBoolean.write(value.`feeRateValid`, buf)
+            FfiConverterOptionalString.write(value.`outputAddress`, buf)
+            FfiConverterBoolean.write(value.`signatureValid`, buf)
+            FfiConverterBoolean.write(value.`blindedMusigValid`, buf)
+            FfiConverterBoolean.write(value.`intervalValid`, buf)
+            FfiConverterBoolean.write(value.`paysSender`, buf)
+            FfiConverterSequenceString.write(value.`errors`, buf)
+    }
+}
+
+
+
+data class BlindNonceResponsePayload(
+    var `publicNonce`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBlindNonceResponsePayload: FfiConverterRustBuffer<BlindNonceResponsePayload> {
+    override fun read(buf: ByteBuffer): BlindNonceResponsePayload {
+        return BlindNonceResponsePayload(
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BlindNonceResponsePayload) = (
+            FfiConverterString.allocationSize(value.`publicNonce`)
+    )
+
+    override fun write(value: BlindNonceResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`publicNonce`, buf)
+    }
+}
+
+
+
+data class BlindSignRequestPayload(
+    /**
+     * Paid token the signature is requested for
+     */
+    var `tokenId`: kotlin.String, 
+    var `challenge`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBlindSignRequestPayload: FfiConverterRustBuffer<BlindSignRequestPayload> {
+    override fun read(buf: ByteBuffer): BlindSignRequestPayload {
+        return BlindSignRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BlindSignRequestPayload) = (
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterString.allocationSize(value.`challenge`)
+    )
+
+    override fun write(value: BlindSignRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterString.write(value.`challenge`, buf)
+    }
+}
+
+
+
+data class BlindSignResponsePayload(
+    var `blindSignature`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBlindSignResponsePayload: FfiConverterRustBuffer<BlindSignResponsePayload> {
+    override fun read(buf: ByteBuffer): BlindSignResponsePayload {
+        return BlindSignResponsePayload(
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BlindSignResponsePayload) = (
+            FfiConverterString.allocationSize(value.`blindSignature`)
+    )
+
+    override fun write(value: BlindSignResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`blindSignature`, buf)
+    }
+}
+
+
+
+/**
+ * Client state between sending the blinded challenge and unblinding the signature
+ */
+data class BlindedToken(
+    /**
+     * New token id, used at deposit instead of the paid token id
+     */
+    var `tokenId`: kotlin.String, 
+    /**
+     * Blinded challenge sent to the token server
+     */
+    var `challenge`: kotlin.String, 
+    /**
+     * Nonce of the unblinded signature
+     */
+    var `publicNonce`: kotlin.String, 
+    /**
+     * Nonce blinding factor
+     */
+    var `alpha`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBlindedToken: FfiConverterRustBuffer<BlindedToken> {
+    override fun read(buf: ByteBuffer): BlindedToken {
+        return BlindedToken(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BlindedToken) = (
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterString.allocationSize(value.`publicNonce`) +
+            FfiConverterString.allocationSize(value.`alpha`)
+    )
+
+    override fun write(value: BlindedToken, buf: ByteBuffer) {
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterString.write(value.`publicNonce`, buf)
+            FfiConverterString.write(value.`alpha`, buf)
+    }
+}
+
+
+
+@Serializable
+data class Coin(
+    var `index`: kotlin.UInt, 
+    var `userPrivkey`: kotlin.String, 
+    var `userPubkey`: kotlin.String, 
+    var `authPrivkey`: kotlin.String, 
+    var `authPubkey`: kotlin.String, 
+    var `derivationPath`: kotlin.String, 
+    var `fingerprint`: kotlin.String, 
+    /**
+     * The coin address is the user_pubkey || auth_pubkey
+     * Used to transfer the coin to another wallet
+     */
+    var `address`: kotlin.String, 
+    /**
+     * The backup address is the address used in backup transactions
+     * The backup address is the p2tr address of the user_pubkey
+     */
+    var `backupAddress`: kotlin.String, 
+    var `serverPubkey`: kotlin.String?, 
+    var `aggregatedPubkey`: kotlin.String?, 
+    /**
+     * The aggregated address is the P2TR address from aggregated_pubkey
+     */
+    var `aggregatedAddress`: kotlin.String?, 
+    var `utxoTxid`: kotlin.String?, 
+    var `utxoVout`: kotlin.UInt?, 
+    var `amount`: kotlin.UInt?, 
+    var `statechainId`: kotlin.String?, 
+    var `signedStatechainId`: kotlin.String?, 
+    var `locktime`: kotlin.UInt?, 
+    var `secretNonce`: kotlin.String?, 
+    var `publicNonce`: kotlin.String?, 
+    var `blindingFactor`: kotlin.String?, 
+    var `serverPublicNonce`: kotlin.String?, 
+    var `txCpfp`: kotlin.String?, 
+    var `txWithdraw`: kotlin.String?, 
+    var `withdrawalAddress`: kotlin.String?, 
+    var `status`: CoinStatus, 
+    var `duplicateIndex`: kotlin.UInt
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCoin: FfiConverterRustBuffer<Coin> {
+    override fun read(buf: ByteBuffer): Coin {
+        return Coin(
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeCoinStatus.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
 
-    companion object {
-        fun destroy(vararg args: Any?) {
-            args.filterIsInstance<Disposable>()
-                .forEach(Disposable::destroy)
-        }
+    override fun allocationSize(value: Coin) = (
+            FfiConverterUInt.allocationSize(value.`index`) +
+            FfiConverterString.allocationSize(value.`userPrivkey`) +
+            FfiConverterString.allocationSize(value.`userPubkey`) +
+            FfiConverterString.allocationSize(value.`authPrivkey`) +
+            FfiConverterString.allocationSize(value.`authPubkey`) +
+            FfiConverterString.allocationSize(value.`derivationPath`) +
+            FfiConverterString.allocationSize(value.`fingerprint`) +
+            FfiConverterString.allocationSize(value.`address`) +
+            FfiConverterString.allocationSize(value.`backupAddress`) +
+            FfiConverterOptionalString.allocationSize(value.`serverPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`aggregatedPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`aggregatedAddress`) +
+            FfiConverterOptionalString.allocationSize(value.`utxoTxid`) +
+            FfiConverterOptionalUInt.allocationSize(value.`utxoVout`) +
+            FfiConverterOptionalUInt.allocationSize(value.`amount`) +
+            FfiConverterOptionalString.allocationSize(value.`statechainId`) +
+            FfiConverterOptionalString.allocationSize(value.`signedStatechainId`) +
+            FfiConverterOptionalUInt.allocationSize(value.`locktime`) +
+            FfiConverterOptionalString.allocationSize(value.`secretNonce`) +
+            FfiConverterOptionalString.allocationSize(value.`publicNonce`) +
+            FfiConverterOptionalString.allocationSize(value.`blindingFactor`) +
+            FfiConverterOptionalString.allocationSize(value.`serverPublicNonce`) +
+            FfiConverterOptionalString.allocationSize(value.`txCpfp`) +
+            FfiConverterOptionalString.allocationSize(value.`txWithdraw`) +
+            FfiConverterOptionalString.allocationSize(value.`withdrawalAddress`) +
+            FfiConverterTypeCoinStatus.allocationSize(value.`status`) +
+            FfiConverterUInt.allocationSize(value.`duplicateIndex`)
+    )
+
+    override fun write(value: Coin, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`index`, buf)
+            FfiConverterString.write(value.`userPrivkey`, buf)
+            FfiConverterString.write(value.`userPubkey`, buf)
+            FfiConverterString.write(value.`authPrivkey`, buf)
+            FfiConverterString.write(value.`authPubkey`, buf)
+            FfiConverterString.write(value.`derivationPath`, buf)
+            FfiConverterString.write(value.`fingerprint`, buf)
+            FfiConverterString.write(value.`address`, buf)
+            FfiConverterString.write(value.`backupAddress`, buf)
+            FfiConverterOptionalString.write(value.`serverPubkey`, buf)
+            FfiConverterOptionalString.write(value.`aggregatedPubkey`, buf)
+            FfiConverterOptionalString.write(value.`aggregatedAddress`, buf)
+            FfiConverterOptionalString.write(value.`utxoTxid`, buf)
+            FfiConverterOptionalUInt.write(value.`utxoVout`, buf)
+            FfiConverterOptionalUInt.write(value.`amount`, buf)
+            FfiConverterOptionalString.write(value.`statechainId`, buf)
+            FfiConverterOptionalString.write(value.`signedStatechainId`, buf)
+            FfiConverterOptionalUInt.write(value.`locktime`, buf)
+            FfiConverterOptionalString.write(value.`secretNonce`, buf)
+            FfiConverterOptionalString.write(value.`publicNonce`, buf)
+            FfiConverterOptionalString.write(value.`blindingFactor`, buf)
+            FfiConverterOptionalString.write(value.`serverPublicNonce`, buf)
+            FfiConverterOptionalString.write(value.`txCpfp`, buf)
+            FfiConverterOptionalString.write(value.`txWithdraw`, buf)
+            FfiConverterOptionalString.write(value.`withdrawalAddress`, buf)
+            FfiConverterTypeCoinStatus.write(value.`status`, buf)
+            FfiConverterUInt.write(value.`duplicateIndex`, buf)
     }
 }
 
-inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
-    try {
-        block(this)
-    } finally {
-        try {
-            // N.B. our implementation is on the nullable type `Disposable?`.
-            this?.destroy()
-        } catch (e: Throwable) {
-            // swallow
-        }
+
+
+data class CoinNonce(
+    var `secretNonce`: kotlin.String, 
+    var `publicNonce`: kotlin.String, 
+    var `blindingFactor`: kotlin.String, 
+    var `signFirstRequestPayload`: SignFirstRequestPayload
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCoinNonce: FfiConverterRustBuffer<CoinNonce> {
+    override fun read(buf: ByteBuffer): CoinNonce {
+        return CoinNonce(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypeSignFirstRequestPayload.read(buf),
+        )
     }
 
-/** Used to instantiate an interface without an actual pointer, for fakes in tests, mostly. */
-object NoPointer
+    override fun allocationSize(value: CoinNonce) = (
+            FfiConverterString.allocationSize(value.`secretNonce`) +
+            FfiConverterString.allocationSize(value.`publicNonce`) +
+            FfiConverterString.allocationSize(value.`blindingFactor`) +
+            FfiConverterTypeSignFirstRequestPayload.allocationSize(value.`signFirstRequestPayload`)
+    )
 
-public object FfiConverterUByte : FfiConverter<UByte, Byte> {
-    override fun lift(value: Byte): UByte {
-        return value.toUByte()
+    override fun write(value: CoinNonce, buf: ByteBuffer) {
+            FfiConverterString.write(value.`secretNonce`, buf)
+            FfiConverterString.write(value.`publicNonce`, buf)
+            FfiConverterString.write(value.`blindingFactor`, buf)
+            FfiConverterTypeSignFirstRequestPayload.write(value.`signFirstRequestPayload`, buf)
     }
+}
 
-    override fun read(buf: ByteBuffer): UByte {
-        return lift(buf.get())
+
+
+class CoinStatusParseError {
+    override fun equals(other: Any?): Boolean {
+        return other is CoinStatusParseError
     }
 
-    override fun lower(value: UByte): Byte {
-        return value.toByte()
+    override fun hashCode(): Int {
+        return javaClass.hashCode()
     }
 
-    override fun allocationSize(value: UByte) = 1UL
+    companion object
+}
 
-    override fun write(
-        value: UByte,
-        buf: ByteBuffer,
-    ) {
-        buf.put(value.toByte())
+public object FfiConverterTypeCoinStatusParseError: FfiConverterRustBuffer<CoinStatusParseError> {
+    override fun read(buf: ByteBuffer): CoinStatusParseError {
+        return CoinStatusParseError()
+    }
+
+    override fun allocationSize(value: CoinStatusParseError) = 0UL
+
+    override fun write(value: CoinStatusParseError, buf: ByteBuffer) {
     }
 }
 
-public object FfiConverterUInt : FfiConverter<UInt, Int> {
-    override fun lift(value: Int): UInt {
-        return value.toUInt()
+
+
+data class ConditionalLatchRequestPayload(
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String, 
+    var `batchId`: kotlin.String, 
+    var `oraclePubKey`: kotlin.String, 
+    var `eventId`: kotlin.String, 
+    var `outcome`: kotlin.String, 
+    var `expirySecs`: kotlin.ULong?
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeConditionalLatchRequestPayload: FfiConverterRustBuffer<ConditionalLatchRequestPayload> {
+    override fun read(buf: ByteBuffer): ConditionalLatchRequestPayload {
+        return ConditionalLatchRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalULong.read(buf),
+        )
     }
 
-    override fun read(buf: ByteBuffer): UInt {
-        return lift(buf.getInt())
+    override fun allocationSize(value: ConditionalLatchRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`batchId`) +
+            FfiConverterString.allocationSize(value.`oraclePubKey`) +
+            FfiConverterString.allocationSize(value.`eventId`) +
+            FfiConverterString.allocationSize(value.`outcome`) +
+            FfiConverterOptionalULong.allocationSize(value.`expirySecs`)
+    )
+
+    override fun write(value: ConditionalLatchRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
+            FfiConverterString.write(value.`oraclePubKey`, buf)
+            FfiConverterString.write(value.`eventId`, buf)
+            FfiConverterString.write(value.`outcome`, buf)
+            FfiConverterOptionalULong.write(value.`expirySecs`, buf)
     }
+}
 
-    override fun lower(value: UInt): Int {
-        return value.toInt()
+
+
+data class ConditionalLatchResponsePayload(
+    var `expiresAt`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeConditionalLatchResponsePayload: FfiConverterRustBuffer<ConditionalLatchResponsePayload> {
+    override fun read(buf: ByteBuffer): ConditionalLatchResponsePayload {
+        return ConditionalLatchResponsePayload(
+            FfiConverterString.read(buf),
+        )
     }
 
-    override fun allocationSize(value: UInt) = 4UL
+    override fun allocationSize(value: ConditionalLatchResponsePayload) = (
+            FfiConverterString.allocationSize(value.`expiresAt`)
+    )
 
-    override fun write(
-        value: UInt,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.toInt())
+    override fun write(value: ConditionalLatchResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`expiresAt`, buf)
     }
 }
 
-public object FfiConverterULong : FfiConverter<ULong, Long> {
-    override fun lift(value: Long): ULong {
-        return value.toULong()
+
+
+data class DecodedScAddress(
+    var `version`: kotlin.UByte, 
+    var `userPubkey`: kotlin.String, 
+    var `authPubkey`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeDecodedSCAddress: FfiConverterRustBuffer<DecodedScAddress> {
+    override fun read(buf: ByteBuffer): DecodedScAddress {
+        return DecodedScAddress(
+            FfiConverterUByte.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-    override fun read(buf: ByteBuffer): ULong {
-        return lift(buf.getLong())
+    override fun allocationSize(value: DecodedScAddress) = (
+            FfiConverterUByte.allocationSize(value.`version`) +
+            FfiConverterString.allocationSize(value.`userPubkey`) +
+            FfiConverterString.allocationSize(value.`authPubkey`)
+    )
+
+    override fun write(value: DecodedScAddress, buf: ByteBuffer) {
+            FfiConverterUByte.write(value.`version`, buf)
+            FfiConverterString.write(value.`userPubkey`, buf)
+            FfiConverterString.write(value.`authPubkey`, buf)
+    }
+}
+
+
+
+@Serializable
+data class DepositInitResult(
+    var `serverPubkey`: kotlin.String, 
+    var `statechainId`: kotlin.String, 
+    var `signedStatechainId`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeDepositInitResult: FfiConverterRustBuffer<DepositInitResult> {
+    override fun read(buf: ByteBuffer): DepositInitResult {
+        return DepositInitResult(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: DepositInitResult) = (
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`signedStatechainId`)
+    )
+
+    override fun write(value: DepositInitResult, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
+    }
+}
+
+
+
+@Serializable
+data class DepositMsg1(
+	@SerialName("auth_key")
+    var `authKey`: kotlin.String, 
+	@SerialName("token_id")
+    var `tokenId`: kotlin.String, 
+	@SerialName("signed_token_id")
+    var `signedTokenId`: kotlin.String, 
+    /**
+     * Token server signature on the token id, if it is a blind token
+     */
+    var `blindSignature`: kotlin.String?
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeDepositMsg1: FfiConverterRustBuffer<DepositMsg1> {
+    override fun read(buf: ByteBuffer): DepositMsg1 {
+        return DepositMsg1(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: DepositMsg1) = (
+            FfiConverterString.allocationSize(value.`authKey`) +
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterString.allocationSize(value.`signedTokenId`) +
+            FfiConverterOptionalString.allocationSize(value.`blindSignature`)
+    )
+
+    override fun write(value: DepositMsg1, buf: ByteBuffer) {
+            FfiConverterString.write(value.`authKey`, buf)
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterString.write(value.`signedTokenId`, buf)
+            FfiConverterOptionalString.write(value.`blindSignature`, buf)
+    }
+}
+
+
+
+@Serializable
+data class DepositMsg1Response(
+	@SerialName("server_pubkey")
+    var `serverPubkey`: kotlin.String, 
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeDepositMsg1Response: FfiConverterRustBuffer<DepositMsg1Response> {
+    override fun read(buf: ByteBuffer): DepositMsg1Response {
+        return DepositMsg1Response(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: DepositMsg1Response) = (
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterString.allocationSize(value.`statechainId`)
+    )
+
+    override fun write(value: DepositMsg1Response, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
+    }
+}
+
+
+
+@Serializable
+data class EncryptedSecrets(
+    /**
+     * Argon2id memory cost in KiB
+     */
+    var `kdfMemoryKib`: kotlin.UInt, 
+    var `kdfIterations`: kotlin.UInt, 
+    var `kdfParallelism`: kotlin.UInt, 
+    var `salt`: kotlin.String, 
+    var `nonce`: kotlin.String, 
+    var `ciphertext`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeEncryptedSecrets: FfiConverterRustBuffer<EncryptedSecrets> {
+    override fun read(buf: ByteBuffer): EncryptedSecrets {
+        return EncryptedSecrets(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: EncryptedSecrets) = (
+            FfiConverterUInt.allocationSize(value.`kdfMemoryKib`) +
+            FfiConverterUInt.allocationSize(value.`kdfIterations`) +
+            FfiConverterUInt.allocationSize(value.`kdfParallelism`) +
+            FfiConverterString.allocationSize(value.`salt`) +
+            FfiConverterString.allocationSize(value.`nonce`) +
+            FfiConverterString.allocationSize(value.`ciphertext`)
+    )
+
+    override fun write(value: EncryptedSecrets, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`kdfMemoryKib`, buf)
+            FfiConverterUInt.write(value.`kdfIterations`, buf)
+            FfiConverterUInt.write(value.`kdfParallelism`, buf)
+            FfiConverterString.write(value.`salt`, buf)
+            FfiConverterString.write(value.`nonce`, buf)
+            FfiConverterString.write(value.`ciphertext`, buf)
+    }
+}
+
+
+
+data class FfiTransferMsg(
+    var `statechainId`: kotlin.String, 
+    var `transferSignature`: kotlin.String, 
+    var `backupTransactions`: List<BackupTx>, 
+    var `t1`: kotlin.ByteArray, 
+    var `userPublicKey`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeFFITransferMsg: FfiConverterRustBuffer<FfiTransferMsg> {
+    override fun read(buf: ByteBuffer): FfiTransferMsg {
+        return FfiTransferMsg(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceTypeBackupTx.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: FfiTransferMsg) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`transferSignature`) +
+            FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTransactions`) +
+            FfiConverterByteArray.allocationSize(value.`t1`) +
+            FfiConverterString.allocationSize(value.`userPublicKey`)
+    )
+
+    override fun write(value: FfiTransferMsg, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`transferSignature`, buf)
+            FfiConverterSequenceTypeBackupTx.write(value.`backupTransactions`, buf)
+            FfiConverterByteArray.write(value.`t1`, buf)
+            FfiConverterString.write(value.`userPublicKey`, buf)
+    }
+}
+
+
+
+data class GetMsgAddrBulkRequestPayload(
+    var `challenge`: kotlin.String, 
+    var `authKeyProofs`: List<AuthKeyProof>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeGetMsgAddrBulkRequestPayload: FfiConverterRustBuffer<GetMsgAddrBulkRequestPayload> {
+    override fun read(buf: ByteBuffer): GetMsgAddrBulkRequestPayload {
+        return GetMsgAddrBulkRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterSequenceTypeAuthKeyProof.read(buf),
+        )
     }
 
-    override fun lower(value: ULong): Long {
-        return value.toLong()
+    override fun allocationSize(value: GetMsgAddrBulkRequestPayload) = (
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterSequenceTypeAuthKeyProof.allocationSize(value.`authKeyProofs`)
+    )
+
+    override fun write(value: GetMsgAddrBulkRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterSequenceTypeAuthKeyProof.write(value.`authKeyProofs`, buf)
     }
+}
 
-    override fun allocationSize(value: ULong) = 8UL
 
-    override fun write(
-        value: ULong,
-        buf: ByteBuffer,
-    ) {
-        buf.putLong(value.toLong())
-    }
+
+data class GetMsgAddrBulkResponsePayload(
+    /**
+     * Encrypted transfer messages for every requested auth key (empty list when there is none)
+     */
+    var `encTransferMsgsPerAuthKey`: Map<kotlin.String, List<kotlin.String>>, 
+    /**
+     * Random hex string that rounds the response size up, hiding the number of messages
+     */
+    var `padding`: kotlin.String
+) {
+    
+    companion object
 }
 
-public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean {
-        return value.toInt() != 0
+public object FfiConverterTypeGetMsgAddrBulkResponsePayload: FfiConverterRustBuffer<GetMsgAddrBulkResponsePayload> {
+    override fun read(buf: ByteBuffer): GetMsgAddrBulkResponsePayload {
+        return GetMsgAddrBulkResponsePayload(
+            FfiConverterMapStringSequenceString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-    override fun read(buf: ByteBuffer): Boolean {
-        return lift(buf.get())
-    }
+    override fun allocationSize(value: GetMsgAddrBulkResponsePayload) = (
+            FfiConverterMapStringSequenceString.allocationSize(value.`encTransferMsgsPerAuthKey`) +
+            FfiConverterString.allocationSize(value.`padding`)
+    )
 
-    override fun lower(value: Boolean): Byte {
-        return if (value) 1.toByte() else 0.toByte()
+    override fun write(value: GetMsgAddrBulkResponsePayload, buf: ByteBuffer) {
+            FfiConverterMapStringSequenceString.write(value.`encTransferMsgsPerAuthKey`, buf)
+            FfiConverterString.write(value.`padding`, buf)
     }
+}
 
-    override fun allocationSize(value: Boolean) = 1UL
 
-    override fun write(
-        value: Boolean,
-        buf: ByteBuffer,
-    ) {
-        buf.put(lower(value))
-    }
+
+@Serializable
+data class GetMsgAddrResponsePayload(
+	@SerialName("list_enc_transfer_msg")
+    var `listEncTransferMsg`: List<kotlin.String>
+) {
+    
+    companion object
 }
 
-public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
-    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
-    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
-    // store our length and avoid writing it out to the buffer.
-    override fun lift(value: RustBuffer.ByValue): String {
-        try {
-            val byteArr = ByteArray(value.len.toInt())
-            value.asByteBuffer()!!.get(byteArr)
-            return byteArr.toString(Charsets.UTF_8)
-        } finally {
-            RustBuffer.free(value)
-        }
+public object FfiConverterTypeGetMsgAddrResponsePayload: FfiConverterRustBuffer<GetMsgAddrResponsePayload> {
+    override fun read(buf: ByteBuffer): GetMsgAddrResponsePayload {
+        return GetMsgAddrResponsePayload(
+            FfiConverterSequenceString.read(buf),
+        )
     }
 
-    override fun read(buf: ByteBuffer): String {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr.toString(Charsets.UTF_8)
-    }
+    override fun allocationSize(value: GetMsgAddrResponsePayload) = (
+            FfiConverterSequenceString.allocationSize(value.`listEncTransferMsg`)
+    )
 
-    fun toUtf8(value: String): ByteBuffer {
-        // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        return Charsets.UTF_8.newEncoder().run {
-            onMalformedInput(CodingErrorAction.REPORT)
-            encode(CharBuffer.wrap(value))
-        }
+    override fun write(value: GetMsgAddrResponsePayload, buf: ByteBuffer) {
+            FfiConverterSequenceString.write(value.`listEncTransferMsg`, buf)
     }
+}
 
-    override fun lower(value: String): RustBuffer.ByValue {
-        val byteBuf = toUtf8(value)
-        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
-        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
-        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
-        rbuf.asByteBuffer()!!.put(byteBuf)
-        return rbuf
-    }
 
-    // We aren't sure exactly how many bytes our string will be once it's UTF-8
-    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
-    // enough.
-    override fun allocationSize(value: String): ULong {
-        val sizeForLength = 4UL
-        val sizeForString = value.length.toULong() * 3UL
-        return sizeForLength + sizeForString
-    }
 
-    override fun write(
-        value: String,
-        buf: ByteBuffer,
-    ) {
-        val byteBuf = toUtf8(value)
-        buf.putInt(byteBuf.limit())
-        buf.put(byteBuf)
-    }
+data class InfoConfig(
+    var `initlock`: kotlin.UInt, 
+    var `interval`: kotlin.UInt, 
+    var `feeRateSatsPerByte`: kotlin.Double
+) {
+    
+    companion object
 }
 
-public object FfiConverterByteArray : FfiConverterRustBuffer<ByteArray> {
-    override fun read(buf: ByteBuffer): ByteArray {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr
+public object FfiConverterTypeInfoConfig: FfiConverterRustBuffer<InfoConfig> {
+    override fun read(buf: ByteBuffer): InfoConfig {
+        return InfoConfig(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterDouble.read(buf),
+        )
     }
 
-    override fun allocationSize(value: ByteArray): ULong {
-        return 4UL + value.size.toULong()
-    }
+    override fun allocationSize(value: InfoConfig) = (
+            FfiConverterUInt.allocationSize(value.`initlock`) +
+            FfiConverterUInt.allocationSize(value.`interval`) +
+            FfiConverterDouble.allocationSize(value.`feeRateSatsPerByte`)
+    )
 
-    override fun write(
-        value: ByteArray,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.size)
-        buf.put(value)
+    override fun write(value: InfoConfig, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`initlock`, buf)
+            FfiConverterUInt.write(value.`interval`, buf)
+            FfiConverterDouble.write(value.`feeRateSatsPerByte`, buf)
     }
 }
 
-@Serializable
-data class Activity(
-    var `utxo`: kotlin.String,
-    var `amount`: kotlin.UInt,
-    var `action`: kotlin.String,
-    var `date`: kotlin.String,
+
+
+data class KeyListResponsePayload(
+    var `listKeyinfo`: List<PubKeyInfo>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeActivity : FfiConverterRustBuffer<Activity> {
-    override fun read(buf: ByteBuffer): Activity {
-        return Activity(
-            FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeKeyListResponsePayload: FfiConverterRustBuffer<KeyListResponsePayload> {
+    override fun read(buf: ByteBuffer): KeyListResponsePayload {
+        return KeyListResponsePayload(
+            FfiConverterSequenceTypePubKeyInfo.read(buf),
         )
     }
 
-    override fun allocationSize(value: Activity) =
-        (
-            FfiConverterString.allocationSize(value.`utxo`) +
-                FfiConverterUInt.allocationSize(value.`amount`) +
-                FfiConverterString.allocationSize(value.`action`) +
-                FfiConverterString.allocationSize(value.`date`)
-        )
+    override fun allocationSize(value: KeyListResponsePayload) = (
+            FfiConverterSequenceTypePubKeyInfo.allocationSize(value.`listKeyinfo`)
+    )
 
-    override fun write(
-        value: Activity,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`utxo`, buf)
-        FfiConverterUInt.write(value.`amount`, buf)
-        FfiConverterString.write(value.`action`, buf)
-        FfiConverterString.write(value.`date`, buf)
+    override fun write(value: KeyListResponsePayload, buf: ByteBuffer) {
+            FfiConverterSequenceTypePubKeyInfo.write(value.`listKeyinfo`, buf)
     }
 }
 
+
+
 @Serializable
-data class AggregatedPublicKey(
-    var `aggregatePubkey`: kotlin.String,
-    var `aggregateAddress`: kotlin.String,
+data class KeyUpdateResponsePayload(
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+    var `t2`: kotlin.String, 
+    var `x1`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeAggregatedPublicKey : FfiConverterRustBuffer<AggregatedPublicKey> {
-    override fun read(buf: ByteBuffer): AggregatedPublicKey {
-        return AggregatedPublicKey(
+public object FfiConverterTypeKeyUpdateResponsePayload: FfiConverterRustBuffer<KeyUpdateResponsePayload> {
+    override fun read(buf: ByteBuffer): KeyUpdateResponsePayload {
+        return KeyUpdateResponsePayload(
+            FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: AggregatedPublicKey) =
-        (
-            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
-                FfiConverterString.allocationSize(value.`aggregateAddress`)
-        )
+    override fun allocationSize(value: KeyUpdateResponsePayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`t2`) +
+            FfiConverterString.allocationSize(value.`x1`)
+    )
 
-    override fun write(
-        value: AggregatedPublicKey,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`aggregatePubkey`, buf)
-        FfiConverterString.write(value.`aggregateAddress`, buf)
+    override fun write(value: KeyUpdateResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`t2`, buf)
+            FfiConverterString.write(value.`x1`, buf)
     }
 }
 
-@Serializable
-data class BackupTx(
-    var `txN`: kotlin.UInt,
-    var `tx`: kotlin.String,
-    var `clientPublicNonce`: kotlin.String,
-    var `serverPublicNonce`: kotlin.String,
-    var `clientPublicKey`: kotlin.String,
-    var `serverPublicKey`: kotlin.String,
-    var `blindingFactor`: kotlin.String,
+
+
+data class LatchReclaimRequestPayload(
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String, 
+    var `batchId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeBackupTx : FfiConverterRustBuffer<BackupTx> {
-    override fun read(buf: ByteBuffer): BackupTx {
-        return BackupTx(
-            FfiConverterUInt.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeLatchReclaimRequestPayload: FfiConverterRustBuffer<LatchReclaimRequestPayload> {
+    override fun read(buf: ByteBuffer): LatchReclaimRequestPayload {
+        return LatchReclaimRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: BackupTx) =
-        (
-            FfiConverterUInt.allocationSize(value.`txN`) +
-                FfiConverterString.allocationSize(value.`tx`) +
-                FfiConverterString.allocationSize(value.`clientPublicNonce`) +
-                FfiConverterString.allocationSize(value.`serverPublicNonce`) +
-                FfiConverterString.allocationSize(value.`clientPublicKey`) +
-                FfiConverterString.allocationSize(value.`serverPublicKey`) +
-                FfiConverterString.allocationSize(value.`blindingFactor`)
-        )
+    override fun allocationSize(value: LatchReclaimRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`batchId`)
+    )
 
-    override fun write(
-        value: BackupTx,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`txN`, buf)
-        FfiConverterString.write(value.`tx`, buf)
-        FfiConverterString.write(value.`clientPublicNonce`, buf)
-        FfiConverterString.write(value.`serverPublicNonce`, buf)
-        FfiConverterString.write(value.`clientPublicKey`, buf)
-        FfiConverterString.write(value.`serverPublicKey`, buf)
-        FfiConverterString.write(value.`blindingFactor`, buf)
+    override fun write(value: LatchReclaimRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
     }
 }
 
-@Serializable
-data class Coin(
-    var `index`: kotlin.UInt,
-    var `userPrivkey`: kotlin.String,
-    var `userPubkey`: kotlin.String,
-    var `authPrivkey`: kotlin.String,
-    var `authPubkey`: kotlin.String,
-    var `derivationPath`: kotlin.String,
-    var `fingerprint`: kotlin.String,
-    /**
-     * The coin address is the user_pubkey || auth_pubkey
-     * Used to transfer the coin to another wallet
-     */
-    var `address`: kotlin.String,
-    /**
-     * The backup address is the address used in backup transactions
-     * The backup address is the p2tr address of the user_pubkey
-     */
-    var `backupAddress`: kotlin.String,
-    var `serverPubkey`: kotlin.String?,
-    var `aggregatedPubkey`: kotlin.String?,
-    /**
-     * The aggregated address is the P2TR address from aggregated_pubkey
-     */
-    var `aggregatedAddress`: kotlin.String?,
-    var `utxoTxid`: kotlin.String?,
-    var `utxoVout`: kotlin.UInt?,
-    var `amount`: kotlin.UInt?,
-    var `statechainId`: kotlin.String?,
-    var `signedStatechainId`: kotlin.String?,
-    var `locktime`: kotlin.UInt?,
-    var `secretNonce`: kotlin.String?,
-    var `publicNonce`: kotlin.String?,
-    var `blindingFactor`: kotlin.String?,
-    var `serverPublicNonce`: kotlin.String?,
-    var `txCpfp`: kotlin.String?,
-    var `txWithdraw`: kotlin.String?,
-    var `withdrawalAddress`: kotlin.String?,
-    var `status`: CoinStatus,
+
+
+data class NewKeyInfo(
+    var `aggregatePubkey`: kotlin.String, 
+    var `aggregateAddress`: kotlin.String, 
+    var `signedStatechainId`: kotlin.String, 
+    var `amount`: kotlin.UInt
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeCoin : FfiConverterRustBuffer<Coin> {
-    override fun read(buf: ByteBuffer): Coin {
-        return Coin(
-            FfiConverterUInt.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeNewKeyInfo: FfiConverterRustBuffer<NewKeyInfo> {
+    override fun read(buf: ByteBuffer): NewKeyInfo {
+        return NewKeyInfo(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalUInt.read(buf),
-            FfiConverterOptionalUInt.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalUInt.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterTypeCoinStatus.read(buf),
+            FfiConverterUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: Coin) =
-        (
-            FfiConverterUInt.allocationSize(value.`index`) +
-                FfiConverterString.allocationSize(value.`userPrivkey`) +
-                FfiConverterString.allocationSize(value.`userPubkey`) +
-                FfiConverterString.allocationSize(value.`authPrivkey`) +
-                FfiConverterString.allocationSize(value.`authPubkey`) +
-                FfiConverterString.allocationSize(value.`derivationPath`) +
-                FfiConverterString.allocationSize(value.`fingerprint`) +
-                FfiConverterString.allocationSize(value.`address`) +
-                FfiConverterString.allocationSize(value.`backupAddress`) +
-                FfiConverterOptionalString.allocationSize(value.`serverPubkey`) +
-                FfiConverterOptionalString.allocationSize(value.`aggregatedPubkey`) +
-                FfiConverterOptionalString.allocationSize(value.`aggregatedAddress`) +
-                FfiConverterOptionalString.allocationSize(value.`utxoTxid`) +
-                FfiConverterOptionalUInt.allocationSize(value.`utxoVout`) +
-                FfiConverterOptionalUInt.allocationSize(value.`amount`) +
-                FfiConverterOptionalString.allocationSize(value.`statechainId`) +
-                FfiConverterOptionalString.allocationSize(value.`signedStatechainId`) +
-                FfiConverterOptionalUInt.allocationSize(value.`locktime`) +
-                FfiConverterOptionalString.allocationSize(value.`secretNonce`) +
-                FfiConverterOptionalString.allocationSize(value.`publicNonce`) +
-                FfiConverterOptionalString.allocationSize(value.`blindingFactor`) +
-                FfiConverterOptionalString.allocationSize(value.`serverPublicNonce`) +
-                FfiConverterOptionalString.allocationSize(value.`txCpfp`) +
-                FfiConverterOptionalString.allocationSize(value.`txWithdraw`) +
-                FfiConverterOptionalString.allocationSize(value.`withdrawalAddress`) +
-                FfiConverterTypeCoinStatus.allocationSize(value.`status`)
-        )
+    override fun allocationSize(value: NewKeyInfo) = (
+            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
+            FfiConverterString.allocationSize(value.`aggregateAddress`) +
+            FfiConverterString.allocationSize(value.`signedStatechainId`) +
+            FfiConverterUInt.allocationSize(value.`amount`)
+    )
 
-    override fun write(
-        value: Coin,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`index`, buf)
-        FfiConverterString.write(value.`userPrivkey`, buf)
-        FfiConverterString.write(value.`userPubkey`, buf)
-        FfiConverterString.write(value.`authPrivkey`, buf)
-        FfiConverterString.write(value.`authPubkey`, buf)
-        FfiConverterString.write(value.`derivationPath`, buf)
-        FfiConverterString.write(value.`fingerprint`, buf)
-        FfiConverterString.write(value.`address`, buf)
-        FfiConverterString.write(value.`backupAddress`, buf)
-        FfiConverterOptionalString.write(value.`serverPubkey`, buf)
-        FfiConverterOptionalString.write(value.`aggregatedPubkey`, buf)
-        FfiConverterOptionalString.write(value.`aggregatedAddress`, buf)
-        FfiConverterOptionalString.write(value.`utxoTxid`, buf)
-        FfiConverterOptionalUInt.write(value.`utxoVout`, buf)
-        FfiConverterOptionalUInt.write(value.`amount`, buf)
-        FfiConverterOptionalString.write(value.`statechainId`, buf)
-        FfiConverterOptionalString.write(value.`signedStatechainId`, buf)
-        FfiConverterOptionalUInt.write(value.`locktime`, buf)
-        FfiConverterOptionalString.write(value.`secretNonce`, buf)
-        FfiConverterOptionalString.write(value.`publicNonce`, buf)
-        FfiConverterOptionalString.write(value.`blindingFactor`, buf)
-        FfiConverterOptionalString.write(value.`serverPublicNonce`, buf)
-        FfiConverterOptionalString.write(value.`txCpfp`, buf)
-        FfiConverterOptionalString.write(value.`txWithdraw`, buf)
-        FfiConverterOptionalString.write(value.`withdrawalAddress`, buf)
-        FfiConverterTypeCoinStatus.write(value.`status`, buf)
+    override fun write(value: NewKeyInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`aggregatePubkey`, buf)
+            FfiConverterString.write(value.`aggregateAddress`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
+            FfiConverterUInt.write(value.`amount`, buf)
     }
 }
 
-data class CoinNonce(
-    var `secretNonce`: kotlin.String,
-    var `publicNonce`: kotlin.String,
-    var `blindingFactor`: kotlin.String,
-    var `signFirstRequestPayload`: SignFirstRequestPayload,
+
+
+data class OracleAttestationRequestPayload(
+    var `statechainId`: kotlin.String, 
+    var `batchId`: kotlin.String, 
+    var `outcome`: kotlin.String, 
+    var `signature`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeCoinNonce : FfiConverterRustBuffer<CoinNonce> {
-    override fun read(buf: ByteBuffer): CoinNonce {
-        return CoinNonce(
+public object FfiConverterTypeOracleAttestationRequestPayload: FfiConverterRustBuffer<OracleAttestationRequestPayload> {
+    override fun read(buf: ByteBuffer): OracleAttestationRequestPayload {
+        return OracleAttestationRequestPayload(
+            FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterTypeSignFirstRequestPayload.read(buf),
         )
     }
 
-    override fun allocationSize(value: CoinNonce) =
-        (
-            FfiConverterString.allocationSize(value.`secretNonce`) +
-                FfiConverterString.allocationSize(value.`publicNonce`) +
-                FfiConverterString.allocationSize(value.`blindingFactor`) +
-                FfiConverterTypeSignFirstRequestPayload.allocationSize(value.`signFirstRequestPayload`)
-        )
+    override fun allocationSize(value: OracleAttestationRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`batchId`) +
+            FfiConverterString.allocationSize(value.`outcome`) +
+            FfiConverterString.allocationSize(value.`signature`)
+    )
 
-    override fun write(
-        value: CoinNonce,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`secretNonce`, buf)
-        FfiConverterString.write(value.`publicNonce`, buf)
-        FfiConverterString.write(value.`blindingFactor`, buf)
-        FfiConverterTypeSignFirstRequestPayload.write(value.`signFirstRequestPayload`, buf)
+    override fun write(value: OracleAttestationRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
+            FfiConverterString.write(value.`outcome`, buf)
+            FfiConverterString.write(value.`signature`, buf)
     }
 }
 
-class CoinStatusParseError {
-    override fun equals(other: Any?): Boolean {
-        return other is CoinStatusParseError
-    }
 
-    override fun hashCode(): Int {
-        return javaClass.hashCode()
-    }
 
+data class PartialSignatureMsg1(
+    var `msg`: kotlin.String, 
+    var `outputPubkey`: kotlin.String, 
+    var `clientPartialSig`: kotlin.String, 
+    var `encodedSession`: kotlin.String, 
+    var `encodedUnsignedTx`: kotlin.String, 
+    var `partialSignatureRequestPayload`: PartialSignatureRequestPayload
+) {
+    
     companion object
 }
 
-public object FfiConverterTypeCoinStatusParseError : FfiConverterRustBuffer<CoinStatusParseError> {
-    override fun read(buf: ByteBuffer): CoinStatusParseError {
-        return CoinStatusParseError()
+public object FfiConverterTypePartialSignatureMsg1: FfiConverterRustBuffer<PartialSignatureMsg1> {
+    override fun read(buf: ByteBuffer): PartialSignatureMsg1 {
+        return PartialSignatureMsg1(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypePartialSignatureRequestPayload.read(buf),
+        )
     }
 
-    override fun allocationSize(value: CoinStatusParseError) = 0UL
+    override fun allocationSize(value: PartialSignatureMsg1) = (
+            FfiConverterString.allocationSize(value.`msg`) +
+            FfiConverterString.allocationSize(value.`outputPubkey`) +
+            FfiConverterString.allocationSize(value.`clientPartialSig`) +
+            FfiConverterString.allocationSize(value.`encodedSession`) +
+            FfiConverterString.allocationSize(value.`encodedUnsignedTx`) +
+            FfiConverterTypePartialSignatureRequestPayload.allocationSize(value.`partialSignatureRequestPayload`)
+    )
 
-    override fun write(
-        value: CoinStatusParseError,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: PartialSignatureMsg1, buf: ByteBuffer) {
+            FfiConverterString.write(value.`msg`, buf)
+            FfiConverterString.write(value.`outputPubkey`, buf)
+            FfiConverterString.write(value.`clientPartialSig`, buf)
+            FfiConverterString.write(value.`encodedSession`, buf)
+            FfiConverterString.write(value.`encodedUnsignedTx`, buf)
+            FfiConverterTypePartialSignatureRequestPayload.write(value.`partialSignatureRequestPayload`, buf)
     }
 }
 
-data class DecodedScAddress(
-    var `version`: kotlin.UByte,
-    var `userPubkey`: kotlin.String,
-    var `authPubkey`: kotlin.String,
+
+
+@Serializable
+data class PartialSignatureRequestPayload(
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+	@SerialName("negate_seckey")
+    var `negateSeckey`: kotlin.UByte, 
+    var `session`: kotlin.String, 
+	@SerialName("signed_statechain_id")
+    var `signedStatechainId`: kotlin.String, 
+	@SerialName("server_pub_nonce")
+    var `serverPubNonce`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDecodedSCAddress : FfiConverterRustBuffer<DecodedScAddress> {
-    override fun read(buf: ByteBuffer): DecodedScAddress {
-        return DecodedScAddress(
+public object FfiConverterTypePartialSignatureRequestPayload: FfiConverterRustBuffer<PartialSignatureRequestPayload> {
+    override fun read(buf: ByteBuffer): PartialSignatureRequestPayload {
+        return PartialSignatureRequestPayload(
+            FfiConverterString.read(buf),
             FfiConverterUByte.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: DecodedScAddress) =
-        (
-            FfiConverterUByte.allocationSize(value.`version`) +
-                FfiConverterString.allocationSize(value.`userPubkey`) +
-                FfiConverterString.allocationSize(value.`authPubkey`)
-        )
+    override fun allocationSize(value: PartialSignatureRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterUByte.allocationSize(value.`negateSeckey`) +
+            FfiConverterString.allocationSize(value.`session`) +
+            FfiConverterString.allocationSize(value.`signedStatechainId`) +
+            FfiConverterString.allocationSize(value.`serverPubNonce`)
+    )
 
-    override fun write(
-        value: DecodedScAddress,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUByte.write(value.`version`, buf)
-        FfiConverterString.write(value.`userPubkey`, buf)
-        FfiConverterString.write(value.`authPubkey`, buf)
+    override fun write(value: PartialSignatureRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterUByte.write(value.`negateSeckey`, buf)
+            FfiConverterString.write(value.`session`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
+            FfiConverterString.write(value.`serverPubNonce`, buf)
     }
 }
 
+
+
 @Serializable
-data class DepositInitResult(
-    var `serverPubkey`: kotlin.String,
-    var `statechainId`: kotlin.String,
-    var `signedStatechainId`: kotlin.String,
+data class PartialSignatureResponsePayload(
+	@SerialName("partial_sig")
+    var `partialSig`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDepositInitResult : FfiConverterRustBuffer<DepositInitResult> {
-    override fun read(buf: ByteBuffer): DepositInitResult {
-        return DepositInitResult(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypePartialSignatureResponsePayload: FfiConverterRustBuffer<PartialSignatureResponsePayload> {
+    override fun read(buf: ByteBuffer): PartialSignatureResponsePayload {
+        return PartialSignatureResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: DepositInitResult) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubkey`) +
-                FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`)
-        )
+    override fun allocationSize(value: PartialSignatureResponsePayload) = (
+            FfiConverterString.allocationSize(value.`partialSig`)
+    )
 
-    override fun write(
-        value: DepositInitResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
+    override fun write(value: PartialSignatureResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`partialSig`, buf)
     }
 }
 
-@Serializable
-data class DepositMsg1(
-	@SerialName("auth_key")
-    var `authKey`: kotlin.String,
-	@SerialName("token_id")
-    var `tokenId`: kotlin.String,
-	@SerialName("signed_token_id")
-    var `signedTokenId`: kotlin.String,
+
+
+data class PaymentHashRequestPayload(
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String, 
+    var `batchId`: kotlin.String, 
+    var `expirySecs`: kotlin.ULong?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDepositMsg1 : FfiConverterRustBuffer<DepositMsg1> {
-    override fun read(buf: ByteBuffer): DepositMsg1 {
-        return DepositMsg1(
+public object FfiConverterTypePaymentHashRequestPayload: FfiConverterRustBuffer<PaymentHashRequestPayload> {
+    override fun read(buf: ByteBuffer): PaymentHashRequestPayload {
+        return PaymentHashRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
+            FfiConverterOptionalULong.read(buf),
         )
     }
 
-    override fun allocationSize(value: DepositMsg1) =
-        (
-            FfiConverterString.allocationSize(value.`authKey`) +
-                FfiConverterString.allocationSize(value.`tokenId`) +
-                FfiConverterString.allocationSize(value.`signedTokenId`)
-        )
+    override fun allocationSize(value: PaymentHashRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`batchId`) +
+            FfiConverterOptionalULong.allocationSize(value.`expirySecs`)
+    )
 
-    override fun write(
-        value: DepositMsg1,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`authKey`, buf)
-        FfiConverterString.write(value.`tokenId`, buf)
-        FfiConverterString.write(value.`signedTokenId`, buf)
+    override fun write(value: PaymentHashRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
+            FfiConverterOptionalULong.write(value.`expirySecs`, buf)
     }
 }
 
-@Serializable
-data class DepositMsg1Response(
-	@SerialName("server_pubkey")
-    var `serverPubkey`: kotlin.String,
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+
+
+data class PaymentHashResponsePayload(
+    var `hash`: kotlin.String, 
+    var `expiresAt`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDepositMsg1Response : FfiConverterRustBuffer<DepositMsg1Response> {
-    override fun read(buf: ByteBuffer): DepositMsg1Response {
-        return DepositMsg1Response(
+public object FfiConverterTypePaymentHashResponsePayload: FfiConverterRustBuffer<PaymentHashResponsePayload> {
+    override fun read(buf: ByteBuffer): PaymentHashResponsePayload {
+        return PaymentHashResponsePayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: DepositMsg1Response) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubkey`) +
-                FfiConverterString.allocationSize(value.`statechainId`)
-        )
+    override fun allocationSize(value: PaymentHashResponsePayload) = (
+            FfiConverterString.allocationSize(value.`hash`) +
+            FfiConverterString.allocationSize(value.`expiresAt`)
+    )
 
-    override fun write(
-        value: DepositMsg1Response,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
-        FfiConverterString.write(value.`statechainId`, buf)
+    override fun write(value: PaymentHashResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`hash`, buf)
+            FfiConverterString.write(value.`expiresAt`, buf)
     }
 }
 
-data class FfiTransferMsg(
-    var `statechainId`: kotlin.String,
-    var `transferSignature`: kotlin.String,
-    var `backupTransactions`: List<BackupTx>,
-    var `t1`: kotlin.ByteArray,
-    var `userPublicKey`: kotlin.String,
+
+
+data class PubKeyInfo(
+    var `serverPubkey`: kotlin.String, 
+    var `txN`: kotlin.UInt, 
+    var `updatedAt`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeFFITransferMsg : FfiConverterRustBuffer<FfiTransferMsg> {
-    override fun read(buf: ByteBuffer): FfiTransferMsg {
-        return FfiTransferMsg(
-            FfiConverterString.read(buf),
+public object FfiConverterTypePubKeyInfo: FfiConverterRustBuffer<PubKeyInfo> {
+    override fun read(buf: ByteBuffer): PubKeyInfo {
+        return PubKeyInfo(
             FfiConverterString.read(buf),
-            FfiConverterSequenceTypeBackupTx.read(buf),
-            FfiConverterByteArray.read(buf),
+            FfiConverterUInt.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: FfiTransferMsg) =
-        (
-            FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`transferSignature`) +
-                FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTransactions`) +
-                FfiConverterByteArray.allocationSize(value.`t1`) +
-                FfiConverterString.allocationSize(value.`userPublicKey`)
-        )
+    override fun allocationSize(value: PubKeyInfo) = (
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterString.allocationSize(value.`updatedAt`)
+    )
 
-    override fun write(
-        value: FfiTransferMsg,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`transferSignature`, buf)
-        FfiConverterSequenceTypeBackupTx.write(value.`backupTransactions`, buf)
-        FfiConverterByteArray.write(value.`t1`, buf)
-        FfiConverterString.write(value.`userPublicKey`, buf)
+    override fun write(value: PubKeyInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterString.write(value.`updatedAt`, buf)
     }
 }
 
-@Serializable
-data class GetMsgAddrResponsePayload(
-	@SerialName("list_enc_transfer_msg")
-    var `listEncTransferMsg`: List<kotlin.String>,
+
+
+data class RecoverStatecoinsRequestPayload(
+    var `challenge`: kotlin.String, 
+    var `authKeyProofs`: List<AuthKeyProof>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeGetMsgAddrResponsePayload : FfiConverterRustBuffer<GetMsgAddrResponsePayload> {
-    override fun read(buf: ByteBuffer): GetMsgAddrResponsePayload {
-        return GetMsgAddrResponsePayload(
-            FfiConverterSequenceString.read(buf),
+public object FfiConverterTypeRecoverStatecoinsRequestPayload: FfiConverterRustBuffer<RecoverStatecoinsRequestPayload> {
+    override fun read(buf: ByteBuffer): RecoverStatecoinsRequestPayload {
+        return RecoverStatecoinsRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterSequenceTypeAuthKeyProof.read(buf),
         )
     }
 
-    override fun allocationSize(value: GetMsgAddrResponsePayload) =
-        (
-            FfiConverterSequenceString.allocationSize(value.`listEncTransferMsg`)
-        )
+    override fun allocationSize(value: RecoverStatecoinsRequestPayload) = (
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterSequenceTypeAuthKeyProof.allocationSize(value.`authKeyProofs`)
+    )
 
-    override fun write(
-        value: GetMsgAddrResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceString.write(value.`listEncTransferMsg`, buf)
+    override fun write(value: RecoverStatecoinsRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterSequenceTypeAuthKeyProof.write(value.`authKeyProofs`, buf)
     }
 }
 
-data class InfoConfig(
-    var `initlock`: kotlin.UInt,
-    var `interval`: kotlin.UInt,
-    var `feeRateSatsPerByte`: kotlin.ULong,
+
+
+data class RecoverStatecoinsResponsePayload(
+    var `statecoins`: List<RecoveredStatecoin>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeInfoConfig : FfiConverterRustBuffer<InfoConfig> {
-    override fun read(buf: ByteBuffer): InfoConfig {
-        return InfoConfig(
-            FfiConverterUInt.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterULong.read(buf),
+public object FfiConverterTypeRecoverStatecoinsResponsePayload: FfiConverterRustBuffer<RecoverStatecoinsResponsePayload> {
+    override fun read(buf: ByteBuffer): RecoverStatecoinsResponsePayload {
+        return RecoverStatecoinsResponsePayload(
+            FfiConverterSequenceTypeRecoveredStatecoin.read(buf),
         )
     }
 
-    override fun allocationSize(value: InfoConfig) =
-        (
-            FfiConverterUInt.allocationSize(value.`initlock`) +
-                FfiConverterUInt.allocationSize(value.`interval`) +
-                FfiConverterULong.allocationSize(value.`feeRateSatsPerByte`)
-        )
+    override fun allocationSize(value: RecoverStatecoinsResponsePayload) = (
+            FfiConverterSequenceTypeRecoveredStatecoin.allocationSize(value.`statecoins`)
+    )
 
-    override fun write(
-        value: InfoConfig,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`initlock`, buf)
-        FfiConverterUInt.write(value.`interval`, buf)
-        FfiConverterULong.write(value.`feeRateSatsPerByte`, buf)
+    override fun write(value: RecoverStatecoinsResponsePayload, buf: ByteBuffer) {
+            FfiConverterSequenceTypeRecoveredStatecoin.write(value.`statecoins`, buf)
     }
 }
 
-data class KeyListResponsePayload(
-    var `listKeyinfo`: List<PubKeyInfo>,
+
+
+data class RecoveredStatecoin(
+    var `authPubKey`: kotlin.String, 
+    var `statechainId`: kotlin.String, 
+    var `serverPubkey`: kotlin.String, 
+    /**
+     * Latest transfer message sent to the auth key, which carries the backup transactions of received statecoins
+     */
+    var `encTransferMsg`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeKeyListResponsePayload : FfiConverterRustBuffer<KeyListResponsePayload> {
-    override fun read(buf: ByteBuffer): KeyListResponsePayload {
-        return KeyListResponsePayload(
-            FfiConverterSequenceTypePubKeyInfo.read(buf),
+public object FfiConverterTypeRecoveredStatecoin: FfiConverterRustBuffer<RecoveredStatecoin> {
+    override fun read(buf: ByteBuffer): RecoveredStatecoin {
+        return RecoveredStatecoin(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: KeyListResponsePayload) =
-        (
-            FfiConverterSequenceTypePubKeyInfo.allocationSize(value.`listKeyinfo`)
-        )
+    override fun allocationSize(value: RecoveredStatecoin) = (
+            FfiConverterString.allocationSize(value.`authPubKey`) +
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`encTransferMsg`)
+    )
 
-    override fun write(
-        value: KeyListResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceTypePubKeyInfo.write(value.`listKeyinfo`, buf)
+    override fun write(value: RecoveredStatecoin, buf: ByteBuffer) {
+            FfiConverterString.write(value.`authPubKey`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterOptionalString.write(value.`encTransferMsg`, buf)
     }
 }
 
+
+
 @Serializable
-data class KeyUpdateResponsePayload(
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
-    var `t2`: kotlin.String,
-    var `x1`: kotlin.String,
+data class ServerConfig(
+    var `initlock`: kotlin.UInt, 
+    var `interval`: kotlin.UInt, 
+    var `batchtimeout`: kotlin.UInt
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeKeyUpdateResponsePayload : FfiConverterRustBuffer<KeyUpdateResponsePayload> {
-    override fun read(buf: ByteBuffer): KeyUpdateResponsePayload {
-        return KeyUpdateResponsePayload(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeServerConfig: FfiConverterRustBuffer<ServerConfig> {
+    override fun read(buf: ByteBuffer): ServerConfig {
+        return ServerConfig(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: KeyUpdateResponsePayload) =
-        (
-            FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`t2`) +
-                FfiConverterString.allocationSize(value.`x1`)
-        )
+    override fun allocationSize(value: ServerConfig) = (
+            FfiConverterUInt.allocationSize(value.`initlock`) +
+            FfiConverterUInt.allocationSize(value.`interval`) +
+            FfiConverterUInt.allocationSize(value.`batchtimeout`)
+    )
 
-    override fun write(
-        value: KeyUpdateResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`t2`, buf)
-        FfiConverterString.write(value.`x1`, buf)
+    override fun write(value: ServerConfig, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`initlock`, buf)
+            FfiConverterUInt.write(value.`interval`, buf)
+            FfiConverterUInt.write(value.`batchtimeout`, buf)
     }
 }
 
-data class NewKeyInfo(
-    var `aggregatePubkey`: kotlin.String,
-    var `aggregateAddress`: kotlin.String,
-    var `signedStatechainId`: kotlin.String,
-    var `amount`: kotlin.UInt,
+
+
+data class ServerPublicNonceResponsePayload(
+    var `serverPubnonce`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeNewKeyInfo : FfiConverterRustBuffer<NewKeyInfo> {
-    override fun read(buf: ByteBuffer): NewKeyInfo {
-        return NewKeyInfo(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeServerPublicNonceResponsePayload: FfiConverterRustBuffer<ServerPublicNonceResponsePayload> {
+    override fun read(buf: ByteBuffer): ServerPublicNonceResponsePayload {
+        return ServerPublicNonceResponsePayload(
             FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: NewKeyInfo) =
-        (
-            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
-                FfiConverterString.allocationSize(value.`aggregateAddress`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`) +
-                FfiConverterUInt.allocationSize(value.`amount`)
-        )
+    override fun allocationSize(value: ServerPublicNonceResponsePayload) = (
+            FfiConverterString.allocationSize(value.`serverPubnonce`)
+    )
 
-    override fun write(
-        value: NewKeyInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`aggregatePubkey`, buf)
-        FfiConverterString.write(value.`aggregateAddress`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
-        FfiConverterUInt.write(value.`amount`, buf)
+    override fun write(value: ServerPublicNonceResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubnonce`, buf)
     }
 }
 
-data class PartialSignatureMsg1(
-    var `msg`: kotlin.String,
-    var `outputPubkey`: kotlin.String,
-    var `clientPartialSig`: kotlin.String,
-    var `encodedSession`: kotlin.String,
-    var `encodedUnsignedTx`: kotlin.String,
-    var `partialSignatureRequestPayload`: PartialSignatureRequestPayload,
+
+
+@Serializable
+data class Settings(
+    var `network`: kotlin.String, 
+    var `blockExplorerUrl`: kotlin.String?, 
+    var `torProxyHost`: kotlin.String?, 
+    var `torProxyPort`: kotlin.String?, 
+    var `torProxyControlPassword`: kotlin.String?, 
+    var `torProxyControlPort`: kotlin.String?, 
+    var `statechainEntityApi`: kotlin.String, 
+    var `torStatechainEntityApi`: kotlin.String?, 
+    var `electrumProtocol`: kotlin.String, 
+    var `electrumHost`: kotlin.String, 
+    var `electrumPort`: kotlin.String, 
+    var `electrumType`: kotlin.String, 
+    var `notifications`: kotlin.Boolean, 
+    var `tutorials`: kotlin.Boolean
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePartialSignatureMsg1 : FfiConverterRustBuffer<PartialSignatureMsg1> {
-    override fun read(buf: ByteBuffer): PartialSignatureMsg1 {
-        return PartialSignatureMsg1(
+public object FfiConverterTypeSettings: FfiConverterRustBuffer<Settings> {
+    override fun read(buf: ByteBuffer): Settings {
+        return Settings(
             FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterTypePartialSignatureRequestPayload.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
         )
     }
 
-    override fun allocationSize(value: PartialSignatureMsg1) =
-        (
-            FfiConverterString.allocationSize(value.`msg`) +
-                FfiConverterString.allocationSize(value.`outputPubkey`) +
-                FfiConverterString.allocationSize(value.`clientPartialSig`) +
-                FfiConverterString.allocationSize(value.`encodedSession`) +
-                FfiConverterString.allocationSize(value.`encodedUnsignedTx`) +
-                FfiConverterTypePartialSignatureRequestPayload.allocationSize(value.`partialSignatureRequestPayload`)
-        )
+    override fun allocationSize(value: Settings) = (
+            FfiConverterString.allocationSize(value.`network`) +
+            FfiConverterOptionalString.allocationSize(value.`blockExplorerUrl`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyHost`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyPort`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyControlPassword`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyControlPort`) +
+            FfiConverterString.allocationSize(value.`statechainEntityApi`) +
+            FfiConverterOptionalString.allocationSize(value.`torStatechainEntityApi`) +
+            FfiConverterString.allocationSize(value.`electrumProtocol`) +
+            FfiConverterString.allocationSize(value.`electrumHost`) +
+            FfiConverterString.allocationSize(value.`electrumPort`) +
+            FfiConverterString.allocationSize(value.`electrumType`) +
+            FfiConverterBoolean.allocationSize(value.`notifications`) +
+            FfiConverterBoolean.allocationSize(value.`tutorials`)
+    )
 
-    override fun write(
-        value: PartialSignatureMsg1,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`msg`, buf)
-        FfiConverterString.write(value.`outputPubkey`, buf)
-        FfiConverterString.write(value.`clientPartialSig`, buf)
-        FfiConverterString.write(value.`encodedSession`, buf)
-        FfiConverterString.write(value.`encodedUnsignedTx`, buf)
-        FfiConverterTypePartialSignatureRequestPayload.write(value.`partialSignatureRequestPayload`, buf)
+    override fun write(value: Settings, buf: ByteBuffer) {
+            FfiConverterString.write(value.`network`, buf)
+            FfiConverterOptionalString.write(value.`blockExplorerUrl`, buf)
+            FfiConverterOptionalString.write(value.`torProxyHost`, buf)
+            FfiConverterOptionalString.write(value.`torProxyPort`, buf)
+            FfiConverterOptionalString.write(value.`torProxyControlPassword`, buf)
+            FfiConverterOptionalString.write(value.`torProxyControlPort`, buf)
+            FfiConverterString.write(value.`statechainEntityApi`, buf)
+            FfiConverterOptionalString.write(value.`torStatechainEntityApi`, buf)
+            FfiConverterString.write(value.`electrumProtocol`, buf)
+            FfiConverterString.write(value.`electrumHost`, buf)
+            FfiConverterString.write(value.`electrumPort`, buf)
+            FfiConverterString.write(value.`electrumType`, buf)
+            FfiConverterBoolean.write(value.`notifications`, buf)
+            FfiConverterBoolean.write(value.`tutorials`, buf)
     }
 }
 
+
+
 @Serializable
-data class PartialSignatureRequestPayload(
+data class SignFirstRequestPayload(
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
-	@SerialName("negate_seckey")
-    var `negateSeckey`: kotlin.UByte,
-    var `session`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("signed_statechain_id")
-    var `signedStatechainId`: kotlin.String,
-	@SerialName("server_pub_nonce")
-    var `serverPubNonce`: kotlin.String,
+    var `signedStatechainId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePartialSignatureRequestPayload : FfiConverterRustBuffer<PartialSignatureRequestPayload> {
-    override fun read(buf: ByteBuffer): PartialSignatureRequestPayload {
-        return PartialSignatureRequestPayload(
-            FfiConverterString.read(buf),
-            FfiConverterUByte.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeSignFirstRequestPayload: FfiConverterRustBuffer<SignFirstRequestPayload> {
+    override fun read(buf: ByteBuffer): SignFirstRequestPayload {
+        return SignFirstRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: PartialSignatureRequestPayload) =
-        (
+    override fun allocationSize(value: SignFirstRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterUByte.allocationSize(value.`negateSeckey`) +
-                FfiConverterString.allocationSize(value.`session`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`) +
-                FfiConverterString.allocationSize(value.`serverPubNonce`)
-        )
+            FfiConverterString.allocationSize(value.`signedStatechainId`)
+    )
 
-    override fun write(
-        value: PartialSignatureRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterUByte.write(value.`negateSeckey`, buf)
-        FfiConverterString.write(value.`session`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
-        FfiConverterString.write(value.`serverPubNonce`, buf)
+    override fun write(value: SignFirstRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
     }
 }
 
+
+
 @Serializable
-data class PartialSignatureResponsePayload(
-	@SerialName("partial_sig")
-    var `partialSig`: kotlin.String,
+data class SignFirstResponsePayload(
+	@SerialName("server_pubnonce")
+    var `serverPubnonce`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePartialSignatureResponsePayload : FfiConverterRustBuffer<PartialSignatureResponsePayload> {
-    override fun read(buf: ByteBuffer): PartialSignatureResponsePayload {
-        return PartialSignatureResponsePayload(
+public object FfiConverterTypeSignFirstResponsePayload: FfiConverterRustBuffer<SignFirstResponsePayload> {
+    override fun read(buf: ByteBuffer): SignFirstResponsePayload {
+        return SignFirstResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: PartialSignatureResponsePayload) =
-        (
-            FfiConverterString.allocationSize(value.`partialSig`)
-        )
+    override fun allocationSize(value: SignFirstResponsePayload) = (
+            FfiConverterString.allocationSize(value.`serverPubnonce`)
+    )
 
-    override fun write(
-        value: PartialSignatureResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`partialSig`, buf)
+    override fun write(value: SignFirstResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubnonce`, buf)
     }
 }
 
-data class PubKeyInfo(
-    var `serverPubkey`: kotlin.String,
-    var `txN`: kotlin.UInt,
-    var `updatedAt`: kotlin.String,
+
+
+data class StatechainBackupTxs(
+    var `statechainId`: kotlin.String, 
+    var `backupTxs`: List<BackupTx>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePubKeyInfo : FfiConverterRustBuffer<PubKeyInfo> {
-    override fun read(buf: ByteBuffer): PubKeyInfo {
-        return PubKeyInfo(
-            FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
+public object FfiConverterTypeStatechainBackupTxs: FfiConverterRustBuffer<StatechainBackupTxs> {
+    override fun read(buf: ByteBuffer): StatechainBackupTxs {
+        return StatechainBackupTxs(
             FfiConverterString.read(buf),
+            FfiConverterSequenceTypeBackupTx.read(buf),
         )
     }
 
-    override fun allocationSize(value: PubKeyInfo) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubkey`) +
-                FfiConverterUInt.allocationSize(value.`txN`) +
-                FfiConverterString.allocationSize(value.`updatedAt`)
-        )
+    override fun allocationSize(value: StatechainBackupTxs) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTxs`)
+    )
 
-    override fun write(
-        value: PubKeyInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
-        FfiConverterUInt.write(value.`txN`, buf)
-        FfiConverterString.write(value.`updatedAt`, buf)
+    override fun write(value: StatechainBackupTxs, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterSequenceTypeBackupTx.write(value.`backupTxs`, buf)
     }
 }
 
+
+
 @Serializable
-data class ServerConfig(
-    var `initlock`: kotlin.UInt,
-    var `interval`: kotlin.UInt,
+data class StatechainInfo(
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+	@SerialName("server_pubnonce")
+    var `serverPubnonce`: kotlin.String, 
+    var `challenge`: kotlin.String, 
+	@SerialName("tx_n")
+    var `txN`: kotlin.UInt
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeServerConfig : FfiConverterRustBuffer<ServerConfig> {
-    override fun read(buf: ByteBuffer): ServerConfig {
-        return ServerConfig(
-            FfiConverterUInt.read(buf),
+public object FfiConverterTypeStatechainInfo: FfiConverterRustBuffer<StatechainInfo> {
+    override fun read(buf: ByteBuffer): StatechainInfo {
+        return StatechainInfo(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
             FfiConverterUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: ServerConfig) =
-        (
-            FfiConverterUInt.allocationSize(value.`initlock`) +
-                FfiConverterUInt.allocationSize(value.`interval`)
-        )
+    override fun allocationSize(value: StatechainInfo) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`serverPubnonce`) +
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterUInt.allocationSize(value.`txN`)
+    )
 
-    override fun write(
-        value: ServerConfig,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`initlock`, buf)
-        FfiConverterUInt.write(value.`interval`, buf)
+    override fun write(value: StatechainInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`serverPubnonce`, buf)
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterUInt.write(value.`txN`, buf)
     }
 }
 
-data class ServerPublicNonceResponsePayload(
-    var `serverPubnonce`: kotlin.String,
+
+
+@Serializable
+data class StatechainInfoResponsePayload(
+	@SerialName("enclave_public_key")
+    var `enclavePublicKey`: kotlin.String, 
+	@SerialName("num_sigs")
+    var `numSigs`: kotlin.UInt, 
+	@SerialName("statechain_info")
+    var `statechainInfo`: List<StatechainInfo>, 
+	@SerialName("x1_pub")
+    var `x1Pub`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeServerPublicNonceResponsePayload : FfiConverterRustBuffer<ServerPublicNonceResponsePayload> {
-    override fun read(buf: ByteBuffer): ServerPublicNonceResponsePayload {
-        return ServerPublicNonceResponsePayload(
+public object FfiConverterTypeStatechainInfoResponsePayload: FfiConverterRustBuffer<StatechainInfoResponsePayload> {
+    override fun read(buf: ByteBuffer): StatechainInfoResponsePayload {
+        return StatechainInfoResponsePayload(
             FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceTypeStatechainInfo.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: ServerPublicNonceResponsePayload) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubnonce`)
-        )
+    override fun allocationSize(value: StatechainInfoResponsePayload) = (
+            FfiConverterString.allocationSize(value.`enclavePublicKey`) +
+            FfiConverterUInt.allocationSize(value.`numSigs`) +
+            FfiConverterSequenceTypeStatechainInfo.allocationSize(value.`statechainInfo`) +
+            FfiConverterOptionalString.allocationSize(value.`x1Pub`)
+    )
 
-    override fun write(
-        value: ServerPublicNonceResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubnonce`, buf)
+    override fun write(value: StatechainInfoResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`enclavePublicKey`, buf)
+            FfiConverterUInt.write(value.`numSigs`, buf)
+            FfiConverterSequenceTypeStatechainInfo.write(value.`statechainInfo`, buf)
+            FfiConverterOptionalString.write(value.`x1Pub`, buf)
     }
 }
 
+
+
 @Serializable
-data class Settings(
-    var `network`: kotlin.String,
-    var `blockExplorerUrl`: kotlin.String?,
-    var `torProxyHost`: kotlin.String?,
-    var `torProxyPort`: kotlin.String?,
-    var `torProxyControlPassword`: kotlin.String?,
-    var `torProxyControlPort`: kotlin.String?,
-    var `statechainEntityApi`: kotlin.String,
-    var `torStatechainEntityApi`: kotlin.String?,
-    var `electrumProtocol`: kotlin.String,
-    var `electrumHost`: kotlin.String,
-    var `electrumPort`: kotlin.String,
-    var `electrumType`: kotlin.String,
-    var `notifications`: kotlin.Boolean,
-    var `tutorials`: kotlin.Boolean,
+data class Token(
+	@SerialName("btc_payment_address")
+    var `btcPaymentAddress`: kotlin.String, 
+    var `fee`: kotlin.String, 
+	@SerialName("lightning_invoice")
+    var `lightningInvoice`: kotlin.String, 
+	@SerialName("processor_id")
+    var `processorId`: kotlin.String, 
+	@SerialName("token_id")
+    var `tokenId`: kotlin.String, 
+    var `confirmed`: kotlin.Boolean, 
+    var `spent`: kotlin.Boolean, 
+    var `expiry`: kotlin.String, 
+    var `status`: TokenStatus?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeSettings : FfiConverterRustBuffer<Settings> {
-    override fun read(buf: ByteBuffer): Settings {
-        return Settings(
-            FfiConverterString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
+public object FfiConverterTypeToken: FfiConverterRustBuffer<Token> {
+    override fun read(buf: ByteBuffer): Token {
+        return Token(
             FfiConverterString.read(buf),
-            FfiConverterOptionalString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalTypeTokenStatus.read(buf),
         )
     }
 
-    override fun allocationSize(value: Settings) =
-        (
-            FfiConverterString.allocationSize(value.`network`) +
-                FfiConverterOptionalString.allocationSize(value.`blockExplorerUrl`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyHost`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyPort`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyControlPassword`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyControlPort`) +
-                FfiConverterString.allocationSize(value.`statechainEntityApi`) +
-                FfiConverterOptionalString.allocationSize(value.`torStatechainEntityApi`) +
-                FfiConverterString.allocationSize(value.`electrumProtocol`) +
-                FfiConverterString.allocationSize(value.`electrumHost`) +
-                FfiConverterString.allocationSize(value.`electrumPort`) +
-                FfiConverterString.allocationSize(value.`electrumType`) +
-                FfiConverterBoolean.allocationSize(value.`notifications`) +
-                FfiConverterBoolean.allocationSize(value.`tutorials`)
-        )
+    override fun allocationSize(value: Token) = (
+            FfiConverterString.allocationSize(value.`btcPaymentAddress`) +
+            FfiConverterString.allocationSize(value.`fee`) +
+            FfiConverterString.allocationSize(value.`lightningInvoice`) +
+            FfiConverterString.allocationSize(value.`processorId`) +
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterBoolean.allocationSize(value.`confirmed`) +
+            FfiConverterBoolean.allocationSize(value.`spent`) +
+            FfiConverterString.allocationSize(value.`expiry`) +
+            FfiConverterOptionalTypeTokenStatus.allocationSize(value.`status`)
+    )
 
-    override fun write(
-        value: Settings,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`network`, buf)
-        FfiConverterOptionalString.write(value.`blockExplorerUrl`, buf)
-        FfiConverterOptionalString.write(value.`torProxyHost`, buf)
-        FfiConverterOptionalString.write(value.`torProxyPort`, buf)
-        FfiConverterOptionalString.write(value.`torProxyControlPassword`, buf)
-        FfiConverterOptionalString.write(value.`torProxyControlPort`, buf)
-        FfiConverterString.write(value.`statechainEntityApi`, buf)
-        FfiConverterOptionalString.write(value.`torStatechainEntityApi`, buf)
-        FfiConverterString.write(value.`electrumProtocol`, buf)
-        FfiConverterString.write(value.`electrumHost`, buf)
-        FfiConverterString.write(value.`electrumPort`, buf)
-        FfiConverterString.write(value.`electrumType`, buf)
-        FfiConverterBoolean.write(value.`notifications`, buf)
-        FfiConverterBoolean.write(value.`tutorials`, buf)
+    override fun write(value: Token, buf: ByteBuffer) {
+            FfiConverterString.write(value.`btcPaymentAddress`, buf)
+            FfiConverterString.write(value.`fee`, buf)
+            FfiConverterString.write(value.`lightningInvoice`, buf)
+            FfiConverterString.write(value.`processorId`, buf)
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterBoolean.write(value.`confirmed`, buf)
+            FfiConverterBoolean.write(value.`spent`, buf)
+            FfiConverterString.write(value.`expiry`, buf)
+            FfiConverterOptionalTypeTokenStatus.write(value.`status`, buf)
     }
 }
 
-@Serializable
-data class SignFirstRequestPayload(
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
-	@SerialName("signed_statechain_id")
-    var `signedStatechainId`: kotlin.String,
+
+
+data class TokenStatusResponsePayload(
+    var `tokenId`: kotlin.String, 
+    var `status`: TokenStatus, 
+    var `expiry`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeSignFirstRequestPayload : FfiConverterRustBuffer<SignFirstRequestPayload> {
-    override fun read(buf: ByteBuffer): SignFirstRequestPayload {
-        return SignFirstRequestPayload(
+public object FfiConverterTypeTokenStatusResponsePayload: FfiConverterRustBuffer<TokenStatusResponsePayload> {
+    override fun read(buf: ByteBuffer): TokenStatusResponsePayload {
+        return TokenStatusResponsePayload(
             FfiConverterString.read(buf),
+            FfiConverterTypeTokenStatus.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: SignFirstRequestPayload) =
-        (
-            FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`)
-        )
+    override fun allocationSize(value: TokenStatusResponsePayload) = (
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterTypeTokenStatus.allocationSize(value.`status`) +
+            FfiConverterString.allocationSize(value.`expiry`)
+    )
 
-    override fun write(
-        value: SignFirstRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
+    override fun write(value: TokenStatusResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterTypeTokenStatus.write(value.`status`, buf)
+            FfiConverterString.write(value.`expiry`, buf)
     }
 }
 
-@Serializable
-data class SignFirstResponsePayload(
-	@SerialName("server_pubnonce")
-    var `serverPubnonce`: kotlin.String,
+
+
+data class TransferBatchCancelRequestPayload(
+    var `batchId`: kotlin.String, 
+    var `cancelRequests`: List<TransferCancelRequestPayload>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeSignFirstResponsePayload : FfiConverterRustBuffer<SignFirstResponsePayload> {
-    override fun read(buf: ByteBuffer): SignFirstResponsePayload {
-        return SignFirstResponsePayload(
+public object FfiConverterTypeTransferBatchCancelRequestPayload: FfiConverterRustBuffer<TransferBatchCancelRequestPayload> {
+    override fun read(buf: ByteBuffer): TransferBatchCancelRequestPayload {
+        return TransferBatchCancelRequestPayload(
             FfiConverterString.read(buf),
+            FfiConverterSequenceTypeTransferCancelRequestPayload.read(buf),
         )
     }
 
-    override fun allocationSize(value: SignFirstResponsePayload) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubnonce`)
-        )
+    override fun allocationSize(value: TransferBatchCancelRequestPayload) = (
+            FfiConverterString.allocationSize(value.`batchId`) +
+            FfiConverterSequenceTypeTransferCancelRequestPayload.allocationSize(value.`cancelRequests`)
+    )
 
-    override fun write(
-        value: SignFirstResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubnonce`, buf)
+    override fun write(value: TransferBatchCancelRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`batchId`, buf)
+            FfiConverterSequenceTypeTransferCancelRequestPayload.write(value.`cancelRequests`, buf)
     }
 }
 
-data class StatechainBackupTxs(
-    var `statechainId`: kotlin.String,
-    var `backupTxs`: List<BackupTx>,
+
+
+data class TransferCancelRequestPayload(
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeStatechainBackupTxs : FfiConverterRustBuffer<StatechainBackupTxs> {
-    override fun read(buf: ByteBuffer): StatechainBackupTxs {
-        return StatechainBackupTxs(
+public object FfiConverterTypeTransferCancelRequestPayload: FfiConverterRustBuffer<TransferCancelRequestPayload> {
+    override fun read(buf: ByteBuffer): TransferCancelRequestPayload {
+        return TransferCancelRequestPayload(
+            FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterSequenceTypeBackupTx.read(buf),
         )
     }
 
-    override fun allocationSize(value: StatechainBackupTxs) =
-        (
+    override fun allocationSize(value: TransferCancelRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTxs`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`)
+    )
 
-    override fun write(
-        value: StatechainBackupTxs,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterSequenceTypeBackupTx.write(value.`backupTxs`, buf)
+    override fun write(value: TransferCancelRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
     }
 }
 
-@Serializable
-data class StatechainInfo(
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
-	@SerialName("server_pubnonce")
-    var `serverPubnonce`: kotlin.String,
-    var `challenge`: kotlin.String,
-	@SerialName("tx_n")
-    var `txN`: kotlin.UInt,
+
+
+data class TransferNotificationPayload(
+    var `notificationType`: TransferNotificationType, 
+    var `statechainId`: kotlin.String, 
+    var `authPubKey`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeStatechainInfo : FfiConverterRustBuffer<StatechainInfo> {
-    override fun read(buf: ByteBuffer): StatechainInfo {
-        return StatechainInfo(
+public object FfiConverterTypeTransferNotificationPayload: FfiConverterRustBuffer<TransferNotificationPayload> {
+    override fun read(buf: ByteBuffer): TransferNotificationPayload {
+        return TransferNotificationPayload(
+            FfiConverterTypeTransferNotificationType.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: StatechainInfo) =
-        (
+    override fun allocationSize(value: TransferNotificationPayload) = (
+            FfiConverterTypeTransferNotificationType.allocationSize(value.`notificationType`) +
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`serverPubnonce`) +
-                FfiConverterString.allocationSize(value.`challenge`) +
-                FfiConverterUInt.allocationSize(value.`txN`)
-        )
+            FfiConverterString.allocationSize(value.`authPubKey`)
+    )
 
-    override fun write(
-        value: StatechainInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`serverPubnonce`, buf)
-        FfiConverterString.write(value.`challenge`, buf)
-        FfiConverterUInt.write(value.`txN`, buf)
+    override fun write(value: TransferNotificationPayload, buf: ByteBuffer) {
+            FfiConverterTypeTransferNotificationType.write(value.`notificationType`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authPubKey`, buf)
     }
 }
 
-@Serializable
-data class StatechainInfoResponsePayload(
-	@SerialName("enclave_public_key")
-    var `enclavePublicKey`: kotlin.String,
-	@SerialName("num_sigs")
-    var `numSigs`: kotlin.UInt,
-	@SerialName("statechain_info")
-    var `statechainInfo`: List<StatechainInfo>,
-	@SerialName("x1_pub")
-    var `x1Pub`: kotlin.String?,
+
+
+data class TransferNotificationRequestPayload(
+    var `challenge`: kotlin.String, 
+    var `authKeyProofs`: List<AuthKeyProof>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeStatechainInfoResponsePayload : FfiConverterRustBuffer<StatechainInfoResponsePayload> {
-    override fun read(buf: ByteBuffer): StatechainInfoResponsePayload {
-        return StatechainInfoResponsePayload(
+public object FfiConverterTypeTransferNotificationRequestPayload: FfiConverterRustBuffer<TransferNotificationRequestPayload> {
+    override fun read(buf: ByteBuffer): TransferNotificationRequestPayload {
+        return TransferNotificationRequestPayload(
             FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterSequenceTypeStatechainInfo.read(buf),
-            FfiConverterOptionalString.read(buf),
+            FfiConverterSequenceTypeAuthKeyProof.read(buf),
         )
     }
 
-    override fun allocationSize(value: StatechainInfoResponsePayload) =
-        (
-            FfiConverterString.allocationSize(value.`enclavePublicKey`) +
-                FfiConverterUInt.allocationSize(value.`numSigs`) +
-                FfiConverterSequenceTypeStatechainInfo.allocationSize(value.`statechainInfo`) +
-                FfiConverterOptionalString.allocationSize(value.`x1Pub`)
-        )
+    override fun allocationSize(value: TransferNotificationRequestPayload) = (
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterSequenceTypeAuthKeyProof.allocationSize(value.`authKeyProofs`)
+    )
 
-    override fun write(
-        value: StatechainInfoResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`enclavePublicKey`, buf)
-        FfiConverterUInt.write(value.`numSigs`, buf)
-        FfiConverterSequenceTypeStatechainInfo.write(value.`statechainInfo`, buf)
-        FfiConverterOptionalString.write(value.`x1Pub`, buf)
+    override fun write(value: TransferNotificationRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterSequenceTypeAuthKeyProof.write(value.`authKeyProofs`, buf)
     }
 }
 
-@Serializable
-data class Token(
-	@SerialName("btc_payment_address")
-    var `btcPaymentAddress`: kotlin.String,
-    var `fee`: kotlin.String,
-	@SerialName("lightning_invoice")
-    var `lightningInvoice`: kotlin.String,
-	@SerialName("processor_id")
-    var `processorId`: kotlin.String,
-	@SerialName("token_id")
-    var `tokenId`: kotlin.String,
-    var `confirmed`: kotlin.Boolean,
-    var `spent`: kotlin.Boolean,
-    var `expiry`: kotlin.String,
+
+
+data class TransferPreimageRequestPayload(
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String, 
+    var `previousUserAuthKey`: kotlin.String, 
+    var `batchId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeToken : FfiConverterRustBuffer<Token> {
-    override fun read(buf: ByteBuffer): Token {
-        return Token(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeTransferPreimageRequestPayload: FfiConverterRustBuffer<TransferPreimageRequestPayload> {
+    override fun read(buf: ByteBuffer): TransferPreimageRequestPayload {
+        return TransferPreimageRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterBoolean.read(buf),
-            FfiConverterBoolean.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: Token) =
-        (
-            FfiConverterString.allocationSize(value.`btcPaymentAddress`) +
-                FfiConverterString.allocationSize(value.`fee`) +
-                FfiConverterString.allocationSize(value.`lightningInvoice`) +
-                FfiConverterString.allocationSize(value.`processorId`) +
-                FfiConverterString.allocationSize(value.`tokenId`) +
-                FfiConverterBoolean.allocationSize(value.`confirmed`) +
-                FfiConverterBoolean.allocationSize(value.`spent`) +
-                FfiConverterString.allocationSize(value.`expiry`)
+    override fun allocationSize(value: TransferPreimageRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`previousUserAuthKey`) +
+            FfiConverterString.allocationSize(value.`batchId`)
+    )
+
+    override fun write(value: TransferPreimageRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`previousUserAuthKey`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
+    }
+}
+
+
+
+data class TransferPreimageResponsePayload(
+    var `preimage`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeTransferPreimageResponsePayload: FfiConverterRustBuffer<TransferPreimageResponsePayload> {
+    override fun read(buf: ByteBuffer): TransferPreimageResponsePayload {
+        return TransferPreimageResponsePayload(
+            FfiConverterString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: TransferPreimageResponsePayload) = (
+            FfiConverterString.allocationSize(value.`preimage`)
+    )
 
-    override fun write(
-        value: Token,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`btcPaymentAddress`, buf)
-        FfiConverterString.write(value.`fee`, buf)
-        FfiConverterString.write(value.`lightningInvoice`, buf)
-        FfiConverterString.write(value.`processorId`, buf)
-        FfiConverterString.write(value.`tokenId`, buf)
-        FfiConverterBoolean.write(value.`confirmed`, buf)
-        FfiConverterBoolean.write(value.`spent`, buf)
-        FfiConverterString.write(value.`expiry`, buf)
+    override fun write(value: TransferPreimageResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`preimage`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferReceiverErrorResponsePayload(
-    var `code`: TransferReceiverError,
-    var `message`: kotlin.String,
+    var `code`: TransferReceiverError, 
+    var `message`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverErrorResponsePayload : FfiConverterRustBuffer<TransferReceiverErrorResponsePayload> {
+public object FfiConverterTypeTransferReceiverErrorResponsePayload: FfiConverterRustBuffer<TransferReceiverErrorResponsePayload> {
     override fun read(buf: ByteBuffer): TransferReceiverErrorResponsePayload {
         return TransferReceiverErrorResponsePayload(
             FfiConverterTypeTransferReceiverError.read(buf),
@@ -2748,63 +3722,61 @@ public object FfiConverterTypeTransferReceiverErrorResponsePayload : FfiConverte
         )
     }
 
-    override fun allocationSize(value: TransferReceiverErrorResponsePayload) =
-        (
+    override fun allocationSize(value: TransferReceiverErrorResponsePayload) = (
             FfiConverterTypeTransferReceiverError.allocationSize(value.`code`) +
-                FfiConverterString.allocationSize(value.`message`)
-        )
+            FfiConverterString.allocationSize(value.`message`)
+    )
 
-    override fun write(
-        value: TransferReceiverErrorResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeTransferReceiverError.write(value.`code`, buf)
-        FfiConverterString.write(value.`message`, buf)
+    override fun write(value: TransferReceiverErrorResponsePayload, buf: ByteBuffer) {
+            FfiConverterTypeTransferReceiverError.write(value.`code`, buf)
+            FfiConverterString.write(value.`message`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferReceiverPostResponsePayload(
 	@SerialName("server_pubkey")
-    var `serverPubkey`: kotlin.String,
+    var `serverPubkey`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverPostResponsePayload : FfiConverterRustBuffer<TransferReceiverPostResponsePayload> {
+public object FfiConverterTypeTransferReceiverPostResponsePayload: FfiConverterRustBuffer<TransferReceiverPostResponsePayload> {
     override fun read(buf: ByteBuffer): TransferReceiverPostResponsePayload {
         return TransferReceiverPostResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferReceiverPostResponsePayload) =
-        (
+    override fun allocationSize(value: TransferReceiverPostResponsePayload) = (
             FfiConverterString.allocationSize(value.`serverPubkey`)
-        )
+    )
 
-    override fun write(
-        value: TransferReceiverPostResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
+    override fun write(value: TransferReceiverPostResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferReceiverRequestPayload(
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("batch_data")
-    var `batchData`: kotlin.String?,
-    var `t2`: kotlin.String,
+    var `batchData`: kotlin.String?, 
+    var `t2`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverRequestPayload : FfiConverterRustBuffer<TransferReceiverRequestPayload> {
+public object FfiConverterTypeTransferReceiverRequestPayload: FfiConverterRustBuffer<TransferReceiverRequestPayload> {
     override fun read(buf: ByteBuffer): TransferReceiverRequestPayload {
         return TransferReceiverRequestPayload(
             FfiConverterString.read(buf),
@@ -2814,148 +3786,148 @@ public object FfiConverterTypeTransferReceiverRequestPayload : FfiConverterRustB
         )
     }
 
-    override fun allocationSize(value: TransferReceiverRequestPayload) =
-        (
+    override fun allocationSize(value: TransferReceiverRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterOptionalString.allocationSize(value.`batchData`) +
-                FfiConverterString.allocationSize(value.`t2`) +
-                FfiConverterString.allocationSize(value.`authSig`)
-        )
+            FfiConverterOptionalString.allocationSize(value.`batchData`) +
+            FfiConverterString.allocationSize(value.`t2`) +
+            FfiConverterString.allocationSize(value.`authSig`)
+    )
 
-    override fun write(
-        value: TransferReceiverRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterOptionalString.write(value.`batchData`, buf)
-        FfiConverterString.write(value.`t2`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
+    override fun write(value: TransferReceiverRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterOptionalString.write(value.`batchData`, buf)
+            FfiConverterString.write(value.`t2`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferSenderRequestPayload(
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String, 
 	@SerialName("new_user_auth_key")
-    var `newUserAuthKey`: kotlin.String,
+    var `newUserAuthKey`: kotlin.String, 
 	@SerialName("batch_id")
-    var `batchId`: kotlin.String?,
+    var `batchId`: kotlin.String?, 
+    var `batchSize`: kotlin.UInt?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferSenderRequestPayload : FfiConverterRustBuffer<TransferSenderRequestPayload> {
+public object FfiConverterTypeTransferSenderRequestPayload: FfiConverterRustBuffer<TransferSenderRequestPayload> {
     override fun read(buf: ByteBuffer): TransferSenderRequestPayload {
         return TransferSenderRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferSenderRequestPayload) =
-        (
+    override fun allocationSize(value: TransferSenderRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`authSig`) +
-                FfiConverterString.allocationSize(value.`newUserAuthKey`) +
-                FfiConverterOptionalString.allocationSize(value.`batchId`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`newUserAuthKey`) +
+            FfiConverterOptionalString.allocationSize(value.`batchId`) +
+            FfiConverterOptionalUInt.allocationSize(value.`batchSize`)
+    )
 
-    override fun write(
-        value: TransferSenderRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
-        FfiConverterString.write(value.`newUserAuthKey`, buf)
-        FfiConverterOptionalString.write(value.`batchId`, buf)
+    override fun write(value: TransferSenderRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`newUserAuthKey`, buf)
+            FfiConverterOptionalString.write(value.`batchId`, buf)
+            FfiConverterOptionalUInt.write(value.`batchSize`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferSenderResponsePayload(
-    var `x1`: kotlin.String,
+    var `x1`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferSenderResponsePayload : FfiConverterRustBuffer<TransferSenderResponsePayload> {
+public object FfiConverterTypeTransferSenderResponsePayload: FfiConverterRustBuffer<TransferSenderResponsePayload> {
     override fun read(buf: ByteBuffer): TransferSenderResponsePayload {
         return TransferSenderResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferSenderResponsePayload) =
-        (
+    override fun allocationSize(value: TransferSenderResponsePayload) = (
             FfiConverterString.allocationSize(value.`x1`)
-        )
+    )
 
-    override fun write(
-        value: TransferSenderResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`x1`, buf)
+    override fun write(value: TransferSenderResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`x1`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferUnlockRequestPayload(
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String, 
 	@SerialName("auth_pub_key")
-    var `authPubKey`: kotlin.String,
+    var `authPubKey`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferUnlockRequestPayload : FfiConverterRustBuffer<TransferUnlockRequestPayload> {
+public object FfiConverterTypeTransferUnlockRequestPayload: FfiConverterRustBuffer<TransferUnlockRequestPayload> {
     override fun read(buf: ByteBuffer): TransferUnlockRequestPayload {
         return TransferUnlockRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferUnlockRequestPayload) =
-        (
+    override fun allocationSize(value: TransferUnlockRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`authSig`) +
-                FfiConverterString.allocationSize(value.`authPubKey`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterOptionalString.allocationSize(value.`authPubKey`)
+    )
 
-    override fun write(
-        value: TransferUnlockRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
-        FfiConverterString.write(value.`authPubKey`, buf)
+    override fun write(value: TransferUnlockRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterOptionalString.write(value.`authPubKey`, buf)
     }
 }
 
+
+
 @Serializable
 data class TransferUpdateMsgRequestPayload(
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String, 
 	@SerialName("new_user_auth_key")
-    var `newUserAuthKey`: kotlin.String,
+    var `newUserAuthKey`: kotlin.String, 
 	@SerialName("enc_transfer_msg")
-    var `encTransferMsg`: kotlin.String,
+    var `encTransferMsg`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferUpdateMsgRequestPayload : FfiConverterRustBuffer<TransferUpdateMsgRequestPayload> {
+public object FfiConverterTypeTransferUpdateMsgRequestPayload: FfiConverterRustBuffer<TransferUpdateMsgRequestPayload> {
     override fun read(buf: ByteBuffer): TransferUpdateMsgRequestPayload {
         return TransferUpdateMsgRequestPayload(
             FfiConverterString.read(buf),
@@ -2965,33 +3937,32 @@ public object FfiConverterTypeTransferUpdateMsgRequestPayload : FfiConverterRust
         )
     }
 
-    override fun allocationSize(value: TransferUpdateMsgRequestPayload) =
-        (
+    override fun allocationSize(value: TransferUpdateMsgRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`authSig`) +
-                FfiConverterString.allocationSize(value.`newUserAuthKey`) +
-                FfiConverterString.allocationSize(value.`encTransferMsg`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`newUserAuthKey`) +
+            FfiConverterString.allocationSize(value.`encTransferMsg`)
+    )
 
-    override fun write(
-        value: TransferUpdateMsgRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
-        FfiConverterString.write(value.`newUserAuthKey`, buf)
-        FfiConverterString.write(value.`encTransferMsg`, buf)
+    override fun write(value: TransferUpdateMsgRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`newUserAuthKey`, buf)
+            FfiConverterString.write(value.`encTransferMsg`, buf)
     }
 }
 
+
+
 data class TxOutpoint(
-    var `txid`: kotlin.String,
-    var `vout`: kotlin.UInt,
+    var `txid`: kotlin.String, 
+    var `vout`: kotlin.UInt
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTxOutpoint : FfiConverterRustBuffer<TxOutpoint> {
+public object FfiConverterTypeTxOutpoint: FfiConverterRustBuffer<TxOutpoint> {
     override fun read(buf: ByteBuffer): TxOutpoint {
         return TxOutpoint(
             FfiConverterString.read(buf),
@@ -2999,41 +3970,52 @@ public object FfiConverterTypeTxOutpoint : FfiConverterRustBuffer<TxOutpoint> {
         )
     }
 
-    override fun allocationSize(value: TxOutpoint) =
-        (
+    override fun allocationSize(value: TxOutpoint) = (
             FfiConverterString.allocationSize(value.`txid`) +
-                FfiConverterUInt.allocationSize(value.`vout`)
-        )
+            FfiConverterUInt.allocationSize(value.`vout`)
+    )
 
-    override fun write(
-        value: TxOutpoint,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`txid`, buf)
-        FfiConverterUInt.write(value.`vout`, buf)
+    override fun write(value: TxOutpoint, buf: ByteBuffer) {
+            FfiConverterString.write(value.`txid`, buf)
+            FfiConverterUInt.write(value.`vout`, buf)
     }
 }
 
+
+
 @Serializable
 data class Wallet(
-    var `name`: kotlin.String,
-    var `mnemonic`: kotlin.String,
-    var `version`: kotlin.String,
-    var `stateEntityEndpoint`: kotlin.String,
-    var `electrumEndpoint`: kotlin.String,
-    var `network`: kotlin.String,
-    var `blockheight`: kotlin.UInt,
-    var `initlock`: kotlin.UInt,
-    var `interval`: kotlin.UInt,
-    var `tokens`: List<Token>,
-    var `activities`: List<Activity>,
-    var `coins`: List<Coin>,
-    var `settings`: Settings,
+    var `name`: kotlin.String, 
+    var `mnemonic`: kotlin.String, 
+    var `version`: kotlin.String, 
+    var `stateEntityEndpoint`: kotlin.String, 
+    var `electrumEndpoint`: kotlin.String, 
+    var `network`: kotlin.String, 
+    var `blockheight`: kotlin.UInt, 
+    var `initlock`: kotlin.UInt, 
+    var `interval`: kotlin.UInt, 
+    var `tokens`: List<Token>, 
+    var `activities`: List<Activity>, 
+    var `coins`: List<Coin>, 
+    var `settings`: Settings, 
+    /**
+     * Mnemonic and coin private keys of a locked wallet
+     */
+    var `encryptedSecrets`: EncryptedSecrets?, 
+    /**
+     * The seed is derived with a BIP39 passphrase, which is not stored and must be given to derive new keys
+     */
+    var `hasPassphrase`: kotlin.Boolean, 
+    /**
+     * Fingerprint of the master key, used to detect a wrong BIP39 passphrase
+     */
+    var `masterFingerprint`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeWallet : FfiConverterRustBuffer<Wallet> {
+public object FfiConverterTypeWallet: FfiConverterRustBuffer<Wallet> {
     override fun read(buf: ByteBuffer): Wallet {
         return Wallet(
             FfiConverterString.read(buf),
@@ -3049,57 +4031,94 @@ public object FfiConverterTypeWallet : FfiConverterRustBuffer<Wallet> {
             FfiConverterSequenceTypeActivity.read(buf),
             FfiConverterSequenceTypeCoin.read(buf),
             FfiConverterTypeSettings.read(buf),
+            FfiConverterOptionalTypeEncryptedSecrets.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: Wallet) =
-        (
+    override fun allocationSize(value: Wallet) = (
             FfiConverterString.allocationSize(value.`name`) +
-                FfiConverterString.allocationSize(value.`mnemonic`) +
-                FfiConverterString.allocationSize(value.`version`) +
-                FfiConverterString.allocationSize(value.`stateEntityEndpoint`) +
-                FfiConverterString.allocationSize(value.`electrumEndpoint`) +
-                FfiConverterString.allocationSize(value.`network`) +
-                FfiConverterUInt.allocationSize(value.`blockheight`) +
-                FfiConverterUInt.allocationSize(value.`initlock`) +
-                FfiConverterUInt.allocationSize(value.`interval`) +
-                FfiConverterSequenceTypeToken.allocationSize(value.`tokens`) +
-                FfiConverterSequenceTypeActivity.allocationSize(value.`activities`) +
-                FfiConverterSequenceTypeCoin.allocationSize(value.`coins`) +
-                FfiConverterTypeSettings.allocationSize(value.`settings`)
+            FfiConverterString.allocationSize(value.`mnemonic`) +
+            FfiConverterString.allocationSize(value.`version`) +
+            FfiConverterString.allocationSize(value.`stateEntityEndpoint`) +
+            FfiConverterString.allocationSize(value.`electrumEndpoint`) +
+            FfiConverterString.allocationSize(value.`network`) +
+            FfiConverterUInt.allocationSize(value.`blockheight`) +
+            FfiConverterUInt.allocationSize(value.`initlock`) +
+            FfiConverterUInt.allocationSize(value.`interval`) +
+            FfiConverterSequenceTypeToken.allocationSize(value.`tokens`) +
+            FfiConverterSequenceTypeActivity.allocationSize(value.`activities`) +
+            FfiConverterSequenceTypeCoin.allocationSize(value.`coins`) +
+            FfiConverterTypeSettings.allocationSize(value.`settings`) +
+            FfiConverterOptionalTypeEncryptedSecrets.allocationSize(value.`encryptedSecrets`) +
+            FfiConverterBoolean.allocationSize(value.`hasPassphrase`) +
+            FfiConverterOptionalString.allocationSize(value.`masterFingerprint`)
+    )
+
+    override fun write(value: Wallet, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`mnemonic`, buf)
+            FfiConverterString.write(value.`version`, buf)
+            FfiConverterString.write(value.`stateEntityEndpoint`, buf)
+            FfiConverterString.write(value.`electrumEndpoint`, buf)
+            FfiConverterString.write(value.`network`, buf)
+            FfiConverterUInt.write(value.`blockheight`, buf)
+            FfiConverterUInt.write(value.`initlock`, buf)
+            FfiConverterUInt.write(value.`interval`, buf)
+            FfiConverterSequenceTypeToken.write(value.`tokens`, buf)
+            FfiConverterSequenceTypeActivity.write(value.`activities`, buf)
+            FfiConverterSequenceTypeCoin.write(value.`coins`, buf)
+            FfiConverterTypeSettings.write(value.`settings`, buf)
+            FfiConverterOptionalTypeEncryptedSecrets.write(value.`encryptedSecrets`, buf)
+            FfiConverterBoolean.write(value.`hasPassphrase`, buf)
+            FfiConverterOptionalString.write(value.`masterFingerprint`, buf)
+    }
+}
+
+
+
+data class WalletBackup(
+    var `wallet`: Wallet, 
+    var `backupTxs`: List<StatechainBackupTxs>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeWalletBackup: FfiConverterRustBuffer<WalletBackup> {
+    override fun read(buf: ByteBuffer): WalletBackup {
+        return WalletBackup(
+            FfiConverterTypeWallet.read(buf),
+            FfiConverterSequenceTypeStatechainBackupTxs.read(buf),
         )
+    }
+
+    override fun allocationSize(value: WalletBackup) = (
+            FfiConverterTypeWallet.allocationSize(value.`wallet`) +
+            FfiConverterSequenceTypeStatechainBackupTxs.allocationSize(value.`backupTxs`)
+    )
 
-    override fun write(
-        value: Wallet,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`name`, buf)
-        FfiConverterString.write(value.`mnemonic`, buf)
-        FfiConverterString.write(value.`version`, buf)
-        FfiConverterString.write(value.`stateEntityEndpoint`, buf)
-        FfiConverterString.write(value.`electrumEndpoint`, buf)
-        FfiConverterString.write(value.`network`, buf)
-        FfiConverterUInt.write(value.`blockheight`, buf)
-        FfiConverterUInt.write(value.`initlock`, buf)
-        FfiConverterUInt.write(value.`interval`, buf)
-        FfiConverterSequenceTypeToken.write(value.`tokens`, buf)
-        FfiConverterSequenceTypeActivity.write(value.`activities`, buf)
-        FfiConverterSequenceTypeCoin.write(value.`coins`, buf)
-        FfiConverterTypeSettings.write(value.`settings`, buf)
+    override fun write(value: WalletBackup, buf: ByteBuffer) {
+            FfiConverterTypeWallet.write(value.`wallet`, buf)
+            FfiConverterSequenceTypeStatechainBackupTxs.write(value.`backupTxs`, buf)
     }
 }
 
+
+
 @Serializable
 data class WithdrawCompletePayload(
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("signed_statechain_id")
-    var `signedStatechainId`: kotlin.String,
+    var `signedStatechainId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeWithdrawCompletePayload : FfiConverterRustBuffer<WithdrawCompletePayload> {
+public object FfiConverterTypeWithdrawCompletePayload: FfiConverterRustBuffer<WithdrawCompletePayload> {
     override fun read(buf: ByteBuffer): WithdrawCompletePayload {
         return WithdrawCompletePayload(
             FfiConverterString.read(buf),
@@ -3107,22 +4126,22 @@ public object FfiConverterTypeWithdrawCompletePayload : FfiConverterRustBuffer<W
         )
     }
 
-    override fun allocationSize(value: WithdrawCompletePayload) =
-        (
+    override fun allocationSize(value: WithdrawCompletePayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`)
-        )
+            FfiConverterString.allocationSize(value.`signedStatechainId`)
+    )
 
-    override fun write(
-        value: WithdrawCompletePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
+    override fun write(value: WithdrawCompletePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
     }
 }
 
+
+
+
 enum class CoinStatus {
+    
     INITIALISED,
     IN_MEMPOOL,
     UNCONFIRMED,
@@ -3131,263 +4150,604 @@ enum class CoinStatus {
     WITHDRAWING,
     TRANSFERRED,
     WITHDRAWN,
+    DUPLICATED,
     ;
-
     companion object
 }
 
-public object FfiConverterTypeCoinStatus : FfiConverterRustBuffer<CoinStatus> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            CoinStatus.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+
+public object FfiConverterTypeCoinStatus: FfiConverterRustBuffer<CoinStatus> {
+    override fun read(buf: ByteBuffer) = try {
+        CoinStatus.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: CoinStatus) = 4UL
 
-    override fun write(
-        value: CoinStatus,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: CoinStatus, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
-sealed class MercuryException : Exception() {
-    class Bip39Exception() : MercuryException() {
+
+
+
+
+
+
+sealed class MercuryException: Exception() {
+    
+    class Bip39Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Bip32Exception() : MercuryException() {
+    
+    class Bip32Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class NetworkConversionException() : MercuryException() {
+    
+    class NetworkConversionException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Secp256k1UpstreamException() : MercuryException() {
+    
+    class Secp256k1UpstreamException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class KeyException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class Bech32Exception(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class HexException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class LocktimeNotBlockHeightException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinConsensusEncodeException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class MusigNonceGenException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class InvalidStatechainAddressException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class InvalidBitcoinAddressException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class StatechainAddressMismatchNetworkException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinAddressMismatchNetworkException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinAddressException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinAbsoluteException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinHashHexException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinPsbtException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class SighashTypeParseException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BitcoinSighashException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class ParseException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class MusigSignException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class SchnorrSignatureValidationException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class MoreThanOneInputException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class UnkownNetwork(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class BackupTransactionDoesNotPayUser(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    /**
+     * The fee rate (sats/vbyte) of backup transaction `tx_n` is above the current fee rate plus the tolerance
+     */
+    class FeeTooHigh(
+        
+        val `txN`: kotlin.UInt?, 
+        
+        val `feeRate`: kotlin.Double, 
+        
+        val `expectedFeeRate`: kotlin.Double, 
+        
+        val `tolerance`: kotlin.Double
+        ) : MercuryException() {
+        override val message
+            get() = "txN=${ `txN` }, feeRate=${ `feeRate` }, expectedFeeRate=${ `expectedFeeRate` }, tolerance=${ `tolerance` }"
+    }
+    
+    /**
+     * The fee rate (sats/vbyte) of backup transaction `tx_n` is below the current fee rate minus the tolerance
+     */
+    class FeeTooLow(
+        
+        val `txN`: kotlin.UInt?, 
+        
+        val `feeRate`: kotlin.Double, 
+        
+        val `expectedFeeRate`: kotlin.Double, 
+        
+        val `tolerance`: kotlin.Double
+        ) : MercuryException() {
+        override val message
+            get() = "txN=${ `txN` }, feeRate=${ `feeRate` }, expectedFeeRate=${ `expectedFeeRate` }, tolerance=${ `tolerance` }"
+    }
+    
+    class OutOfRangeException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class SerdeJsonException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class SecpException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class KeyException() : MercuryException() {
+    
+    class NoBackupTransactionFound(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Bech32Exception() : MercuryException() {
+    
+    class Tx1HasMoreThanOneInput(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class HexException() : MercuryException() {
+    
+    class InvalidSignature(
+        
+        val `reason`: kotlin.String
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "reason=${ `reason` }"
     }
-
-    class LocktimeNotBlockHeightException() : MercuryException() {
+    
+    class EmptyWitness(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinConsensusEncodeException() : MercuryException() {
+    
+    class EmptyWitnessData(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class MusigNonceGenException() : MercuryException() {
+    
+    class IncorrectChallenge(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class InvalidStatechainAddressException() : MercuryException() {
+    
+    class InvalidT1(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class InvalidBitcoinAddressException() : MercuryException() {
+    
+    class IncorrectAggregatedPublicKey(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class StatechainAddressMismatchNetworkException() : MercuryException() {
+    
+    class T1MustBeExactly32BytesException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinAddressMismatchNetworkException() : MercuryException() {
+    
+    class NoX1Pub(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinAddressException() : MercuryException() {
+    
+    class NoAggregatedPubkeyException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinAbsoluteException() : MercuryException() {
+    
+    class CoinNotFound(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinHashHexException() : MercuryException() {
+    
+    /**
+     * Backup transaction `tx_n` of the transfer message failed validation
+     */
+    class SignatureSchemeValidationException(
+        
+        val `txN`: kotlin.UInt, 
+        
+        val `reason`: kotlin.String
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "txN=${ `txN` }, reason=${ `reason` }"
     }
-
-    class BitcoinPsbtException() : MercuryException() {
+    
+    class NoPreviousLockTimeException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class SighashTypeParseException() : MercuryException() {
+    
+    class TransactionHasNoInputs(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinSighashException() : MercuryException() {
+    
+    class TransactionHasNoOutputs(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class ParseException() : MercuryException() {
+    
+    /**
+     * The transaction has no output at index `vout`
+     */
+    class OutputNotFound(
+        
+        val `vout`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "vout=${ `vout` }"
     }
-
-    class MusigSignException() : MercuryException() {
+    
+    /**
+     * The output script is not a taproot key spend output
+     */
+    class InvalidTaprootOutput(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class SchnorrSignatureValidationException() : MercuryException() {
+    
+    /**
+     * The transaction outputs spend more than its input (sats)
+     */
+    class OutputExceedsInput(
+        
+        val `input`: kotlin.ULong, 
+        
+        val `output`: kotlin.ULong
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "input=${ `input` }, output=${ `output` }"
     }
-
-    class MoreThanOneInputException() : MercuryException() {
+    
+    class Utf8Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class UnkownNetwork() : MercuryException() {
+    
+    /**
+     * The transfer message does not carry one backup transaction per server signature
+     */
+    class BackupCountMismatch(
+        
+        val `backups`: kotlin.UInt, 
+        
+        val `numSigs`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "backups=${ `backups` }, numSigs=${ `numSigs` }"
     }
-
-    class BackupTransactionDoesNotPayUser() : MercuryException() {
+    
+    class DuplicateBackupTxN(
+        
+        val `txN`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "txN=${ `txN` }"
     }
-
-    class FeeTooHigh() : MercuryException() {
+    
+    /**
+     * The backup transactions are not numbered 1, 2, 3... in order
+     */
+    class NonContiguousBackupTxN(
+        
+        val `expected`: kotlin.UInt, 
+        
+        val `found`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "expected=${ `expected` }, found=${ `found` }"
     }
-
-    class FeeTooLow() : MercuryException() {
+    
+    /**
+     * The statechain info at the same position as backup transaction `tx_n` is for another transaction
+     */
+    class StatechainInfoMismatch(
+        
+        val `txN`: kotlin.UInt, 
+        
+        val `statechainTxN`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "txN=${ `txN` }, statechainTxN=${ `statechainTxN` }"
     }
-
-    class OutOfRangeException() : MercuryException() {
+    
+    /**
+     * Backup transaction `tx_n` does not spend the same Tx0 outpoint as the first backup transaction
+     */
+    class BackupSpendsDifferentOutpoint(
+        
+        val `txN`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "txN=${ `txN` }"
     }
-
-    class SerdeJsonException() : MercuryException() {
+    
+    /**
+     * The latest backup transaction does not have the lowest locktime of the chain
+     */
+    class LatestBackupLocktimeNotLowest(
+        
+        val `latestLocktime`: kotlin.UInt, 
+        
+        val `txN`: kotlin.UInt, 
+        
+        val `locktime`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "latestLocktime=${ `latestLocktime` }, txN=${ `txN` }, locktime=${ `locktime` }"
     }
-
-    class SecpException() : MercuryException() {
+    
+    /**
+     * A PSBT input has no taproot key spend signature
+     */
+    class PsbtMissingSignature(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class NoBackupTransactionFound() : MercuryException() {
+    
+    /**
+     * A PSBT input does not carry the output it spends
+     */
+    class PsbtMissingWitnessUtxo(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Tx1HasMoreThanOneInput() : MercuryException() {
+    
+    /**
+     * The wallet secrets are encrypted, it must be unlocked first
+     */
+    class WalletLocked(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class InvalidSignature() : MercuryException() {
+    
+    class WalletNotLocked(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class EmptyWitness() : MercuryException() {
+    
+    class IncorrectPassword(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class EmptyWitnessData() : MercuryException() {
+    
+    class EncryptionException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class IncorrectChallenge() : MercuryException() {
+    
+    class InvalidMnemonicWordCount(
+        
+        val `wordCount`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "wordCount=${ `wordCount` }"
     }
-
-    class InvalidT1() : MercuryException() {
+    
+    /**
+     * The wallet seed uses a BIP39 passphrase and none was given
+     */
+    class PassphraseRequired(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class IncorrectAggregatedPublicKey() : MercuryException() {
+    
+    /**
+     * The passphrase does not derive the keys of the existing coins
+     */
+    class IncorrectPassphrase(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class T1MustBeExactly32BytesException() : MercuryException() {
+    
+    /**
+     * The recovered statecoin is owned by another auth key than the coin
+     */
+    class RecoveredStatecoinKeyMismatch(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class NoX1Pub() : MercuryException() {
+    
+    class InvalidWalletBackup(
+        
+        val `reason`: kotlin.String
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "reason=${ `reason` }"
     }
-
-    class NoAggregatedPubkeyException() : MercuryException() {
+    
+    /**
+     * The backup file was created by another version of the format
+     */
+    class UnsupportedWalletBackupVersion(
+        
+        val `version`: kotlin.UInt, 
+        
+        val `supportedVersion`: kotlin.UInt
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "version=${ `version` }, supportedVersion=${ `supportedVersion` }"
     }
-
-    class CoinNotFound() : MercuryException() {
+    
+    /**
+     * The transaction fee (sats) is larger than the amount it spends
+     */
+    class FeeExceedsAmount(
+        
+        val `fee`: kotlin.ULong, 
+        
+        val `amount`: kotlin.ULong
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "fee=${ `fee` }, amount=${ `amount` }"
     }
-
-    class SignatureSchemeValidationException() : MercuryException() {
+    
+    /**
+     * The key derived by the signer is not the key of the coin (e.g. another seed or passphrase)
+     */
+    class SignerKeyMismatch(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class NoPreviousLockTimeException() : MercuryException() {
+    
+    /**
+     * The user and auth keys of a coin were not derived from the same seed and address index
+     */
+    class KeyDerivationMismatch(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
+    
 
     companion object ErrorHandler : UniffiRustCallStatusErrorHandler<MercuryException> {
         override fun lift(error_buf: RustBuffer.ByValue): MercuryException = FfiConverterTypeMercuryError.lift(error_buf)
     }
+
+    
 }
 
 public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryException> {
     override fun read(buf: ByteBuffer): MercuryException {
-        return when (buf.getInt()) {
+        
+
+        return when(buf.getInt()) {
             1 -> MercuryException.Bip39Exception()
             2 -> MercuryException.Bip32Exception()
             3 -> MercuryException.NetworkConversionException()
@@ -3414,14 +4774,26 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             24 -> MercuryException.MoreThanOneInputException()
             25 -> MercuryException.UnkownNetwork()
             26 -> MercuryException.BackupTransactionDoesNotPayUser()
-            27 -> MercuryException.FeeTooHigh()
-            28 -> MercuryException.FeeTooLow()
+            27 -> MercuryException.FeeTooHigh(
+                FfiConverterOptionalUInt.read(buf),
+                FfiConverterDouble.read(buf),
+                FfiConverterDouble.read(buf),
+                FfiConverterDouble.read(buf),
+                )
+            28 -> MercuryException.FeeTooLow(
+                FfiConverterOptionalUInt.read(buf),
+                FfiConverterDouble.read(buf),
+                FfiConverterDouble.read(buf),
+                FfiConverterDouble.read(buf),
+                )
             29 -> MercuryException.OutOfRangeException()
             30 -> MercuryException.SerdeJsonException()
             31 -> MercuryException.SecpException()
             32 -> MercuryException.NoBackupTransactionFound()
             33 -> MercuryException.Tx1HasMoreThanOneInput()
-            34 -> MercuryException.InvalidSignature()
+            34 -> MercuryException.InvalidSignature(
+                FfiConverterString.read(buf),
+                )
             35 -> MercuryException.EmptyWitness()
             36 -> MercuryException.EmptyWitnessData()
             37 -> MercuryException.IncorrectChallenge()
@@ -3431,14 +4803,76 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             41 -> MercuryException.NoX1Pub()
             42 -> MercuryException.NoAggregatedPubkeyException()
             43 -> MercuryException.CoinNotFound()
-            44 -> MercuryException.SignatureSchemeValidationException()
+            44 -> MercuryException.SignatureSchemeValidationException(
+                FfiConverterUInt.read(buf),
+                FfiConverterString.read(buf),
+                )
             45 -> MercuryException.NoPreviousLockTimeException()
+            46 -> MercuryException.TransactionHasNoInputs()
+            47 -> MercuryException.TransactionHasNoOutputs()
+            48 -> MercuryException.OutputNotFound(
+                FfiConverterUInt.read(buf),
+                )
+            49 -> MercuryException.InvalidTaprootOutput()
+            50 -> MercuryException.OutputExceedsInput(
+                FfiConverterULong.read(buf),
+                FfiConverterULong.read(buf),
+                )
+            51 -> MercuryException.Utf8Exception()
+            52 -> MercuryException.BackupCountMismatch(
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                )
+            53 -> MercuryException.DuplicateBackupTxN(
+                FfiConverterUInt.read(buf),
+                )
+            54 -> MercuryException.NonContiguousBackupTxN(
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                )
+            55 -> MercuryException.StatechainInfoMismatch(
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                )
+            56 -> MercuryException.BackupSpendsDifferentOutpoint(
+                FfiConverterUInt.read(buf),
+                )
+            57 -> MercuryException.LatestBackupLocktimeNotLowest(
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                )
+            58 -> MercuryException.PsbtMissingSignature()
+            59 -> MercuryException.PsbtMissingWitnessUtxo()
+            60 -> MercuryException.WalletLocked()
+            61 -> MercuryException.WalletNotLocked()
+            62 -> MercuryException.IncorrectPassword()
+            63 -> MercuryException.EncryptionException()
+            64 -> MercuryException.InvalidMnemonicWordCount(
+                FfiConverterUInt.read(buf),
+                )
+            65 -> MercuryException.PassphraseRequired()
+            66 -> MercuryException.IncorrectPassphrase()
+            67 -> MercuryException.RecoveredStatecoinKeyMismatch()
+            68 -> MercuryException.InvalidWalletBackup(
+                FfiConverterString.read(buf),
+                )
+            69 -> MercuryException.UnsupportedWalletBackupVersion(
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                )
+            70 -> MercuryException.FeeExceedsAmount(
+                FfiConverterULong.read(buf),
+                FfiConverterULong.read(buf),
+                )
+            71 -> MercuryException.SignerKeyMismatch()
+            72 -> MercuryException.KeyDerivationMismatch()
             else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
     }
 
     override fun allocationSize(value: MercuryException): ULong {
-        return when (value) {
+        return when(value) {
             is MercuryException.Bip39Exception -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
@@ -3546,10 +4980,18 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.FeeTooHigh -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterOptionalUInt.allocationSize(value.`txN`)
+                + FfiConverterDouble.allocationSize(value.`feeRate`)
+                + FfiConverterDouble.allocationSize(value.`expectedFeeRate`)
+                + FfiConverterDouble.allocationSize(value.`tolerance`)
             )
             is MercuryException.FeeTooLow -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterOptionalUInt.allocationSize(value.`txN`)
+                + FfiConverterDouble.allocationSize(value.`feeRate`)
+                + FfiConverterDouble.allocationSize(value.`expectedFeeRate`)
+                + FfiConverterDouble.allocationSize(value.`tolerance`)
             )
             is MercuryException.OutOfRangeException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3574,6 +5016,7 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.InvalidSignature -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterString.allocationSize(value.`reason`)
             )
             is MercuryException.EmptyWitness -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3614,19 +5057,146 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.SignatureSchemeValidationException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterUInt.allocationSize(value.`txN`)
+                + FfiConverterString.allocationSize(value.`reason`)
             )
             is MercuryException.NoPreviousLockTimeException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
             )
+            is MercuryException.TransactionHasNoInputs -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.TransactionHasNoOutputs -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.OutputNotFound -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`vout`)
+            )
+            is MercuryException.InvalidTaprootOutput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.OutputExceedsInput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterULong.allocationSize(value.`input`)
+                + FfiConverterULong.allocationSize(value.`output`)
+            )
+            is MercuryException.Utf8Exception -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.BackupCountMismatch -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`backups`)
+                + FfiConverterUInt.allocationSize(value.`numSigs`)
+            )
+            is MercuryException.DuplicateBackupTxN -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`txN`)
+            )
+            is MercuryException.NonContiguousBackupTxN -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`expected`)
+                + FfiConverterUInt.allocationSize(value.`found`)
+            )
+            is MercuryException.StatechainInfoMismatch -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`txN`)
+                + FfiConverterUInt.allocationSize(value.`statechainTxN`)
+            )
+            is MercuryException.BackupSpendsDifferentOutpoint -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`txN`)
+            )
+            is MercuryException.LatestBackupLocktimeNotLowest -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`latestLocktime`)
+                + FfiConverterUInt.allocationSize(value.`txN`)
+                + FfiConverterUInt.allocationSize(value.`locktime`)
+            )
+            is MercuryException.PsbtMissingSignature -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.PsbtMissingWitnessUtxo -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.WalletLocked -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.WalletNotLocked -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.IncorrectPassword -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.EncryptionException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.InvalidMnemonicWordCount -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`wordCount`)
+            )
+            is MercuryException.PassphraseRequired -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.IncorrectPassphrase -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.RecoveredStatecoinKeyMismatch -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.InvalidWalletBackup -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`reason`)
+            )
+            is MercuryException.UnsupportedWalletBackupVersion -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterUInt.allocationSize(value.`version`)
+                + FfiConverterUInt.allocationSize(value.`supportedVersion`)
+            )
+            is MercuryException.FeeExceedsAmount -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterULong.allocationSize(value.`fee`)
+                + FfiConverterULong.allocationSize(value.`amount`)
+            )
+            is MercuryException.SignerKeyMismatch -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.KeyDerivationMismatch -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
         }
     }
 
-    override fun write(
-        value: MercuryException,
-        buf: ByteBuffer,
-    ) {
-        when (value) {
+    override fun write(value: MercuryException, buf: ByteBuffer) {
+        when(value) {
             is MercuryException.Bip39Exception -> {
                 buf.putInt(1)
                 Unit
@@ -3733,10 +5303,18 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             }
             is MercuryException.FeeTooHigh -> {
                 buf.putInt(27)
+                FfiConverterOptionalUInt.write(value.`txN`, buf)
+                FfiConverterDouble.write(value.`feeRate`, buf)
+                FfiConverterDouble.write(value.`expectedFeeRate`, buf)
+                FfiConverterDouble.write(value.`tolerance`, buf)
                 Unit
             }
             is MercuryException.FeeTooLow -> {
                 buf.putInt(28)
+                FfiConverterOptionalUInt.write(value.`txN`, buf)
+                FfiConverterDouble.write(value.`feeRate`, buf)
+                FfiConverterDouble.write(value.`expectedFeeRate`, buf)
+                FfiConverterDouble.write(value.`tolerance`, buf)
                 Unit
             }
             is MercuryException.OutOfRangeException -> {
@@ -3755,92 +5333,298 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
                 buf.putInt(32)
                 Unit
             }
-            is MercuryException.Tx1HasMoreThanOneInput -> {
-                buf.putInt(33)
+            is MercuryException.Tx1HasMoreThanOneInput -> {
+                buf.putInt(33)
+                Unit
+            }
+            is MercuryException.InvalidSignature -> {
+                buf.putInt(34)
+                FfiConverterString.write(value.`reason`, buf)
+                Unit
+            }
+            is MercuryException.EmptyWitness -> {
+                buf.putInt(35)
+                Unit
+            }
+            is MercuryException.EmptyWitnessData -> {
+                buf.putInt(36)
+                Unit
+            }
+            is MercuryException.IncorrectChallenge -> {
+                buf.putInt(37)
+                Unit
+            }
+            is MercuryException.InvalidT1 -> {
+                buf.putInt(38)
+                Unit
+            }
+            is MercuryException.IncorrectAggregatedPublicKey -> {
+                buf.putInt(39)
+                Unit
+            }
+            is MercuryException.T1MustBeExactly32BytesException -> {
+                buf.putInt(40)
+                Unit
+            }
+            is MercuryException.NoX1Pub -> {
+                buf.putInt(41)
+                Unit
+            }
+            is MercuryException.NoAggregatedPubkeyException -> {
+                buf.putInt(42)
+                Unit
+            }
+            is MercuryException.CoinNotFound -> {
+                buf.putInt(43)
+                Unit
+            }
+            is MercuryException.SignatureSchemeValidationException -> {
+                buf.putInt(44)
+                FfiConverterUInt.write(value.`txN`, buf)
+                FfiConverterString.write(value.`reason`, buf)
+                Unit
+            }
+            is MercuryException.NoPreviousLockTimeException -> {
+                buf.putInt(45)
+                Unit
+            }
+            is MercuryException.TransactionHasNoInputs -> {
+                buf.putInt(46)
+                Unit
+            }
+            is MercuryException.TransactionHasNoOutputs -> {
+                buf.putInt(47)
+                Unit
+            }
+            is MercuryException.OutputNotFound -> {
+                buf.putInt(48)
+                FfiConverterUInt.write(value.`vout`, buf)
+                Unit
+            }
+            is MercuryException.InvalidTaprootOutput -> {
+                buf.putInt(49)
+                Unit
+            }
+            is MercuryException.OutputExceedsInput -> {
+                buf.putInt(50)
+                FfiConverterULong.write(value.`input`, buf)
+                FfiConverterULong.write(value.`output`, buf)
+                Unit
+            }
+            is MercuryException.Utf8Exception -> {
+                buf.putInt(51)
+                Unit
+            }
+            is MercuryException.BackupCountMismatch -> {
+                buf.putInt(52)
+                FfiConverterUInt.write(value.`backups`, buf)
+                FfiConverterUInt.write(value.`numSigs`, buf)
+                Unit
+            }
+            is MercuryException.DuplicateBackupTxN -> {
+                buf.putInt(53)
+                FfiConverterUInt.write(value.`txN`, buf)
+                Unit
+            }
+            is MercuryException.NonContiguousBackupTxN -> {
+                buf.putInt(54)
+                FfiConverterUInt.write(value.`expected`, buf)
+                FfiConverterUInt.write(value.`found`, buf)
+                Unit
+            }
+            is MercuryException.StatechainInfoMismatch -> {
+                buf.putInt(55)
+                FfiConverterUInt.write(value.`txN`, buf)
+                FfiConverterUInt.write(value.`statechainTxN`, buf)
+                Unit
+            }
+            is MercuryException.BackupSpendsDifferentOutpoint -> {
+                buf.putInt(56)
+                FfiConverterUInt.write(value.`txN`, buf)
+                Unit
+            }
+            is MercuryException.LatestBackupLocktimeNotLowest -> {
+                buf.putInt(57)
+                FfiConverterUInt.write(value.`latestLocktime`, buf)
+                FfiConverterUInt.write(value.`txN`, buf)
+                FfiConverterUInt.write(value.`locktime`, buf)
+                Unit
+            }
+            is MercuryException.PsbtMissingSignature -> {
+                buf.putInt(58)
+                Unit
+            }
+            is MercuryException.PsbtMissingWitnessUtxo -> {
+                buf.putInt(59)
+                Unit
+            }
+            is MercuryException.WalletLocked -> {
+                buf.putInt(60)
                 Unit
             }
-            is MercuryException.InvalidSignature -> {
-                buf.putInt(34)
+            is MercuryException.WalletNotLocked -> {
+                buf.putInt(61)
                 Unit
             }
-            is MercuryException.EmptyWitness -> {
-                buf.putInt(35)
+            is MercuryException.IncorrectPassword -> {
+                buf.putInt(62)
                 Unit
             }
-            is MercuryException.EmptyWitnessData -> {
-                buf.putInt(36)
+            is MercuryException.EncryptionException -> {
+                buf.putInt(63)
                 Unit
             }
-            is MercuryException.IncorrectChallenge -> {
-                buf.putInt(37)
+            is MercuryException.InvalidMnemonicWordCount -> {
+                buf.putInt(64)
+                FfiConverterUInt.write(value.`wordCount`, buf)
                 Unit
             }
-            is MercuryException.InvalidT1 -> {
-                buf.putInt(38)
+            is MercuryException.PassphraseRequired -> {
+                buf.putInt(65)
                 Unit
             }
-            is MercuryException.IncorrectAggregatedPublicKey -> {
-                buf.putInt(39)
+            is MercuryException.IncorrectPassphrase -> {
+                buf.putInt(66)
                 Unit
             }
-            is MercuryException.T1MustBeExactly32BytesException -> {
-                buf.putInt(40)
+            is MercuryException.RecoveredStatecoinKeyMismatch -> {
+                buf.putInt(67)
                 Unit
             }
-            is MercuryException.NoX1Pub -> {
-                buf.putInt(41)
+            is MercuryException.InvalidWalletBackup -> {
+                buf.putInt(68)
+                FfiConverterString.write(value.`reason`, buf)
                 Unit
             }
-            is MercuryException.NoAggregatedPubkeyException -> {
-                buf.putInt(42)
+            is MercuryException.UnsupportedWalletBackupVersion -> {
+                buf.putInt(69)
+                FfiConverterUInt.write(value.`version`, buf)
+                FfiConverterUInt.write(value.`supportedVersion`, buf)
                 Unit
             }
-            is MercuryException.CoinNotFound -> {
-                buf.putInt(43)
+            is MercuryException.FeeExceedsAmount -> {
+                buf.putInt(70)
+                FfiConverterULong.write(value.`fee`, buf)
+                FfiConverterULong.write(value.`amount`, buf)
                 Unit
             }
-            is MercuryException.SignatureSchemeValidationException -> {
-                buf.putInt(44)
+            is MercuryException.SignerKeyMismatch -> {
+                buf.putInt(71)
                 Unit
             }
-            is MercuryException.NoPreviousLockTimeException -> {
-                buf.putInt(45)
+            is MercuryException.KeyDerivationMismatch -> {
+                buf.putInt(72)
                 Unit
             }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
+
+}
+
+
+
+
+enum class TokenStatus {
+    
+    PENDING,
+    PAID,
+    EXPIRED,
+    SPENT,
+    REFUNDED,
+    ;
+    companion object
+}
+
+
+public object FfiConverterTypeTokenStatus: FfiConverterRustBuffer<TokenStatus> {
+    override fun read(buf: ByteBuffer) = try {
+        TokenStatus.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: TokenStatus) = 4UL
+
+    override fun write(value: TokenStatus, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class TransferNotificationType {
+    
+    /**
+     * An encrypted transfer message is available for the auth key
+     */
+    TRANSFER_MSG,
+    /**
+     * All coins of the batch the transfer belongs to have been unlocked
+     */
+    BATCH_UNLOCKED,
+    /**
+     * The server key share of the statecoin has been updated to the receiver
+     */
+    KEY_UPDATED,
+    ;
+    companion object
+}
+
+
+public object FfiConverterTypeTransferNotificationType: FfiConverterRustBuffer<TransferNotificationType> {
+    override fun read(buf: ByteBuffer) = try {
+        TransferNotificationType.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: TransferNotificationType) = 4UL
+
+    override fun write(value: TransferNotificationType, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
 }
 
+
+
+
+
+
 @Serializable
 enum class TransferReceiverError {
+    
 	@SerialName("StatecoinBatchLockedError")
     STATECOIN_BATCH_LOCKED_ERROR,
 	@SerialName("ExpiredBatchTimeError")
     EXPIRED_BATCH_TIME_ERROR,
     ;
-
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverError : FfiConverterRustBuffer<TransferReceiverError> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            TransferReceiverError.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+
+public object FfiConverterTypeTransferReceiverError: FfiConverterRustBuffer<TransferReceiverError> {
+    override fun read(buf: ByteBuffer) = try {
+        TransferReceiverError.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: TransferReceiverError) = 4UL
 
-    override fun write(
-        value: TransferReceiverError,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: TransferReceiverError, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
-public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
+
+
+
+
+
+public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
     override fun read(buf: ByteBuffer): kotlin.UInt? {
         if (buf.get().toInt() == 0) {
             return null
@@ -3856,10 +5640,7 @@ public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
         }
     }
 
-    override fun write(
-        value: kotlin.UInt?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -3869,7 +5650,68 @@ public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
     }
 }
 
-public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?> {
+
+
+
+public object FfiConverterOptionalULong: FfiConverterRustBuffer<kotlin.ULong?> {
+    override fun read(buf: ByteBuffer): kotlin.ULong? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterULong.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ULong?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterULong.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ULong?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterULong.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterOptionalDouble: FfiConverterRustBuffer<kotlin.Double?> {
+    override fun read(buf: ByteBuffer): kotlin.Double? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterDouble.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Double?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterDouble.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Double?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterDouble.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
     override fun read(buf: ByteBuffer): kotlin.String? {
         if (buf.get().toInt() == 0) {
             return null
@@ -3885,10 +5727,7 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
         }
     }
 
-    override fun write(
-        value: kotlin.String?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -3898,7 +5737,68 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
     }
 }
 
-public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.String>> {
+
+
+
+public object FfiConverterOptionalTypeEncryptedSecrets: FfiConverterRustBuffer<EncryptedSecrets?> {
+    override fun read(buf: ByteBuffer): EncryptedSecrets? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeEncryptedSecrets.read(buf)
+    }
+
+    override fun allocationSize(value: EncryptedSecrets?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeEncryptedSecrets.allocationSize(value)
+        }
+    }
+
+    override fun write(value: EncryptedSecrets?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeEncryptedSecrets.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterOptionalTypeTokenStatus: FfiConverterRustBuffer<TokenStatus?> {
+    override fun read(buf: ByteBuffer): TokenStatus? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeTokenStatus.read(buf)
+    }
+
+    override fun allocationSize(value: TokenStatus?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeTokenStatus.allocationSize(value)
+        }
+    }
+
+    override fun write(value: TokenStatus?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeTokenStatus.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
     override fun read(buf: ByteBuffer): List<kotlin.String> {
         val len = buf.getInt()
         return List<kotlin.String>(len) {
@@ -3906,124 +5806,224 @@ public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.St
         }
     }
 
-    override fun allocationSize(value: List<kotlin.String>): ULong {
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeActivity: FfiConverterRustBuffer<List<Activity>> {
+    override fun read(buf: ByteBuffer): List<Activity> {
+        val len = buf.getInt()
+        return List<Activity>(len) {
+            FfiConverterTypeActivity.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Activity>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeActivity.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Activity>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeActivity.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeAuthKeyProof: FfiConverterRustBuffer<List<AuthKeyProof>> {
+    override fun read(buf: ByteBuffer): List<AuthKeyProof> {
+        val len = buf.getInt()
+        return List<AuthKeyProof>(len) {
+            FfiConverterTypeAuthKeyProof.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<AuthKeyProof>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeAuthKeyProof.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<AuthKeyProof>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeAuthKeyProof.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeBackupTx: FfiConverterRustBuffer<List<BackupTx>> {
+    override fun read(buf: ByteBuffer): List<BackupTx> {
+        val len = buf.getInt()
+        return List<BackupTx>(len) {
+            FfiConverterTypeBackupTx.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<BackupTx>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBackupTx.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<BackupTx>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBackupTx.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeBackupTxReport: FfiConverterRustBuffer<List<BackupTxReport>> {
+    override fun read(buf: ByteBuffer): List<BackupTxReport> {
+        val len = buf.getInt()
+        return List<BackupTxReport>(len) {
+            FfiConverterTypeBackupTxReport.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<BackupTxReport>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeBackupTxReport.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<kotlin.String>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<BackupTxReport>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterString.write(it, buf)
+            FfiConverterTypeBackupTxReport.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeActivity : FfiConverterRustBuffer<List<Activity>> {
-    override fun read(buf: ByteBuffer): List<Activity> {
+
+
+
+public object FfiConverterSequenceTypeCoin: FfiConverterRustBuffer<List<Coin>> {
+    override fun read(buf: ByteBuffer): List<Coin> {
         val len = buf.getInt()
-        return List<Activity>(len) {
-            FfiConverterTypeActivity.read(buf)
+        return List<Coin>(len) {
+            FfiConverterTypeCoin.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<Activity>): ULong {
+    override fun allocationSize(value: List<Coin>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeActivity.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeCoin.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<Activity>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<Coin>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeActivity.write(it, buf)
+            FfiConverterTypeCoin.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeBackupTx : FfiConverterRustBuffer<List<BackupTx>> {
-    override fun read(buf: ByteBuffer): List<BackupTx> {
+
+
+
+public object FfiConverterSequenceTypePubKeyInfo: FfiConverterRustBuffer<List<PubKeyInfo>> {
+    override fun read(buf: ByteBuffer): List<PubKeyInfo> {
         val len = buf.getInt()
-        return List<BackupTx>(len) {
-            FfiConverterTypeBackupTx.read(buf)
+        return List<PubKeyInfo>(len) {
+            FfiConverterTypePubKeyInfo.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<BackupTx>): ULong {
+    override fun allocationSize(value: List<PubKeyInfo>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeBackupTx.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypePubKeyInfo.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<BackupTx>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<PubKeyInfo>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeBackupTx.write(it, buf)
+            FfiConverterTypePubKeyInfo.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeCoin : FfiConverterRustBuffer<List<Coin>> {
-    override fun read(buf: ByteBuffer): List<Coin> {
+
+
+
+public object FfiConverterSequenceTypeRecoveredStatecoin: FfiConverterRustBuffer<List<RecoveredStatecoin>> {
+    override fun read(buf: ByteBuffer): List<RecoveredStatecoin> {
         val len = buf.getInt()
-        return List<Coin>(len) {
-            FfiConverterTypeCoin.read(buf)
+        return List<RecoveredStatecoin>(len) {
+            FfiConverterTypeRecoveredStatecoin.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<Coin>): ULong {
+    override fun allocationSize(value: List<RecoveredStatecoin>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeCoin.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeRecoveredStatecoin.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<Coin>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<RecoveredStatecoin>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeCoin.write(it, buf)
+            FfiConverterTypeRecoveredStatecoin.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypePubKeyInfo : FfiConverterRustBuffer<List<PubKeyInfo>> {
-    override fun read(buf: ByteBuffer): List<PubKeyInfo> {
+
+
+
+public object FfiConverterSequenceTypeStatechainBackupTxs: FfiConverterRustBuffer<List<StatechainBackupTxs>> {
+    override fun read(buf: ByteBuffer): List<StatechainBackupTxs> {
         val len = buf.getInt()
-        return List<PubKeyInfo>(len) {
-            FfiConverterTypePubKeyInfo.read(buf)
+        return List<StatechainBackupTxs>(len) {
+            FfiConverterTypeStatechainBackupTxs.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<PubKeyInfo>): ULong {
+    override fun allocationSize(value: List<StatechainBackupTxs>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypePubKeyInfo.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeStatechainBackupTxs.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<PubKeyInfo>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<StatechainBackupTxs>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypePubKeyInfo.write(it, buf)
+            FfiConverterTypeStatechainBackupTxs.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeStatechainInfo : FfiConverterRustBuffer<List<StatechainInfo>> {
+
+
+
+public object FfiConverterSequenceTypeStatechainInfo: FfiConverterRustBuffer<List<StatechainInfo>> {
     override fun read(buf: ByteBuffer): List<StatechainInfo> {
         val len = buf.getInt()
         return List<StatechainInfo>(len) {
@@ -4037,10 +6037,7 @@ public object FfiConverterSequenceTypeStatechainInfo : FfiConverterRustBuffer<Li
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<StatechainInfo>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<StatechainInfo>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeStatechainInfo.write(it, buf)
@@ -4048,7 +6045,10 @@ public object FfiConverterSequenceTypeStatechainInfo : FfiConverterRustBuffer<Li
     }
 }
 
-public object FfiConverterSequenceTypeToken : FfiConverterRustBuffer<List<Token>> {
+
+
+
+public object FfiConverterSequenceTypeToken: FfiConverterRustBuffer<List<Token>> {
     override fun read(buf: ByteBuffer): List<Token> {
         val len = buf.getInt()
         return List<Token>(len) {
@@ -4062,10 +6062,7 @@ public object FfiConverterSequenceTypeToken : FfiConverterRustBuffer<List<Token>
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<Token>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<Token>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeToken.write(it, buf)
@@ -4073,539 +6070,679 @@ public object FfiConverterSequenceTypeToken : FfiConverterRustBuffer<List<Token>
     }
 }
 
-@Throws(MercuryException::class)
-fun `createAggregatedAddress`(
-    `coin`: Coin,
-    `network`: kotlin.String,
-): AggregatedPublicKey {
-    return FfiConverterTypeAggregatedPublicKey.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_aggregated_address(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
-    )
-}
 
-@Throws(MercuryException::class)
-fun `createAndCommitNonces`(`coin`: Coin): CoinNonce {
-    return FfiConverterTypeCoinNonce.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_and_commit_nonces(
-                FfiConverterTypeCoin.lower(`coin`),
-                _status,
-            )
-        },
-    )
+
+
+public object FfiConverterSequenceTypeTransferCancelRequestPayload: FfiConverterRustBuffer<List<TransferCancelRequestPayload>> {
+    override fun read(buf: ByteBuffer): List<TransferCancelRequestPayload> {
+        val len = buf.getInt()
+        return List<TransferCancelRequestPayload>(len) {
+            FfiConverterTypeTransferCancelRequestPayload.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<TransferCancelRequestPayload>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeTransferCancelRequestPayload.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<TransferCancelRequestPayload>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeTransferCancelRequestPayload.write(it, buf)
+        }
+    }
 }
 
-@Throws(MercuryException::class)
-fun `createCpfpTx`(
-    `backupTx`: BackupTx,
-    `coin`: Coin,
-    `toAddress`: kotlin.String,
-    `feeRateSatsPerByte`: kotlin.ULong,
-    `network`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_cpfp_tx(
-                FfiConverterTypeBackupTx.lower(`backupTx`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`toAddress`),
-                FfiConverterULong.lower(`feeRateSatsPerByte`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+
+
+public object FfiConverterMapStringSequenceString: FfiConverterRustBuffer<Map<kotlin.String, List<kotlin.String>>> {
+    override fun read(buf: ByteBuffer): Map<kotlin.String, List<kotlin.String>> {
+        val len = buf.getInt()
+        return buildMap<kotlin.String, List<kotlin.String>>(len) {
+            repeat(len) {
+                val k = FfiConverterString.read(buf)
+                val v = FfiConverterSequenceString.read(buf)
+                this[k] = v
+            }
+        }
+    }
+
+    override fun allocationSize(value: Map<kotlin.String, List<kotlin.String>>): ULong {
+        val spaceForMapSize = 4UL
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterSequenceString.allocationSize(v)
+        }.sum()
+        return spaceForMapSize + spaceForChildren
+    }
+
+    override fun write(value: Map<kotlin.String, List<kotlin.String>>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        // The parens on `(k, v)` here ensure we're calling the right method,
+        // which is important for compatibility with older android devices.
+        // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
+        value.forEach { (k, v) ->
+            FfiConverterString.write(k, buf)
+            FfiConverterSequenceString.write(v, buf)
+        }
+    }
+}
+        /**
+         * Blinds the token server nonce and computes the challenge for a new random token id.
+         * The signer public key is x-only, the nonce is a compressed public key (hex).
+         */
+    @Throws(MercuryException::class) fun `blindToken`(`signerPubkey`: kotlin.String, `publicNonce`: kotlin.String): BlindedToken {
+            return FfiConverterTypeBlindedToken.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_blind_token(
+        FfiConverterString.lower(`signerPubkey`),FfiConverterString.lower(`publicNonce`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Re-encrypts the secrets of a locked wallet with a new password
+         */
+    @Throws(MercuryException::class) fun `changeWalletPassword`(`wallet`: Wallet, `oldPassword`: kotlin.String, `newPassword`: kotlin.String): Wallet {
+            return FfiConverterTypeWallet.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_change_wallet_password(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`oldPassword`),FfiConverterString.lower(`newPassword`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createDepositMsg1`(
-    `coin`: Coin,
-    `tokenId`: kotlin.String,
-): DepositMsg1 {
-    return FfiConverterTypeDepositMsg1.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_deposit_msg1(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`tokenId`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createAggregatedAddress`(`coin`: Coin, `network`: kotlin.String): AggregatedPublicKey {
+            return FfiConverterTypeAggregatedPublicKey.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_aggregated_address(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createAndCommitNonces`(`coin`: Coin): CoinNonce {
+            return FfiConverterTypeCoinNonce.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_and_commit_nonces(
+        FfiConverterTypeCoin.lower(`coin`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createSignature`(
-    `msg`: kotlin.String,
-    `clientPartialSigHex`: kotlin.String,
-    `serverPartialSigHex`: kotlin.String,
-    `sessionHex`: kotlin.String,
-    `outputPubkeyHex`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_signature(
-                FfiConverterString.lower(`msg`),
-                FfiConverterString.lower(`clientPartialSigHex`),
-                FfiConverterString.lower(`serverPartialSigHex`),
-                FfiConverterString.lower(`sessionHex`),
-                FfiConverterString.lower(`outputPubkeyHex`),
-                _status,
-            )
-        },
+        /**
+         * Proves the ownership of the auth key of the coin by signing a challenge issued by the server
+         */
+    @Throws(MercuryException::class) fun `createAuthKeyProof`(`coin`: Coin, `challenge`: kotlin.String): AuthKeyProof {
+            return FfiConverterTypeAuthKeyProof.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_auth_key_proof(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`challenge`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Deposit message of a blind token, with the unblinded token server signature on the token id
+         */
+    @Throws(MercuryException::class) fun `createBlindDepositMsg1`(`coin`: Coin, `tokenId`: kotlin.String, `blindSignature`: kotlin.String): DepositMsg1 {
+            return FfiConverterTypeDepositMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_blind_deposit_msg1(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`tokenId`),FfiConverterString.lower(`blindSignature`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createTransferSignature`(
-    `recipientAddress`: kotlin.String,
-    `inputTxid`: kotlin.String,
-    `inputVout`: kotlin.UInt,
-    `clientSeckey`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_signature(
-                FfiConverterString.lower(`recipientAddress`),
-                FfiConverterString.lower(`inputTxid`),
-                FfiConverterUInt.lower(`inputVout`),
-                FfiConverterString.lower(`clientSeckey`),
-                _status,
-            )
-        },
+        /**
+         * Exports the unsigned CPFP transaction as a base64 PSBT, to be signed with the user key and imported with `import_signed_psbt`
+         */
+    @Throws(MercuryException::class) fun `createCpfpPsbt`(`backupTx`: BackupTx, `coin`: Coin, `toAddress`: kotlin.String, `feeRateSatsPerByte`: kotlin.Double, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_cpfp_psbt(
+        FfiConverterTypeBackupTx.lower(`backupTx`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`toAddress`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createCpfpTx`(`backupTx`: BackupTx, `coin`: Coin, `toAddress`: kotlin.String, `feeRateSatsPerByte`: kotlin.Double, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_cpfp_tx(
+        FfiConverterTypeBackupTx.lower(`backupTx`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`toAddress`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createTransferUpdateMsg`(
-    `x1`: kotlin.String,
-    `recipientAddress`: kotlin.String,
-    `coin`: Coin,
-    `transferSignature`: kotlin.String,
-    `backupTransactions`: List<BackupTx>,
-): TransferUpdateMsgRequestPayload {
-    return FfiConverterTypeTransferUpdateMsgRequestPayload.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_update_msg(
-                FfiConverterString.lower(`x1`),
-                FfiConverterString.lower(`recipientAddress`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`transferSignature`),
-                FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createDepositMsg1`(`coin`: Coin, `tokenId`: kotlin.String): DepositMsg1 {
+            return FfiConverterTypeDepositMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_deposit_msg1(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`tokenId`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createGetMsgAddrBulkRequestPayload`(`coins`: List<Coin>, `challenge`: kotlin.String): GetMsgAddrBulkRequestPayload {
+            return FfiConverterTypeGetMsgAddrBulkRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_get_msg_addr_bulk_request_payload(
+        FfiConverterSequenceTypeCoin.lower(`coins`),FfiConverterString.lower(`challenge`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `decodeStatechainAddress`(`scAddress`: kotlin.String): DecodedScAddress {
-    return FfiConverterTypeDecodedSCAddress.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_decode_statechain_address(
-                FfiConverterString.lower(`scAddress`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createRecoverStatecoinsRequestPayload`(`coins`: List<Coin>, `challenge`: kotlin.String): RecoverStatecoinsRequestPayload {
+            return FfiConverterTypeRecoverStatecoinsRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_recover_statecoins_request_payload(
+        FfiConverterSequenceTypeCoin.lower(`coins`),FfiConverterString.lower(`challenge`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createSignature`(`msg`: kotlin.String, `clientPartialSigHex`: kotlin.String, `serverPartialSigHex`: kotlin.String, `sessionHex`: kotlin.String, `outputPubkeyHex`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_signature(
+        FfiConverterString.lower(`msg`),FfiConverterString.lower(`clientPartialSigHex`),FfiConverterString.lower(`serverPartialSigHex`),FfiConverterString.lower(`sessionHex`),FfiConverterString.lower(`outputPubkeyHex`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `duplicateCoinToInitializedState`(
-    `wallet`: Wallet,
-    `authPubkey`: kotlin.String,
-): Coin {
-    return FfiConverterTypeCoin.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(
-                FfiConverterTypeWallet.lower(`wallet`),
-                FfiConverterString.lower(`authPubkey`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createTransferNotificationRequestPayload`(`coins`: List<Coin>, `challenge`: kotlin.String): TransferNotificationRequestPayload {
+            return FfiConverterTypeTransferNotificationRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_notification_request_payload(
+        FfiConverterSequenceTypeCoin.lower(`coins`),FfiConverterString.lower(`challenge`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createTransferSignature`(`recipientAddress`: kotlin.String, `inputTxid`: kotlin.String, `inputVout`: kotlin.UInt, `clientSeckey`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_signature(
+        FfiConverterString.lower(`recipientAddress`),FfiConverterString.lower(`inputTxid`),FfiConverterUInt.lower(`inputVout`),FfiConverterString.lower(`clientSeckey`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `ffiValidateSignatureScheme`(
-    `ffiTransferMsg`: FfiTransferMsg,
-    `statechainInfo`: StatechainInfoResponsePayload,
-    `tx0Hex`: kotlin.String,
-    `feeRateTolerance`: kotlin.UInt,
-    `currentFeeRateSatsPerByte`: kotlin.UInt,
-    `interval`: kotlin.UInt,
-): kotlin.UInt {
-    return FfiConverterUInt.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_validate_signature_scheme(
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterUInt.lower(`feeRateTolerance`),
-                FfiConverterUInt.lower(`currentFeeRateSatsPerByte`),
-                FfiConverterUInt.lower(`interval`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createTransferUpdateMsg`(`x1`: kotlin.String, `recipientAddress`: kotlin.String, `coin`: Coin, `transferSignature`: kotlin.String, `backupTransactions`: List<BackupTx>): TransferUpdateMsgRequestPayload {
+            return FfiConverterTypeTransferUpdateMsgRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_update_msg(
+        FfiConverterString.lower(`x1`),FfiConverterString.lower(`recipientAddress`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`transferSignature`),FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `decodeStatechainAddress`(`scAddress`: kotlin.String): DecodedScAddress {
+            return FfiConverterTypeDecodedSCAddress.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_decode_statechain_address(
+        FfiConverterString.lower(`scAddress`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `ffiVerifyTransferSignature`(
-    `newUserPubkey`: kotlin.String,
-    `tx0Outpoint`: TxOutpoint,
-    `ffiTransferMsg`: FfiTransferMsg,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(
-                FfiConverterString.lower(`newUserPubkey`),
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                _status,
-            )
-        },
+        /**
+         * Derives the coins of `count` consecutive address indexes starting at `start_index`
+         */
+    @Throws(MercuryException::class) fun `deriveRecoveryCoins`(`wallet`: Wallet, `startIndex`: kotlin.UInt, `count`: kotlin.UInt, `passphrase`: kotlin.String): List<Coin> {
+            return FfiConverterSequenceTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_derive_recovery_coins(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterUInt.lower(`startIndex`),FfiConverterUInt.lower(`count`),FfiConverterString.lower(`passphrase`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `duplicateCoinToInitializedState`(`wallet`: Wallet, `authPubkey`: kotlin.String): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`authPubkey`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiCreateTransferReceiverRequestPayload`(
-    `statechainInfo`: StatechainInfoResponsePayload,
-    `ffiTransferMsg`: FfiTransferMsg,
-    `coin`: Coin,
-): TransferReceiverRequestPayload {
-    return FfiConverterTypeTransferReceiverRequestPayload.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(
-                FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterTypeCoin.lower(`coin`),
-                _status,
-            )
-        },
+        /**
+         * Encrypts the wallet and its backup transactions with the password, returning the content of the backup file
+         */
+    @Throws(MercuryException::class) fun `exportWalletBackup`(`wallet`: Wallet, `backupTxs`: List<StatechainBackupTxs>, `password`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_export_wallet_backup(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterSequenceTypeStatechainBackupTxs.lower(`backupTxs`),FfiConverterString.lower(`password`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `ffiValidateBackupChain`(`ffiTransferMsg`: FfiTransferMsg, `statechainInfo`: StatechainInfoResponsePayload, `tx0Hex`: kotlin.String, `feeRateTolerance`: kotlin.Double, `currentFeeRateSatsPerByte`: kotlin.Double, `interval`: kotlin.UInt, `network`: kotlin.String): BackupChainReport {
+            return FfiConverterTypeBackupChainReport.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_validate_backup_chain(
+        FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),FfiConverterString.lower(`tx0Hex`),FfiConverterDouble.lower(`feeRateTolerance`),FfiConverterDouble.lower(`currentFeeRateSatsPerByte`),FfiConverterUInt.lower(`interval`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiDecryptTransferMsg`(
-    `encryptedMessage`: kotlin.String,
-    `privateKeyWif`: kotlin.String,
-): FfiTransferMsg {
-    return FfiConverterTypeFFITransferMsg.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(
-                FfiConverterString.lower(`encryptedMessage`),
-                FfiConverterString.lower(`privateKeyWif`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `ffiValidateSignatureScheme`(`ffiTransferMsg`: FfiTransferMsg, `statechainInfo`: StatechainInfoResponsePayload, `tx0Hex`: kotlin.String, `feeRateTolerance`: kotlin.Double, `currentFeeRateSatsPerByte`: kotlin.Double, `interval`: kotlin.UInt): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_validate_signature_scheme(
+        FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),FfiConverterString.lower(`tx0Hex`),FfiConverterDouble.lower(`feeRateTolerance`),FfiConverterDouble.lower(`currentFeeRateSatsPerByte`),FfiConverterUInt.lower(`interval`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `ffiVerifyTransferSignature`(`newUserPubkey`: kotlin.String, `tx0Outpoint`: TxOutpoint, `ffiTransferMsg`: FfiTransferMsg): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(
+        FfiConverterString.lower(`newUserPubkey`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiValidateTx0OutputPubkey`(
-    `enclavePublicKey`: kotlin.String,
-    `ffiTransferMsg`: FfiTransferMsg,
-    `tx0Outpoint`: TxOutpoint,
-    `tx0Hex`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(
-                FfiConverterString.lower(`enclavePublicKey`),
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `fiiCreateTransferReceiverRequestPayload`(`statechainInfo`: StatechainInfoResponsePayload, `ffiTransferMsg`: FfiTransferMsg, `coin`: Coin): TransferReceiverRequestPayload {
+            return FfiConverterTypeTransferReceiverRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(
+        FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterTypeCoin.lower(`coin`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `fiiDecryptTransferMsg`(`encryptedMessage`: kotlin.String, `privateKeyWif`: kotlin.String): FfiTransferMsg {
+            return FfiConverterTypeFFITransferMsg.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(
+        FfiConverterString.lower(`encryptedMessage`),FfiConverterString.lower(`privateKeyWif`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiVerifyLatestBackupTxPaysToUserPubkey`(
-    `ffiTransferMsg`: FfiTransferMsg,
-    `clientPubkeyShare`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterString.lower(`clientPubkeyShare`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `fiiValidateTx0OutputPubkey`(`enclavePublicKey`: kotlin.String, `ffiTransferMsg`: FfiTransferMsg, `tx0Outpoint`: TxOutpoint, `tx0Hex`: kotlin.String, `network`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(
+        FfiConverterString.lower(`enclavePublicKey`),FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterString.lower(`tx0Hex`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `fiiVerifyLatestBackupTxPaysToUserPubkey`(`ffiTransferMsg`: FfiTransferMsg, `clientPubkeyShare`: kotlin.String, `network`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
+        FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterString.lower(`clientPubkeyShare`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `generateMnemonic`(): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_generate_mnemonic(
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `generateMnemonic`(): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_generate_mnemonic(
+        _status)
+}
     )
+    }
+    
+
+        /**
+         * Generates a mnemonic of 12, 15, 18, 21 or 24 words
+         */
+    @Throws(MercuryException::class) fun `generateMnemonicWithWordCount`(`wordCount`: kotlin.UInt): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_generate_mnemonic_with_word_count(
+        FfiConverterUInt.lower(`wordCount`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getBlockheight`(`bkpTx`: BackupTx): kotlin.UInt {
-    return FfiConverterUInt.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_blockheight(
-                FfiConverterTypeBackupTx.lower(`bkpTx`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getBlockheight`(`bkpTx`: BackupTx): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_blockheight(
+        FfiConverterTypeBackupTx.lower(`bkpTx`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getMasterFingerprint`(`wallet`: Wallet, `passphrase`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_master_fingerprint(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`passphrase`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getNewCoin`(`wallet`: Wallet): Coin {
-    return FfiConverterTypeCoin.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_coin(
-                FfiConverterTypeWallet.lower(`wallet`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getNewCoin`(`wallet`: Wallet): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_coin(
+        FfiConverterTypeWallet.lower(`wallet`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getNewCoinWithPassphrase`(`wallet`: Wallet, `passphrase`: kotlin.String): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_coin_with_passphrase(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`passphrase`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getNewKeyInfo`(
-    `serverPublicKeyHex`: kotlin.String,
-    `coin`: Coin,
-    `statechainId`: kotlin.String,
-    `tx0Outpoint`: TxOutpoint,
-    `tx0Hex`: kotlin.String,
-    `network`: kotlin.String,
-): NewKeyInfo {
-    return FfiConverterTypeNewKeyInfo.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_key_info(
-                FfiConverterString.lower(`serverPublicKeyHex`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`statechainId`),
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getNewKeyInfo`(`serverPublicKeyHex`: kotlin.String, `coin`: Coin, `statechainId`: kotlin.String, `tx0Outpoint`: TxOutpoint, `tx0Hex`: kotlin.String, `network`: kotlin.String): NewKeyInfo {
+            return FfiConverterTypeNewKeyInfo.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_key_info(
+        FfiConverterString.lower(`serverPublicKeyHex`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`statechainId`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterString.lower(`tx0Hex`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getOutputAddressFromTx0`(`tx0Outpoint`: TxOutpoint, `tx0Hex`: kotlin.String, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_output_address_from_tx0(
+        FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterString.lower(`tx0Hex`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getOutputAddressFromTx0`(
-    `tx0Outpoint`: TxOutpoint,
-    `tx0Hex`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_output_address_from_tx0(
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getPartialSigRequest`(`coin`: Coin, `blockHeight`: kotlin.UInt, `initlock`: kotlin.UInt, `interval`: kotlin.UInt, `feeRateSatsPerByte`: kotlin.Double, `qtBackupTx`: kotlin.UInt, `toAddress`: kotlin.String, `network`: kotlin.String, `isWithdrawal`: kotlin.Boolean): PartialSignatureMsg1 {
+            return FfiConverterTypePartialSignatureMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_partial_sig_request(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterUInt.lower(`blockHeight`),FfiConverterUInt.lower(`initlock`),FfiConverterUInt.lower(`interval`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterUInt.lower(`qtBackupTx`),FfiConverterString.lower(`toAddress`),FfiConverterString.lower(`network`),FfiConverterBoolean.lower(`isWithdrawal`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getTx0Outpoint`(`backupTransactions`: List<BackupTx>): TxOutpoint {
+            return FfiConverterTypeTxOutpoint.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_tx0_outpoint(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getPartialSigRequest`(
-    `coin`: Coin,
-    `blockHeight`: kotlin.UInt,
-    `initlock`: kotlin.UInt,
-    `interval`: kotlin.UInt,
-    `feeRateSatsPerByte`: kotlin.UInt,
-    `qtBackupTx`: kotlin.UInt,
-    `toAddress`: kotlin.String,
-    `network`: kotlin.String,
-    `isWithdrawal`: kotlin.Boolean,
-): PartialSignatureMsg1 {
-    return FfiConverterTypePartialSignatureMsg1.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_partial_sig_request(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterUInt.lower(`blockHeight`),
-                FfiConverterUInt.lower(`initlock`),
-                FfiConverterUInt.lower(`interval`),
-                FfiConverterUInt.lower(`feeRateSatsPerByte`),
-                FfiConverterUInt.lower(`qtBackupTx`),
-                FfiConverterString.lower(`toAddress`),
-                FfiConverterString.lower(`network`),
-                FfiConverterBoolean.lower(`isWithdrawal`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getUserBackupAddress`(`coin`: Coin, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_user_backup_address(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `handleDepositMsg1Response`(`coin`: Coin, `depositMsg1Response`: DepositMsg1Response): DepositInitResult {
+            return FfiConverterTypeDepositInitResult.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterTypeDepositMsg1Response.lower(`depositMsg1Response`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getTx0Outpoint`(`backupTransactions`: List<BackupTx>): TxOutpoint {
-    return FfiConverterTypeTxOutpoint.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_tx0_outpoint(
-                FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),
-                _status,
-            )
-        },
+        /**
+         * Verifies the key spend signature of every input against the output it spends, finalizes them and
+         * returns the signed transaction (hex)
+         */
+    @Throws(MercuryException::class) fun `importSignedPsbt`(`psbtBase64`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_import_signed_psbt(
+        FfiConverterString.lower(`psbtBase64`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Decrypts and verifies the content of a backup file
+         */
+    @Throws(MercuryException::class) fun `importWalletBackup`(`backupFile`: kotlin.String, `password`: kotlin.String): WalletBackup {
+            return FfiConverterTypeWalletBackup.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_import_wallet_backup(
+        FfiConverterString.lower(`backupFile`),FfiConverterString.lower(`password`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getUserBackupAddress`(
-    `coin`: Coin,
-    `network`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_user_backup_address(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `isEnclavePubkeyPartOfCoin`(`coin`: Coin, `enclavePubkey`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`enclavePubkey`),_status)
+}
     )
+    }
+    
+ fun `isWalletLocked`(`wallet`: Wallet): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_is_wallet_locked(
+        FfiConverterTypeWallet.lower(`wallet`),_status)
 }
-
-@Throws(MercuryException::class)
-fun `handleDepositMsg1Response`(
-    `coin`: Coin,
-    `depositMsg1Response`: DepositMsg1Response,
-): DepositInitResult {
-    return FfiConverterTypeDepositInitResult.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterTypeDepositMsg1Response.lower(`depositMsg1Response`),
-                _status,
-            )
-        },
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `latestBackupTxPaysToUserPubkey`(`backupTxs`: List<BackupTx>, `coin`: Coin, `network`: kotlin.String): BackupTx {
+            return FfiConverterTypeBackupTx.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTxs`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `isEnclavePubkeyPartOfCoin`(
-    `coin`: Coin,
-    `enclavePubkey`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`enclavePubkey`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `lockWallet`(`wallet`: Wallet, `password`: kotlin.String): Wallet {
+            return FfiConverterTypeWallet.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_lock_wallet(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`password`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `newBackupTransaction`(`encodedUnsignedTx`: kotlin.String, `signatureHex`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_new_backup_transaction(
+        FfiConverterString.lower(`encodedUnsignedTx`),FfiConverterString.lower(`signatureHex`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `latestBackupTxPaysToUserPubkey`(
-    `backupTxs`: List<BackupTx>,
-    `coin`: Coin,
-    `network`: kotlin.String,
-): BackupTx {
-    return FfiConverterTypeBackupTx.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(
-                FfiConverterSequenceTypeBackupTx.lower(`backupTxs`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+        /**
+         * Fills in the statechain and aggregated key of a derived coin owned by a recovered statecoin.
+         * The UTXO, amount and locktime are set by the caller from the blockchain and the backup transactions.
+         */
+    @Throws(MercuryException::class) fun `recoverCoin`(`coin`: Coin, `recoveredStatecoin`: RecoveredStatecoin, `network`: kotlin.String): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_recover_coin(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterTypeRecoveredStatecoin.lower(`recoveredStatecoin`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `signAuthChallenge`(`challenge`: kotlin.String, `coin`: Coin): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_auth_challenge(
+        FfiConverterString.lower(`challenge`),FfiConverterTypeCoin.lower(`coin`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `newBackupTransaction`(
-    `encodedUnsignedTx`: kotlin.String,
-    `signatureHex`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_new_backup_transaction(
-                FfiConverterString.lower(`encodedUnsignedTx`),
-                FfiConverterString.lower(`signatureHex`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `signMessage`(`message`: kotlin.String, `coin`: Coin): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_message(
+        FfiConverterString.lower(`message`),FfiConverterTypeCoin.lower(`coin`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Signs the outcome of an event with the oracle key (hex). Used by oracles run locally.
+         */
+    @Throws(MercuryException::class) fun `signOracleAttestation`(`eventId`: kotlin.String, `outcome`: kotlin.String, `oracleSeckey`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_oracle_attestation(
+        FfiConverterString.lower(`eventId`),FfiConverterString.lower(`outcome`),FfiConverterString.lower(`oracleSeckey`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `signMessage`(
-    `message`: kotlin.String,
-    `coin`: Coin,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_message(
-                FfiConverterString.lower(`message`),
-                FfiConverterTypeCoin.lower(`coin`),
-                _status,
-            )
-        },
+        /**
+         * Unblinds the token server signature into a BIP340 signature on the token id, which is verified before being returned
+         */
+    @Throws(MercuryException::class) fun `unblindTokenSignature`(`blindedToken`: BlindedToken, `blindSignature`: kotlin.String, `signerPubkey`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_unblind_token_signature(
+        FfiConverterTypeBlindedToken.lower(`blindedToken`),FfiConverterString.lower(`blindSignature`),FfiConverterString.lower(`signerPubkey`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `unlockWallet`(`wallet`: Wallet, `password`: kotlin.String): Wallet {
+            return FfiConverterTypeWallet.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_unlock_wallet(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`password`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `validateAddress`(
-    `address`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_validate_address(
-                FfiConverterString.lower(`address`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `validateAddress`(`address`: kotlin.String, `network`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_validate_address(
+        FfiConverterString.lower(`address`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Checks that the transfer message carries the complete backup chain signed by the server: one backup per signature,
+         * numbered from 1 in order, all spending the same Tx0 outpoint, with the latest one having the lowest locktime.
+         */
+    @Throws(MercuryException::class) fun `verifyBackupChain`(`backupTransactions`: List<BackupTx>, `statechainInfo`: StatechainInfoResponsePayload)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_backup_chain(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),_status)
+}
+    
+    
+
+        /**
+         * Checks that the token id was signed by the token server (x-only public key)
+         */ fun `verifyBlindToken`(`signerPubkey`: kotlin.String, `tokenId`: kotlin.String, `signature`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_blind_token(
+        FfiConverterString.lower(`signerPubkey`),FfiConverterString.lower(`tokenId`),FfiConverterString.lower(`signature`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `verifyBlindedMusigScheme`(
-    `backupTx`: BackupTx,
-    `tx0Hex`: kotlin.String,
-    `statechainInfo`: StatechainInfo,
-) = uniffiRustCallWithError(MercuryException) { _status ->
+    @Throws(MercuryException::class) fun `verifyBlindedMusigScheme`(`backupTx`: BackupTx, `tx0Hex`: kotlin.String, `statechainInfo`: StatechainInfo)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
     UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_blinded_musig_scheme(
-        FfiConverterTypeBackupTx.lower(`backupTx`),
-        FfiConverterString.lower(`tx0Hex`),
-        FfiConverterTypeStatechainInfo.lower(`statechainInfo`),
-        _status,
-    )
+        FfiConverterTypeBackupTx.lower(`backupTx`),FfiConverterString.lower(`tx0Hex`),FfiConverterTypeStatechainInfo.lower(`statechainInfo`),_status)
 }
+    
+    
 
-@Throws(MercuryException::class)
-fun `verifyTransactionSignature`(
-    `txNHex`: kotlin.String,
-    `tx0Hex`: kotlin.String,
-    `feeRateTolerance`: kotlin.UInt,
-    `currentFeeRateSatsPerByte`: kotlin.UInt,
-) = uniffiRustCallWithError(MercuryException) { _status ->
-    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_transaction_signature(
-        FfiConverterString.lower(`txNHex`),
-        FfiConverterString.lower(`tx0Hex`),
-        FfiConverterUInt.lower(`feeRateTolerance`),
-        FfiConverterUInt.lower(`currentFeeRateSatsPerByte`),
-        _status,
+        /**
+         * Checks that the oracle (x-only public key) attested the outcome of the event
+         */ fun `verifyOracleAttestation`(`oraclePubKey`: kotlin.String, `eventId`: kotlin.String, `outcome`: kotlin.String, `signature`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_oracle_attestation(
+        FfiConverterString.lower(`oraclePubKey`),FfiConverterString.lower(`eventId`),FfiConverterString.lower(`outcome`),FfiConverterString.lower(`signature`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `verifyTransactionSignature`(`txNHex`: kotlin.String, `tx0Hex`: kotlin.String, `feeRateTolerance`: kotlin.Double, `currentFeeRateSatsPerByte`: kotlin.Double)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_transaction_signature(
+        FfiConverterString.lower(`txNHex`),FfiConverterString.lower(`tx0Hex`),FfiConverterDouble.lower(`feeRateTolerance`),FfiConverterDouble.lower(`currentFeeRateSatsPerByte`),_status)
 }
+    
+    
+
+
@@ -4,6 +4,7 @@ const load = () => {
     return {
         confirmationTarget: config.get('confirmationTarget'),
         statechainEntity: config.get('statechainEntity'),
+        tokenServer: config.has('tokenServer') ? config.get('tokenServer') : null,
         torProxy: config.get('torProxy'),
         databaseFile: config.get('databaseFile'),
         electrumServer: config.get('electrumServer'),
@@ -1,6 +1,6 @@
-use std::{thread, time::Duration};
+use std::{io, thread, time::Duration};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::{Parser, Subcommand};
 use serde_json::json;
 
@@ -8,6 +8,9 @@ use serde_json::json;
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 struct Cli {
+    /// Read the BIP39 passphrase of the wallet seed from the standard input (defaults to the ML_WALLET_PASSPHRASE environment variable)
+    #[arg(long, global = true)]
+    passphrase: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -17,12 +20,43 @@ enum Commands {
     /// Create a wallet
     CreateWallet { 
         /// The name of the wallet to create
-        name: String 
+        name: String,
+        /// Number of words of the mnemonic (12, 15, 18, 21 or 24)
+        #[arg(long, default_value_t = 12)]
+        words: u32,
+    },
+    /// Create a wallet from a mnemonic read from the standard input and recover the statecoins it owns
+    RecoverWallet {
+        name: String,
+        /// Number of consecutive unused address indexes after which the scan stops
+        #[arg(short, long, default_value_t = 20)]
+        gap_limit: u32,
+        /// JSON file with the backup transactions of deposited statecoins, by statechain id
+        #[arg(short, long)]
+        backup_file: Option<String>,
+    },
+    /// Write the wallet and its backup transactions to a backup file encrypted with a password read from the standard input
+    ExportBackup {
+        wallet_name: String,
+        file: String,
+    },
+    /// Restore a wallet and its backup transactions from a backup file. The wallet is stored encrypted with the backup password.
+    ImportBackup {
+        file: String,
     },
     /// Get new token.
     NewToken { },
+    /// Refresh the status and expiry of the wallet tokens
+    ListTokens { wallet_name: String },
     /// Get new deposit address. Used to fund a new statecoin.
-    NewDepositAddress { wallet_name: String, token_id: String, amount: u32 },
+    NewDepositAddress {
+        wallet_name: String,
+        token_id: String,
+        amount: u32,
+        /// Exchange the token for a blind token, so the deposit cannot be linked to the token payment
+        #[arg(long)]
+        blind: bool,
+    },
     /// Broadcast the backup transaction to the network
     BroadcastBackupTransaction { 
         wallet_name: String,
@@ -59,12 +93,35 @@ enum Commands {
         /// Batch id for atomic transfers
         batch_id: Option<String>,
     },
+    /// Send several statechain coins in a single atomic batch
+    TransferSendBatch {
+        wallet_name: String,
+        /// Transfers in the format <statechain_id>:<transfer_address>
+        #[arg(required = true)]
+        transfers: Vec<String>,
+        /// Force send (required when a coin is duplicated)
+        #[arg(short, long)]
+        force_send: bool,
+    },
+    /// Cancel a pending transfer that the receiver has not completed yet
+    TransferCancel {
+        wallet_name: String,
+        statechain_id: String,
+    },
     /// Send a statechain coin to a transfer address
-    TransferReceive { wallet_name: String },
+    TransferReceive {
+        wallet_name: String,
+        /// Wait for a server notification instead of returning when there is nothing to receive
+        #[arg(short='w', long)]
+        wait: bool,
+    },
     /// Create a payment hash for a lightning latch
     PaymentHash {
         wallet_name: String, 
         statechain_id: String, 
+        /// Requested latch expiry in seconds (bounded by the server limits)
+        #[arg(short, long)]
+        expiry: Option<u64>,
     },
     /// Confirm pending invoice
     ConfirmPendingInvoice {
@@ -77,10 +134,88 @@ enum Commands {
         statechain_id: String,
         batch_id: String,
     },
+    /// Reclaim a coin whose latch expired, sending it again to a new address of the wallet
+    ReclaimLatch {
+        wallet_name: String, 
+        statechain_id: String,
+        batch_id: String,
+    },
+    /// Latch a transfer on the attestation of an oracle to an outcome
+    ConditionalLatch {
+        wallet_name: String, 
+        statechain_id: String,
+        /// X-only public key of the oracle
+        oracle_pub_key: String,
+        /// Event announced by the oracle
+        event_id: String,
+        /// Outcome that releases the coin to the receiver
+        outcome: String,
+        /// Requested latch expiry in seconds (bounded by the server limits)
+        #[arg(short, long)]
+        expiry: Option<u64>,
+    },
+    /// Submit an oracle attestation for a conditional transfer
+    SubmitAttestation {
+        statechain_id: String,
+        batch_id: String,
+        outcome: String,
+        signature: String,
+    },
     /// Get the payment hash by batch id
     GetPaymentHash {
         batch_id: String,
+    },
+    /// Verify that a BOLT11 invoice pays the lightning latch of the batch
+    VerifyInvoice {
+        batch_id: String,
+        invoice: String,
+        /// Expected invoice amount in satoshis
+        #[arg(short, long)]
+        amount: Option<u64>,
+    },
+    /// Encrypt the mnemonic and coin private keys of the wallet with a password read from the standard input
+    SetPassword {
+        wallet_name: String,
+    },
+    /// Change the wallet password. The current and new passwords are read from the standard input.
+    ChangePassword {
+        wallet_name: String,
+    },
+    /// Show whether the wallet secrets are encrypted
+    LockStatus { wallet_name: String },
+}
+
+/// Reads a line from the standard input, after printing the prompt on the standard error.
+/// Secrets are not taken from the arguments, so that they do not end up in the shell history or the process list.
+fn read_secret(prompt: &str) -> Result<String> {
+
+    eprint!("{}: ", prompt);
+
+    let mut secret = String::new();
+    io::stdin().read_line(&mut secret)?;
+
+    let secret = secret.trim_end_matches(['\r', '\n']).to_string();
+
+    if secret.is_empty() {
+        return Err(anyhow!("{} cannot be empty", prompt));
     }
+
+    Ok(secret)
+}
+
+fn read_wallet_password(wallet_name: &str) -> Result<String> {
+    read_secret(&format!("Password of wallet {}", wallet_name))
+}
+
+fn read_new_password() -> Result<String> {
+
+    let password = read_secret("New password")?;
+
+    if read_secret("Repeat the new password")? != password {
+        return Err(anyhow!("The passwords do not match"));
+    }
+
+    Ok(password)
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -90,15 +225,59 @@ async fn main() -> Result<()> {
 
     let client_config = mercuryrustlib::client_config::load().await;
 
+    mercuryrustlib::wallet::set_password_prompt(read_wallet_password);
+
+    if cli.passphrase {
+        mercuryrustlib::wallet::set_passphrase(&read_secret("BIP39 passphrase")?);
+    }
+
     match cli.command {
-        Commands::CreateWallet { name } => {
-            let wallet = mercuryrustlib::wallet::create_wallet(
+        Commands::CreateWallet { name, words } => {
+            let wallet = mercuryrustlib::wallet::create_wallet_with_word_count(
                 &name, 
-                &client_config).await?;
+                &client_config,
+                words).await?;
 
             mercuryrustlib::sqlite_manager::insert_wallet(&client_config.pool, &wallet).await?;
             println!("Wallet created: {:?}", wallet);
         },
+        Commands::RecoverWallet { name, gap_limit, backup_file } => {
+            let mnemonic = read_secret("Mnemonic")?;
+
+            let wallet = mercuryrustlib::wallet::create_wallet_from_mnemonic(
+                &name, 
+                &client_config,
+                &mnemonic).await?;
+
+            mercuryrustlib::sqlite_manager::insert_wallet(&client_config.pool, &wallet).await?;
+
+            let recovery_result = mercuryrustlib::recovery::recover_wallet(&client_config, &name, gap_limit, backup_file.as_deref()).await?;
+
+            let obj = json!({
+                "recovered_statechain_ids": recovery_result.recovered_statechain_ids,
+                "missing_backup_statechain_ids": recovery_result.missing_backup_statechain_ids,
+            });
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::ExportBackup { wallet_name, file } => {
+            let backup_password = read_new_password()?;
+
+            mercuryrustlib::backup::export_backup(&client_config, &wallet_name, &file, &backup_password).await?;
+
+            let obj = json!({"file": file});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::ImportBackup { file } => {
+            let backup_password = read_secret("Backup password")?;
+
+            let wallet = mercuryrustlib::backup::import_backup(&client_config, &file, &backup_password).await?;
+
+            let obj = json!({"wallet_name": wallet.name, "coins": wallet.coins.len()});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
         Commands::NewToken { } => {
             let token_id = mercuryrustlib::deposit::get_token(&client_config).await?;
 
@@ -106,8 +285,19 @@ async fn main() -> Result<()> {
 
             println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         },
-        Commands::NewDepositAddress { wallet_name, token_id, amount } => {
-            let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet_name, &token_id, amount).await?;
+        Commands::ListTokens { wallet_name } => {
+            let tokens = mercuryrustlib::deposit::update_tokens(&client_config, &wallet_name).await?;
+
+            let obj = json!({"tokens": tokens});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::NewDepositAddress { wallet_name, token_id, amount, blind } => {
+            let address = if blind {
+                mercuryrustlib::deposit::get_blind_deposit_bitcoin_address(&client_config, &wallet_name, &token_id, amount).await?
+            } else {
+                mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet_name, &token_id, amount).await?
+            };
 
             let obj = json!({"address": address});
 
@@ -151,7 +341,7 @@ async fn main() -> Result<()> {
 
             if generate_batch_id {
                 // Generate a random batch_id
-                let batch_id = Some(uuid::Uuid::new_v4().to_string()).unwrap();
+                let batch_id = uuid::Uuid::new_v4().to_string();
 
                 obj["batch_id"] = json!(batch_id);
             }
@@ -169,7 +359,34 @@ async fn main() -> Result<()> {
 
             println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         },
-        Commands::TransferReceive { wallet_name } => {
+        Commands::TransferSendBatch { wallet_name, transfers, force_send } => {
+            mercuryrustlib::coin_status::update_coins(&client_config, &wallet_name).await?;
+
+            let mut batch_transfers = Vec::<(String, String)>::new();
+
+            for transfer in transfers {
+                match transfer.split_once(':') {
+                    Some((statechain_id, to_address)) => batch_transfers.push((statechain_id.to_string(), to_address.to_string())),
+                    None => return Err(anyhow::anyhow!("Invalid transfer {}. Expected <statechain_id>:<transfer_address>", transfer)),
+                }
+            }
+
+            let batch_id = mercuryrustlib::transfer_sender::execute_batch(&client_config, &wallet_name, &batch_transfers, force_send).await?;
+
+            let obj = json!({"Transfer": "sent", "batch_id": batch_id});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::TransferCancel { wallet_name, statechain_id } => {
+            mercuryrustlib::coin_status::update_coins(&client_config, &wallet_name).await?;
+
+            mercuryrustlib::transfer_sender::cancel(&client_config, &wallet_name, &statechain_id).await?;
+
+            let obj = json!({"Transfer": "cancelled"});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::TransferReceive { wallet_name, wait } => {
             mercuryrustlib::coin_status::update_coins(&client_config, &wallet_name).await?;
 
             let mut received_statechain_ids = Vec::<String>::new();
@@ -180,7 +397,14 @@ async fn main() -> Result<()> {
 
                 if transfer_receive_result.is_there_batch_locked {
                     println!("Statecoin batch still locked. Waiting until expiration or unlock.");
-                    thread::sleep(Duration::from_secs(5));
+                    if wait {
+                        mercuryrustlib::transfer_receiver::wait_for_transfer_notification(&client_config, &wallet_name).await?;
+                    } else {
+                        thread::sleep(Duration::from_secs(5));
+                    }
+                } else if wait && received_statechain_ids.is_empty() {
+                    println!("Waiting for incoming transfers.");
+                    mercuryrustlib::transfer_receiver::wait_for_transfer_notification(&client_config, &wallet_name).await?;
                 } else {
                     break;
                 }
@@ -190,8 +414,8 @@ async fn main() -> Result<()> {
 
             println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         },
-        Commands::PaymentHash { wallet_name, statechain_id} => {
-            let response = mercuryrustlib::lightning_latch::create_pre_image(&client_config, &wallet_name, &statechain_id).await?;
+        Commands::PaymentHash { wallet_name, statechain_id, expiry } => {
+            let response = mercuryrustlib::lightning_latch::create_pre_image(&client_config, &wallet_name, &statechain_id, expiry).await?;
 
             let obj = json!(response);
 
@@ -208,11 +432,67 @@ async fn main() -> Result<()> {
 
             println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         },
+        Commands::ReclaimLatch { wallet_name, statechain_id, batch_id } => {
+            let own_address = mercuryrustlib::lightning_latch::reclaim(&client_config, &wallet_name, &statechain_id, &batch_id).await?;
+
+            let obj = json!({"Coin": "sent again to this wallet", "address": own_address});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::ConditionalLatch { wallet_name, statechain_id, oracle_pub_key, event_id, outcome, expiry } => {
+            let response = mercuryrustlib::oracle_latch::create_conditional_latch(&client_config, &wallet_name, &statechain_id, &oracle_pub_key, &event_id, &outcome, expiry).await?;
+
+            let obj = json!(response);
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::SubmitAttestation { statechain_id, batch_id, outcome, signature } => {
+            let message = mercuryrustlib::oracle_latch::submit_attestation(&client_config, &statechain_id, &batch_id, &outcome, &signature).await?;
+
+            let obj = json!({"message": message});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
         Commands::GetPaymentHash { batch_id } => {
             let payment_hash = mercuryrustlib::lightning_latch::get_payment_hash(&client_config, &batch_id).await?;
 
             let obj = json!({"payment_hash": payment_hash});
 
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::VerifyInvoice { batch_id, invoice, amount } => {
+            let decoded_invoice = mercuryrustlib::latch_invoice::verify_latch_invoice(&client_config, &invoice, &batch_id, amount).await?;
+
+            let obj = json!(decoded_invoice);
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::SetPassword { wallet_name } => {
+            let new_password = read_new_password()?;
+
+            mercuryrustlib::wallet::set_wallet_password(&client_config, &wallet_name, &new_password).await?;
+
+            let obj = json!({"message": "Wallet encrypted"});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::ChangePassword { wallet_name } => {
+            let password = read_wallet_password(&wallet_name)?;
+            mercuryrustlib::wallet::unlock_wallet(&client_config, &wallet_name, &password).await?;
+
+            let new_password = read_new_password()?;
+
+            mercuryrustlib::wallet::change_wallet_password(&client_config, &wallet_name, &new_password).await?;
+
+            let obj = json!({"message": "Wallet password changed"});
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::LockStatus { wallet_name } => {
+            let locked = mercuryrustlib::wallet::is_wallet_locked(&client_config, &wallet_name).await?;
+
+            let obj = json!({"locked": locked});
+
             println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         }
     }
@@ -166,4 +166,53 @@ const getToken = async (clientConfig, db, walletName) => {
     return token;
 }
 
-module.exports = { getDepositBitcoinAddress, createTx1, getToken };
\ No newline at end of file
+const getTokenStatusFromServer = async (clientConfig, tokenId) => {
+
+    const token_server_url = clientConfig.tokenServer;
+
+    if (!token_server_url) {
+        throw new Error("The token server is not configured");
+    }
+
+    const path = `token/status/${tokenId}`;
+    const url = token_server_url + '/' + path;
+
+    const torProxy = clientConfig.torProxy;
+
+    let socksAgent = undefined;
+
+    if (torProxy) {
+        socksAgent = { httpAgent: new SocksProxyAgent(torProxy) };
+    }
+
+    const response = await axios.get(url, socksAgent);
+
+    if (response.status != 200) {
+        throw new Error(`Token status error: ${response.data}`);
+    }
+
+    return response.data;
+}
+
+const updateTokens = async (clientConfig, db, walletName) => {
+
+    let wallet = await sqlite_manager.getWallet(db, walletName);
+
+    for (let token of wallet.tokens) {
+
+        // spent and refunded are final states
+        if (token.status === "SPENT" || token.status === "REFUNDED") {
+            continue;
+        }
+
+        const tokenStatus = await getTokenStatusFromServer(clientConfig, token.token_id);
+
+        wallet = mercury_wasm.updateTokenStatus(tokenStatus, wallet);
+    }
+
+    await sqlite_manager.updateWallet(db, wallet);
+
+    return wallet.tokens;
+}
+
+module.exports = { getDepositBitcoinAddress, createTx1, getToken, updateTokens };
\ No newline at end of file
@@ -69,6 +69,13 @@ const getDepositBitcoinAddress = async (clientConfig, walletName, amount) => {
     return address_info;
 }
 
+const updateTokens = async (clientConfig, walletName) => {
+    const db = await getDatabase(clientConfig);
+    const tokens = await deposit.updateTokens(clientConfig, db, walletName);
+    db.close();
+    return tokens;
+}
+
 const getWalletTokens = async (clientConfig, walletName) => {
     const db = await getDatabase(clientConfig);
     let wallet = await sqlite_manager.getWallet(db, walletName);
@@ -257,6 +264,7 @@ module.exports = {
     newToken, 
     getDepositBitcoinAddress, 
     getWalletTokens, 
+    updateTokens,
     broadcastBackupTransaction, 
     listStatecoins, 
     withdrawCoin, 
@@ -34,17 +34,17 @@ const execute = async (clientConfig, electrumClient, db, wallet_name) => {
     }
     const currentBlockheight = blockHeader.height;
 
-    let uniqueAuthPubkeys = new Set();
+    let uniqueAuthPubkeys = new Map();
 
     wallet.coins.forEach(coin => {
-        uniqueAuthPubkeys.add(coin.auth_pubkey);
+        uniqueAuthPubkeys.set(coin.auth_pubkey, coin);
     });
 
     let encMsgsPerAuthPubkey = new Map();
 
-    for (let authPubkey of uniqueAuthPubkeys) {
+    for (let [authPubkey, authCoin] of uniqueAuthPubkeys) {
         try {
-            let encMessages = await getMsgAddr(clientConfig, authPubkey);
+            let encMessages = await getMsgAddr(clientConfig, authCoin);
             if (encMessages.length === 0) {
                // console.log("No messages");
                 continue;
@@ -123,11 +123,9 @@ const execute = async (clientConfig, electrumClient, db, wallet_name) => {
     };
 }
 
-const getMsgAddr = async (clientConfig, auth_pubkey) => {
+const getMsgAddr = async (clientConfig, coin) => {
 
     const statechain_entity_url = clientConfig.statechainEntity;
-    const path = "transfer/get_msg_addr/";
-    const url = statechain_entity_url + '/' + path + auth_pubkey;
 
     const torProxy = clientConfig.torProxy;
 
@@ -139,6 +137,13 @@ const getMsgAddr = async (clientConfig, auth_pubkey) => {
 
     let response;
     try {
+        const challengeResponse = await axios.post(statechain_entity_url + '/transfer/challenge', { auth_pub_keys: [coin.auth_pubkey] }, socksAgent);
+        const challenge = challengeResponse.data.challenge;
+        const authSig = mercury_wasm.signAuthChallenge(challenge, coin);
+
+        const path = "transfer/get_msg_addr/";
+        const url = statechain_entity_url + '/' + path + coin.auth_pubkey + '?challenge=' + challenge + '&auth_sig=' + authSig;
+
         response = await axios.get(url, socksAgent);
     } catch (error) {
         throw new Error('Failed to get message address from mercury server');
@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS blinded_tokens (
+    paid_token_id TEXT UNIQUE,
+    blinded_token TEXT NOT NULL
+);
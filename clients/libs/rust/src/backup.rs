@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use mercurylib::wallet::{backup::{export_wallet_backup, import_wallet_backup}, encryption::DerivedKey, StatechainBackupTxs, Wallet};
+
+use crate::{client_config::ClientConfig, sqlite_manager::{find_backup_txs, get_stored_wallet, get_wallet, insert_or_update_backup_txs, insert_wallet}, wallet::set_session_key};
+
+/// Writes the wallet, its coins, activities and backup transactions to an encrypted backup file
+pub async fn export_backup(client_config: &ClientConfig, wallet_name: &str, file_path: &str, backup_password: &str) -> Result<()> {
+
+    let wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let mut statechain_ids = HashSet::<&str>::new();
+    let mut backup_txs = Vec::<StatechainBackupTxs>::new();
+
+    for coin in wallet.coins.iter() {
+
+        let statechain_id = match &coin.statechain_id {
+            Some(statechain_id) => statechain_id,
+            None => continue,
+        };
+
+        // duplicated coins share the statechain id
+        if !statechain_ids.insert(statechain_id) {
+            continue;
+        }
+
+        if let Some(txs) = find_backup_txs(&client_config.pool, statechain_id).await? {
+            backup_txs.push(StatechainBackupTxs {
+                statechain_id: statechain_id.clone(),
+                backup_txs: txs,
+            });
+        }
+    }
+
+    let backup_file = export_wallet_backup(&wallet, &backup_txs, backup_password)?;
+
+    std::fs::write(file_path, backup_file)?;
+
+    Ok(())
+}
+
+/// Restores a wallet and its backup transactions from a backup file. An existing wallet with the same name is never overwritten.
+/// The wallet is stored encrypted with the backup password, which can be changed afterwards.
+pub async fn import_backup(client_config: &ClientConfig, file_path: &str, backup_password: &str) -> Result<Wallet> {
+
+    let backup_file = std::fs::read_to_string(file_path)?;
+
+    let wallet_backup = import_wallet_backup(&backup_file, backup_password)?;
+
+    let wallet = wallet_backup.wallet;
+
+    if get_stored_wallet(&client_config.pool, &wallet.name).await.is_ok() {
+        return Err(anyhow!("Wallet {} already exists", wallet.name));
+    }
+
+    set_session_key(&wallet.name, DerivedKey::derive(backup_password, None)?);
+
+    insert_wallet(&client_config.pool, &wallet).await?;
+
+    for statechain_backup_txs in wallet_backup.backup_txs.iter() {
+        insert_or_update_backup_txs(&client_config.pool, &statechain_backup_txs.statechain_id, &statechain_backup_txs.backup_txs).await?;
+    }
+
+    Ok(wallet)
+}
@@ -5,7 +5,7 @@ use mercurylib::wallet::{cpfp_tx, CoinStatus};
 
 pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, to_address: &str, fee_rate: Option<f64>) -> Result<()> {
     
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let is_address_valid = mercurylib::validate_address(to_address, &wallet.network)?;
 
@@ -13,7 +13,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         return Err(anyhow!("Invalid address"));
     }
 
-    let backup_txs = get_backup_txs(&client_config.pool, &statechain_id).await?;
+    let backup_txs = get_backup_txs(&client_config.pool, statechain_id).await?;
     
     // If the user sends to himself, he will have two coins with same statechain_id
     // In this case, we need to find the one with the lowest locktime
@@ -32,7 +32,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         return Err(anyhow::anyhow!("Coin status must be CONFIRMED or IN_TRANSFER to transfer it. The current status is {}", coin.status));
     }
 
-    let backup_tx = cpfp_tx::latest_backup_tx_pays_to_user_pubkey(&backup_txs, &coin,  &wallet.network)?;
+    let backup_tx = cpfp_tx::latest_backup_tx_pays_to_user_pubkey(&backup_txs, coin,  &wallet.network)?;
 
     let fee_rate = match fee_rate {
         Some(fee_rate) => fee_rate,
@@ -54,7 +54,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         },
     };
 
-    let cpfp_tx = cpfp_tx::create_cpfp_tx(&backup_tx, &coin, to_address, fee_rate, &wallet.network)?;
+    let cpfp_tx = cpfp_tx::create_cpfp_tx(&backup_tx, coin, to_address, fee_rate, &wallet.network)?;
 
     let tx_bytes = hex::decode(&backup_tx.tx)?;
     let txid = client_config.electrum_client.transaction_broadcast_raw(&tx_bytes)?;
@@ -72,7 +72,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
 
     update_wallet(&client_config.pool, &wallet).await?;
 
-    crate::utils::complete_withdraw(statechain_id, &signed_statechain_id, &client_config).await?;
+    crate::utils::complete_withdraw(statechain_id, &signed_statechain_id, client_config).await?;
 
     Ok(())
 }
\ No newline at end of file
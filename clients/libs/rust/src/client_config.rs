@@ -27,6 +27,8 @@ pub struct ClientConfig {
     pub tor_proxy: Option<String>,
     /// Confirmation target
     pub max_fee_rate: f64,
+    /// Token server address, used for the token status and blind tokens
+    pub token_server: Option<String>,
 }
 
 fn check_and_set_settings() -> String {
@@ -57,6 +59,8 @@ impl ClientConfig {
         let confirmation_target = settings.get_int("confirmation_target").unwrap() as u32;
         let max_fee_rate = settings.get_int("max_fee_rate").unwrap() as f64;
 
+        let token_server = settings.get_string("token_server").ok();
+
         let tor_proxy = match settings.get_string("tor_proxy") {
             Ok(proxy) => Some(proxy.to_string()),
             Err(_) => None,
@@ -101,7 +105,8 @@ impl ClientConfig {
             confirmation_target,
             pool,
             tor_proxy,
-            max_fee_rate
+            max_fee_rate,
+            token_server,
         }
     }
 
@@ -121,6 +126,6 @@ impl ClientConfig {
 }
 
 pub async fn load() -> ClientConfig {
-    let client_config = ClientConfig::load().await;
-    client_config
+    
+    ClientConfig::load().await
 }
\ No newline at end of file
@@ -26,7 +26,7 @@ async fn check_deposit(client_config: &ClientConfig, coin: &mut Coin, wallet_net
 
     let mut utxo: Option<ListUnspentRes> = None;
 
-    let address = Address::from_str(&coin.aggregated_address.as_ref().unwrap())?.require_network(client_config.network)?;
+    let address = Address::from_str(coin.aggregated_address.as_ref().unwrap())?.require_network(client_config.network)?;
 
     let utxo_list =  client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
 
@@ -61,13 +61,13 @@ async fn check_deposit(client_config: &ClientConfig, coin: &mut Coin, wallet_net
 
         let backup_tx = create_tx1(client_config, coin, wallet_netwotk, &utxo_txid, utxo_vout).await?;
 
-        let activity_utxo = format!("{}:{}", utxo.tx_hash.to_string(), utxo.tx_pos);
+        let activity_utxo = format!("{}:{}", utxo.tx_hash, utxo.tx_pos);
 
-        let activity = Some(create_activity(&activity_utxo, utxo.value as u32, "deposit"));
+        let activity = create_activity(&activity_utxo, utxo.value as u32, "deposit");
         // return Ok(Some(activity));
 
         deposit_result = Some(DepositResult {
-            activity: activity.unwrap(),
+            activity,
             backup_tx
         });
     }
@@ -94,7 +94,7 @@ async fn check_transfer(client_config: &ClientConfig, coin: &Coin) -> Result<boo
 
     let statechain_id = coin.statechain_id.as_ref().unwrap();
 
-    let statechain_info = crate::utils::get_statechain_info(statechain_id, &client_config).await?;
+    let statechain_info = crate::utils::get_statechain_info(statechain_id, client_config).await?;
 
     // if the statechain info is not found, we assume the coin has been transferred
     if statechain_info.is_none() {
@@ -106,9 +106,9 @@ async fn check_transfer(client_config: &ClientConfig, coin: &Coin) -> Result<boo
     let enclave_public_key = statechain_info.enclave_public_key;
 
     // if the enclave's public key is no longer part of the coin, the coin has been transferred
-    let is_transferred = !is_enclave_pubkey_part_of_coin(&coin, &enclave_public_key)?;
+    let is_transferred = !is_enclave_pubkey_part_of_coin(coin, &enclave_public_key)?;
 
-    return Ok(is_transferred);
+    Ok(is_transferred)
 }
 
 async fn check_withdrawal(client_config: &ClientConfig, coin: &mut Coin) -> Result<()> {
@@ -136,7 +136,7 @@ async fn check_withdrawal(client_config: &ClientConfig, coin: &mut Coin) -> Resu
         return Err(anyhow!("Coin does not have withdrawal_address"));
     }
 
-    let address = Address::from_str(&coin.withdrawal_address.as_ref().unwrap())?.require_network(client_config.network)?;
+    let address = Address::from_str(coin.withdrawal_address.as_ref().unwrap())?.require_network(client_config.network)?;
 
     let utxo_list =  client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
 
@@ -173,7 +173,7 @@ async fn check_withdrawal(client_config: &ClientConfig, coin: &mut Coin) -> Resu
     Ok(())
 }
 
-async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &Vec<Coin>) -> Result<Vec<Coin>>{
+async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &[Coin]) -> Result<Vec<Coin>>{
 
     let mut duplicated_coin_list : Vec<Coin> = Vec::new();
 
@@ -183,7 +183,7 @@ async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &Vec
             continue;
         }
 
-        let address = Address::from_str(&coin.aggregated_address.as_ref().unwrap())?.require_network(client_config.network)?;
+        let address = Address::from_str(coin.aggregated_address.as_ref().unwrap())?.require_network(client_config.network)?;
 
         let utxo_list =  client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
 
@@ -204,7 +204,7 @@ async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &Vec
                 continue;
             }
 
-            max_duplicated_index = max_duplicated_index + 1;
+            max_duplicated_index += 1;
 
             let mut duplicated_coin = coin.clone();
             duplicated_coin.status = CoinStatus::DUPLICATED;
@@ -222,7 +222,7 @@ async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &Vec
 
 pub async fn update_coins(client_config: &ClientConfig, wallet_name: &str) -> Result<()> {
     
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let network = wallet.network.clone();
 
@@ -238,7 +238,7 @@ pub async fn update_coins(client_config: &ClientConfig, wallet_name: &str) -> Re
                 let backup_tx = deposit_result.backup_tx;
 
                 wallet.activities.push(activity);
-                insert_backup_txs(&client_config.pool, &coin.statechain_id.as_ref().unwrap(), &[backup_tx].to_vec()).await?;
+                insert_backup_txs(&client_config.pool, coin.statechain_id.as_ref().unwrap(), &[backup_tx].to_vec()).await?;
             }
         } else if coin.status == CoinStatus::IN_TRANSFER {
 
@@ -1,18 +1,37 @@
 use anyhow::{anyhow, Result, Ok};
-use mercurylib::{deposit::{create_deposit_msg1, create_aggregated_address}, wallet::{Wallet, BackupTx, CoinStatus, Coin}, transaction:: get_user_backup_address, utils::get_blockheight};
+use mercurylib::{deposit::{blind_token::{blind_token, unblind_token_signature, BlindNonceResponsePayload, BlindSignRequestPayload, BlindSignResponsePayload}, create_aggregated_address, create_blind_deposit_msg1, create_deposit_msg1, TokenStatusResponsePayload}, wallet::{Wallet, BackupTx, CoinStatus, Coin, Token, TokenStatus}, transaction:: get_user_backup_address, utils::get_blockheight};
+use reqwest::StatusCode;
 
-use crate::{client_config::ClientConfig, sqlite_manager::{get_wallet, update_wallet}, transaction::new_transaction, utils::info_config};
+use crate::{client_config::ClientConfig, sqlite_manager::{delete_blinded_token, get_blinded_token, get_wallet, insert_blinded_token, update_wallet}, transaction::new_transaction, utils::info_config};
 
 pub async fn get_deposit_bitcoin_address(client_config: &ClientConfig, wallet_name: &str, token_id: &str, amount: u32) -> Result<String> {
 
-    let token_id = uuid::Uuid::parse_str(&token_id)?;
+    let token_id = uuid::Uuid::parse_str(token_id)?;
     // println!("Deposit: {} {} {}", wallet_name, token_id, amount);
-    let wallet = get_wallet(&client_config.pool, &wallet_name).await?;
-    let mut wallet = init(&client_config, &wallet, token_id).await?;
+    let wallet = get_wallet(&client_config.pool, wallet_name).await?;
+    let wallet = init(client_config, &wallet, token_id).await?;
+
+    set_deposit_address(client_config, wallet, amount).await
+}
+
+/// Exchanges the paid token for a blind token and deposits with it, so the statecoin cannot be linked to the token payment
+pub async fn get_blind_deposit_bitcoin_address(client_config: &ClientConfig, wallet_name: &str, paid_token_id: &str, amount: u32) -> Result<String> {
+
+    let (token_id, blind_signature) = get_blind_token(client_config, paid_token_id).await?;
+
+    let wallet = get_wallet(&client_config.pool, wallet_name).await?;
+    let wallet = init_deposit(client_config, &wallet, &token_id, Some(blind_signature)).await?;
+
+    delete_blinded_token(&client_config.pool, paid_token_id).await?;
+
+    set_deposit_address(client_config, wallet, amount).await
+}
+
+async fn set_deposit_address(client_config: &ClientConfig, mut wallet: Wallet, amount: u32) -> Result<String> {
 
     let coin = wallet.coins.last_mut().unwrap();
 
-    let aggregated_public_key = create_aggregated_address(&coin, wallet.network.clone())?;
+    let aggregated_public_key = create_aggregated_address(coin, wallet.network.clone())?;
 
     coin.amount = Some(amount);
     coin.aggregated_address = Some(aggregated_public_key.aggregate_address.clone());
@@ -37,9 +56,9 @@ pub async fn create_tx1(client_config: &ClientConfig, coin: &mut Coin, wallet_ne
 
     coin.status = CoinStatus::IN_MEMPOOL;
 
-    let to_address = get_user_backup_address(&coin, wallet_netwotk.to_string())?;
+    let to_address = get_user_backup_address(coin, wallet_netwotk.to_string())?;
 
-    let server_info = info_config(&client_config).await?;
+    let server_info = info_config(client_config).await?;
 
     let fee_rate_sats_per_byte = if server_info.fee_rate_sats_per_byte > client_config.max_fee_rate {
         client_config.max_fee_rate
@@ -48,7 +67,7 @@ pub async fn create_tx1(client_config: &ClientConfig, coin: &mut Coin, wallet_ne
     };
 
     let signed_tx = new_transaction(
-        &client_config, 
+        client_config, 
         coin, 
         &to_address, 
         0, 
@@ -89,16 +108,23 @@ pub async fn create_tx1(client_config: &ClientConfig, coin: &mut Coin, wallet_ne
 }
 
 pub async fn init(client_config: &ClientConfig, wallet: &Wallet, token_id: uuid::Uuid) -> Result<Wallet> {
+    init_deposit(client_config, wallet, &token_id.to_string(), None).await
+}
+
+async fn init_deposit(client_config: &ClientConfig, wallet: &Wallet, token_id: &str, blind_signature: Option<String>) -> Result<Wallet> {
 
     let mut wallet = wallet.clone();
 
-    let coin = wallet.get_new_coin()?;
+    let coin = wallet.get_new_coin_with_passphrase(&crate::wallet::session_passphrase())?;
 
     wallet.coins.push(coin.clone());
 
     update_wallet(&client_config.pool, &wallet).await?;
 
-    let deposit_msg_1 = create_deposit_msg1(&coin, &token_id.to_string())?;
+    let deposit_msg_1 = match &blind_signature {
+        Some(blind_signature) => create_blind_deposit_msg1(&coin, token_id, blind_signature)?,
+        None => create_deposit_msg1(&coin, token_id)?,
+    };
 
     // println!("deposit_msg_1: {:?}", deposit_msg_1);
 
@@ -106,7 +132,7 @@ pub async fn init(client_config: &ClientConfig, wallet: &Wallet, token_id: uuid:
     let path = "deposit/init/pod";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let response = request.json(&deposit_msg_1).send().await?;
 
@@ -138,7 +164,7 @@ pub async fn get_token(client_config: &ClientConfig) -> Result<String> {
     let path = "deposit/get_token";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.get(&format!("{}/{}", endpoint, path));
+    let request = client.get(format!("{}/{}", endpoint, path));
 
     let response = request.send().await?;
 
@@ -151,5 +177,113 @@ pub async fn get_token(client_config: &ClientConfig) -> Result<String> {
 
     let token: mercurylib::deposit::TokenID = serde_json::from_str(value.as_str())?;
 
-    return Ok(token.token_id);
+    Ok(token.token_id)
+}
+
+/// Exchanges a paid token for a blind token: a new token id signed by the token server, which cannot be linked to
+/// the payment. Returns the new token id and the unblinded signature.
+pub async fn get_blind_token(client_config: &ClientConfig, paid_token_id: &str) -> Result<(String, String)> {
+
+    let token_server = client_config.token_server.as_ref().ok_or(anyhow!("The token server is not configured"))?;
+
+    let client = client_config.get_reqwest_client()?;
+
+    let response = client.get(&format!("{}/token/blind_pubkey", token_server)).send().await?;
+
+    if response.status() != 200 {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let value: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    let signer_pubkey = value["public_key"].as_str().ok_or(anyhow!("The token server returned no blind signing key"))?.to_string();
+
+    // the same challenge is sent again if a previous attempt did not complete
+    let blinded_token = match get_blinded_token(&client_config.pool, paid_token_id).await? {
+        Some(blinded_token) => blinded_token,
+        None => {
+            let response = client.post(&format!("{}/token/blind_nonce/{}", token_server, paid_token_id)).send().await?;
+
+            if response.status() != 200 {
+                let response_body = response.text().await?;
+                return Err(anyhow!(response_body));
+            }
+
+            let blind_nonce: BlindNonceResponsePayload = serde_json::from_str(&response.text().await?)?;
+
+            let blinded_token = blind_token(&signer_pubkey, &blind_nonce.public_nonce)?;
+
+            insert_blinded_token(&client_config.pool, paid_token_id, &blinded_token).await?;
+
+            blinded_token
+        }
+    };
+
+    let blind_sign_request = BlindSignRequestPayload {
+        token_id: paid_token_id.to_string(),
+        challenge: blinded_token.challenge.clone(),
+    };
+
+    let response = client.post(&format!("{}/token/blind_sign", token_server)).json(&blind_sign_request).send().await?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        // the signing session expired before the challenge was signed, a new nonce is needed
+        delete_blinded_token(&client_config.pool, paid_token_id).await?;
+        let response_body = response.text().await?;
+        return Err(anyhow!("{} Retry to start a new blind signing session.", response_body));
+    }
+
+    if response.status() != 200 {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let blind_sign_response: BlindSignResponsePayload = serde_json::from_str(&response.text().await?)?;
+
+    let signature = unblind_token_signature(&blinded_token, &blind_sign_response.blind_signature, &signer_pubkey)?;
+
+    Ok((blinded_token.token_id, signature))
+}
+
+pub async fn get_token_status(client_config: &ClientConfig, token_id: &str) -> Result<TokenStatusResponsePayload> {
+
+    let endpoint = client_config.token_server.as_ref().ok_or(anyhow!("The token server is not configured"))?;
+    let path = format!("token/status/{}", token_id);
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.get(format!("{}/{}", endpoint, path));
+
+    let response = request.send().await?;
+
+    if response.status() != 200 {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let value = response.text().await?;
+
+    let token_status: TokenStatusResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(token_status)
+}
+
+/// Refreshes the status of the wallet tokens that are not spent or refunded
+pub async fn update_tokens(client_config: &ClientConfig, wallet_name: &str) -> Result<Vec<Token>> {
+
+    let mut wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    for token in wallet.tokens.iter_mut() {
+
+        if token.status == Some(TokenStatus::SPENT) || token.status == Some(TokenStatus::REFUNDED) {
+            continue;
+        }
+
+        let token_status = get_token_status(client_config, &token.token_id).await?;
+
+        token.set_status(token_status.status, &token_status.expiry);
+    }
+
+    update_wallet(&client_config.pool, &wallet).await?;
+
+    Ok(wallet.tokens)
 }
@@ -0,0 +1,281 @@
+use std::{future::Future, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription};
+use mercurylib::transfer::sender::PaymentHashResponsePayload;
+use serde::{Deserialize, Serialize};
+
+use crate::{client_config::ClientConfig, lightning_latch::get_latch_info};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DecodedInvoice {
+    pub payment_hash: String,
+    pub amount_msat: Option<u64>,
+    pub timestamp: u64,
+    pub expiry_secs: u64,
+    pub expires_at: String,
+    pub description: Option<String>,
+    pub payee_pubkey: String,
+}
+
+/// Decodes a BOLT11 invoice. Fails if the invoice signature is not valid.
+pub fn decode_invoice(invoice: &str) -> Result<DecodedInvoice> {
+
+    let invoice = Bolt11Invoice::from_str(invoice.trim())
+        .map_err(|e| anyhow!("Invalid invoice: {}", e))?;
+
+    let timestamp = invoice.duration_since_epoch().as_secs();
+    let expiry_secs = invoice.expiry_time().as_secs();
+
+    let expires_at = DateTime::<Utc>::from_timestamp((timestamp + expiry_secs) as i64, 0)
+        .ok_or(anyhow!("Invalid invoice expiry"))?;
+
+    let description = match invoice.description() {
+        Bolt11InvoiceDescription::Direct(description) => Some(description.to_string()),
+        Bolt11InvoiceDescription::Hash(_) => None,
+    };
+
+    Ok(DecodedInvoice {
+        payment_hash: invoice.payment_hash().to_string(),
+        amount_msat: invoice.amount_milli_satoshis(),
+        timestamp,
+        expiry_secs,
+        expires_at: expires_at.to_rfc3339(),
+        description,
+        payee_pubkey: invoice.get_payee_pub_key().to_string(),
+    })
+}
+
+/// Checks that the invoice pays the latch of the batch and expires before it.
+pub async fn verify_latch_invoice(client_config: &ClientConfig, invoice: &str, batch_id: &str, expected_amount_sat: Option<u64>) -> Result<DecodedInvoice> {
+
+    let decoded_invoice = decode_invoice(invoice)?;
+
+    let latch_info = get_latch_info(client_config, batch_id).await?
+        .ok_or(anyhow!("No latch found for batch {}", batch_id))?;
+
+    check_latch_invoice(&decoded_invoice, &latch_info, expected_amount_sat, Utc::now())?;
+
+    Ok(decoded_invoice)
+}
+
+/// Checks the payment hash, amount and expiry of the decoded invoice against the latch at the time `now`
+fn check_latch_invoice(decoded_invoice: &DecodedInvoice, latch_info: &PaymentHashResponsePayload, expected_amount_sat: Option<u64>, now: DateTime<Utc>) -> Result<()> {
+
+    if decoded_invoice.payment_hash != latch_info.hash.to_lowercase() {
+        return Err(anyhow!("Invoice payment hash {} does not match latch hash {}", decoded_invoice.payment_hash, latch_info.hash));
+    }
+
+    if let Some(expected_amount_sat) = expected_amount_sat {
+        if decoded_invoice.amount_msat != Some(expected_amount_sat * 1000) {
+            return Err(anyhow!("Invoice amount does not match the expected amount of {} sats", expected_amount_sat));
+        }
+    }
+
+    let invoice_expires_at = DateTime::parse_from_rfc3339(&decoded_invoice.expires_at)?.with_timezone(&Utc);
+    let latch_expires_at = DateTime::parse_from_rfc3339(&latch_info.expires_at)?.with_timezone(&Utc);
+
+    if invoice_expires_at <= now {
+        return Err(anyhow!("Invoice has expired"));
+    }
+
+    // the invoice must not be payable after the coin can be reclaimed
+    if invoice_expires_at > latch_expires_at {
+        return Err(anyhow!("Invoice expires at {}, after the latch expiry {}", decoded_invoice.expires_at, latch_info.expires_at));
+    }
+
+    Ok(())
+}
+
+/// Lightning node able to create hold invoices for a given payment hash
+pub trait HoldInvoiceBackend {
+    /// Returns the BOLT11 payment request of the new hold invoice
+    fn add_hold_invoice(&self, payment_hash: &str, amount_msat: u64, expiry_secs: u64, memo: &str) -> impl Future<Output = Result<String>> + Send;
+}
+
+/// LND node accessed through its REST API
+pub struct LndRestBackend {
+    url: String,
+    /// Hex encoded macaroon with invoice write permission
+    macaroon: String,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct LndAddHoldInvoiceResponse {
+    payment_request: String,
+}
+
+impl LndRestBackend {
+    pub fn new(url: &str, macaroon: &str, tls_cert_path: Option<&str>) -> Result<Self> {
+
+        let mut builder = reqwest::Client::builder();
+
+        // LND uses a self-signed certificate by default
+        if let Some(tls_cert_path) = tls_cert_path {
+            let pem = std::fs::read(tls_cert_path)?;
+            builder = builder.add_root_certificate(reqwest::Certificate::from_pem(&pem)?);
+        }
+
+        Ok(LndRestBackend {
+            url: url.trim_end_matches('/').to_string(),
+            macaroon: macaroon.to_string(),
+            client: builder.build()?,
+        })
+    }
+}
+
+impl HoldInvoiceBackend for LndRestBackend {
+    fn add_hold_invoice(&self, payment_hash: &str, amount_msat: u64, expiry_secs: u64, memo: &str) -> impl Future<Output = Result<String>> + Send {
+
+        let payment_hash = hex::decode(payment_hash);
+        let memo = memo.to_string();
+
+        async move {
+            let body = serde_json::json!({
+                "hash": STANDARD.encode(payment_hash?),
+                "value_msat": amount_msat.to_string(),
+                "expiry": expiry_secs.to_string(),
+                "memo": memo,
+            });
+
+            let response = self.client
+                .post(&format!("{}/v2/invoices/hodl", self.url))
+                .header("Grpc-Metadata-macaroon", &self.macaroon)
+                .json(&body)
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let text = response.text().await?;
+                return Err(anyhow!("LND add hold invoice failed. status: {}, error: {}", status, text));
+            }
+
+            let response: LndAddHoldInvoiceResponse = response.json().await?;
+
+            Ok(response.payment_request)
+        }
+    }
+}
+
+/// Creates a hold invoice locked to the latch of the batch, expiring no later than the latch.
+pub async fn create_latch_invoice<B: HoldInvoiceBackend>(client_config: &ClientConfig, backend: &B, batch_id: &str, amount_msat: u64, memo: &str) -> Result<String> {
+
+    let latch_info = get_latch_info(client_config, batch_id).await?
+        .ok_or(anyhow!("No latch found for batch {}", batch_id))?;
+
+    let latch_expires_at = DateTime::parse_from_rfc3339(&latch_info.expires_at)?.with_timezone(&Utc);
+
+    let remaining = (latch_expires_at - Utc::now()).num_seconds();
+
+    if remaining <= 0 {
+        return Err(anyhow!("Latch of batch {} has expired", batch_id));
+    }
+
+    let payment_request = backend.add_hold_invoice(&latch_info.hash, amount_msat, remaining as u64, memo).await?;
+
+    let expected_amount_sat = if amount_msat % 1000 == 0 { Some(amount_msat / 1000) } else { None };
+
+    verify_latch_invoice(client_config, &payment_request, batch_id, expected_amount_sat).await?;
+
+    Ok(payment_request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mainnet invoice of 1000 sats created at 2023-10-02T23:03:09Z, expiring after one week
+    const INVOICE: &str = "lnbc10u1pj3knpdsp5k9f25s2wpzewkf9c78pftkgnkuuz82erkcjml7zkgsp7znyhs5yspp5rxz3tkc7ydgln3u7ez6duhp0g6jpzgtnn7ph5xrjy6muh9xm07wqdp2f9h8vmmfvdjjqen0wgsy6ctfdeehgcteyp6x76m9dcxqyjw5qcqpj9qyysgq6z9whs8am75r6mzcgt76vlwgk5g9yq5g8xefdxx6few6d5why7fs7h5g2dx9hk7s60ywtnkyc0f3p0cha4a9kmgkq5jvu5e7hvsaawqpjtf8p4";
+    const PAYMENT_HASH: &str = "198515db1e2351f9c79ec8b4de5c2f46a41121739f837a187226b7cb94db7f9c";
+
+    fn latch_info(hash: &str, expires_at: &str) -> PaymentHashResponsePayload {
+        PaymentHashResponsePayload {
+            hash: hash.to_string(),
+            expires_at: expires_at.to_string(),
+        }
+    }
+
+    fn at(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_decode_invoice() {
+
+        let decoded_invoice = decode_invoice(INVOICE).unwrap();
+
+        assert_eq!(decoded_invoice.payment_hash, PAYMENT_HASH);
+        assert_eq!(decoded_invoice.amount_msat, Some(1_000_000));
+        assert_eq!(decoded_invoice.timestamp, 1696287789);
+        assert_eq!(decoded_invoice.expiry_secs, 604800);
+        assert_eq!(at(&decoded_invoice.expires_at), at("2023-10-09T23:03:09Z"));
+        assert_eq!(decoded_invoice.description, Some(String::from("Invoice for Mainstay token")));
+
+        assert!(decode_invoice("lnbc10u1invoice").is_err());
+    }
+
+    #[test]
+    fn test_check_latch_invoice() {
+
+        let decoded_invoice = decode_invoice(INVOICE).unwrap();
+        let now = at("2023-10-05T00:00:00Z");
+
+        // the latch hash may be given in upper case
+        let latch = latch_info(&PAYMENT_HASH.to_uppercase(), "2023-10-10T00:00:00Z");
+
+        assert!(check_latch_invoice(&decoded_invoice, &latch, Some(1000), now).is_ok());
+        assert!(check_latch_invoice(&decoded_invoice, &latch, None, now).is_ok());
+    }
+
+    #[test]
+    fn test_check_latch_invoice_wrong_payment_hash() {
+
+        let decoded_invoice = decode_invoice(INVOICE).unwrap();
+
+        let latch = latch_info("b152aa414e08b2eb24b8f1c295d913b73823ab23b625bff8564403e14c978509", "2023-10-10T00:00:00Z");
+
+        let result = check_latch_invoice(&decoded_invoice, &latch, Some(1000), at("2023-10-05T00:00:00Z"));
+
+        assert!(result.unwrap_err().to_string().contains("does not match latch hash"));
+    }
+
+    #[test]
+    fn test_check_latch_invoice_wrong_amount() {
+
+        let decoded_invoice = decode_invoice(INVOICE).unwrap();
+
+        let latch = latch_info(PAYMENT_HASH, "2023-10-10T00:00:00Z");
+
+        let result = check_latch_invoice(&decoded_invoice, &latch, Some(999), at("2023-10-05T00:00:00Z"));
+
+        assert!(result.unwrap_err().to_string().contains("does not match the expected amount"));
+    }
+
+    #[test]
+    fn test_check_latch_invoice_expired() {
+
+        let decoded_invoice = decode_invoice(INVOICE).unwrap();
+
+        let latch = latch_info(PAYMENT_HASH, "2023-10-10T00:00:00Z");
+
+        let result = check_latch_invoice(&decoded_invoice, &latch, Some(1000), at("2023-10-09T23:03:09Z"));
+
+        assert!(result.unwrap_err().to_string().contains("Invoice has expired"));
+    }
+
+    #[test]
+    fn test_check_latch_invoice_expires_after_latch() {
+
+        let decoded_invoice = decode_invoice(INVOICE).unwrap();
+
+        let latch = latch_info(PAYMENT_HASH, "2023-10-09T00:00:00Z");
+
+        let result = check_latch_invoice(&decoded_invoice, &latch, Some(1000), at("2023-10-05T00:00:00Z"));
+
+        assert!(result.unwrap_err().to_string().contains("after the latch expiry"));
+    }
+}
@@ -1,8 +1,12 @@
+pub mod backup;
 pub mod broadcast_backup_tx;
 pub mod client_config;
 pub mod coin_status;
 pub mod deposit;
+pub mod latch_invoice;
 pub mod lightning_latch;
+pub mod oracle_latch;
+pub mod recovery;
 pub mod sqlite_manager;
 pub mod transaction;
 pub mod transfer_receiver;
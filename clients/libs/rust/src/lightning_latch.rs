@@ -1,23 +1,26 @@
 
-use crate::{client_config::ClientConfig, sqlite_manager::get_wallet};
+use crate::{client_config::ClientConfig, sqlite_manager::{get_wallet, update_wallet}};
 use anyhow::{anyhow, Result};
-use mercurylib::{transfer::sender::{PaymentHashRequestPayload, PaymentHashResponsePayload, TransferPreimageRequestPayload, TransferPreimageResponsePayload}, wallet::CoinStatus};
+use chrono::Utc;
+use mercurylib::{transfer::sender::{LatchReclaimRequestPayload, PaymentHashRequestPayload, PaymentHashResponsePayload, TransferPreimageRequestPayload, TransferPreimageResponsePayload}, wallet::{Activity, CoinStatus}};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
 pub struct CreatePreImageResponse {
     pub hash: String,
     pub batch_id: String,
+    pub expires_at: String,
 }
 
 pub async fn create_pre_image(
     client_config: &ClientConfig, 
     wallet_name: &str, 
-    statechain_id: &str) -> Result<CreatePreImageResponse> 
+    statechain_id: &str,
+    expiry_secs: Option<u64>) -> Result<CreatePreImageResponse> 
 {
-    let batch_id = Some(uuid::Uuid::new_v4().to_string()).unwrap();
+    let batch_id = uuid::Uuid::new_v4().to_string();
 
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let coin = wallet.coins
         .iter_mut()
@@ -48,13 +51,14 @@ pub async fn create_pre_image(
         statechain_id: statechain_id.to_string(),
         auth_sig: signed_statechain_id.to_string(),
         batch_id: batch_id.clone(),
+        expiry_secs,
     };
 
     let endpoint = client_config.statechain_entity.clone();
     let path = "transfer/paymenthash";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let response = request.json(&payment_hash_payload).send().await?;
 
@@ -70,12 +74,13 @@ pub async fn create_pre_image(
     Ok(CreatePreImageResponse {
         hash: payment_hash_response_payload.hash,
         batch_id,
+        expires_at: payment_hash_response_payload.expires_at,
     })
 }
 
 pub async fn confirm_pending_invoice(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str) -> Result<()> {
 
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let coin = wallet.coins
         .iter_mut()
@@ -93,7 +98,7 @@ pub async fn confirm_pending_invoice(client_config: &ClientConfig, wallet_name:
     let path = "transfer/unlock";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
 
     let transfer_unlock_request_payload = mercurylib::transfer::receiver::TransferUnlockRequestPayload {
         statechain_id: statechain_id.to_string(),
@@ -112,7 +117,7 @@ pub async fn confirm_pending_invoice(client_config: &ClientConfig, wallet_name:
 
 pub async fn retrieve_pre_image(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, batch_id: &str) -> Result<String> {
 
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let coin = wallet.coins
         .iter_mut()
@@ -130,7 +135,7 @@ pub async fn retrieve_pre_image(client_config: &ClientConfig, wallet_name: &str,
     let path = "transfer/transfer_preimage";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
 
     let transfer_preimage_request_payload = TransferPreimageRequestPayload {
         statechain_id: statechain_id.to_string(),
@@ -147,16 +152,81 @@ pub async fn retrieve_pre_image(client_config: &ClientConfig, wallet_name: &str,
 }
 
 
+/// Reclaims a coin whose latch expired (unpaid, or the oracle condition failed).
+/// The receiver of the latched transfer already holds a backup transaction with a lower locktime, so the coin is sent
+/// again to a new address of this wallet to replace it. Returns that address; the coin is back once it is received.
+pub async fn reclaim(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, batch_id: &str) -> Result<String> {
+
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let coin = wallet.coins
+        .iter()
+        .filter(|tx| tx.statechain_id == Some(statechain_id.to_string()) && tx.status == CoinStatus::IN_TRANSFER) // Filter coins in transfer with the specified statechain_id
+        .min_by_key(|tx| tx.locktime.unwrap_or(u32::MAX)); // Find the one with the lowest locktime
+
+    if coin.is_none() {
+        return Err(anyhow!("No coin in transfer associated with this statechain ID was found"));
+    }
+
+    let coin = coin.unwrap();
+
+    let signed_statechain_id = coin.signed_statechain_id.as_ref().unwrap();
+
+    let path = "transfer/reclaim";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
+
+    let latch_reclaim_request_payload = LatchReclaimRequestPayload {
+        statechain_id: statechain_id.to_string(),
+        auth_sig: signed_statechain_id.to_string(),
+        batch_id: batch_id.to_string(),
+    };
+
+    let response = request.json(&latch_reclaim_request_payload).send().await?;
+
+    if response.status() != 200 {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let activity = Activity {
+        utxo: format!("{}:{}", coin.utxo_txid.as_ref().unwrap(), coin.utxo_vout.unwrap()),
+        amount: coin.amount.unwrap(),
+        action: "Lightning latch reclaimed".to_string(),
+        date: Utc::now().to_rfc3339(),
+    };
+
+    wallet.activities.push(activity);
+
+    update_wallet(&client_config.pool, &wallet).await?;
+
+    let own_address = crate::transfer_receiver::new_transfer_address(client_config, wallet_name).await?;
+
+    crate::transfer_sender::execute(client_config, &own_address, wallet_name, statechain_id, false, None).await
+        .map_err(|err| anyhow!("The latch expired, but the coin could not be sent again to replace the receiver's backup transaction: {}. Send the coin to a new address of this wallet.", err))?;
+
+    Ok(own_address)
+}
+
 pub async fn get_payment_hash(client_config: &ClientConfig, batch_id: &str) -> Result<Option<String>> {
 
+    let latch_info = get_latch_info(client_config, batch_id).await?;
+
+    Ok(latch_info.map(|latch_info| latch_info.hash))
+}
+
+/// Returns the payment hash and expiry of the latch of the batch
+pub async fn get_latch_info(client_config: &ClientConfig, batch_id: &str) -> Result<Option<PaymentHashResponsePayload>> {
+
     let path = format!("transfer/paymenthash/{}", batch_id);
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.get(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.get(format!("{}/{}", client_config.statechain_entity, path));
 
     let response = request.send().await?;
     
-    if response.status() == 401 {
+    if response.status() == 401 || response.status() == 404 {
         return Ok(None);
     } else if response.status() != 200 {
         let response_body = response.text().await?;
@@ -167,5 +237,5 @@ pub async fn get_payment_hash(client_config: &ClientConfig, batch_id: &str) -> R
 
     let payment_hash_response_payload: PaymentHashResponsePayload = serde_json::from_str(value.as_str())?;
 
-    Ok(Some(payment_hash_response_payload.hash))
+    Ok(Some(payment_hash_response_payload))
 }
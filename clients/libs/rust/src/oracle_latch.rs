@@ -0,0 +1,100 @@
+use crate::{client_config::ClientConfig, sqlite_manager::get_wallet};
+use anyhow::{anyhow, Result};
+use mercurylib::{transfer::sender::{ConditionalLatchRequestPayload, ConditionalLatchResponsePayload, OracleAttestationRequestPayload}, wallet::CoinStatus};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateConditionalLatchResponse {
+    pub batch_id: String,
+    pub expires_at: String,
+}
+
+/// Latches the coin so that the transfer only completes if the oracle attests the outcome of the event
+pub async fn create_conditional_latch(
+    client_config: &ClientConfig,
+    wallet_name: &str,
+    statechain_id: &str,
+    oracle_pub_key: &str,
+    event_id: &str,
+    outcome: &str,
+    expiry_secs: Option<u64>) -> Result<CreateConditionalLatchResponse>
+{
+    let batch_id = uuid::Uuid::new_v4().to_string();
+
+    let wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let coin = wallet.coins
+        .iter()
+        .filter(|tx| tx.statechain_id == Some(statechain_id.to_string())) // Filter coins with the specified statechain_id
+        .min_by_key(|tx| tx.locktime.unwrap_or(u32::MAX)); // Find the one with the lowest locktime
+
+    if coin.is_none() {
+        return Err(anyhow!("No coins associated with this statechain ID were found"));
+    }
+
+    let coin = coin.unwrap();
+
+    if coin.status != CoinStatus::CONFIRMED && coin.status != CoinStatus::IN_TRANSFER {
+        return Err(anyhow::anyhow!("Coin status must be CONFIRMED or IN_TRANSFER to transfer it. The current status is {}", coin.status));
+    }
+
+    let signed_statechain_id = coin.signed_statechain_id.as_ref().unwrap();
+
+    let conditional_latch_payload = ConditionalLatchRequestPayload {
+        statechain_id: statechain_id.to_string(),
+        auth_sig: signed_statechain_id.to_string(),
+        batch_id: batch_id.clone(),
+        oracle_pub_key: oracle_pub_key.to_string(),
+        event_id: event_id.to_string(),
+        outcome: outcome.to_string(),
+        expiry_secs,
+    };
+
+    let path = "transfer/conditional";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
+
+    let response = request.json(&conditional_latch_payload).send().await?;
+
+    if response.status() != 200 {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let value = response.text().await?;
+
+    let conditional_latch_response_payload: ConditionalLatchResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(CreateConditionalLatchResponse {
+        batch_id,
+        expires_at: conditional_latch_response_payload.expires_at,
+    })
+}
+
+/// Submits the oracle signature of an outcome. Anyone holding the attestation can submit it.
+pub async fn submit_attestation(client_config: &ClientConfig, statechain_id: &str, batch_id: &str, outcome: &str, signature: &str) -> Result<String> {
+
+    let oracle_attestation_payload = OracleAttestationRequestPayload {
+        statechain_id: statechain_id.to_string(),
+        batch_id: batch_id.to_string(),
+        outcome: outcome.to_string(),
+        signature: signature.to_string(),
+    };
+
+    let path = "transfer/attestation";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
+
+    let response = request.json(&oracle_attestation_payload).send().await?;
+
+    if response.status() != 200 {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let value: serde_json::Value = response.json().await?;
+
+    Ok(value["message"].as_str().unwrap_or_default().to_string())
+}
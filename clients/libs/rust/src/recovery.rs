@@ -0,0 +1,280 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use bitcoin::Address;
+use electrum_client::ElectrumApi;
+use mercurylib::{recovery::{create_recover_statecoins_request_payload, derive_recovery_coins, recover_coin, RecoverStatecoinsResponsePayload, RecoveredStatecoin, MAX_RECOVERY_AUTH_KEYS}, transfer::receiver::{decrypt_transfer_msg, get_tx0_outpoint, verify_latest_backup_tx_pays_to_user_pubkey}, utils::get_blockheight, wallet::{BackupTx, Coin, CoinStatus}};
+use reqwest::StatusCode;
+
+use crate::{client_config::ClientConfig, sqlite_manager::{get_wallet, insert_or_update_backup_txs, update_wallet}, utils::create_activity, wallet::session_passphrase};
+
+pub struct RecoveryResult {
+    pub recovered_statechain_ids: Vec<String>,
+    /// Statecoins recovered without backup transactions, which cannot be broadcast until they are restored
+    pub missing_backup_statechain_ids: Vec<String>,
+}
+
+async fn recover_statecoins(client_config: &ClientConfig, coins: &Vec<Coin>) -> Result<Vec<RecoveredStatecoin>> {
+
+    let challenge = crate::transfer_receiver::get_auth_challenge(client_config, coins).await?;
+
+    let recover_statecoins_request_payload = create_recover_statecoins_request_payload(coins, &challenge)?;
+
+    let path = "recovery/statecoins";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
+
+    let response = request.json(&recover_statecoins_request_payload).send().await?;
+
+    if response.status() != StatusCode::OK {
+        let error_message = response.text().await?;
+        return Err(anyhow!(error_message));
+    }
+
+    let value = response.text().await?;
+
+    let response_payload: RecoverStatecoinsResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(response_payload.statecoins)
+}
+
+/// Backup transactions of the statecoin, from its latest transfer message or from the backup file
+fn get_backup_txs(coin: &Coin, recovered_statecoin: &RecoveredStatecoin, backup_file_txs: &HashMap<String, Vec<BackupTx>>, network: &str) -> Result<Option<Vec<BackupTx>>> {
+
+    if let Some(enc_transfer_msg) = &recovered_statecoin.enc_transfer_msg {
+
+        let transfer_msg = decrypt_transfer_msg(enc_transfer_msg, &coin.auth_privkey)?;
+
+        if verify_latest_backup_tx_pays_to_user_pubkey(&transfer_msg, &coin.user_pubkey, network)? {
+            return Ok(Some(transfer_msg.backup_transactions));
+        }
+    }
+
+    Ok(backup_file_txs.get(&recovered_statecoin.statechain_id).cloned())
+}
+
+/// Scans the address indexes of the wallet until `gap_limit` consecutive indexes own no statecoin and adds the
+/// statecoins found to the wallet. The backup file is a JSON object of the backup transactions by statechain id.
+pub async fn recover_wallet(client_config: &ClientConfig, wallet_name: &str, gap_limit: u32, backup_file: Option<&str>) -> Result<RecoveryResult> {
+
+    if gap_limit == 0 {
+        return Err(anyhow!("The gap limit must be greater than zero"));
+    }
+
+    let backup_file_txs: HashMap<String, Vec<BackupTx>> = match backup_file {
+        Some(backup_file) => serde_json::from_str(&std::fs::read_to_string(backup_file)?)?,
+        None => HashMap::new(),
+    };
+
+    let mut wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let passphrase = session_passphrase();
+
+    let mut recovered_coins = Vec::<(Coin, RecoveredStatecoin)>::new();
+
+    let mut next_index = 0;
+    let mut scan_end = gap_limit;
+
+    while next_index < scan_end {
+
+        let coins = derive_recovery_coins(&wallet, next_index, scan_end - next_index, passphrase.clone())?;
+
+        next_index = scan_end;
+
+        // the server looks up a limited number of auth keys per request
+        for chunk in coins.chunks(MAX_RECOVERY_AUTH_KEYS) {
+
+            for recovered_statecoin in recover_statecoins(client_config, &chunk.to_vec()).await? {
+
+                let coin = chunk.iter().find(|coin| coin.auth_pubkey == recovered_statecoin.auth_pub_key)
+                    .ok_or(anyhow!("The server returned a statecoin for an auth key that was not requested"))?;
+
+                scan_end = scan_end.max(coin.index + 1 + gap_limit);
+
+                recovered_coins.push((coin.clone(), recovered_statecoin));
+            }
+        }
+    }
+
+    let block_header = client_config.electrum_client.block_headers_subscribe_raw()?;
+    let blockheight = block_header.height;
+
+    let mut recovered_statechain_ids = Vec::<String>::new();
+    let mut missing_backup_statechain_ids = Vec::<String>::new();
+
+    for (coin, recovered_statecoin) in recovered_coins {
+
+        let statechain_id = recovered_statecoin.statechain_id.clone();
+
+        if wallet.coins.iter().any(|wallet_coin| wallet_coin.statechain_id.as_ref() == Some(&statechain_id)) {
+            continue;
+        }
+
+        let mut coin = recover_coin(&coin, &recovered_statecoin, wallet.network.clone())?;
+
+        let backup_txs = get_backup_txs(&coin, &recovered_statecoin, &backup_file_txs, &wallet.network)?;
+
+        let tx0_outpoint = match &backup_txs {
+            Some(backup_txs) => Some(get_tx0_outpoint(backup_txs)?),
+            None => None,
+        };
+
+        let address = Address::from_str(coin.aggregated_address.as_ref().unwrap())?.require_network(client_config.network)?;
+
+        let utxo_list = client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
+
+        let utxo = utxo_list.into_iter().find(|unspent| match &tx0_outpoint {
+            Some(tx0_outpoint) => unspent.tx_hash.to_string() == tx0_outpoint.txid && unspent.tx_pos as u32 == tx0_outpoint.vout,
+            None => true,
+        });
+
+        // the statecoin was never funded or its output has been spent
+        let utxo = match utxo {
+            Some(utxo) => utxo,
+            None => continue,
+        };
+
+        coin.utxo_txid = Some(utxo.tx_hash.to_string());
+        coin.utxo_vout = Some(utxo.tx_pos as u32);
+        coin.amount = Some(utxo.value as u32);
+
+        coin.status = CoinStatus::IN_MEMPOOL;
+
+        if utxo.height > 0 {
+            let confirmations = blockheight - utxo.height + 1;
+
+            coin.status = if confirmations as u32 >= client_config.confirmation_target { CoinStatus::CONFIRMED } else { CoinStatus::UNCONFIRMED };
+        }
+
+        match backup_txs {
+            Some(backup_txs) => {
+                let latest_backup_tx = backup_txs.iter().max_by_key(|backup_tx| backup_tx.tx_n).ok_or(anyhow!("Empty backup transaction list"))?;
+                coin.locktime = Some(get_blockheight(latest_backup_tx)?);
+
+                insert_or_update_backup_txs(&client_config.pool, &statechain_id, &backup_txs).await?;
+            },
+            None => missing_backup_statechain_ids.push(statechain_id.clone()),
+        }
+
+        let activity_utxo = format!("{}:{}", utxo.tx_hash, utxo.tx_pos);
+        wallet.activities.push(create_activity(&activity_utxo, utxo.value as u32, "Recovery"));
+
+        wallet.coins.push(coin);
+
+        recovered_statechain_ids.push(statechain_id);
+    }
+
+    update_wallet(&client_config.pool, &wallet).await?;
+
+    Ok(RecoveryResult {
+        recovered_statechain_ids,
+        missing_backup_statechain_ids,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use mercurylib::wallet::{Settings, Wallet};
+
+    use super::*;
+
+    const STATECHAIN_ID: &str = "c5d4b9c6b2a44a6e9a2e0b1c1d9e8f7a";
+
+    fn test_coin() -> Coin {
+
+        let settings = Settings {
+            network: "regtest".to_string(),
+            block_explorerURL: None,
+            torProxyHost: None,
+            torProxyPort: None,
+            torProxyControlPassword: None,
+            torProxyControlPort: None,
+            statechainEntityApi: "http://127.0.0.1:8000".to_string(),
+            torStatechainEntityApi: None,
+            electrumProtocol: "tcp".to_string(),
+            electrumHost: "127.0.0.1".to_string(),
+            electrumPort: "50001".to_string(),
+            electrumType: "electrs".to_string(),
+            notifications: false,
+            tutorials: false,
+        };
+
+        let wallet = Wallet {
+            name: "w1".to_string(),
+            mnemonic: "ticket sock try two evidence employ fresh beauty settle general ridge lonely".to_string(),
+            version: "0.1.0".to_string(),
+            state_entity_endpoint: "http://127.0.0.1:8000".to_string(),
+            electrum_endpoint: "tcp://127.0.0.1:50001".to_string(),
+            network: "regtest".to_string(),
+            blockheight: 0,
+            initlock: 10000,
+            interval: 10,
+            tokens: Vec::new(),
+            activities: Vec::new(),
+            coins: Vec::new(),
+            settings,
+            encrypted_secrets: None,
+            has_passphrase: false,
+            master_fingerprint: None,
+        };
+
+        wallet.get_coin_at_index(0, "").unwrap()
+    }
+
+    fn recovered_statecoin(coin: &Coin, enc_transfer_msg: Option<String>) -> RecoveredStatecoin {
+        RecoveredStatecoin {
+            auth_pub_key: coin.auth_pubkey.clone(),
+            statechain_id: STATECHAIN_ID.to_string(),
+            server_pubkey: "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".to_string(),
+            enc_transfer_msg,
+        }
+    }
+
+    fn backup_file_txs() -> HashMap<String, Vec<BackupTx>> {
+
+        let backup_tx = BackupTx {
+            tx_n: 1,
+            tx: "02000000000000000000".to_string(),
+            client_public_nonce: String::new(),
+            server_public_nonce: String::new(),
+            client_public_key: String::new(),
+            server_public_key: String::new(),
+            blinding_factor: String::new(),
+        };
+
+        HashMap::from([(STATECHAIN_ID.to_string(), vec![backup_tx])])
+    }
+
+    #[test]
+    fn test_get_backup_txs_from_backup_file() {
+
+        let coin = test_coin();
+
+        let backup_txs = get_backup_txs(&coin, &recovered_statecoin(&coin, None), &backup_file_txs(), "regtest").unwrap();
+
+        let tx_ns: Vec<u32> = backup_txs.unwrap().iter().map(|backup_tx| backup_tx.tx_n).collect();
+        assert_eq!(tx_ns, vec![1]);
+    }
+
+    #[test]
+    fn test_get_backup_txs_missing() {
+
+        let coin = test_coin();
+
+        let backup_txs = get_backup_txs(&coin, &recovered_statecoin(&coin, None), &HashMap::new(), "regtest").unwrap();
+
+        assert!(backup_txs.is_none());
+    }
+
+    #[test]
+    fn test_get_backup_txs_invalid_transfer_msg() {
+
+        let coin = test_coin();
+
+        // a transfer message that cannot be decrypted is an error, not a fallback to the backup file
+        let recovered_statecoin = recovered_statecoin(&coin, Some("00".to_string()));
+
+        assert!(get_backup_txs(&coin, &recovered_statecoin, &backup_file_txs(), "regtest").is_err());
+    }
+}
@@ -1,11 +1,26 @@
-use mercurylib::wallet::{Wallet, BackupTx};
+use mercurylib::{deposit::blind_token::BlindedToken, wallet::{Wallet, BackupTx}};
 use serde_json::json;
 use sqlx::{Pool, Sqlite, Row};
 use anyhow::{anyhow, Result};
 
+use crate::wallet::{session_key, session_key_for, set_session_key};
+
+/// Encrypts the wallet secrets with the key of the session. An encrypted wallet is never stored unencrypted.
+fn wallet_to_store(wallet: &Wallet) -> Result<Wallet> {
+    let mut wallet = wallet.clone();
+    if !wallet.is_locked() {
+        match session_key(&wallet.name) {
+            Some(key) => wallet.lock_with_key(&key)?,
+            None if wallet.encrypted_secrets.is_some() => return Err(anyhow!("Wallet {} is encrypted, a password is required to store it", wallet.name)),
+            None => (),
+        }
+    }
+    Ok(wallet)
+}
+
 pub async fn insert_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
 
-    let wallet_json = json!(wallet).to_string();
+    let wallet_json = json!(wallet_to_store(wallet)?).to_string();
 
     let query = "INSERT INTO wallet (wallet_name, wallet_json) VALUES ($1, $2)";
 
@@ -18,7 +33,8 @@ pub async fn insert_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
     Ok(())
 }
 
-pub async fn get_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet> {
+/// Wallet as stored, without decrypting the secrets
+pub async fn get_stored_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet> {
     
     let query = "SELECT wallet_json FROM wallet WHERE wallet_name = $1";
 
@@ -38,9 +54,25 @@ pub async fn get_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet
     Ok(wallet)
 }
 
+/// Wallet with the secrets decrypted with the key of the session
+pub async fn get_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet> {
+
+    let mut wallet = get_stored_wallet(pool, wallet_name).await?;
+
+    if let Some(encrypted_secrets) = wallet.encrypted_secrets.clone() {
+        if wallet.is_locked() {
+            let key = session_key_for(wallet_name, &encrypted_secrets)?;
+            wallet.unlock_with_key(&key)?;
+            set_session_key(wallet_name, key);
+        }
+    }
+
+    Ok(wallet)
+}
+
 pub async fn update_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
     
-    let wallet_json = json!(wallet).to_string();
+    let wallet_json = json!(wallet_to_store(wallet)?).to_string();
 
     let query = "UPDATE wallet SET wallet_json = $1 WHERE wallet_name = $2";
 
@@ -103,6 +135,25 @@ pub async fn get_backup_txs(pool: &Pool<Sqlite>, statechain_id: &str,) -> Result
     Ok(backup_txs)
 }
 
+/// Backup transactions of the statecoin, if there are any
+pub async fn find_backup_txs(pool: &Pool<Sqlite>, statechain_id: &str) -> Result<Option<Vec<BackupTx>>> {
+
+    let query = "SELECT txs FROM backup_txs WHERE statechain_id = $1";
+
+    let row = sqlx::query(query)
+        .bind(statechain_id)
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => {
+            let backup_txs_json: String = row.get(0);
+            Ok(Some(serde_json::from_str(&backup_txs_json)?))
+        },
+        None => Ok(None),
+    }
+}
+
 pub async fn insert_or_update_backup_txs(pool: &Pool<Sqlite>, statechain_id: &str, backup_txs: &Vec<BackupTx>) -> Result<()> {
 
     let mut transaction = pool.begin().await?;
@@ -127,4 +178,48 @@ pub async fn insert_or_update_backup_txs(pool: &Pool<Sqlite>, statechain_id: &st
     transaction.commit().await?;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Stores the blinded token of a paid token before its challenge is sent, since the token server signs a single challenge per token
+pub async fn insert_blinded_token(pool: &Pool<Sqlite>, paid_token_id: &str, blinded_token: &BlindedToken) -> Result<()> {
+
+    let query = "INSERT INTO blinded_tokens (paid_token_id, blinded_token) VALUES ($1, $2)";
+
+    let _ = sqlx::query(query)
+        .bind(paid_token_id)
+        .bind(json!(blinded_token).to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_blinded_token(pool: &Pool<Sqlite>, paid_token_id: &str) -> Result<Option<BlindedToken>> {
+
+    let query = "SELECT blinded_token FROM blinded_tokens WHERE paid_token_id = $1";
+
+    let row = sqlx::query(query)
+        .bind(paid_token_id)
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => {
+            let blinded_token_json: String = row.get(0);
+            Ok(Some(serde_json::from_str(&blinded_token_json)?))
+        },
+        None => Ok(None),
+    }
+}
+
+pub async fn delete_blinded_token(pool: &Pool<Sqlite>, paid_token_id: &str) -> Result<()> {
+
+    let query = "DELETE FROM blinded_tokens WHERE paid_token_id = $1";
+
+    let _ = sqlx::query(query)
+        .bind(paid_token_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
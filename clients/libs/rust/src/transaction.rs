@@ -6,6 +6,7 @@ use secp256k1_zkp::musig::MusigPartialSignature;
 use serde_json::Value;
 use crate::client_config::ClientConfig;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn new_transaction(
     client_config: &ClientConfig, 
     coin: &mut Coin, 
@@ -20,12 +21,12 @@ pub async fn new_transaction(
 
     // TODO: validate address first
 
-    let coin_nonce = mercurylib::transaction::create_and_commit_nonces(&coin)?;
+    let coin_nonce = mercurylib::transaction::create_and_commit_nonces(coin)?;
     coin.secret_nonce = Some(coin_nonce.secret_nonce);
     coin.public_nonce = Some(coin_nonce.public_nonce);
     coin.blinding_factor = Some(coin_nonce.blinding_factor);
 
-    let server_public_nonce = sign_first(&client_config, &coin_nonce.sign_first_request_payload).await?;
+    let server_public_nonce = sign_first(client_config, &coin_nonce.sign_first_request_payload).await?;
 
     coin.server_public_nonce = Some(server_public_nonce);
 
@@ -38,7 +39,7 @@ pub async fn new_transaction(
     };
 
     let partial_sig_request = get_partial_sig_request(
-        &coin, 
+        coin, 
         block_height, 
         initlock, 
         interval, 
@@ -50,7 +51,7 @@ pub async fn new_transaction(
 
     let server_partial_sig_request = partial_sig_request.partial_signature_request_payload;
 
-    let server_partial_sig = sign_second(&client_config, &server_partial_sig_request).await?;
+    let server_partial_sig = sign_second(client_config, &server_partial_sig_request).await?;
 
     let client_partial_sig_hex = partial_sig_request.client_partial_sig;
     let server_partial_sig_hex = hex::encode(server_partial_sig.serialize());
@@ -74,7 +75,7 @@ pub async fn sign_first(client_config: &ClientConfig, sign_first_request_payload
     let path = "sign/first";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     // let value = request.json(&sign_first_request_payload).send().await?.text().await?;
 
@@ -107,7 +108,7 @@ pub async fn sign_second(client_config: &ClientConfig, partial_sig_request: &Par
     let path = "sign/second";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let value = request.json(&partial_sig_request).send().await?.text().await?;
 
@@ -1,20 +1,20 @@
-use std::{collections::{HashMap, HashSet}, str::FromStr};
+use std::{collections::HashMap, str::FromStr};
 
 use crate::{sqlite_manager::{get_wallet, update_wallet, insert_or_update_backup_txs}, client_config::ClientConfig, utils};
 use anyhow::{anyhow, Ok, Result};
 use bitcoin::{Txid, Address};
 use chrono::Utc;
 use electrum_client::ElectrumApi;
-use mercurylib::{utils::{get_network, InfoConfig}, wallet::{Activity, Coin, CoinStatus}};
+use mercurylib::{transfer::receiver::{TransferNotificationPayload, MAX_MSG_ADDR_BULK_AUTH_KEYS}, utils::{get_network, InfoConfig}, wallet::{Activity, Coin, CoinStatus}};
 use reqwest::StatusCode;
 
 pub async fn new_transfer_address(client_config: &ClientConfig, wallet_name: &str) -> Result<String>{
 
-    let wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let wallet = get_wallet(&client_config.pool, wallet_name).await?;
     
     let mut wallet = wallet.clone();
 
-    let coin = wallet.get_new_coin()?;
+    let coin = wallet.get_new_coin_with_passphrase(&crate::wallet::session_passphrase())?;
 
     wallet.coins.push(coin.clone());
 
@@ -30,27 +30,13 @@ pub struct TransferReceiveResult {
 
 pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<TransferReceiveResult>{
 
-    let mut wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
-    let info_config = utils::info_config(&client_config).await.unwrap();
+    let info_config = utils::info_config(client_config).await.unwrap();
 
-    let mut unique_auth_pubkeys: HashSet<String> = HashSet::new();
-    
-    for coin in wallet.coins.iter() {
-        unique_auth_pubkeys.insert(coin.auth_pubkey.clone());
-    }
-
-    let mut enc_msgs_per_auth_pubkey: HashMap<String, Vec<String>> = HashMap::new();
-
-    for auth_pubkey in unique_auth_pubkeys {
+    let mut enc_msgs_per_auth_pubkey = get_msg_addr_bulk(&wallet.coins, client_config).await?;
 
-        let enc_messages = get_msg_addr(&auth_pubkey, &client_config).await?;
-        if enc_messages.len() == 0 {
-            continue;
-        }
-
-        enc_msgs_per_auth_pubkey.insert(auth_pubkey.clone(), enc_messages);
-    }
+    enc_msgs_per_auth_pubkey.retain(|_, enc_messages| !enc_messages.is_empty());
 
     let mut is_there_batch_locked = false;
 
@@ -69,12 +55,12 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
 
             if coin.is_some() {
 
-                let mut coin = coin.unwrap();
+                let coin = coin.unwrap();
 
-                let message_result = process_encrypted_message(client_config, &mut coin, enc_message, &wallet.network, &info_config, &mut temp_activities).await;
+                let message_result = process_encrypted_message(client_config, coin, enc_message, &wallet.network, &info_config, &mut temp_activities).await;
 
                 if message_result.is_err() {
-                    println!("Error: {}", message_result.err().unwrap().to_string());
+                    println!("Error: {}", message_result.err().unwrap());
                     continue;
                 }
 
@@ -93,7 +79,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
                 let new_coin = mercurylib::transfer::receiver::duplicate_coin_to_initialized_state(&wallet, &auth_pubkey);
 
                 if new_coin.is_err() {
-                    println!("Error: {}", new_coin.err().unwrap().to_string());
+                    println!("Error: {}", new_coin.err().unwrap());
                     continue;
                 }
 
@@ -102,7 +88,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
                 let message_result = process_encrypted_message(client_config, &mut new_coin, enc_message, &wallet.network, &info_config, &mut temp_activities).await;
 
                 if message_result.is_err() {
-                    println!("Error: {}", message_result.err().unwrap().to_string());
+                    println!("Error: {}", message_result.err().unwrap());
                     continue;
                 }
 
@@ -132,18 +118,118 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
     })
 }
 
-async fn get_msg_addr(auth_pubkey: &str, client_config: &ClientConfig) -> Result<Vec<String>> {
+/// Requests a single use challenge for the auth keys of the coins. Requests using the challenge must carry proofs of all of them.
+pub async fn get_auth_challenge(client_config: &ClientConfig, coins: &[Coin]) -> Result<String> {
 
-    let path = format!("transfer/get_msg_addr/{}", auth_pubkey.to_string());
+    let path = "transfer/challenge";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.get(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
 
-    let value = request.send().await?.text().await?;
+    let auth_challenge_request_payload = mercurylib::transfer::receiver::AuthChallengeRequestPayload {
+        auth_pub_keys: coins.iter().map(|coin| coin.auth_pubkey.clone()).collect(),
+    };
 
-    let response: mercurylib::transfer::receiver::GetMsgAddrResponsePayload = serde_json::from_str(value.as_str())?;
+    let response = request.json(&auth_challenge_request_payload).send().await?;
 
-    Ok(response.list_enc_transfer_msg)
+    if response.status() != StatusCode::OK {
+        let error_message = response.text().await?;
+        return Err(anyhow!(error_message));
+    }
+
+    let value = response.text().await?;
+
+    let response: mercurylib::transfer::receiver::AuthChallengeResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(response.challenge)
+}
+
+async fn get_msg_addr_bulk(coins: &[Coin], client_config: &ClientConfig) -> Result<HashMap<String, Vec<String>>> {
+
+    if coins.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut enc_transfer_msgs_per_auth_key = HashMap::<String, Vec<String>>::new();
+
+    // the server looks up a limited number of auth keys per request
+    for chunk in coins.chunks(MAX_MSG_ADDR_BULK_AUTH_KEYS) {
+
+        let challenge = get_auth_challenge(client_config, chunk).await?;
+
+        let get_msg_addr_bulk_request_payload = mercurylib::transfer::receiver::create_get_msg_addr_bulk_request_payload(&chunk.to_vec(), &challenge)?;
+
+        let path = "transfer/get_msg_addr_bulk";
+
+        let client = client_config.get_reqwest_client()?;
+        let request = client.post(&format!("{}/{}", client_config.statechain_entity, path));
+
+        let response = request.json(&get_msg_addr_bulk_request_payload).send().await?;
+
+        if response.status() != StatusCode::OK {
+            let error_message = response.text().await?;
+            return Err(anyhow!(error_message));
+        }
+
+        let value = response.text().await?;
+
+        let response: mercurylib::transfer::receiver::GetMsgAddrBulkResponsePayload = serde_json::from_str(value.as_str())?;
+
+        // duplicated coins in different chunks share the same auth key
+        for (auth_key, enc_transfer_msgs) in response.enc_transfer_msgs_per_auth_key {
+            enc_transfer_msgs_per_auth_key.insert(auth_key, enc_transfer_msgs);
+        }
+    }
+
+    Ok(enc_transfer_msgs_per_auth_key)
+}
+
+/// Subscribes to the transfer notifications of all the wallet auth keys and returns the first one received.
+pub async fn wait_for_transfer_notification(client_config: &ClientConfig, wallet_name: &str) -> Result<TransferNotificationPayload> {
+
+    let wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    if wallet.coins.is_empty() {
+        return Err(anyhow!("The wallet has no coins to receive transfers."));
+    }
+
+    let challenge = get_auth_challenge(client_config, &wallet.coins).await?;
+
+    let transfer_notification_request_payload = mercurylib::transfer::receiver::create_transfer_notification_request_payload(&wallet.coins, &challenge)?;
+
+    let path = "transfer/notifications";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
+
+    let mut response = request.json(&transfer_notification_request_payload).send().await?;
+
+    if response.status() != StatusCode::OK {
+        let error_message = response.text().await?;
+        return Err(anyhow!(error_message));
+    }
+
+    let mut buffer = String::new();
+
+    while let Some(chunk) = response.chunk().await? {
+
+        buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+        // server-sent events are separated by a blank line
+        while let Some(pos) = buffer.find("\n\n") {
+
+            let event: String = buffer.drain(..pos + 2).collect();
+
+            for line in event.lines() {
+                if let Some(data) = line.strip_prefix("data:") {
+                    let notification: TransferNotificationPayload = serde_json::from_str(data.trim())?;
+                    return Ok(notification);
+                }
+            }
+        }
+    }
+
+    Err(anyhow!("Transfer notification stream closed by the server."))
 }
 
 pub struct MessageResult {
@@ -168,7 +254,7 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
         return Err(anyhow::anyhow!("Invalid transfer signature".to_string()));
     }
 
-    let statechain_info = utils::get_statechain_info(&transfer_msg.statechain_id, &client_config).await?;
+    let statechain_info = utils::get_statechain_info(&transfer_msg.statechain_id, client_config).await?;
 
     if statechain_info.is_none() {
         return Err(anyhow::anyhow!("Statechain info not found".to_string()));
@@ -188,11 +274,9 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
         return Err(anyhow::anyhow!("Latest Backup Tx does not pay to the expected public key".to_string()));
     }
 
-    if statechain_info.num_sigs != transfer_msg.backup_transactions.len() as u32 {
-        return Err(anyhow::anyhow!("num_sigs is not correct".to_string()));
-    }
+    mercurylib::transfer::receiver::verify_backup_chain(&transfer_msg.backup_transactions, &statechain_info)?;
 
-    let (is_tx0_output_unspent, tx0_status) = verify_tx0_output_is_unspent_and_confirmed(&client_config.electrum_client, &tx0_outpoint, &tx0_hex, &network, client_config.confirmation_target).await?;
+    let (is_tx0_output_unspent, tx0_status) = verify_tx0_output_is_unspent_and_confirmed(&client_config.electrum_client, &tx0_outpoint, &tx0_hex, network, client_config.confirmation_target).await?;
 
     if !is_tx0_output_unspent {
         return Err(anyhow::anyhow!("tx0 output is spent or not confirmed".to_string()));
@@ -219,17 +303,17 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
 
     let previous_lock_time = previous_lock_time.unwrap();
 
-    let transfer_receiver_request_payload = mercurylib::transfer::receiver::create_transfer_receiver_request_payload(&statechain_info, &transfer_msg, &coin)?;
+    let transfer_receiver_request_payload = mercurylib::transfer::receiver::create_transfer_receiver_request_payload(&statechain_info, &transfer_msg, coin)?;
 
     // unlock the statecoin - it might be part of a batch
 
     // the pub_auth_key has not been updated yet in the server (it will be updated after the transfer/receive call)
     // So we need to manually sign the statechain_id with the client_auth_key
-    let signed_statechain_id_for_unlock = mercurylib::transfer::receiver::sign_message(&transfer_msg.statechain_id, &coin)?;
+    let signed_statechain_id_for_unlock = mercurylib::transfer::receiver::sign_message(&transfer_msg.statechain_id, coin)?;
 
-    unlock_statecoin(&client_config, &transfer_msg.statechain_id, &signed_statechain_id_for_unlock, &coin.auth_pubkey).await?;
+    unlock_statecoin(client_config, &transfer_msg.statechain_id, &signed_statechain_id_for_unlock, &coin.auth_pubkey).await?;
 
-    let transfer_receiver_result = send_transfer_receiver_request_payload(&client_config, &transfer_receiver_request_payload).await;
+    let transfer_receiver_result = send_transfer_receiver_request_payload(client_config, &transfer_receiver_request_payload).await;
 
     let server_public_key_hex = match transfer_receiver_result {
         std::result::Result::Ok(server_public_key_hex) => {
@@ -248,7 +332,7 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
         }
     };
 
-    let new_key_info = mercurylib::transfer::receiver::get_new_key_info(&server_public_key_hex, &coin, &transfer_msg.statechain_id, &tx0_outpoint, &tx0_hex, network)?;
+    let new_key_info = mercurylib::transfer::receiver::get_new_key_info(&server_public_key_hex, coin, &transfer_msg.statechain_id, &tx0_outpoint, &tx0_hex, network)?;
 
     coin.server_pubkey = Some(server_public_key_hex);
     coin.aggregated_pubkey = Some(new_key_info.aggregate_pubkey);
@@ -288,7 +372,7 @@ async fn get_tx0(electrum_client: &electrum_client::Client, tx0_txid: &str) -> R
     let tx0_txid = Txid::from_str(tx0_txid)?;
     let tx_bytes = electrum_client.batch_transaction_get_raw(&[tx0_txid])?;
 
-    if tx_bytes.len() == 0 {
+    if tx_bytes.is_empty() {
         return Err(anyhow!("tx0 not found"));
     }
 
@@ -300,9 +384,9 @@ async fn get_tx0(electrum_client: &electrum_client::Client, tx0_txid: &str) -> R
 }
 
 async fn verify_tx0_output_is_unspent_and_confirmed(electrum_client: &electrum_client::Client, tx0_outpoint: &mercurylib::transfer::receiver::TxOutpoint, tx0_hex: &str, network: &str, confirmation_target: u32) -> Result<(bool, CoinStatus)> {
-    let output_address = mercurylib::transfer::receiver::get_output_address_from_tx0(&tx0_outpoint, &tx0_hex, &network)?;
+    let output_address = mercurylib::transfer::receiver::get_output_address_from_tx0(tx0_outpoint, tx0_hex, network)?;
 
-    let network = get_network(&network)?;
+    let network = get_network(network)?;
     let address = Address::from_str(&output_address)?.require_network(network)?;
     let script = address.script_pubkey();
     let script = script.as_script();
@@ -334,7 +418,7 @@ async fn unlock_statecoin(client_config: &ClientConfig, statechain_id: &str, sig
     let path = "transfer/unlock";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.post(format!("{}/{}", client_config.statechain_entity, path));
 
     let transfer_unlock_request_payload = mercurylib::transfer::receiver::TransferUnlockRequestPayload {
         statechain_id: statechain_id.to_string(),
@@ -362,7 +446,7 @@ async fn send_transfer_receiver_request_payload(client_config: &ClientConfig, tr
 
     let client = client_config.get_reqwest_client()?;
 
-        let request: reqwest::RequestBuilder = client.post(&format!("{}/{}", client_config.statechain_entity, path));
+        let request: reqwest::RequestBuilder = client.post(format!("{}/{}", client_config.statechain_entity, path));
 
         let response = request.json(&transfer_receiver_request_payload).send().await?;
 
@@ -389,12 +473,12 @@ async fn send_transfer_receiver_request_payload(client_config: &ClientConfig, tr
 
         if status == StatusCode::OK {
             let response: mercurylib::transfer::receiver::TransferReceiverPostResponsePayload = serde_json::from_str(value.as_str())?;
-            return Ok(TransferReceiveRequestResult {
+            Ok(TransferReceiveRequestResult {
                 is_batch_locked: false,
                 server_pubkey: Some(response.server_pubkey)
-            });
+            })
         } else {
-            return Err(anyhow::anyhow!("{}: {}", "Failed to update transfer message".to_string(), value));
+            Err(anyhow::anyhow!("{}: {}", "Failed to update transfer message".to_string(), value))
         }
     
 }
\ No newline at end of file
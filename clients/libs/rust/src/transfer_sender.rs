@@ -1,8 +1,11 @@
+use std::future::Future;
+
 use crate::{client_config::ClientConfig, sqlite_manager::{get_backup_txs, get_wallet, update_backup_txs, update_wallet}, transaction::new_transaction, utils::info_config};
 use anyhow::{anyhow, Result};
 use chrono::Utc;
-use mercurylib::{wallet::{Coin, BackupTx, Activity, CoinStatus}, utils::get_blockheight, decode_transfer_address, transfer::sender::{TransferSenderRequestPayload, TransferSenderResponsePayload, create_transfer_signature, create_transfer_update_msg}};
+use mercurylib::{wallet::{Coin, BackupTx, Activity, CoinStatus}, utils::get_blockheight, decode_transfer_address, transfer::sender::{TransferBatchCancelRequestPayload, TransferCancelRequestPayload, TransferSenderRequestPayload, TransferSenderResponsePayload, create_transfer_signature, create_transfer_update_msg}};
 use electrum_client::ElectrumApi;
+use reqwest::StatusCode;
 
 pub async fn execute(
     client_config: &ClientConfig, 
@@ -13,7 +16,36 @@ pub async fn execute(
     batch_id: Option<String>) -> Result<()> 
 {
 
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let x1 = register_transfer(client_config, &mut wallet, recipient_address, statechain_id, force_send, batch_id, None).await?;
+
+    send_transfer_msg(client_config, &mut wallet, recipient_address, statechain_id, &x1).await
+}
+
+// If the user sends to himself, he will have two coins with same statechain_id
+// In this case, we need to find the one with the lowest locktime
+fn find_coin_to_send<'a>(wallet: &'a mut mercurylib::wallet::Wallet, statechain_id: &str) -> Result<&'a mut Coin> {
+
+    let coin = wallet.coins
+        .iter_mut()
+        .filter(|c| c.statechain_id == Some(statechain_id.to_string()) && c.status != CoinStatus::DUPLICATED) // Filter coins with the specified statechain_id
+        .min_by_key(|c| c.locktime.unwrap_or(u32::MAX)); // Find the one with the lowest locktime
+
+    coin.ok_or(anyhow!("No coins associated with this statechain ID were found"))
+}
+
+/// Checks that the coin can be sent and registers the transfer on the server, returning x1.
+/// Nothing is sent to the receiver yet, so the transfer can still be cancelled.
+async fn register_transfer(
+    client_config: &ClientConfig, 
+    wallet: &mut mercurylib::wallet::Wallet, 
+    recipient_address: &str, 
+    statechain_id: &str,
+    force_send: bool,
+    batch_id: Option<String>,
+    batch_size: Option<u32>) -> Result<String> 
+{
 
     let is_address_valid = mercurylib::validate_address(recipient_address, &wallet.network)?;
 
@@ -21,18 +53,12 @@ pub async fn execute(
         return Err(anyhow!("Invalid address"));
     }
 
-    let mut backup_transactions = get_backup_txs(&client_config.pool, &statechain_id).await?;
+    let backup_transactions = get_backup_txs(&client_config.pool, statechain_id).await?;
 
-    if backup_transactions.len() == 0 {
+    if backup_transactions.is_empty() {
         return Err(anyhow!("No backup transaction associated with this statechain ID were found"));
     }
 
-    let qt_backup_tx = backup_transactions.len() as u32;
-
-    backup_transactions.sort_by(|a, b| a.tx_n.cmp(&b.tx_n));
-
-    let new_tx_n = backup_transactions.len() as u32 + 1;
-
     let is_coin_duplicated = wallet.coins.iter().any(|c| {
         c.statechain_id == Some(statechain_id.to_string()) &&
         c.status == CoinStatus::DUPLICATED
@@ -40,7 +66,7 @@ pub async fn execute(
 
     let are_there_duplicate_coins_withdrawn = wallet.coins.iter().any(|c| {
         c.statechain_id == Some(statechain_id.to_string()) &&
-        (c.status == CoinStatus::WITHDRAWING || c.status == CoinStatus::WITHDRAWING) &&
+        (c.status == CoinStatus::WITHDRAWING || c.status == CoinStatus::WITHDRAWN) &&
         c.duplicate_index > 0
     });
 
@@ -50,18 +76,7 @@ pub async fn execute(
         This coin can be withdrawn, however."));
     }
 
-    // If the user sends to himself, he will have two coins with same statechain_id
-    // In this case, we need to find the one with the lowest locktime
-    let coin = wallet.coins
-        .iter_mut()
-        .filter(|c| c.statechain_id == Some(statechain_id.to_string()) && c.status != CoinStatus::DUPLICATED) // Filter coins with the specified statechain_id
-        .min_by_key(|c| c.locktime.unwrap_or(u32::MAX)); // Find the one with the lowest locktime
-
-    if coin.is_none() {
-        return Err(anyhow!("No coins associated with this statechain ID were found"));
-    }
-
-    let coin = coin.unwrap();
+    let coin = find_coin_to_send(wallet, statechain_id)?;
 
     if is_coin_duplicated && !force_send {
         return Err(anyhow::anyhow!("Coin is duplicated. If you want to proceed, use the command '--force, -f' option. \
@@ -91,11 +106,33 @@ pub async fn execute(
     let signed_statechain_id = coin.signed_statechain_id.as_ref().unwrap();
 
     let (_, _, recipient_auth_pubkey) = decode_transfer_address(recipient_address)?;  
-    let x1 = get_new_x1(&client_config,  statechain_id, signed_statechain_id, &recipient_auth_pubkey.to_string(), batch_id).await?;
+    get_new_x1(client_config,  statechain_id, signed_statechain_id, &recipient_auth_pubkey.to_string(), batch_id, batch_size).await
+}
+
+/// Signs the backup transaction to the receiver and sends the transfer message of a registered transfer
+async fn send_transfer_msg(
+    client_config: &ClientConfig, 
+    wallet: &mut mercurylib::wallet::Wallet, 
+    recipient_address: &str, 
+    statechain_id: &str,
+    x1: &str) -> Result<()> 
+{
+
+    let mut backup_transactions = get_backup_txs(&client_config.pool, statechain_id).await?;
+
+    let qt_backup_tx = backup_transactions.len() as u32;
+
+    backup_transactions.sort_by(|a, b| a.tx_n.cmp(&b.tx_n));
+
+    let new_tx_n = backup_transactions.len() as u32 + 1;
+
+    let network = wallet.network.clone();
+
+    let coin = find_coin_to_send(wallet, statechain_id)?;
 
     let bkp_tx1 = &backup_transactions[0];
 
-    let signed_tx = create_backup_tx_to_receiver(client_config, coin, bkp_tx1, recipient_address, qt_backup_tx, &wallet.network).await?;
+    let signed_tx = create_backup_tx_to_receiver(client_config, coin, bkp_tx1, recipient_address, qt_backup_tx, &network).await?;
 
     let backup_tx = BackupTx {
         tx_n: new_tx_n,
@@ -115,13 +152,13 @@ pub async fn execute(
 
     let transfer_signature = create_transfer_signature(recipient_address, input_txid, input_vout, client_seckey)?; 
 
-    let transfer_update_msg_request_payload = create_transfer_update_msg(&x1, recipient_address, &coin, &transfer_signature, &backup_transactions)?;
+    let transfer_update_msg_request_payload = create_transfer_update_msg(x1, recipient_address, coin, &transfer_signature, &backup_transactions)?;
 
     let endpoint = client_config.statechain_entity.clone();
     let path = "transfer/update_msg";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let status = request.json(&transfer_update_msg_request_payload).send().await?.status();
 
@@ -129,7 +166,7 @@ pub async fn execute(
         return Err(anyhow::anyhow!("Failed to update transfer message".to_string()));
     }
 
-    update_backup_txs(&client_config.pool, &coin.statechain_id.as_ref().unwrap(), &backup_transactions).await?;
+    update_backup_txs(&client_config.pool, coin.statechain_id.as_ref().unwrap(), &backup_transactions).await?;
 
     let date = Utc::now(); // This will get the current date and time in UTC
     let iso_string = date.to_rfc3339(); // Converts the date to an ISO 8601 string
@@ -143,8 +180,213 @@ pub async fn execute(
         date: iso_string
     };
 
-    wallet.activities.push(activity);
     coin.status = CoinStatus::IN_TRANSFER;
+    wallet.activities.push(activity);
+
+    update_wallet(&client_config.pool, wallet).await?;
+
+    Ok(())
+}
+
+/// Cancels a pending transfer before the receiver completes it and moves the coin back to CONFIRMED.
+/// The backup transaction to the cancelled recipient remains in the backup history,
+/// so the coin should be sent again (e.g. to a new address of this wallet) to replace it.
+pub async fn cancel(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str) -> Result<()> {
+
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let coin = wallet.coins
+        .iter_mut()
+        .find(|c| c.statechain_id == Some(statechain_id.to_string()) && c.status == CoinStatus::IN_TRANSFER);
+
+    if coin.is_none() {
+        return Err(anyhow!("No coin in transfer associated with this statechain ID was found"));
+    }
+
+    let coin = coin.unwrap();
+
+    let signed_statechain_id = coin.signed_statechain_id.as_ref().unwrap();
+
+    let transfer_cancel_request_payload = TransferCancelRequestPayload {
+        statechain_id: statechain_id.to_string(),
+        auth_sig: signed_statechain_id.to_string(),
+    };
+
+    let endpoint = client_config.statechain_entity.clone();
+    let path = "transfer/cancel";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", endpoint, path));
+
+    let response = request.json(&transfer_cancel_request_payload).send().await?;
+
+    let status = response.status();
+
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or("Unexpected error".to_string());
+        return Err(anyhow!(format!("status: {}, error: {}", status, text)));
+    }
+
+    let date = Utc::now();
+    let iso_string = date.to_rfc3339();
+
+    let utxo = format!("{}:{}", coin.utxo_txid.as_ref().unwrap(), coin.utxo_vout.unwrap());
+
+    let activity = Activity {
+        utxo,
+        amount: coin.amount.unwrap(),
+        action: "Transfer cancelled".to_string(),
+        date: iso_string
+    };
+
+    coin.status = CoinStatus::CONFIRMED;
+
+    wallet.activities.push(activity);
+
+    update_wallet(&client_config.pool, &wallet).await?;
+
+    Ok(())
+}
+
+/// Sends several coins (statechain_id, recipient address) in a single atomic batch and returns the batch id.
+/// Every coin is registered with the size of the batch before any transfer message is sent, so the server keeps
+/// the batch locked until all of them are registered. If a registration or a transfer message fails,
+/// the whole batch is cancelled, including the transfers whose messages were already sent.
+pub async fn execute_batch(
+    client_config: &ClientConfig, 
+    wallet_name: &str, 
+    transfers: &[(String, String)],
+    force_send: bool) -> Result<String> 
+{
+    if transfers.is_empty() {
+        return Err(anyhow!("No transfers provided"));
+    }
+
+    let mut unique_statechain_ids = transfers.iter().map(|(statechain_id, _)| statechain_id).collect::<Vec<_>>();
+    unique_statechain_ids.sort();
+    unique_statechain_ids.dedup();
+
+    if unique_statechain_ids.len() != transfers.len() {
+        return Err(anyhow!("The same statechain ID cannot be sent twice in a batch"));
+    }
+
+    let batch_id = uuid::Uuid::new_v4().to_string();
+    let batch_size = transfers.len() as u32;
+
+    let statechain_ids = transfers.iter().map(|(statechain_id, _)| statechain_id.clone()).collect::<Vec<_>>();
+
+    let x1_list = run_batch_step(
+        transfers.iter().collect(),
+        |(statechain_id, recipient_address)| {
+            let batch_id = batch_id.clone();
+            async move {
+                let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+                register_transfer(client_config, &mut wallet, recipient_address, statechain_id, force_send, Some(batch_id), Some(batch_size)).await
+            }
+        },
+        || cancel_batch(client_config, wallet_name, &batch_id, &statechain_ids)).await?;
+
+    // the receivers of the messages already sent cannot complete their transfers until the whole batch is sent
+    run_batch_step(
+        transfers.iter().zip(x1_list.iter()).collect(),
+        |((statechain_id, recipient_address), x1)| async move {
+            let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+            send_transfer_msg(client_config, &mut wallet, recipient_address, statechain_id, x1).await
+        },
+        || cancel_batch(client_config, wallet_name, &batch_id, &statechain_ids)).await?;
+
+    Ok(batch_id)
+}
+
+/// Runs the step on every transfer of the batch in order. If a step fails, the batch is rolled back.
+async fn run_batch_step<I, T, S, SFut, R, RFut>(items: Vec<I>, mut step: S, rollback: R) -> Result<Vec<T>>
+where
+    S: FnMut(I) -> SFut,
+    SFut: Future<Output = Result<T>>,
+    R: FnOnce() -> RFut,
+    RFut: Future<Output = Result<()>>,
+{
+    let mut results = Vec::<T>::new();
+
+    for item in items {
+
+        match step(item).await {
+            Ok(result) => results.push(result),
+            Err(err) => {
+                if let Err(rollback_err) = rollback().await {
+                    return Err(anyhow!("Batch transfer failed: {}. Rollback failed: {}", err, rollback_err));
+                }
+
+                return Err(anyhow!("Batch transfer failed and was rolled back: {}", err));
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Cancels all the transfers registered under the batch id and moves the coins back to CONFIRMED.
+pub async fn cancel_batch(client_config: &ClientConfig, wallet_name: &str, batch_id: &str, statechain_ids: &Vec<String>) -> Result<()> {
+
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
+
+    let mut cancel_requests = Vec::<TransferCancelRequestPayload>::new();
+
+    for statechain_id in statechain_ids {
+
+        let coin = wallet.coins
+            .iter()
+            .find(|c| c.statechain_id == Some(statechain_id.to_string()) && (c.status == CoinStatus::IN_TRANSFER || c.status == CoinStatus::CONFIRMED));
+
+        // coins in any other state could not have been registered in the batch
+        if coin.is_none() || coin.unwrap().signed_statechain_id.is_none() {
+            continue;
+        }
+
+        let coin = coin.unwrap();
+
+        cancel_requests.push(TransferCancelRequestPayload {
+            statechain_id: statechain_id.to_string(),
+            auth_sig: coin.signed_statechain_id.as_ref().unwrap().to_string(),
+        });
+    }
+
+    let transfer_batch_cancel_request_payload = TransferBatchCancelRequestPayload {
+        batch_id: batch_id.to_string(),
+        cancel_requests,
+    };
+
+    let endpoint = client_config.statechain_entity.clone();
+    let path = "transfer/cancel_batch";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(format!("{}/{}", endpoint, path));
+
+    let response = request.json(&transfer_batch_cancel_request_payload).send().await?;
+
+    let status = response.status();
+
+    // NOT_FOUND means that no transfer was registered under this batch id
+    if !status.is_success() && status != StatusCode::NOT_FOUND {
+        let text = response.text().await.unwrap_or("Unexpected error".to_string());
+        return Err(anyhow!(format!("status: {}, error: {}", status, text)));
+    }
+
+    for coin in wallet.coins.iter_mut() {
+        if coin.status == CoinStatus::IN_TRANSFER && statechain_ids.iter().any(|id| coin.statechain_id == Some(id.to_string())) {
+
+            let activity = Activity {
+                utxo: format!("{}:{}", coin.utxo_txid.as_ref().unwrap(), coin.utxo_vout.unwrap()),
+                amount: coin.amount.unwrap(),
+                action: "Transfer cancelled".to_string(),
+                date: Utc::now().to_rfc3339(),
+            };
+
+            coin.status = CoinStatus::CONFIRMED;
+
+            wallet.activities.push(activity);
+        }
+    }
 
     update_wallet(&client_config.pool, &wallet).await?;
 
@@ -155,7 +397,7 @@ async fn create_backup_tx_to_receiver(client_config: &ClientConfig, coin: &mut C
 
     let block_height = Some(get_blockheight(bkp_tx1)?);
 
-    let server_info = info_config(&client_config).await?;
+    let server_info = info_config(client_config).await?;
 
     let fee_rate_sats_per_byte = if server_info.fee_rate_sats_per_byte > client_config.max_fee_rate {
         client_config.max_fee_rate
@@ -179,19 +421,20 @@ async fn create_backup_tx_to_receiver(client_config: &ClientConfig, coin: &mut C
     Ok(signed_tx)
 }
 
-async fn get_new_x1(client_config: &ClientConfig,  statechain_id: &str, signed_statechain_id: &str, recipient_auth_pubkey: &str, batch_id: Option<String>) -> Result<String> {
+async fn get_new_x1(client_config: &ClientConfig,  statechain_id: &str, signed_statechain_id: &str, recipient_auth_pubkey: &str, batch_id: Option<String>, batch_size: Option<u32>) -> Result<String> {
     
     let endpoint = client_config.statechain_entity.clone();
     let path = "transfer/sender";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let transfer_sender_request_payload = TransferSenderRequestPayload {
         statechain_id: statechain_id.to_string(),
         auth_sig: signed_statechain_id.to_string(),
         new_user_auth_key: recipient_auth_pubkey.to_string(),
         batch_id,
+        batch_size,
     };
 
     let value = match request.json(&transfer_sender_request_payload).send().await {
@@ -211,9 +454,77 @@ async fn get_new_x1(client_config: &ClientConfig,  statechain_id: &str, signed_s
         },
     };
 
-    let response: TransferSenderResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: TransferSenderResponsePayload = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
 
     Ok(response.x1)
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_batch_step_rolls_back_messages_already_sent() {
+
+        let sent = RefCell::new(Vec::<&str>::new());
+        let rolled_back = Cell::new(0);
+
+        // the message of the first coin is sent before the second one fails
+        let result = run_batch_step(
+            vec!["statechain_1", "statechain_2", "statechain_3"],
+            |statechain_id| {
+                let result = if statechain_id == "statechain_2" {
+                    Err(anyhow!("Failed to update transfer message"))
+                } else {
+                    sent.borrow_mut().push(statechain_id);
+                    Ok(())
+                };
+                async move { result }
+            },
+            || {
+                rolled_back.set(rolled_back.get() + 1);
+                async { Ok(()) }
+            }).await;
+
+        assert_eq!(result.unwrap_err().to_string(), "Batch transfer failed and was rolled back: Failed to update transfer message");
+        assert_eq!(*sent.borrow(), vec!["statechain_1"]);
+        assert_eq!(rolled_back.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_step_reports_failed_rollback() {
+
+        let result = run_batch_step(
+            vec!["statechain_1", "statechain_2"],
+            |statechain_id| async move {
+                if statechain_id == "statechain_2" {
+                    return Err(anyhow!("Failed to update transfer message"));
+                }
+                Ok(())
+            },
+            || async { Err(anyhow!("Batch transfer already completed by a receiver.")) }).await;
+
+        assert_eq!(result.unwrap_err().to_string(), "Batch transfer failed: Failed to update transfer message. Rollback failed: Batch transfer already completed by a receiver.");
+    }
+
+    #[tokio::test]
+    async fn test_batch_step_does_not_roll_back_on_success() {
+
+        let rolled_back = Cell::new(false);
+
+        let result = run_batch_step(
+            vec!["statechain_1", "statechain_2"],
+            |statechain_id| async move { Ok(format!("x1 of {}", statechain_id)) },
+            || {
+                rolled_back.set(true);
+                async { Ok(()) }
+            }).await;
+
+        assert_eq!(result.unwrap(), vec!["x1 of statechain_1", "x1 of statechain_2"]);
+        assert!(!rolled_back.get());
+    }
+}
@@ -11,7 +11,7 @@ pub async fn info_config(client_config: &ClientConfig) -> Result<InfoConfig>{
     let path = "info/config";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.get(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.get(format!("{}/{}", client_config.statechain_entity, path));
 
     let value = request.send().await?.text().await?;
 
@@ -42,22 +42,22 @@ pub fn create_activity(utxo: &str, amount: u32, action: &str) -> Activity {
     let date = Utc::now(); // This will get the current date and time in UTC
     let iso_string = date.to_rfc3339(); // Converts the date to an ISO 8601 string
 
-    let activity = Activity {
+    
+
+    Activity {
         utxo: utxo.to_string(),
         amount,
         action: action.to_string(),
         date: iso_string
-    };
-
-    activity
+    }
 }
 
 pub async fn get_statechain_info(statechain_id: &str, client_config: &ClientConfig) -> Result<Option<StatechainInfoResponsePayload>> {
 
-    let path = format!("info/statechain/{}", statechain_id.to_string());
+    let path = format!("info/statechain/{}", statechain_id);
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.get(&format!("{}/{}", client_config.statechain_entity, path));
+    let request = client.get(format!("{}/{}", client_config.statechain_entity, path));
 
     let response = request.send().await?;
 
@@ -78,7 +78,7 @@ pub async fn complete_withdraw(statechain_id: &str, signed_statechain_id: &str,
     let path = "withdraw/complete";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let delete_statechain_payload = WithdrawCompletePayload {
         statechain_id: statechain_id.to_string(),
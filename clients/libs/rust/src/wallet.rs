@@ -1,16 +1,154 @@
-use anyhow::Result;
+use std::{collections::HashMap, env, str::FromStr, sync::{Mutex, OnceLock}};
+
+use anyhow::{anyhow, Result};
 use electrum_client::ElectrumApi;
-use mercurylib::wallet::{generate_mnemonic, Settings, Wallet};
+use mercurylib::wallet::{encryption::{DerivedKey, EncryptedSecrets}, generate_mnemonic_with_word_count, Settings, Wallet};
+
+use crate::{utils::info_config, client_config::ClientConfig, sqlite_manager};
+
+/// Keys derived from the passwords of the wallets unlocked in this process, so the KDF only runs once per wallet
+fn session_keys() -> &'static Mutex<HashMap<String, DerivedKey>> {
+    static KEYS: OnceLock<Mutex<HashMap<String, DerivedKey>>> = OnceLock::new();
+    KEYS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+static PASSWORD_PROMPT: OnceLock<fn(&str) -> Result<String>> = OnceLock::new();
+
+static PASSPHRASE: OnceLock<String> = OnceLock::new();
+
+/// Sets the BIP39 passphrase of the wallets used in this process
+pub fn set_passphrase(passphrase: &str) {
+    let _ = PASSPHRASE.set(passphrase.to_string());
+}
+
+/// BIP39 passphrase used to derive new keys, falling back to the `ML_WALLET_PASSPHRASE` environment variable
+pub fn session_passphrase() -> String {
+    PASSPHRASE.get().cloned()
+        .or_else(|| env::var("ML_WALLET_PASSPHRASE").ok())
+        .unwrap_or_default()
+}
+
+/// Sets the function asked for the password of a locked wallet that was not unlocked in this session
+/// (e.g. a prompt on the terminal). Without it, locked wallets must be unlocked with `unlock_wallet`.
+pub fn set_password_prompt(prompt: fn(&str) -> Result<String>) {
+    let _ = PASSWORD_PROMPT.set(prompt);
+}
+
+/// Key of the session used to encrypt the wallet when it is stored
+pub fn session_key(wallet_name: &str) -> Option<DerivedKey> {
+    session_keys().lock().unwrap().get(wallet_name).cloned()
+}
+
+/// Key of the session for the encrypted secrets of a stored wallet. The password is asked for if there is no such key.
+pub fn session_key_for(wallet_name: &str, encrypted_secrets: &EncryptedSecrets) -> Result<DerivedKey> {
+
+    if let Some(key) = session_key(wallet_name) {
+        if key.matches(encrypted_secrets) {
+            return Ok(key);
+        }
+    }
+
+    let prompt = PASSWORD_PROMPT.get().ok_or(anyhow!("Wallet {} is locked, a password is required", wallet_name))?;
+
+    let password = prompt(wallet_name)?;
+
+    Ok(DerivedKey::derive(&password, Some(encrypted_secrets))?)
+}
+
+pub fn set_session_key(wallet_name: &str, key: DerivedKey) {
+    session_keys().lock().unwrap().insert(wallet_name.to_string(), key);
+}
+
+/// Verifies the password and keeps the key derived from it for the rest of the session
+pub async fn unlock_wallet(client_config: &ClientConfig, wallet_name: &str, password: &str) -> Result<()> {
+
+    let mut wallet = sqlite_manager::get_stored_wallet(&client_config.pool, wallet_name).await?;
+
+    if !wallet.is_locked() {
+        return Err(anyhow!("Wallet {} is not encrypted", wallet_name));
+    }
+
+    let key = DerivedKey::derive(password, wallet.encrypted_secrets.as_ref())?;
+
+    wallet.unlock_with_key(&key)?;
+
+    set_session_key(wallet_name, key);
+
+    Ok(())
+}
 
-use crate::{utils::info_config, client_config::ClientConfig};
+/// Forgets the key of `unlock_wallet` for the rest of the session
+pub fn lock_wallet(wallet_name: &str) {
+    session_keys().lock().unwrap().remove(wallet_name);
+}
+
+/// Encrypts the secrets of a wallet that has no password yet
+pub async fn set_wallet_password(client_config: &ClientConfig, wallet_name: &str, new_password: &str) -> Result<()> {
+
+    if is_wallet_locked(client_config, wallet_name).await? {
+        return Err(anyhow!("Wallet {} already has a password", wallet_name));
+    }
+
+    let wallet = sqlite_manager::get_wallet(&client_config.pool, wallet_name).await?;
+
+    set_session_key(wallet_name, DerivedKey::derive(new_password, None)?);
+
+    sqlite_manager::update_wallet(&client_config.pool, &wallet).await?;
+
+    Ok(())
+}
+
+/// Encrypts the wallet secrets with a new password. The current password is the one the wallet was unlocked with.
+pub async fn change_wallet_password(client_config: &ClientConfig, wallet_name: &str, new_password: &str) -> Result<()> {
+
+    if !is_wallet_locked(client_config, wallet_name).await? {
+        return Err(anyhow!("Wallet {} is not encrypted", wallet_name));
+    }
+
+    let wallet = sqlite_manager::get_wallet(&client_config.pool, wallet_name).await?;
+
+    set_session_key(wallet_name, DerivedKey::derive(new_password, None)?);
+
+    sqlite_manager::update_wallet(&client_config.pool, &wallet).await?;
+
+    Ok(())
+}
+
+/// Whether the secrets of the stored wallet are encrypted
+pub async fn is_wallet_locked(client_config: &ClientConfig, wallet_name: &str) -> Result<bool> {
+    let wallet = sqlite_manager::get_stored_wallet(&client_config.pool, wallet_name).await?;
+    Ok(wallet.is_locked())
+}
 
 pub async fn create_wallet(
     name: &str, 
     client_config: &ClientConfig
 ) -> Result<Wallet> {
-    let mnemonic = generate_mnemonic()?;
+    create_wallet_with_word_count(name, client_config, 12).await
+}
+
+pub async fn create_wallet_with_word_count(
+    name: &str, 
+    client_config: &ClientConfig,
+    word_count: u32,
+) -> Result<Wallet> {
+    let mnemonic = generate_mnemonic_with_word_count(word_count)?;
+    create_wallet_from_mnemonic(name, client_config, &mnemonic).await
+}
 
-    let server_info = info_config(&client_config).await?;
+/// Creates a wallet with an existing mnemonic, e.g. to recover its statecoins
+pub async fn create_wallet_from_mnemonic(
+    name: &str, 
+    client_config: &ClientConfig,
+    mnemonic: &str,
+) -> Result<Wallet> {
+    let mnemonic = bip39::Mnemonic::from_str(mnemonic)?.to_string();
+
+    // only the flag and the master fingerprint are stored, the passphrase must be given again to derive new coins
+    let passphrase = session_passphrase();
+    let has_passphrase = !passphrase.is_empty();
+
+    let server_info = info_config(client_config).await?;
 
     let block_header = client_config.electrum_client.block_headers_subscribe_raw()?;
     let blockheight = block_header.height as u32;
@@ -40,7 +178,7 @@ pub async fn create_wallet(
         tutorials,
     };
 
-    let wallet = Wallet {
+    let mut wallet = Wallet {
         name: name.to_string(),
         mnemonic,
         version: String::from("0.1.0"),
@@ -54,8 +192,13 @@ pub async fn create_wallet(
         activities: Vec::new(),
         coins: Vec::new(),
         settings,
+        encrypted_secrets: None,
+        has_passphrase,
+        master_fingerprint: None,
     };
 
+    wallet.master_fingerprint = Some(wallet.get_master_fingerprint(&passphrase)?);
+
     // save wallet to database
 
     Ok(wallet)
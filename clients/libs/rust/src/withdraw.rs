@@ -7,7 +7,7 @@ use mercurylib::wallet::{Activity, CoinStatus};
 
 pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, to_address: &str, fee_rate: Option<f64>, duplicated_index: Option<u32>) -> Result<()>{
 
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let is_address_valid = mercurylib::validate_address(to_address, &wallet.network)?;
 
@@ -15,9 +15,9 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         return Err(anyhow!("Invalid address"));
     }
 
-    let backup_txs = get_backup_txs(&client_config.pool, &statechain_id).await?;
+    let backup_txs = get_backup_txs(&client_config.pool, statechain_id).await?;
     
-    if backup_txs.len() == 0 {
+    if backup_txs.is_empty() {
         return Err(anyhow!("No backup transaction associated with this statechain ID were found"));
     }
 
@@ -60,7 +60,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         return Err(anyhow::anyhow!("Coin status must be CONFIRMED or IN_TRANSFER or DUPLICATED to withdraw it. The current status is {}", coin.status));
     }
 
-    let server_info = info_config(&client_config).await?;
+    let server_info = info_config(client_config).await?;
 
     let fee_rate_sats_per_byte = match fee_rate {
         Some(fee_rate) => fee_rate,
@@ -74,7 +74,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
     let signed_tx = new_transaction(
         client_config, 
         coin,
-        &to_address,
+        to_address,
         qt_backup_tx,
         true,
         None,
@@ -139,7 +139,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
     });
 
     if !is_there_more_duplicated_coins {
-        crate::utils::complete_withdraw(statechain_id, &signed_statechain_id, &client_config).await?;
+        crate::utils::complete_withdraw(statechain_id, &signed_statechain_id, client_config).await?;
     }
 
     Ok(())
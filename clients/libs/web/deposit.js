@@ -151,4 +151,47 @@ const createTx1 = async (clientConfig, coin, walletNetwork, tx0Hash, tx0Vout) =>
     return backupTx;
 }
 
-export default { getToken, getDepositBitcoinAddress, createTx1 }
+const getTokenStatusFromServer = async (clientConfig, tokenId) => {
+
+    const token_server_url = clientConfig.tokenServer;
+
+    if (!token_server_url) {
+        throw new Error("The token server is not configured");
+    }
+
+    const path = `token/status/${tokenId}`;
+    const url = token_server_url + '/' + path;
+
+    const response = await axios.get(url);
+
+    if (response.status != 200) {
+        throw new Error(`Token status error: ${response.data}`);
+    }
+
+    return response.data;
+}
+
+const updateTokens = async (clientConfig, walletName) => {
+
+    await initWasm(wasmUrl);
+
+    let wallet = storageManager.getItem(walletName);
+
+    for (let token of wallet.tokens) {
+
+        // spent and refunded are final states
+        if (token.status === "SPENT" || token.status === "REFUNDED") {
+            continue;
+        }
+
+        const tokenStatus = await getTokenStatusFromServer(clientConfig, token.token_id);
+
+        wallet = mercury_wasm.updateTokenStatus(tokenStatus, wallet);
+    }
+
+    storageManager.setItem(walletName, wallet, true);
+
+    return wallet.tokens;
+}
+
+export default { getToken, updateTokens, getDepositBitcoinAddress, createTx1 }
@@ -21,11 +21,16 @@ const newTransferAddress = async (walletName) => {
     return coin.address;
 }
 
-const getMsgAddr = async (clientConfig, auth_pubkey) => {
+const getMsgAddr = async (clientConfig, coin) => {
 
     const statechain_entity_url = clientConfig.statechainEntity;
+
+    const challengeResponse = await axios.post(statechain_entity_url + '/transfer/challenge', { auth_pub_keys: [coin.auth_pubkey] });
+    const challenge = challengeResponse.data.challenge;
+    const authSig = mercury_wasm.signAuthChallenge(challenge, coin);
+
     const path = "transfer/get_msg_addr/";
-    const url = statechain_entity_url + '/' + path + auth_pubkey;
+    const url = statechain_entity_url + '/' + path + coin.auth_pubkey + '?challenge=' + challenge + '&auth_sig=' + authSig;
 
     const response = await axios.get(url);
 
@@ -255,17 +260,17 @@ const execute = async (clientConfig, walletName) => {
 
     const serverInfo = await utils.infoConfig(clientConfig);
 
-    let uniqueAuthPubkeys = new Set();
+    let uniqueAuthPubkeys = new Map();
 
     wallet.coins.forEach(coin => {
-        uniqueAuthPubkeys.add(coin.auth_pubkey);
+        uniqueAuthPubkeys.set(coin.auth_pubkey, coin);
     });
 
     let encMsgsPerAuthPubkey = new Map();
 
-    for (let authPubkey of uniqueAuthPubkeys) {
+    for (let [authPubkey, authCoin] of uniqueAuthPubkeys) {
         try {
-            let encMessages = await getMsgAddr(clientConfig, authPubkey);
+            let encMessages = await getMsgAddr(clientConfig, authCoin);
             if (encMessages.length === 0) {
                 console.log("No messages");
                 continue;
@@ -35,7 +35,7 @@ pub fn execute_bitcoin_command(bitcoin_command: &str) -> Result<String> {
     if output.status.success() {
         return Ok(String::from_utf8_lossy(&output.stdout).to_string().trim().to_string());
     } else {
-        return Err(anyhow!("Command execution failed:\n{}", String::from_utf8_lossy(&output.stderr)));
+        Err(anyhow!("Command execution failed:\n{}", String::from_utf8_lossy(&output.stderr)))
     }
 }
 
@@ -66,9 +66,7 @@ pub fn generatetoaddress(num_blocks: u32, address: &str) -> Result<String> {
 
 pub fn getnewaddress() -> Result<String> {
 
-    let bitcoin_command = format!(
-        "bitcoin-cli -regtest -rpcuser=user -rpcpassword=pass getnewaddress"
-    );
+    let bitcoin_command = "bitcoin-cli -regtest -rpcuser=user -rpcpassword=pass getnewaddress".to_string();
 
     execute_bitcoin_command(&bitcoin_command)
 }
\ No newline at end of file
@@ -7,6 +7,7 @@ pub mod tb01_simple_transfer;
 pub mod tb02_transfer_address_reuse;
 pub mod tb03_simple_atomic_transfer;
 pub mod tb04_simple_lightning_latch;
+pub mod tb05_transfer_cancel;
 pub mod tm01_sender_double_spends;
 use anyhow::{Result, Ok};
 
@@ -17,6 +18,7 @@ async fn main() -> Result<()> {
     tb02_transfer_address_reuse::execute().await?;
     tb03_simple_atomic_transfer::execute().await?;
     tb04_simple_lightning_latch::execute().await?;
+    tb05_transfer_cancel::execute().await?;
     tm01_sender_double_spends::execute().await?;
     ta01_sign_second_not_called::execute().await?;
     ta02_duplicate_deposits::execute().await?;
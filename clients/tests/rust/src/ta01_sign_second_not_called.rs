@@ -11,7 +11,7 @@ pub async fn sign_first(client_config: &ClientConfig, sign_first_request_payload
     let path = "sign/first";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let value = request.json(&sign_first_request_payload).send().await?.text().await?;
 
@@ -30,12 +30,12 @@ pub async fn new_transaction_only_sign_first(
     client_config: &ClientConfig, 
     coin: &mut Coin) -> Result<()> {
 
-    let coin_nonce = create_and_commit_nonces(&coin)?;
+    let coin_nonce = create_and_commit_nonces(coin)?;
     coin.secret_nonce = Some(coin_nonce.secret_nonce);
     coin.public_nonce = Some(coin_nonce.public_nonce);
     coin.blinding_factor = Some(coin_nonce.blinding_factor);
 
-    let _ = sign_first(&client_config, &coin_nonce.sign_first_request_payload).await?;
+    let _ = sign_first(client_config, &coin_nonce.sign_first_request_payload).await?;
 
     Ok(())
 }
@@ -48,7 +48,7 @@ pub async fn execute_only_sign_first(
     batch_id: Option<String>) -> Result<()> 
 {
 
-    let mut wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet = get_wallet(&client_config.pool, wallet_name).await?;
 
     let coin = wallet.coins
         .iter_mut()
@@ -65,9 +65,9 @@ pub async fn execute_only_sign_first(
     let signed_statechain_id = coin.signed_statechain_id.as_ref().unwrap();
 
     let (_, _, recipient_auth_pubkey) = decode_transfer_address(recipient_address)?;  
-    let _ = get_new_x1(&client_config,  statechain_id, signed_statechain_id, &recipient_auth_pubkey.to_string(), batch_id).await?;
+    let _ = get_new_x1(client_config,  statechain_id, signed_statechain_id, &recipient_auth_pubkey.to_string(), batch_id).await?;
 
-    let _ = new_transaction_only_sign_first(client_config, coin).await?;
+    new_transaction_only_sign_first(client_config, coin).await?;
 
     Ok(())
 
@@ -79,13 +79,14 @@ async fn get_new_x1(client_config: &ClientConfig,  statechain_id: &str, signed_s
     let path = "transfer/sender";
 
     let client = client_config.get_reqwest_client()?;
-    let request = client.post(&format!("{}/{}", endpoint, path));
+    let request = client.post(format!("{}/{}", endpoint, path));
 
     let transfer_sender_request_payload = TransferSenderRequestPayload {
         statechain_id: statechain_id.to_string(),
         auth_sig: signed_statechain_id.to_string(),
         new_user_auth_key: recipient_auth_pubkey.to_string(),
         batch_id,
+        batch_size: None,
     };
 
     let value = match request.json(&transfer_sender_request_payload).send().await {
@@ -105,7 +106,7 @@ async fn get_new_x1(client_config: &ClientConfig,  statechain_id: &str, signed_s
         },
     };
 
-    let response: TransferSenderResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: TransferSenderResponsePayload = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
 
     Ok(response.x1)
 }
@@ -116,7 +117,7 @@ async fn ta01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &address)?;
 
@@ -132,7 +133,7 @@ async fn ta01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1 = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(address.clone())).unwrap();
 
@@ -142,27 +143,27 @@ async fn ta01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let batch_id = None;
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
     execute_only_sign_first(
-        &client_config, 
+        client_config, 
         &wallet2_transfer_adress, 
         &wallet1.name, 
-        &statechain_id, 
+        statechain_id, 
         batch_id).await?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1 = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
     let batch_id = None;
 
     let force_send = false;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_ok());
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet2.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
     let received_statechain_ids = transfer_receive_result.received_statechain_ids;
 
     assert!(received_statechain_ids.contains(&statechain_id.to_string()));
@@ -174,7 +175,7 @@ async fn ta01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     assert!(new_coin.status == CoinStatus::CONFIRMED);
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1 = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
     let new_coin = wallet1.coins.iter().find(|&coin| coin.statechain_id == Some(statechain_id.to_string())).unwrap();
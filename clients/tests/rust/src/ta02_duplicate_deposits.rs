@@ -11,7 +11,7 @@ async fn withdraw_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let deposit_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let deposit_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &deposit_address)?;
 
@@ -27,7 +27,7 @@ async fn withdraw_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let new_coin = wallet.coins.iter().find(|&coin| coin.aggregated_address == Some(deposit_address.clone())).unwrap();
 
@@ -46,7 +46,7 @@ async fn withdraw_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
     let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(deposit_address.clone()) && coin.status == CoinStatus::CONFIRMED);
@@ -63,13 +63,13 @@ async fn withdraw_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
 
     let statechain_id = new_coin.statechain_id.as_ref().unwrap();
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
     let batch_id = None;
 
     let force_send = false;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id.clone()).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id.clone()).await;
 
     assert!(result.is_err());
 
@@ -80,13 +80,13 @@ async fn withdraw_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
 
     let fee_rate = None;
 
-    let result = mercuryrustlib::withdraw::execute(&client_config, &wallet1.name, statechain_id, &core_wallet_address, fee_rate, Some(1)).await;
+    let result = mercuryrustlib::withdraw::execute(client_config, &wallet1.name, statechain_id, &core_wallet_address, fee_rate, Some(1)).await;
 
     assert!(result.is_ok());
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_err());
 
@@ -96,7 +96,7 @@ async fn withdraw_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
         This transfer cannot be performed because the recipient would reject it due to the difference in signature count.\
         This coin can be withdrawn, however.");
 
-    let result = mercuryrustlib::withdraw::execute(&client_config, &wallet1.name, statechain_id, &core_wallet_address, fee_rate, None).await;
+    let result = mercuryrustlib::withdraw::execute(client_config, &wallet1.name, statechain_id, &core_wallet_address, fee_rate, None).await;
 
     assert!(result.is_ok());
     
@@ -109,7 +109,7 @@ async fn transfer_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let deposit_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let deposit_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &deposit_address)?;
 
@@ -125,7 +125,7 @@ async fn transfer_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let new_coin = wallet.coins.iter().find(|&coin| coin.aggregated_address == Some(deposit_address.clone())).unwrap();
 
@@ -144,7 +144,7 @@ async fn transfer_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
     let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(deposit_address.clone()) && coin.status == CoinStatus::CONFIRMED);
@@ -161,23 +161,23 @@ async fn transfer_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
 
     let statechain_id = new_coin.statechain_id.as_ref().unwrap();
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
     let batch_id = None;
 
     let force_send = true;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id.clone()).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id.clone()).await;
 
     assert!(result.is_ok());
     
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet2.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
     let received_statechain_ids = transfer_receive_result.received_statechain_ids;
 
     assert!(received_statechain_ids.contains(&statechain_id.to_string()));
     assert!(received_statechain_ids.len() == 1);
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
     let transferred_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(deposit_address.clone()) && coin.status == CoinStatus::TRANSFERRED);
@@ -194,7 +194,7 @@ async fn transfer_flow(client_config: &ClientConfig, wallet1: &Wallet, wallet2:
 
     let fee_rate = None;
 
-    let result = mercuryrustlib::withdraw::execute(&client_config, &wallet1.name, statechain_id, &core_wallet_address, fee_rate, Some(1)).await;
+    let result = mercuryrustlib::withdraw::execute(client_config, &wallet1.name, statechain_id, &core_wallet_address, fee_rate, Some(1)).await;
 
     assert!(result.is_err());
 
@@ -12,7 +12,7 @@ async fn try_to_send_unconfirmed_coin(client_config: &ClientConfig, to_address:
 
     let force_send = false;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, to_address, &wallet.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, to_address, &wallet.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_err());
 
@@ -31,9 +31,9 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     let amount = 1000;
 
-    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
@@ -78,7 +78,7 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
@@ -86,16 +86,16 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     assert!(new_coin.status == CoinStatus::IN_MEMPOOL);
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
     let statechain_id = new_coin.statechain_id.as_ref().unwrap();
 
-    try_to_send_unconfirmed_coin(&client_config, &wallet2_transfer_adress, &wallet1, statechain_id, &CoinStatus::IN_MEMPOOL.to_string()).await?;
+    try_to_send_unconfirmed_coin(client_config, &wallet2_transfer_adress, wallet1, statechain_id, &CoinStatus::IN_MEMPOOL.to_string()).await?;
 
     let core_wallet_address = bitcoin_core::getnewaddress()?;
     let _ = bitcoin_core::generatetoaddress(1, &core_wallet_address)?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
@@ -103,12 +103,12 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     assert!(new_coin.status == CoinStatus::UNCONFIRMED);
 
-    try_to_send_unconfirmed_coin(&client_config, &wallet2_transfer_adress, &wallet1, statechain_id, &CoinStatus::UNCONFIRMED.to_string()).await?;
+    try_to_send_unconfirmed_coin(client_config, &wallet2_transfer_adress, wallet1, statechain_id, &CoinStatus::UNCONFIRMED.to_string()).await?;
 
     let remaining_blocks = client_config.confirmation_target - 1;
     let _ = bitcoin_core::generatetoaddress(remaining_blocks, &core_wallet_address)?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
@@ -120,11 +120,11 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     let force_send = false;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_ok());
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
@@ -132,19 +132,19 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     assert!(new_coin.status == CoinStatus::IN_TRANSFER);
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet2.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
     let received_statechain_ids = transfer_receive_result.received_statechain_ids;
 
     assert!(received_statechain_ids.contains(&statechain_id.to_string()));
     assert!(received_statechain_ids.len() == 1);
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let new_coin = wallet.coins.iter().find(|&coin| coin.aggregated_address == Some(address.clone())).unwrap();
 
     assert!(new_coin.status == CoinStatus::TRANSFERRED);
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet2.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet2.name).await?;
     let local_wallet_2: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet2.name).await?;
     let new_coin = local_wallet_2.coins.iter().find(|&coin| coin.aggregated_address == Some(address.clone())).unwrap();
 
@@ -152,11 +152,11 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     let fee_rate = None;
 
-    let result = mercuryrustlib::withdraw::execute(&client_config, &wallet2.name, &statechain_id, &core_wallet_address, fee_rate, None).await;
+    let result = mercuryrustlib::withdraw::execute(client_config, &wallet2.name, statechain_id, &core_wallet_address, fee_rate, None).await;
 
     assert!(result.is_ok());
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet2.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet2.name).await?;
     let local_wallet_2: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet2.name).await?;
     let new_coin = local_wallet_2.coins.iter().find(|&coin| coin.aggregated_address == Some(address.clone())).unwrap();
 
@@ -164,7 +164,7 @@ async fn sucessfully_transfer(client_config: &ClientConfig, wallet1: &Wallet, wa
 
     let _ = bitcoin_core::generatetoaddress(client_config.confirmation_target, &core_wallet_address)?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet2.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet2.name).await?;
     let local_wallet_2: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet2.name).await?;
     let new_coin = local_wallet_2.coins.iter().find(|&coin| coin.aggregated_address == Some(address.clone())).unwrap();
 
@@ -13,7 +13,7 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &address)?;
 
@@ -21,7 +21,7 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &address)?;
 
@@ -37,7 +37,7 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
 
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
@@ -47,7 +47,7 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
         assert!(coin.status == CoinStatus::CONFIRMED);
     }
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
     for coin in wallet1.coins.iter() {
         let batch_id = None;
@@ -56,12 +56,12 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
         let statechain_id = coin.statechain_id.as_ref().unwrap();
 
-        let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, &statechain_id, force_send, batch_id).await;
+        let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
         assert!(result.is_ok());
     }
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet2.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
     let received_statechain_ids = transfer_receive_result.received_statechain_ids;
 
     let wallet2: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet2.name).await?;
@@ -89,7 +89,7 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let fee_rate = None;
 
-    let result = mercuryrustlib::withdraw::execute(&client_config, &wallet2.name, &statechain_id, &core_wallet_address, fee_rate, None).await;
+    let result = mercuryrustlib::withdraw::execute(client_config, &wallet2.name, &statechain_id, &core_wallet_address, fee_rate, None).await;
 
     assert!(result.is_ok());
 
@@ -99,12 +99,12 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let _ = bitcoin_core::generatetoaddress(client_config.confirmation_target, &core_wallet_address)?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet2.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet2.name).await?;
     let wallet2: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet2.name).await?;
 
     assert!(wallet2.coins[0].status == CoinStatus::WITHDRAWN);
 
-    let wallet1_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet1.name).await?;
+    let wallet1_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet1.name).await?;
 
     let batch_id = None;
 
@@ -112,21 +112,21 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let force_send = false;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet1_transfer_adress, &wallet2.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet1_transfer_adress, &wallet2.name, &statechain_id, force_send, batch_id).await;
 
     assert!(result.is_ok());
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet1.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet1.name).await?;
     let received_statechain_ids = transfer_receive_result.received_statechain_ids;
 
     assert!(received_statechain_ids.contains(&statechain_id.to_string()));
     assert!(received_statechain_ids.len() == 1);
 
-    let result = mercuryrustlib::withdraw::execute(&client_config, &wallet1.name, &statechain_id, &core_wallet_address, fee_rate, None).await;
+    let result = mercuryrustlib::withdraw::execute(client_config, &wallet1.name, &statechain_id, &core_wallet_address, fee_rate, None).await;
 
     assert!(result.is_ok());
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let withdrawn_coin = wallet1.coins.iter().find(|&coin| coin.statechain_id == Some(statechain_id.to_string()) && coin.status == CoinStatus::WITHDRAWING);
     let transferred_coin = wallet1.coins.iter().find(|&coin| coin.statechain_id == Some(statechain_id.to_string()) && coin.status == CoinStatus::TRANSFERRED);
@@ -136,7 +136,7 @@ async fn tb02(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet)
 
     let _ = bitcoin_core::generatetoaddress(client_config.confirmation_target, &core_wallet_address)?;
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let withdrawn_coin = wallet1.coins.iter().find(|&coin| coin.statechain_id == Some(statechain_id.to_string()) && coin.status == CoinStatus::WITHDRAWN);
 
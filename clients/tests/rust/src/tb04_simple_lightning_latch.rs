@@ -14,7 +14,7 @@ pub async fn tb04(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wall
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let deposit_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let deposit_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &deposit_address)?;
 
@@ -30,41 +30,41 @@ pub async fn tb04(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wall
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(deposit_address.clone()) && coin.status == CoinStatus::CONFIRMED).unwrap();
     let statechain_id = new_coin.statechain_id.as_ref().unwrap();
 
-    let response = mercuryrustlib::lightning_latch::create_pre_image(&client_config, &wallet1.name, statechain_id).await?;
+    let response = mercuryrustlib::lightning_latch::create_pre_image(client_config, &wallet1.name, statechain_id, None).await?;
 
     let batch_id = response.batch_id;
     let hash = response.hash;
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
     let force_send = false;
 
-    mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, Some(batch_id.clone())).await?;
+    mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, Some(batch_id.clone())).await?;
     
     // receiver confirms that payment hash was generated by the server
-    let response = mercuryrustlib::lightning_latch::get_payment_hash(&client_config, &batch_id).await?;
+    let response = mercuryrustlib::lightning_latch::get_payment_hash(client_config, &batch_id).await?;
 
     assert!(response.is_some());
     assert!(response.unwrap() == hash);
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet2.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
 
     assert!(transfer_receive_result.is_there_batch_locked);
     assert!(transfer_receive_result.received_statechain_ids.is_empty());
 
-    mercuryrustlib::lightning_latch::confirm_pending_invoice(&client_config, &wallet1.name, &statechain_id).await?;
+    mercuryrustlib::lightning_latch::confirm_pending_invoice(client_config, &wallet1.name, statechain_id).await?;
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet2.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
 
     assert!(!transfer_receive_result.is_there_batch_locked);
     assert!(!transfer_receive_result.received_statechain_ids.is_empty());
 
-    let pre_image = mercuryrustlib::lightning_latch::retrieve_pre_image(&client_config, &wallet1.name, &statechain_id, &batch_id).await?;
+    let pre_image = mercuryrustlib::lightning_latch::retrieve_pre_image(client_config, &wallet1.name, statechain_id, &batch_id).await?;
 
     let pre_image_bytes = hex::decode(pre_image)?;
 
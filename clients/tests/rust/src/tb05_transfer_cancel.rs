@@ -0,0 +1,109 @@
+use std::{env, process::Command, thread, time::Duration};
+use anyhow::{Result, Ok};
+use mercuryrustlib::{client_config::ClientConfig, CoinStatus, Wallet};
+
+use crate::{bitcoin_core, electrs};
+
+pub async fn tb05(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet) -> Result<()> {
+
+    let amount = 1000;
+
+    let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
+
+    let wallet1_address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
+
+    let _ = bitcoin_core::sendtoaddress(amount, &wallet1_address)?;
+
+    let core_wallet_address = bitcoin_core::getnewaddress()?;
+    let remaining_blocks = client_config.confirmation_target;
+    let _ = bitcoin_core::generatetoaddress(remaining_blocks, &core_wallet_address)?;
+
+    // It appears that Electrs takes a few seconds to index the transaction
+    let mut is_tx_indexed = false;
+
+    while !is_tx_indexed {
+        is_tx_indexed = electrs::check_address(client_config, &wallet1_address, amount).await?;
+        thread::sleep(Duration::from_secs(1));
+    }
+
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
+    let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
+    let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(wallet1_address.clone()) && coin.status == CoinStatus::CONFIRMED).unwrap();
+    let statechain_id = new_coin.statechain_id.as_ref().unwrap();
+
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
+
+    let force_send = false;
+
+    // transfer_sender and transfer_update_msg are both called, so the transfer message is sent to the receiver
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, None).await;
+
+    assert!(result.is_ok());
+
+    let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
+    let new_coin = wallet1.coins.iter().find(|&coin| coin.statechain_id == Some(statechain_id.clone())).unwrap();
+    assert!(new_coin.status == CoinStatus::IN_TRANSFER);
+
+    let result = mercuryrustlib::transfer_sender::cancel(client_config, &wallet1.name, statechain_id).await;
+
+    assert!(result.is_ok());
+
+    let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
+    let new_coin = wallet1.coins.iter().find(|&coin| coin.statechain_id == Some(statechain_id.clone())).unwrap();
+    assert!(new_coin.status == CoinStatus::CONFIRMED);
+
+    // the cancelled transfer message is no longer delivered to the receiver
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
+
+    assert!(!transfer_receive_result.is_there_batch_locked);
+    assert!(transfer_receive_result.received_statechain_ids.is_empty());
+
+    // there is no pending transfer left to cancel
+    let result = mercuryrustlib::transfer_sender::cancel(client_config, &wallet1.name, statechain_id).await;
+
+    assert!(result.is_err());
+
+    // the coin can be sent again after the cancellation
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, None).await;
+
+    assert!(result.is_ok());
+
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet2.name).await?;
+
+    assert!(transfer_receive_result.received_statechain_ids.len() == 1);
+    assert!(transfer_receive_result.received_statechain_ids[0] == *statechain_id);
+
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
+    let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
+    let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(wallet1_address.clone())).unwrap();
+    assert!(new_coin.status == CoinStatus::TRANSFERRED);
+
+    Ok(())
+}
+
+pub async fn execute() -> Result<()> {
+
+    let _ = Command::new("rm").arg("wallet.db").arg("wallet.db-shm").arg("wallet.db-wal").output().expect("failed to execute process");
+
+    env::set_var("ML_NETWORK", "regtest");
+
+    let client_config = mercuryrustlib::client_config::load().await;
+
+    let wallet1 = mercuryrustlib::wallet::create_wallet(
+        "wallet1",
+        &client_config).await?;
+
+    mercuryrustlib::sqlite_manager::insert_wallet(&client_config.pool, &wallet1).await?;
+
+    let wallet2 = mercuryrustlib::wallet::create_wallet(
+        "wallet2",
+        &client_config).await?;
+
+    mercuryrustlib::sqlite_manager::insert_wallet(&client_config.pool, &wallet2).await?;
+
+    tb05(&client_config, &wallet1, &wallet2).await?;
+
+    println!("TB05 - Transfer Cancel Test completed successfully");
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ async fn tm01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet,
 
     let token_id = mercuryrustlib::deposit::get_token(client_config).await?;
 
-    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(&client_config, &wallet1.name, &token_id, amount).await?;
+    let address = mercuryrustlib::deposit::get_deposit_bitcoin_address(client_config, &wallet1.name, &token_id, amount).await?;
 
     let _ = bitcoin_core::sendtoaddress(amount, &address)?;
 
@@ -26,7 +26,7 @@ async fn tm01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet,
         thread::sleep(Duration::from_secs(1));
     }
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
     let new_coin = wallet1.coins.iter().find(|&coin| coin.aggregated_address == Some(address.clone())).unwrap();
 
@@ -38,32 +38,32 @@ async fn tm01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet,
 
     let statechain_id = new_coin.statechain_id.as_ref().unwrap();
 
-    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet2.name).await?;
+    let wallet2_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet2.name).await?;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_ok());
 
-    let wallet3_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet3.name).await?;
+    let wallet3_transfer_adress = mercuryrustlib::transfer_receiver::new_transfer_address(client_config, &wallet3.name).await?;
 
     let batch_id = None;
 
     // this first "double spend" is legitimate, as it will overwrite the previous transaction
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet3_transfer_adress, &wallet1.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet3_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_ok());
 
-    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet3.name).await?;
+    let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(client_config, &wallet3.name).await?;
     let received_statechain_ids = transfer_receive_result.received_statechain_ids;
 
     assert!(received_statechain_ids.len() == 1);
 
-    assert!(received_statechain_ids[0] == statechain_id.to_string());
+    assert!(received_statechain_ids[0] == *statechain_id);
 
     let batch_id = None;
 
     // this second "double spend" is not legitimate, as the statecoin has already been received by wallet3
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_err());
 
@@ -72,12 +72,12 @@ async fn tm01(client_config: &ClientConfig, wallet1: &Wallet, wallet2: &Wallet,
     // If we update wallet1, the error will happen when we try to send the coin to wallet2
     // The step above tested that the sender can double spend the coin, but the server will not accept it
 
-    mercuryrustlib::coin_status::update_coins(&client_config, &wallet1.name).await?;
+    mercuryrustlib::coin_status::update_coins(client_config, &wallet1.name).await?;
     let wallet1: mercuryrustlib::Wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet1.name).await?;
 
     let batch_id = None;
 
-    let result = mercuryrustlib::transfer_sender::execute(&client_config, &wallet2_transfer_adress, &wallet1.name, &statechain_id, force_send, batch_id).await;
+    let result = mercuryrustlib::transfer_sender::execute(client_config, &wallet2_transfer_adress, &wallet1.name, statechain_id, force_send, batch_id).await;
 
     assert!(result.is_err());
 
@@ -10,6 +10,7 @@
 const clientConfig = {
   esploraServer: "http://localhost:8094/regtest",
   statechainEntity: "http://127.0.0.1:8000",
+  tokenServer: "http://127.0.0.1:8001",
   network: "regtest",
   feeRateTolerance: 5,
   confirmationTarget: 2,
@@ -0,0 +1,51 @@
+#![no_main]
+
+//! Feeds untrusted transfer messages through the receiver validation.
+//! The message is encrypted to a fixed auth key first, so the fuzzer reaches the parsing
+//! and validation code instead of stopping at the decryption.
+//!
+//! Run from the lib directory with `cargo +nightly fuzz run transfer_msg_validation`.
+
+use bitcoin::{secp256k1::{Secp256k1, SecretKey}, Network, PrivateKey};
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+use mercurylib::transfer::receiver::{decrypt_transfer_msg, validate_signature_scheme, StatechainInfo, StatechainInfoResponsePayload};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    transfer_msg: Vec<u8>,
+    statechain_info: Vec<(String, String, u32)>,
+    tx0: Vec<u8>,
+    interval: u32,
+}
+
+fuzz_target!(|input: Input| {
+
+    let secp = Secp256k1::new();
+    let auth_key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+    let auth_key_wif = PrivateKey::new(auth_key, Network::Regtest).to_wif();
+    let auth_pubkey = auth_key.public_key(&secp);
+
+    // arbitrary ciphertext
+    let _ = decrypt_transfer_msg(&hex::encode(&input.transfer_msg), &auth_key_wif);
+
+    let encrypted_msg = ecies::encrypt(&auth_pubkey.serialize(), &input.transfer_msg).unwrap();
+
+    let transfer_msg = match decrypt_transfer_msg(&hex::encode(encrypted_msg), &auth_key_wif) {
+        Ok(transfer_msg) => transfer_msg,
+        Err(_) => return,
+    };
+
+    let statechain_info = StatechainInfoResponsePayload {
+        enclave_public_key: String::new(),
+        num_sigs: input.statechain_info.len() as u32,
+        statechain_info: input.statechain_info.into_iter().map(|(server_pubnonce, challenge, tx_n)| StatechainInfo {
+            statechain_id: transfer_msg.statechain_id.clone(),
+            server_pubnonce,
+            challenge,
+            tx_n,
+        }).collect(),
+        x1_pub: None,
+    };
+
+    let _ = validate_signature_scheme(&transfer_msg, &statechain_info, &hex::encode(&input.tx0), 1.0, 1.0, input.interval);
+});
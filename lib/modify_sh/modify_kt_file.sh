@@ -9,6 +9,7 @@ FILE_PATTERN="*.kt"
 # The text to find for adding @Serializable
 FIND_TEXT_SERVER_CONFIG="data class ServerConfig("
 FIND_TEXT_WALLET="data class Wallet("
+FIND_TEXT_ENCRYPTED_SECRETS="data class EncryptedSecrets("
 FIND_TEXT_TOKEN="data class Token("
 FIND_TEXT_ACTIVITY="data class Activity("
 FIND_TEXT_COIN="data class Coin("
@@ -53,6 +54,7 @@ PACKAGE_REMOVE_TEXT="package com.mercurylayer"
 find "$SEARCH_DIR" -type f -name "$FILE_PATTERN" -exec sed -i \
     -e "/$FIND_TEXT_SERVER_CONFIG/i $ADD_TEXT" \
     -e "/$FIND_TEXT_WALLET/i $ADD_TEXT" \
+    -e "/$FIND_TEXT_ENCRYPTED_SECRETS/i $ADD_TEXT" \
     -e "/$FIND_TEXT_TOKEN/i $ADD_TEXT" \
     -e "/$FIND_TEXT_ACTIVITY/i $ADD_TEXT" \
     -e "/$FIND_TEXT_COIN/i $ADD_TEXT" \
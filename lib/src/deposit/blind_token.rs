@@ -0,0 +1,239 @@
+//! Blind Schnorr signatures over deposit tokens.
+//!
+//! After the token payment, the client asks the token server to sign a new random token id without revealing it.
+//! The unblinded signature is a BIP340 signature on the token id, which the mercury server verifies at deposit
+//! with the token server public key, without learning which payment the token came from.
+//!
+//! The token server must sign a single challenge per nonce. Signing two challenges with the same nonce reveals its key.
+
+use std::str::FromStr;
+
+use bitcoin::{hashes::{sha256, Hash, HashEngine}, secp256k1::{rand::{self, Rng}, schnorr::Signature, Message, Parity, PublicKey, Scalar, Secp256k1, SecretKey, XOnlyPublicKey}};
+use serde::{Serialize, Deserialize};
+
+use crate::error::MercuryError;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BlindNonceResponsePayload {
+    pub public_nonce: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BlindSignRequestPayload {
+    /// Paid token the signature is requested for
+    pub token_id: String,
+    pub challenge: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BlindSignResponsePayload {
+    pub blind_signature: String,
+}
+
+/// Client state between sending the blinded challenge and unblinding the signature
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BlindedToken {
+    /// New token id, used at deposit instead of the paid token id
+    pub token_id: String,
+    /// Blinded challenge sent to the token server
+    pub challenge: String,
+    /// Nonce of the unblinded signature
+    pub public_nonce: String,
+    /// Nonce blinding factor
+    pub alpha: String,
+}
+
+/// BIP340 challenge, hash(R || P || m) with the "BIP0340/challenge" tag
+fn schnorr_challenge(public_nonce: &XOnlyPublicKey, signer_pubkey: &XOnlyPublicKey, msg: &Message) -> [u8; 32] {
+
+    let tag = sha256::Hash::hash(b"BIP0340/challenge");
+
+    let mut engine = sha256::Hash::engine();
+    engine.input(tag.as_byte_array());
+    engine.input(tag.as_byte_array());
+    engine.input(&public_nonce.serialize());
+    engine.input(&signer_pubkey.serialize());
+    engine.input(msg.as_ref());
+
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+fn token_message(token_id: &str) -> Message {
+    Message::from_hashed_data::<sha256::Hash>(token_id.as_bytes())
+}
+
+/// Blinds the token server nonce and computes the challenge for a new random token id.
+/// The signer public key is x-only, the nonce is a compressed public key (hex).
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn blind_token(signer_pubkey: &str, public_nonce: &str) -> Result<BlindedToken, MercuryError> {
+
+    let secp = Secp256k1::new();
+
+    let signer_pubkey = XOnlyPublicKey::from_str(signer_pubkey)?;
+    let public_nonce = PublicKey::from_str(public_nonce)?;
+
+    let signer_point = PublicKey::from_x_only_public_key(signer_pubkey, Parity::Even);
+
+    let mut rng = rand::thread_rng();
+
+    let token_id = hex::encode(rng.gen::<[u8; 32]>());
+    let msg = token_message(&token_id);
+
+    // the unblinded nonce R' = R + alpha*G + beta*P must have an even y coordinate
+    loop {
+        let alpha = SecretKey::new(&mut rng);
+        let beta = SecretKey::new(&mut rng);
+
+        let blinded_nonce = public_nonce
+            .combine(&PublicKey::from_secret_key(&secp, &alpha))?
+            .combine(&signer_point.mul_tweak(&secp, &Scalar::from(beta))?)?;
+
+        let (blinded_nonce, parity) = blinded_nonce.x_only_public_key();
+
+        if parity == Parity::Odd {
+            continue;
+        }
+
+        // the hash is reduced modulo the curve order, which only fails with negligible probability
+        let challenge = match SecretKey::from_slice(&schnorr_challenge(&blinded_nonce, &signer_pubkey, &msg)) {
+            Ok(challenge) => challenge,
+            Err(_) => continue,
+        };
+
+        let challenge = challenge.add_tweak(&Scalar::from(beta))?;
+
+        return Ok(BlindedToken {
+            token_id,
+            challenge: hex::encode(challenge.secret_bytes()),
+            public_nonce: blinded_nonce.to_string(),
+            alpha: hex::encode(alpha.secret_bytes()),
+        });
+    }
+}
+
+/// Unblinds the token server signature into a BIP340 signature on the token id, which is verified before being returned
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn unblind_token_signature(blinded_token: &BlindedToken, blind_signature: &str, signer_pubkey: &str) -> Result<String, MercuryError> {
+
+    let blind_signature = SecretKey::from_str(blind_signature)?;
+    let alpha = SecretKey::from_str(&blinded_token.alpha)?;
+    let public_nonce = XOnlyPublicKey::from_str(&blinded_token.public_nonce)?;
+
+    let s = blind_signature.add_tweak(&Scalar::from(alpha))?;
+
+    let mut signature = public_nonce.serialize().to_vec();
+    signature.extend_from_slice(&s.secret_bytes());
+
+    let signature = hex::encode(signature);
+
+    if !verify_blind_token(signer_pubkey, &blinded_token.token_id, &signature) {
+        return Err(MercuryError::InvalidSignature { reason: "unblinded signature does not verify with the token server key".to_string() });
+    }
+
+    Ok(signature)
+}
+
+/// Checks that the token id was signed by the token server (x-only public key)
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_blind_token(signer_pubkey: &str, token_id: &str, signature: &str) -> bool {
+
+    let signer_pubkey = match XOnlyPublicKey::from_str(signer_pubkey) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+
+    let signature = match Signature::from_str(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    let secp = Secp256k1::new();
+    secp.verify_schnorr(&signature, &token_message(token_id), &signer_pubkey).is_ok()
+}
+
+/// Returns the x-only public key of the token server signing key (hex)
+pub fn blind_signer_pubkey(signer_seckey: &str) -> Result<String, MercuryError> {
+
+    let secp = Secp256k1::new();
+    let signer_seckey = SecretKey::from_str(signer_seckey)?;
+
+    Ok(signer_seckey.x_only_public_key(&secp).0.to_string())
+}
+
+/// Generates a token server nonce. Returns the secret nonce and the public nonce sent to the client (hex).
+pub fn generate_blind_nonce() -> (String, String) {
+
+    let secp = Secp256k1::new();
+    let secret_nonce = SecretKey::new(&mut rand::thread_rng());
+
+    (hex::encode(secret_nonce.secret_bytes()), PublicKey::from_secret_key(&secp, &secret_nonce).to_string())
+}
+
+/// Token server side of the signature, s = k + e*x
+pub fn blind_sign(signer_seckey: &str, secret_nonce: &str, challenge: &str) -> Result<String, MercuryError> {
+
+    let secp = Secp256k1::new();
+
+    let mut signer_seckey = SecretKey::from_str(signer_seckey)?;
+    let secret_nonce = SecretKey::from_str(secret_nonce)?;
+    let challenge = SecretKey::from_str(challenge)?;
+
+    // the clients use the even point of the x-only public key
+    if signer_seckey.x_only_public_key(&secp).1 == Parity::Odd {
+        signer_seckey = signer_seckey.negate();
+    }
+
+    let s = challenge
+        .mul_tweak(&Scalar::from(signer_seckey))?
+        .add_tweak(&Scalar::from(secret_nonce))?;
+
+    Ok(hex::encode(s.secret_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign_token(signer_seckey: &str) -> (BlindedToken, String) {
+
+        let signer_pubkey = blind_signer_pubkey(signer_seckey).unwrap();
+        let (secret_nonce, public_nonce) = generate_blind_nonce();
+
+        let blinded_token = blind_token(&signer_pubkey, &public_nonce).unwrap();
+        let blind_signature = blind_sign(signer_seckey, &secret_nonce, &blinded_token.challenge).unwrap();
+
+        (blinded_token, blind_signature)
+    }
+
+    #[test]
+    fn test_unblinded_signature_is_valid() {
+
+        for _ in 0..8 {
+            let signer_seckey = hex::encode(SecretKey::new(&mut rand::thread_rng()).secret_bytes());
+            let signer_pubkey = blind_signer_pubkey(&signer_seckey).unwrap();
+
+            let (blinded_token, blind_signature) = sign_token(&signer_seckey);
+
+            let signature = unblind_token_signature(&blinded_token, &blind_signature, &signer_pubkey).unwrap();
+
+            assert!(verify_blind_token(&signer_pubkey, &blinded_token.token_id, &signature));
+            assert!(!verify_blind_token(&signer_pubkey, "another token id", &signature));
+        }
+    }
+
+    #[test]
+    fn test_signature_from_another_signer_is_rejected() {
+
+        let signer_seckey = hex::encode(SecretKey::new(&mut rand::thread_rng()).secret_bytes());
+        let other_seckey = hex::encode(SecretKey::new(&mut rand::thread_rng()).secret_bytes());
+        let other_pubkey = blind_signer_pubkey(&other_seckey).unwrap();
+
+        let (blinded_token, blind_signature) = sign_token(&signer_seckey);
+
+        assert!(unblind_token_signature(&blinded_token, &blind_signature, &other_pubkey).is_err());
+    }
+}
@@ -1,7 +1,9 @@
+pub mod blind_token;
+
 use std::str::FromStr;
 
-use crate::{error::MercuryError, utils::get_network, wallet::Coin};
-use bitcoin::{hashes::sha256, PrivateKey, secp256k1, Address};
+use crate::{error::MercuryError, signer::{CoinKey, InMemorySigner, Signer}, utils::get_network, wallet::Coin};
+use bitcoin::{hashes::sha256, Address};
 use secp256k1_zkp::{Message, Secp256k1, PublicKey};
 use serde::{Serialize, Deserialize};
 
@@ -10,12 +12,23 @@ pub struct TokenID {
     pub token_id: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct TokenStatusResponsePayload {
+    pub token_id: String,
+    pub status: crate::wallet::TokenStatus,
+    pub expiry: String, // RFC 3339
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct DepositMsg1 {
     pub auth_key: String,
     pub token_id: String,
     pub signed_token_id: String,
+    /// Token server signature on the token id, if it is a blind token
+    #[serde(default)]
+    pub blind_signature: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -42,12 +55,19 @@ pub struct AggregatedPublicKey {
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn create_deposit_msg1(coin: &Coin, token_id: &str) -> Result<DepositMsg1, MercuryError>{
+    create_deposit_msg1_with_signer(coin, token_id, None, &InMemorySigner)
+}
+
+/// Deposit message of a blind token, with the unblinded token server signature on the token id
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_blind_deposit_msg1(coin: &Coin, token_id: &str, blind_signature: &str) -> Result<DepositMsg1, MercuryError>{
+    create_deposit_msg1_with_signer(coin, token_id, Some(blind_signature.to_string()), &InMemorySigner)
+}
+
+pub fn create_deposit_msg1_with_signer(coin: &Coin, token_id: &str, blind_signature: Option<String>, signer: &dyn Signer) -> Result<DepositMsg1, MercuryError>{
     let msg = Message::from_hashed_data::<sha256::Hash>(token_id.to_string().as_bytes());
 
-    let secp = Secp256k1::new();
-    let auth_secret_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
-    let keypair = secp256k1::KeyPair::from_seckey_slice(&secp, auth_secret_key.as_ref())?;
-    let signed_token_id = secp.sign_schnorr(&msg, &keypair);
+    let signed_token_id = signer.sign_schnorr(coin, CoinKey::Auth, &msg)?;
 
     let auth_xonly_pubkey = PublicKey::from_str(&coin.auth_pubkey)?.x_only_public_key().0;
 
@@ -55,6 +75,7 @@ pub fn create_deposit_msg1(coin: &Coin, token_id: &str) -> Result<DepositMsg1, M
         auth_key: auth_xonly_pubkey.to_string(),
         token_id: token_id.to_string(),
         signed_token_id: signed_token_id.to_string(),
+        blind_signature,
     };
 
     Ok(deposit_msg_1)
@@ -62,18 +83,17 @@ pub fn create_deposit_msg1(coin: &Coin, token_id: &str) -> Result<DepositMsg1, M
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn handle_deposit_msg_1_response(coin: &Coin, deposit_msg_1_response: &DepositMsg1Response) -> Result<DepositInitResult, MercuryError> {
+    handle_deposit_msg_1_response_with_signer(coin, deposit_msg_1_response, &InMemorySigner)
+}
 
-    let secp = Secp256k1::new();
+pub fn handle_deposit_msg_1_response_with_signer(coin: &Coin, deposit_msg_1_response: &DepositMsg1Response, signer: &dyn Signer) -> Result<DepositInitResult, MercuryError> {
 
     let server_pubkey_share = PublicKey::from_str(&deposit_msg_1_response.server_pubkey).unwrap();
 
     let statechain_id = deposit_msg_1_response.statechain_id.to_string();
 
-    let auth_secret_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
-    let keypair = secp256k1::KeyPair::from_seckey_slice(&secp, auth_secret_key.as_ref()).unwrap();
-
     let msg = Message::from_hashed_data::<sha256::Hash>(statechain_id.to_string().as_bytes());
-    let signed_statechain_id = secp.sign_schnorr(&msg, &keypair);
+    let signed_statechain_id = signer.sign_schnorr(coin, CoinKey::Auth, &msg)?;
 
     Ok(DepositInitResult {
         server_pubkey: server_pubkey_share.to_string(),
@@ -90,7 +110,7 @@ pub fn create_aggregated_address(coin: &Coin, network: String) -> Result<Aggrega
     let secp = Secp256k1::new();
 
     let user_pubkey_share = PublicKey::from_str(&coin.user_pubkey)?;
-    let server_pubkey_share = PublicKey::from_str(&coin.server_pubkey.as_ref().unwrap())?;
+    let server_pubkey_share = PublicKey::from_str(coin.server_pubkey.as_ref().unwrap())?;
 
     let aggregate_pubkey = user_pubkey_share.combine(&server_pubkey_share)?;
 
@@ -27,13 +27,15 @@ pub enum MercuryError {
 
 use bitcoin::{bip32, sighash::SighashTypeParseError};
 use secp256k1_zkp::{musig::{MusigNonceGenError, MusigSignError, ParseError}, scalar::OutOfRangeError, UpstreamError};
+use serde::Serialize;
 
-// TODO: UniFFI apparently does not support adding fields to the error enum variants.
-//       For example, NetworkConversionError(String) is not supported.
-//       This makes it impossible to detail the error message. Look into this issue.
+// UniFFI does not support tuple fields in the error enum variants, and the errors from other libs
+// cannot cross the FFI boundary. Variants that need to carry context use named fields of plain types instead.
+// Errors are serialized with the variant name in the `type` field, so wasm clients receive the same context.
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, thiserror::Error, Serialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Error))]
+#[serde(tag = "type")]
 pub enum MercuryError {
     Bip39Error,
     Bip32Error,
@@ -61,14 +63,16 @@ pub enum MercuryError {
     MoreThanOneInputError,
     UnkownNetwork,
     BackupTransactionDoesNotPayUser,
-    FeeTooHigh,
-    FeeTooLow,
+    /// The fee rate (sats/vbyte) of backup transaction `tx_n` is above the current fee rate plus the tolerance
+    FeeTooHigh { tx_n: Option<u32>, fee_rate: f64, expected_fee_rate: f64, tolerance: f64 },
+    /// The fee rate (sats/vbyte) of backup transaction `tx_n` is below the current fee rate minus the tolerance
+    FeeTooLow { tx_n: Option<u32>, fee_rate: f64, expected_fee_rate: f64, tolerance: f64 },
     OutOfRangeError,
     SerdeJsonError,
     SecpError,
     NoBackupTransactionFound,
     Tx1HasMoreThanOneInput,
-    InvalidSignature,
+    InvalidSignature { reason: String },
     EmptyWitness,
     EmptyWitnessData,
     IncorrectChallenge,
@@ -78,13 +82,100 @@ pub enum MercuryError {
     NoX1Pub,
     NoAggregatedPubkeyError,
     CoinNotFound,
-    SignatureSchemeValidationError,
+    /// Backup transaction `tx_n` of the transfer message failed validation
+    SignatureSchemeValidationError { tx_n: u32, reason: String },
     NoPreviousLockTimeError,
+    TransactionHasNoInputs,
+    TransactionHasNoOutputs,
+    /// The transaction has no output at index `vout`
+    OutputNotFound { vout: u32 },
+    /// The output script is not a taproot key spend output
+    InvalidTaprootOutput,
+    /// The transaction outputs spend more than its input (sats)
+    OutputExceedsInput { input: u64, output: u64 },
+    Utf8Error,
+    /// The transfer message does not carry one backup transaction per server signature
+    BackupCountMismatch { backups: u32, num_sigs: u32 },
+    DuplicateBackupTxN { tx_n: u32 },
+    /// The backup transactions are not numbered 1, 2, 3... in order
+    NonContiguousBackupTxN { expected: u32, found: u32 },
+    /// The statechain info at the same position as backup transaction `tx_n` is for another transaction
+    StatechainInfoMismatch { tx_n: u32, statechain_tx_n: u32 },
+    /// Backup transaction `tx_n` does not spend the same Tx0 outpoint as the first backup transaction
+    BackupSpendsDifferentOutpoint { tx_n: u32 },
+    /// The latest backup transaction does not have the lowest locktime of the chain
+    LatestBackupLocktimeNotLowest { latest_locktime: u32, tx_n: u32, locktime: u32 },
+    /// A PSBT input has no taproot key spend signature
+    PsbtMissingSignature,
+    /// A PSBT input does not carry the output it spends
+    PsbtMissingWitnessUtxo,
+    /// The wallet secrets are encrypted, it must be unlocked first
+    WalletLocked,
+    WalletNotLocked,
+    IncorrectPassword,
+    EncryptionError,
+    InvalidMnemonicWordCount { word_count: u32 },
+    /// The wallet seed uses a BIP39 passphrase and none was given
+    PassphraseRequired,
+    /// The passphrase does not derive the keys of the existing coins
+    IncorrectPassphrase,
+    /// The recovered statecoin is owned by another auth key than the coin
+    RecoveredStatecoinKeyMismatch,
+    InvalidWalletBackup { reason: String },
+    /// The backup file was created by another version of the format
+    UnsupportedWalletBackupVersion { version: u32, supported_version: u32 },
+    /// The transaction fee (sats) is larger than the amount it spends
+    FeeExceedsAmount { fee: u64, amount: u64 },
+    /// The key derived by the signer is not the key of the coin (e.g. another seed or passphrase)
+    SignerKeyMismatch,
+    /// The user and auth keys of a coin were not derived from the same seed and address index
+    KeyDerivationMismatch,
+}
+
+fn backup_tx_prefix(tx_n: &Option<u32>) -> String {
+    match tx_n {
+        Some(tx_n) => format!("Backup transaction {}: ", tx_n),
+        None => String::new(),
+    }
 }
 
 impl core::fmt::Display for MercuryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{:?}", self))
+        match self {
+            MercuryError::FeeTooHigh { tx_n, fee_rate, expected_fee_rate, tolerance } =>
+                write!(f, "{}Fee rate {} sats/vbyte is higher than the current fee rate {} (tolerance {})", backup_tx_prefix(tx_n), fee_rate, expected_fee_rate, tolerance),
+            MercuryError::FeeTooLow { tx_n, fee_rate, expected_fee_rate, tolerance } =>
+                write!(f, "{}Fee rate {} sats/vbyte is lower than the current fee rate {} (tolerance {})", backup_tx_prefix(tx_n), fee_rate, expected_fee_rate, tolerance),
+            MercuryError::FeeExceedsAmount { fee, amount } =>
+                write!(f, "Fee of {} sats exceeds the amount of {} sats", fee, amount),
+            MercuryError::InvalidSignature { reason } =>
+                write!(f, "Invalid signature: {}", reason),
+            MercuryError::SignatureSchemeValidationError { tx_n, reason } =>
+                write!(f, "Backup transaction {} failed validation: {}", tx_n, reason),
+            MercuryError::OutputNotFound { vout } =>
+                write!(f, "Transaction has no output {}", vout),
+            MercuryError::OutputExceedsInput { input, output } =>
+                write!(f, "Output of {} sats exceeds the input of {} sats", output, input),
+            MercuryError::BackupCountMismatch { backups, num_sigs } =>
+                write!(f, "Transfer message has {} backup transactions but the server signed {}", backups, num_sigs),
+            MercuryError::DuplicateBackupTxN { tx_n } =>
+                write!(f, "Backup transaction {} appears more than once", tx_n),
+            MercuryError::NonContiguousBackupTxN { expected, found } =>
+                write!(f, "Expected backup transaction {}, found {}", expected, found),
+            MercuryError::StatechainInfoMismatch { tx_n, statechain_tx_n } =>
+                write!(f, "Backup transaction {} is matched with the statechain info of transaction {}", tx_n, statechain_tx_n),
+            MercuryError::BackupSpendsDifferentOutpoint { tx_n } =>
+                write!(f, "Backup transaction {} spends a different outpoint than the first backup transaction", tx_n),
+            MercuryError::LatestBackupLocktimeNotLowest { latest_locktime, tx_n, locktime } =>
+                write!(f, "Latest backup locktime {} is not lower than the locktime {} of backup transaction {}", latest_locktime, locktime, tx_n),
+            MercuryError::InvalidMnemonicWordCount { word_count } =>
+                write!(f, "Invalid mnemonic length of {} words, expected 12, 15, 18, 21 or 24", word_count),
+            MercuryError::InvalidWalletBackup { reason } =>
+                write!(f, "Invalid wallet backup: {}", reason),
+            MercuryError::UnsupportedWalletBackupVersion { version, supported_version } =>
+                write!(f, "Wallet backup version {} is not supported, expected version {}", version, supported_version),
+            _ => f.write_fmt(format_args!("{:?}", self)),
+        }
     }
 }
 
@@ -160,6 +251,12 @@ impl From<bitcoin::psbt::Error> for MercuryError {
     }
 }
 
+impl From<bitcoin::psbt::PsbtParseError> for MercuryError {
+    fn from(_: bitcoin::psbt::PsbtParseError) -> Self {
+        MercuryError::BitcoinPsbtError
+    }
+}
+
 impl From<SighashTypeParseError> for MercuryError {
     fn from(_: SighashTypeParseError) -> Self {
         MercuryError::SighashTypeParseError
@@ -190,6 +287,12 @@ impl From<OutOfRangeError> for MercuryError {
     }
 }
 
+impl From<std::string::FromUtf8Error> for MercuryError {
+    fn from(_: std::string::FromUtf8Error) -> Self {
+        MercuryError::Utf8Error
+    }
+}
+
 impl From<serde_json::Error> for MercuryError {
     fn from(_: serde_json::Error) -> Self {
         MercuryError::SerdeJsonError
@@ -4,6 +4,9 @@ pub mod withdraw;
 pub mod wallet;
 pub mod utils;
 pub mod transaction;
+pub mod psbt;
+pub mod recovery;
+pub mod signer;
 pub mod unifii_interface;
 pub mod error;
 
@@ -14,6 +17,7 @@ use bip39::Mnemonic;
 use bitcoin::{bip32::{ChildNumber, DerivationPath, ExtendedPrivKey}, secp256k1::{ffi::types::AlignedType, AllPreallocated, PublicKey, Secp256k1, SecretKey}, Address};
 
 use error::MercuryError;
+use wallet::Wallet;
 
 #[cfg(feature = "bindings")]
 uniffi::setup_scaffolding!();
@@ -64,13 +68,13 @@ pub fn decode_transfer_address(sc_address: &str) -> core::result::Result<(u8, Pu
 fn get_key(secp: &Secp256k1<AllPreallocated<'_>>, root: ExtendedPrivKey, derivation_path: &str, change_index: u32, address_index:u32) -> core::result::Result<SecretKey, MercuryError> {
     // derive child xpub
     let path = DerivationPath::from_str(derivation_path)?;
-    let child = root.derive_priv(&secp, &path)?;
+    let child = root.derive_priv(secp, &path)?;
 
     // generate key at m/change_index_number/address_index_number
     let change_index_number = ChildNumber::from_normal_idx(change_index)?;
     let address_index_number = ChildNumber::from_normal_idx(address_index)?;
 
-    let secret_key = child.derive_priv(&secp, &[change_index_number, address_index_number])?.private_key;
+    let secret_key = child.derive_priv(secp, &[change_index_number, address_index_number])?.private_key;
 
     Ok(secret_key)
 }
@@ -94,16 +98,23 @@ pub fn get_sc_address(mnemonic: &str, index: u32, network: &str) -> core::result
     let root = ExtendedPrivKey::new_master(network, &seed)?;
 
     let user_derivation_path = "m/86h/0h/0h";
-    let user_seckey = get_key(&secp, root, &user_derivation_path, 0, index)?;
+    let user_seckey = get_key(&secp, root, user_derivation_path, 0, index)?;
     let user_pubkey = user_seckey.public_key(&secp);
 
     let auth_derivation_path = "m/89h/0h/0h";
-    let auth_seckey = get_key(&secp, root, &auth_derivation_path, 0, index)?;
+    let auth_seckey = get_key(&secp, root, auth_derivation_path, 0, index)?;
     let auth_pubkey = auth_seckey.public_key(&secp);
 
     encode_sc_address(&user_pubkey, &auth_pubkey, network)
 }
 
+/// Statechain address of the coin at the address index of a wallet whose seed may use a BIP39 passphrase.
+/// Fails with PassphraseRequired or IncorrectPassphrase if the passphrase does not match the wallet.
+pub fn get_sc_address_with_passphrase(wallet: &Wallet, passphrase: &str, index: u32) -> core::result::Result<String, MercuryError> {
+    let coin = wallet.get_coin_at_index(index, passphrase)?;
+    Ok(coin.address)
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn validate_address(address: &str, network: &str) -> core::result::Result<bool, MercuryError> {
 
@@ -141,6 +152,7 @@ pub fn validate_address(address: &str, network: &str) -> core::result::Result<bo
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::wallet::tests::test_wallet;
 
     #[test]
     fn it_works() {
@@ -156,4 +168,19 @@ mod tests {
         let expected_sc_address = "ml1qqpgha2armzyvwwglqty24ztegut27neyvlkpu3894adsgascq96tjqr78gy6adlzsre3fqyrxdx8n68henrd6fzcgfwcltu3sesuh05nvxs2dd888";
         assert_eq!(sc_address, expected_sc_address);
     }
+
+    #[test]
+    fn sc_address_with_passphrase() {
+        let mut wallet = test_wallet();
+        wallet.coins.clear();
+        wallet.has_passphrase = true;
+        wallet.master_fingerprint = Some(wallet.get_master_fingerprint("TREZOR").unwrap());
+
+        let sc_address = get_sc_address_with_passphrase(&wallet, "TREZOR", 0).unwrap();
+        let expected_sc_address = "tml1qqp59kx5f7w0wtavl9ezwjnz7d8exgvajz6xd2497nca77lhs9ejgrczydg27q8r5phkyd959fwzltrgrwlcxa3tp5dujstyq7gdd5xhlqmqy2udgf";
+        assert_eq!(sc_address, expected_sc_address);
+
+        assert!(matches!(get_sc_address_with_passphrase(&wallet, "", 0), Err(MercuryError::PassphraseRequired)));
+        assert!(matches!(get_sc_address_with_passphrase(&wallet, "trezor", 0), Err(MercuryError::IncorrectPassphrase)));
+    }
 }
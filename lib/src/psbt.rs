@@ -0,0 +1,245 @@
+//! PSBT export and import of CPFP transactions, so that they can be signed outside of the library (e.g. on an
+//! air-gapped or hardware signer).
+//!
+//! CPFP transactions spend the backup output with the user key alone, so the signer only needs the key at the
+//! BIP32 origin of the input. Backup and withdrawal transactions are not exported: they are signed with a blinded
+//! musig session shared with the server, which external signers do not support.
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use bitcoin::{bip32::{DerivationPath, Fingerprint, KeySource}, psbt::{Input, Psbt}, secp256k1::schnorr, sighash::{self, SighashCache, TapSighashType}, taproot::{self, TapLeafHash}, Transaction, TxOut, Witness};
+use secp256k1_zkp::{Message, Secp256k1, XOnlyPublicKey};
+
+use crate::{error::MercuryError, wallet::Coin};
+
+/// BIP32 origin of the coin user key, in the form expected by `Input::tap_key_origins`.
+/// It is empty if the coin has no fingerprint or derivation path.
+pub fn user_key_origins(coin: &Coin, user_xonly_pubkey: XOnlyPublicKey) -> Result<BTreeMap<XOnlyPublicKey, (Vec<TapLeafHash>, KeySource)>, MercuryError> {
+
+    let mut origins = BTreeMap::new();
+
+    if coin.fingerprint.is_empty() || coin.derivation_path.is_empty() {
+        return Ok(origins);
+    }
+
+    let fingerprint = Fingerprint::from_str(&coin.fingerprint)?;
+    let derivation_path = DerivationPath::from_str(&coin.derivation_path)?;
+
+    origins.insert(user_xonly_pubkey, (vec![], (fingerprint, derivation_path)));
+
+    Ok(origins)
+}
+
+/// Moves the key spend signatures to the final witnesses and clears the data fields as per the spec
+pub(crate) fn finalize_key_spend_inputs(inputs: &mut [Input]) -> Result<(), MercuryError> {
+
+    for input in inputs.iter_mut() {
+
+        let tap_key_sig = input.tap_key_sig.ok_or(MercuryError::PsbtMissingSignature)?;
+
+        let mut script_witness: Witness = Witness::new();
+        script_witness.push(tap_key_sig.to_vec());
+        input.final_script_witness = Some(script_witness);
+
+        input.partial_sigs = BTreeMap::new();
+        input.sighash_type = None;
+        input.redeem_script = None;
+        input.witness_script = None;
+        input.bip32_derivation = BTreeMap::new();
+    }
+
+    Ok(())
+}
+
+/// Virtual size of the transaction once every input has a key spend signature
+pub(crate) fn signed_vsize(psbt: &Psbt) -> Result<usize, MercuryError> {
+
+    let mut psbt = psbt.clone();
+
+    let dummy_sig = schnorr::Signature::from_slice(&[1u8; 64])?;
+
+    for input in psbt.inputs.iter_mut() {
+        let hash_ty = input
+            .sighash_type
+            .and_then(|psbt_sighash_type| psbt_sighash_type.taproot_hash_ty().ok())
+            .unwrap_or(TapSighashType::All);
+
+        input.tap_key_sig = Some(taproot::Signature { sig: dummy_sig, hash_ty });
+    }
+
+    finalize_key_spend_inputs(&mut psbt.inputs)?;
+
+    Ok(psbt.extract_tx().vsize())
+}
+
+/// Verifies the key spend signature of every input against the output it spends, finalizes them and
+/// returns the signed transaction (hex)
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn import_signed_psbt(psbt_base64: &str) -> Result<String, MercuryError> {
+
+    let mut psbt = Psbt::from_str(psbt_base64)?;
+
+    let prevouts = psbt.inputs.iter()
+        .map(|input| input.witness_utxo.clone().ok_or(MercuryError::PsbtMissingWitnessUtxo))
+        .collect::<Result<Vec<TxOut>, MercuryError>>()?;
+
+    let secp = Secp256k1::new();
+
+    let mut sighash_cache = SighashCache::new(&psbt.unsigned_tx);
+
+    for (index, input) in psbt.inputs.iter().enumerate() {
+
+        let tap_key_sig = input.tap_key_sig.ok_or(MercuryError::PsbtMissingSignature)?;
+
+        if !prevouts[index].script_pubkey.is_v1_p2tr() {
+            return Err(MercuryError::InvalidTaprootOutput);
+        }
+
+        let output_key = XOnlyPublicKey::from_slice(&prevouts[index].script_pubkey.as_bytes()[2..])?;
+
+        let hash = sighash_cache.taproot_key_spend_signature_hash(index, &sighash::Prevouts::All(&prevouts), tap_key_sig.hash_ty)?;
+
+        let msg: Message = hash.into();
+
+        if secp.verify_schnorr(&tap_key_sig.sig, &msg, &output_key).is_err() {
+            return Err(MercuryError::InvalidSignature { reason: format!("signature of input {} does not match the output it spends", index) });
+        }
+    }
+
+    finalize_key_spend_inputs(&mut psbt.inputs)?;
+
+    let signed_tx: Transaction = psbt.extract_tx();
+
+    Ok(hex::encode(bitcoin::consensus::encode::serialize(&signed_tx)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::{absolute, Address, Network, OutPoint, TxIn, Txid};
+    use secp256k1_zkp::PublicKey;
+    use crate::{signer::{InMemorySigner, Signer}, wallet::{cpfp_tx::{create_cpfp_psbt, create_cpfp_tx}, tests::test_wallet, BackupTx}};
+
+    fn backup_tx_paying_to(coin: &Coin, value: u64) -> BackupTx {
+
+        let backup_address = Address::from_str(&coin.backup_address).unwrap().require_network(Network::Testnet).unwrap();
+
+        let tx = Transaction {
+            version: 2,
+            lock_time: absolute::LockTime::from_height(1000).unwrap(),
+            input: vec![TxIn {
+                previous_output: OutPoint { txid: Txid::from_str("0000000000000000000000000000000000000000000000000000000000000001").unwrap(), vout: 0 },
+                ..Default::default()
+            }],
+            output: vec![TxOut { value, script_pubkey: backup_address.script_pubkey() }],
+        };
+
+        BackupTx {
+            tx_n: 1,
+            tx: hex::encode(bitcoin::consensus::encode::serialize(&tx)),
+            client_public_nonce: String::new(),
+            server_public_nonce: String::new(),
+            client_public_key: String::new(),
+            server_public_key: String::new(),
+            blinding_factor: String::new(),
+        }
+    }
+
+    /// Signs the first input as an external signer would, from the PSBT alone
+    fn sign_externally(psbt_base64: &str, coin: &Coin) -> String {
+
+        let mut psbt = Psbt::from_str(psbt_base64).unwrap();
+
+        let prevouts: Vec<TxOut> = psbt.inputs.iter().map(|input| input.witness_utxo.clone().unwrap()).collect();
+
+        let hash = SighashCache::new(&psbt.unsigned_tx)
+            .taproot_key_spend_signature_hash(0, &sighash::Prevouts::All(&prevouts), TapSighashType::All)
+            .unwrap();
+
+        let sig = InMemorySigner.sign_taproot_key_spend(coin, &hash.into()).unwrap();
+
+        psbt.inputs[0].tap_key_sig = Some(taproot::Signature { sig, hash_ty: TapSighashType::All });
+
+        psbt.to_string()
+    }
+
+    #[test]
+    fn test_cpfp_psbt_input_metadata() {
+        let wallet = test_wallet();
+        let coin = &wallet.coins[0];
+        let backup_tx = backup_tx_paying_to(coin, 10000);
+
+        let psbt = Psbt::from_str(&create_cpfp_psbt(&backup_tx, coin, &coin.backup_address, 2.0, "testnet").unwrap()).unwrap();
+        let input = &psbt.inputs[0];
+
+        let user_xonly_pubkey = PublicKey::from_str(&coin.user_pubkey).unwrap().x_only_public_key().0;
+        let backup_address = Address::from_str(&coin.backup_address).unwrap().require_network(Network::Testnet).unwrap();
+
+        assert_eq!(input.tap_internal_key, Some(user_xonly_pubkey));
+        assert_eq!(input.witness_utxo, Some(TxOut { value: 10000, script_pubkey: backup_address.script_pubkey() }));
+
+        let (leaf_hashes, (fingerprint, derivation_path)) = input.tap_key_origins.get(&user_xonly_pubkey).unwrap();
+        assert!(leaf_hashes.is_empty());
+        assert_eq!(fingerprint.to_string(), coin.fingerprint);
+        assert_eq!(*derivation_path, DerivationPath::from_str(&coin.derivation_path).unwrap());
+
+        assert!(input.tap_key_sig.is_none());
+    }
+
+    #[test]
+    fn test_cpfp_psbt_without_key_origin() {
+        let wallet = test_wallet();
+        let mut coin = wallet.coins[0].clone();
+        coin.fingerprint = String::new();
+        coin.derivation_path = String::new();
+        let backup_tx = backup_tx_paying_to(&coin, 10000);
+
+        let psbt = Psbt::from_str(&create_cpfp_psbt(&backup_tx, &coin, &coin.backup_address, 2.0, "testnet").unwrap()).unwrap();
+
+        assert!(psbt.inputs[0].tap_key_origins.is_empty());
+    }
+
+    #[test]
+    fn test_import_externally_signed_cpfp_psbt() {
+        let wallet = test_wallet();
+        let coin = &wallet.coins[0];
+        let backup_tx = backup_tx_paying_to(coin, 10000);
+
+        let psbt = create_cpfp_psbt(&backup_tx, coin, &coin.backup_address, 2.0, "testnet").unwrap();
+        let signed_psbt = sign_externally(&psbt, coin);
+
+        let signed_tx: Transaction = bitcoin::consensus::deserialize(&hex::decode(import_signed_psbt(&signed_psbt).unwrap()).unwrap()).unwrap();
+        let cpfp_tx: Transaction = bitcoin::consensus::deserialize(&hex::decode(create_cpfp_tx(&backup_tx, coin, &coin.backup_address, 2.0, "testnet").unwrap()).unwrap()).unwrap();
+
+        // same transaction as the one signed by the library, with a key spend witness
+        assert_eq!(signed_tx.txid(), cpfp_tx.txid());
+        assert_eq!(signed_tx.input[0].witness.len(), 1);
+        assert_eq!(signed_tx.vsize(), cpfp_tx.vsize());
+    }
+
+    #[test]
+    fn test_import_unsigned_psbt() {
+        let wallet = test_wallet();
+        let coin = &wallet.coins[0];
+        let backup_tx = backup_tx_paying_to(coin, 10000);
+
+        let psbt = create_cpfp_psbt(&backup_tx, coin, &coin.backup_address, 2.0, "testnet").unwrap();
+
+        assert!(matches!(import_signed_psbt(&psbt), Err(MercuryError::PsbtMissingSignature)));
+    }
+
+    #[test]
+    fn test_import_psbt_signed_with_other_key() {
+        let mut wallet = test_wallet();
+        let other_coin = wallet.get_new_coin().unwrap();
+        wallet.coins.push(other_coin);
+
+        let coin = &wallet.coins[0];
+        let backup_tx = backup_tx_paying_to(coin, 10000);
+
+        let psbt = create_cpfp_psbt(&backup_tx, coin, &coin.backup_address, 2.0, "testnet").unwrap();
+        let signed_psbt = sign_externally(&psbt, &wallet.coins[1]);
+
+        assert!(matches!(import_signed_psbt(&signed_psbt), Err(MercuryError::InvalidSignature { .. })));
+    }
+}
@@ -0,0 +1,184 @@
+//! Recovery of the statecoins of a wallet from its mnemonic.
+//!
+//! The coin keys are derived along the same path as `Wallet::get_new_coin`, and the server is asked which statecoins
+//! are currently owned by the auth keys of each index. Scanning stops after a gap of indexes with no statecoin.
+
+use serde::{Serialize, Deserialize};
+
+use crate::{deposit::create_aggregated_address, error::MercuryError, transfer::receiver::{create_auth_key_proofs, sign_message, AuthKeyProof}, wallet::{Coin, Wallet}};
+
+/// Maximum number of auth keys the server looks up in a single recovery request
+pub const MAX_RECOVERY_AUTH_KEYS: usize = 100;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct RecoverStatecoinsRequestPayload {
+    pub challenge: String, // issued by /transfer/challenge to every auth key
+    pub auth_key_proofs: Vec<AuthKeyProof>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct RecoveredStatecoin {
+    pub auth_pub_key: String,
+    pub statechain_id: String,
+    pub server_pubkey: String,
+    /// Latest transfer message sent to the auth key, which carries the backup transactions of received statecoins
+    pub enc_transfer_msg: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct RecoverStatecoinsResponsePayload {
+    pub statecoins: Vec<RecoveredStatecoin>,
+}
+
+/// Derives the coins of `count` consecutive address indexes starting at `start_index`
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn derive_recovery_coins(wallet: &Wallet, start_index: u32, count: u32, passphrase: String) -> Result<Vec<Coin>, MercuryError> {
+    (start_index..start_index + count)
+        .map(|index| wallet.get_coin_at_index(index, &passphrase))
+        .collect()
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_recover_statecoins_request_payload(coins: &Vec<Coin>, challenge: &str) -> Result<RecoverStatecoinsRequestPayload, MercuryError> {
+
+    Ok(RecoverStatecoinsRequestPayload {
+        challenge: challenge.to_string(),
+        auth_key_proofs: create_auth_key_proofs(coins, challenge)?,
+    })
+}
+
+/// Fills in the statechain and aggregated key of a derived coin owned by a recovered statecoin.
+/// The UTXO, amount and locktime are set by the caller from the blockchain and the backup transactions.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn recover_coin(coin: &Coin, recovered_statecoin: &RecoveredStatecoin, network: String) -> Result<Coin, MercuryError> {
+
+    if coin.auth_pubkey != recovered_statecoin.auth_pub_key {
+        return Err(MercuryError::RecoveredStatecoinKeyMismatch);
+    }
+
+    let mut coin = coin.clone();
+
+    coin.server_pubkey = Some(recovered_statecoin.server_pubkey.clone());
+
+    let aggregated_public_key = create_aggregated_address(&coin, network)?;
+
+    coin.aggregated_pubkey = Some(aggregated_public_key.aggregate_pubkey);
+    coin.aggregated_address = Some(aggregated_public_key.aggregate_address);
+    coin.statechain_id = Some(recovered_statecoin.statechain_id.clone());
+    coin.signed_statechain_id = Some(sign_message(&recovered_statecoin.statechain_id, &coin)?);
+
+    Ok(coin)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bitcoin::hashes::sha256;
+    use secp256k1_zkp::{schnorr::Signature, Message, PublicKey, Secp256k1};
+
+    use crate::{transfer::receiver::auth_challenge_message, wallet::tests::test_wallet};
+
+    use super::*;
+
+    const SERVER_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn recovered_statecoin(auth_pub_key: &str) -> RecoveredStatecoin {
+        RecoveredStatecoin {
+            auth_pub_key: auth_pub_key.to_string(),
+            statechain_id: String::from("c5d4b9c6b2a44a6e9a2e0b1c1d9e8f7a"),
+            server_pubkey: SERVER_PUBKEY.to_string(),
+            enc_transfer_msg: None,
+        }
+    }
+
+    #[test]
+    fn test_derive_recovery_coins() {
+
+        let wallet = test_wallet();
+
+        let coins = derive_recovery_coins(&wallet, 0, 3, String::new()).unwrap();
+
+        let indexes: Vec<u32> = coins.iter().map(|coin| coin.index).collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+
+        // the first index is the coin created by the wallet
+        assert_eq!(coins[0].auth_pubkey, wallet.coins[0].auth_pubkey);
+        assert_eq!(coins[0].user_pubkey, wallet.coins[0].user_pubkey);
+
+        let coins = derive_recovery_coins(&wallet, 5, 2, String::new()).unwrap();
+
+        let indexes: Vec<u32> = coins.iter().map(|coin| coin.index).collect();
+        assert_eq!(indexes, vec![5, 6]);
+
+        assert!(derive_recovery_coins(&wallet, 0, 0, String::new()).unwrap().is_empty());
+
+        let result = derive_recovery_coins(&wallet, 0, 1, String::from("wrong passphrase"));
+        assert!(matches!(result, Err(MercuryError::IncorrectPassphrase)));
+    }
+
+    #[test]
+    fn test_create_recover_statecoins_request_payload() {
+
+        let wallet = test_wallet();
+        let coins = derive_recovery_coins(&wallet, 0, 3, String::new()).unwrap();
+
+        let challenge = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+        let payload = create_recover_statecoins_request_payload(&coins, challenge).unwrap();
+
+        assert_eq!(payload.challenge, challenge);
+        assert_eq!(payload.auth_key_proofs.len(), coins.len());
+
+        let secp = Secp256k1::new();
+
+        for (coin, auth_key_proof) in coins.iter().zip(payload.auth_key_proofs.iter()) {
+            assert_eq!(auth_key_proof.auth_pub_key, coin.auth_pubkey);
+
+            let auth_key = PublicKey::from_str(&auth_key_proof.auth_pub_key).unwrap();
+            let signature = Signature::from_str(&auth_key_proof.auth_sig).unwrap();
+            let msg = Message::from_hashed_data::<sha256::Hash>(auth_challenge_message(&auth_key_proof.auth_pub_key, challenge).as_bytes());
+
+            assert!(secp.verify_schnorr(&signature, &msg, &auth_key.x_only_public_key().0).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_recover_coin() {
+
+        let wallet = test_wallet();
+        let coins = derive_recovery_coins(&wallet, 0, 2, String::new()).unwrap();
+
+        let recovered_statecoin = recovered_statecoin(&coins[0].auth_pubkey);
+
+        let coin = recover_coin(&coins[0], &recovered_statecoin, wallet.network.clone()).unwrap();
+
+        assert_eq!(coin.statechain_id, Some(recovered_statecoin.statechain_id.clone()));
+        assert_eq!(coin.server_pubkey, Some(SERVER_PUBKEY.to_string()));
+        assert!(coin.aggregated_pubkey.is_some());
+        assert!(coin.aggregated_address.is_some());
+
+        // the statechain id is signed by the auth key
+        let auth_key = PublicKey::from_str(&coin.auth_pubkey).unwrap();
+        let signature = Signature::from_str(coin.signed_statechain_id.as_ref().unwrap()).unwrap();
+        let msg = Message::from_hashed_data::<sha256::Hash>(recovered_statecoin.statechain_id.as_bytes());
+
+        assert!(Secp256k1::new().verify_schnorr(&signature, &msg, &auth_key.x_only_public_key().0).is_ok());
+    }
+
+    #[test]
+    fn test_recover_coin_key_mismatch() {
+
+        let wallet = test_wallet();
+        let coins = derive_recovery_coins(&wallet, 0, 2, String::new()).unwrap();
+
+        let recovered_statecoin = recovered_statecoin(&coins[1].auth_pubkey);
+
+        let result = recover_coin(&coins[0], &recovered_statecoin, wallet.network.clone());
+
+        assert!(matches!(result, Err(MercuryError::RecoveredStatecoinKeyMismatch)));
+    }
+}
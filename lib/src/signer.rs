@@ -0,0 +1,217 @@
+//! Operations that need the coin private keys.
+//!
+//! The library functions that sign or decrypt have a `_with_signer` variant taking a `Signer`, so the keys can be kept
+//! outside of the `Coin` (e.g. in an HSM or a remote signer). The variants without a signer use `InMemorySigner`.
+
+use std::str::FromStr;
+
+use bip39::Mnemonic;
+use bitcoin::{bip32::{ChildNumber, DerivationPath, ExtendedPrivKey}, key::TapTweak, Network, PrivateKey};
+use secp256k1_zkp::{musig::{MusigPartialSignature, MusigPubNonce, MusigSecNonce, MusigSession, MusigSessionId}, new_musig_nonce_pair, schnorr::Signature, KeyPair, Message, PublicKey, Scalar, Secp256k1, SecretKey};
+
+use crate::{error::MercuryError, utils::get_network, wallet::{key_derivation::AUTH_KEY_DERIVATION_PATH, Coin, Wallet}};
+
+/// Key of the coin an operation is done with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoinKey {
+    /// Key share aggregated with the server key, also used for the backup address
+    User,
+    /// Key authenticating the coin owner to the server and receiving the transfer messages
+    Auth,
+}
+
+pub trait Signer {
+    /// Signs the message with the coin key (BIP340)
+    fn sign_schnorr(&self, coin: &Coin, key: CoinKey, msg: &Message) -> Result<Signature, MercuryError>;
+
+    /// Signs a taproot key spend of an output paying to the user key (no script tree)
+    fn sign_taproot_key_spend(&self, coin: &Coin, msg: &Message) -> Result<Signature, MercuryError>;
+
+    /// Generates the nonce pair of the user key for a blinded musig session
+    fn musig_nonce_pair(&self, coin: &Coin, session_id: MusigSessionId) -> Result<(MusigSecNonce, MusigPubNonce), MercuryError>;
+
+    /// Creates the partial signature of the user key for a blinded musig session
+    fn musig_partial_sign(&self, coin: &Coin, session: &MusigSession, sec_nonce: MusigSecNonce, negate_seckey: bool) -> Result<MusigPartialSignature, MercuryError>;
+
+    /// Decrypts a message encrypted to the auth key (ECIES)
+    fn ecies_decrypt(&self, coin: &Coin, ciphertext: &[u8]) -> Result<Vec<u8>, MercuryError>;
+
+    /// Returns `tweak` plus the user key (or its negation), used for the key update of transfers
+    fn user_key_tweak_add(&self, coin: &Coin, tweak: &Scalar, negate: bool) -> Result<SecretKey, MercuryError>;
+}
+
+/// Key store able to return the secret keys of the coin, which gets a `Signer` implementation for free
+pub trait SecretKeyProvider {
+    fn secret_key(&self, coin: &Coin, key: CoinKey) -> Result<SecretKey, MercuryError>;
+}
+
+impl<T: SecretKeyProvider> Signer for T {
+
+    fn sign_schnorr(&self, coin: &Coin, key: CoinKey, msg: &Message) -> Result<Signature, MercuryError> {
+        let secp = Secp256k1::new();
+        let keypair = KeyPair::from_secret_key(&secp, &self.secret_key(coin, key)?);
+        Ok(secp.sign_schnorr(msg, &keypair))
+    }
+
+    fn sign_taproot_key_spend(&self, coin: &Coin, msg: &Message) -> Result<Signature, MercuryError> {
+        let secp = Secp256k1::new();
+        let keypair = KeyPair::from_secret_key(&secp, &self.secret_key(coin, CoinKey::User)?);
+        let keypair = keypair.tap_tweak(&secp, None).to_inner();
+        Ok(secp.sign_schnorr(msg, &keypair))
+    }
+
+    fn musig_nonce_pair(&self, coin: &Coin, session_id: MusigSessionId) -> Result<(MusigSecNonce, MusigPubNonce), MercuryError> {
+        let secp = Secp256k1::new();
+        let seckey = self.secret_key(coin, CoinKey::User)?;
+        let pubkey = PublicKey::from_str(&coin.user_pubkey)?;
+        Ok(new_musig_nonce_pair(&secp, session_id, None, Some(seckey), pubkey, None, None)?)
+    }
+
+    fn musig_partial_sign(&self, coin: &Coin, session: &MusigSession, sec_nonce: MusigSecNonce, negate_seckey: bool) -> Result<MusigPartialSignature, MercuryError> {
+        let secp = Secp256k1::new();
+        let keypair = KeyPair::from_secret_key(&secp, &self.secret_key(coin, CoinKey::User)?);
+        Ok(session.blinded_partial_sign_without_keyaggcoeff(&secp, sec_nonce, &keypair, negate_seckey)?)
+    }
+
+    fn ecies_decrypt(&self, coin: &Coin, ciphertext: &[u8]) -> Result<Vec<u8>, MercuryError> {
+        let seckey = self.secret_key(coin, CoinKey::Auth)?;
+        ecies::decrypt(seckey.secret_bytes().as_slice(), ciphertext).map_err(|_| MercuryError::SecpError)
+    }
+
+    fn user_key_tweak_add(&self, coin: &Coin, tweak: &Scalar, negate: bool) -> Result<SecretKey, MercuryError> {
+        let seckey = self.secret_key(coin, CoinKey::User)?;
+        let seckey = if negate { seckey.negate() } else { seckey };
+        Ok(seckey.add_tweak(tweak)?)
+    }
+}
+
+/// Signs with the WIF private keys stored in the coin
+pub struct InMemorySigner;
+
+impl SecretKeyProvider for InMemorySigner {
+    fn secret_key(&self, coin: &Coin, key: CoinKey) -> Result<SecretKey, MercuryError> {
+        let wif = match key {
+            CoinKey::User => &coin.user_privkey,
+            CoinKey::Auth => &coin.auth_privkey,
+        };
+        Ok(PrivateKey::from_wif(wif)?.inner)
+    }
+}
+
+/// Derives the coin keys from the wallet seed with the coin derivation path, so the coin does not need to store them
+pub struct SeedSigner {
+    root: ExtendedPrivKey,
+}
+
+impl SeedSigner {
+    pub fn new(mnemonic: &str, passphrase: &str, network: Network) -> Result<Self, MercuryError> {
+        let seed = Mnemonic::from_str(mnemonic)?.to_seed(passphrase);
+        let root = ExtendedPrivKey::new_master(network, &seed)?;
+        Ok(SeedSigner { root })
+    }
+
+    pub fn from_wallet(wallet: &Wallet, passphrase: &str) -> Result<Self, MercuryError> {
+        if wallet.has_passphrase && passphrase.is_empty() {
+            return Err(MercuryError::PassphraseRequired);
+        }
+        SeedSigner::new(&wallet.mnemonic, passphrase, get_network(&wallet.network)?)
+    }
+}
+
+impl SecretKeyProvider for SeedSigner {
+    fn secret_key(&self, coin: &Coin, key: CoinKey) -> Result<SecretKey, MercuryError> {
+
+        let user_path = DerivationPath::from_str(&coin.derivation_path)?;
+
+        let path = match key {
+            CoinKey::User => user_path,
+            CoinKey::Auth => {
+                // the auth key has the same change and address index as the user key
+                let user_path: &[ChildNumber] = user_path.as_ref();
+                let indexes = &user_path[user_path.len().saturating_sub(2)..];
+                DerivationPath::from_str(AUTH_KEY_DERIVATION_PATH)?.extend(indexes)
+            },
+        };
+
+        let secp = Secp256k1::new();
+        let secret_key = self.root.derive_priv(&secp, &path)?.private_key;
+
+        let coin_pubkey = match key {
+            CoinKey::User => &coin.user_pubkey,
+            CoinKey::Auth => &coin.auth_pubkey,
+        };
+
+        if secret_key.public_key(&secp) != PublicKey::from_str(coin_pubkey)? {
+            return Err(MercuryError::SignerKeyMismatch);
+        }
+
+        Ok(secret_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::tests::test_wallet;
+
+    #[test]
+    fn test_seed_signer_derives_coin_keys() {
+        let wallet = test_wallet();
+        let coin = &wallet.coins[0];
+
+        let seed_signer = SeedSigner::from_wallet(&wallet, "").unwrap();
+
+        for key in [CoinKey::User, CoinKey::Auth] {
+            assert_eq!(seed_signer.secret_key(coin, key).unwrap(), InMemorySigner.secret_key(coin, key).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_seed_signer_signature() {
+        let wallet = test_wallet();
+        let coin = &wallet.coins[0];
+
+        let secp = Secp256k1::new();
+        let msg = Message::from_slice(&[7u8; 32]).unwrap();
+
+        let sig = SeedSigner::from_wallet(&wallet, "").unwrap().sign_schnorr(coin, CoinKey::Auth, &msg).unwrap();
+
+        let auth_pubkey = PublicKey::from_str(&coin.auth_pubkey).unwrap();
+        assert!(secp.verify_schnorr(&sig, &msg, &auth_pubkey.x_only_public_key().0).is_ok());
+    }
+
+    #[test]
+    fn test_seed_signer_wrong_passphrase() {
+        let wallet = test_wallet();
+        let coin = &wallet.coins[0];
+
+        let seed_signer = SeedSigner::new(&wallet.mnemonic, "TREZOR", get_network(&wallet.network).unwrap()).unwrap();
+
+        assert!(matches!(seed_signer.secret_key(coin, CoinKey::User), Err(MercuryError::SignerKeyMismatch)));
+        assert!(matches!(seed_signer.secret_key(coin, CoinKey::Auth), Err(MercuryError::SignerKeyMismatch)));
+    }
+
+    #[test]
+    fn test_seed_signer_coin_of_other_wallet() {
+        let wallet = test_wallet();
+        let mut coin = wallet.coins[0].clone();
+
+        // keys of another coin at the derivation path of this one
+        let other_coin = wallet.get_new_coin().unwrap();
+        coin.user_pubkey = other_coin.user_pubkey;
+        coin.auth_pubkey = other_coin.auth_pubkey;
+
+        let seed_signer = SeedSigner::from_wallet(&wallet, "").unwrap();
+
+        assert!(matches!(seed_signer.secret_key(&coin, CoinKey::User), Err(MercuryError::SignerKeyMismatch)));
+        assert!(matches!(seed_signer.secret_key(&coin, CoinKey::Auth), Err(MercuryError::SignerKeyMismatch)));
+    }
+
+    #[test]
+    fn test_seed_signer_passphrase_required() {
+        let mut wallet = test_wallet();
+        wallet.has_passphrase = true;
+
+        assert!(matches!(SeedSigner::from_wallet(&wallet, ""), Err(MercuryError::PassphraseRequired)));
+    }
+}
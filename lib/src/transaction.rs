@@ -1,10 +1,10 @@
-use std::{str::FromStr, collections::BTreeMap};
+use std::str::FromStr;
 
-use bitcoin::{Txid, ScriptBuf, Transaction, absolute, TxIn, OutPoint, Witness, TxOut, psbt::{Psbt, Input, PsbtSighashType}, sighash::{TapSighashType, SighashCache, self, TapSighash}, taproot::{TapTweakHash, self}, hashes::Hash, Address, PrivateKey, Network};
-use secp256k1_zkp::{SecretKey, PublicKey,  Secp256k1, schnorr::Signature, Message, musig::{MusigSessionId, MusigPubNonce, BlindingFactor, MusigSession, MusigPartialSignature, blinded_musig_pubkey_xonly_tweak_add, blinded_musig_negate_seckey, MusigAggNonce, MusigSecNonce}, new_musig_nonce_pair, KeyPair, rand::{self, Rng}};
+use bitcoin::{Txid, ScriptBuf, Transaction, absolute, TxIn, OutPoint, Witness, TxOut, psbt::{Psbt, Input, PsbtSighashType}, sighash::{TapSighashType, SighashCache, self, TapSighash}, taproot::{TapTweakHash, self}, hashes::Hash, Address, Network};
+use secp256k1_zkp::{SecretKey, PublicKey,  Secp256k1, schnorr::Signature, Message, musig::{MusigSessionId, MusigPubNonce, BlindingFactor, MusigSession, MusigPartialSignature, blinded_musig_pubkey_xonly_tweak_add, blinded_musig_negate_seckey, MusigAggNonce, MusigSecNonce}, rand::{self, Rng}};
 use serde::{Serialize, Deserialize};
 
-use crate::{decode_transfer_address, error::MercuryError, utils::{self, get_network}, wallet::Coin};
+use crate::{decode_transfer_address, error::MercuryError, signer::{InMemorySigner, Signer}, utils::{self, get_network}, wallet::Coin};
 
 #[derive(Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
@@ -63,15 +63,14 @@ pub struct PartialSignatureResponsePayload {
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn create_and_commit_nonces(coin: &Coin) -> core::result::Result<CoinNonce, MercuryError>{
-    
-    let secp = Secp256k1::new();
+    create_and_commit_nonces_with_signer(coin, &InMemorySigner)
+}
 
-    let client_session_id = MusigSessionId::new(&mut rand::thread_rng());
+pub fn create_and_commit_nonces_with_signer(coin: &Coin, signer: &dyn Signer) -> core::result::Result<CoinNonce, MercuryError>{
 
-    let client_seckey = PrivateKey::from_wif(&coin.user_privkey)?.inner;
-    let client_pubkey = PublicKey::from_str(&coin.user_pubkey)?;
+    let client_session_id = MusigSessionId::new(&mut rand::thread_rng());
 
-    let (client_sec_nonce, client_pub_nonce) = new_musig_nonce_pair(&secp, client_session_id, None, Some(client_seckey), client_pubkey, None, None)?;
+    let (client_sec_nonce, client_pub_nonce) = signer.musig_nonce_pair(coin, client_session_id)?;
 
     let blinding_factor = BlindingFactor::new(&mut rand::thread_rng());
 
@@ -100,7 +99,7 @@ fn get_locktime_for_withdrawal_transaction (block_height: u32) -> u32 {
 
     // sometimes locktime is set a bit further back, for privacy reasons
     if number == 0 {
-        locktime = locktime - rng.gen_range(0..=99);
+        locktime -= rng.gen_range(0..=99);
     }
 
     std::cmp::max(0, locktime) as u32
@@ -122,11 +121,11 @@ pub fn create_tx_out(
 
     let recipient_address = if to_address.starts_with(crate::MAINNET_HRP) || to_address.starts_with(crate::TESTNET_HRP) {
         let (_, recipient_user_pubkey, _) = decode_transfer_address(to_address)?;
-        let new_address = Address::p2tr(&Secp256k1::new(), recipient_user_pubkey.x_only_public_key().0, None, network);
-        new_address
+        
+        Address::p2tr(&Secp256k1::new(), recipient_user_pubkey.x_only_public_key().0, None, network)
     } else {
-        let new_address = Address::from_str(&to_address).unwrap().require_network(network)?;
-        new_address
+        
+        Address::from_str(to_address).unwrap().require_network(network)?
     };
 
     let tx_out = TxOut { value: amount_out, script_pubkey: recipient_address.script_pubkey() };
@@ -161,6 +160,7 @@ pub fn get_user_backup_address(coin: &Coin, network: String) -> core::result::Re
     Ok(to_address.to_string())
 }
 
+#[allow(clippy::too_many_arguments)]
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn get_partial_sig_request(
     coin: &Coin, 
@@ -172,6 +172,22 @@ pub fn get_partial_sig_request(
     to_address: String,
     network: String,
     is_withdrawal: bool) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+{
+    get_partial_sig_request_with_signer(coin, block_height, initlock, interval, fee_rate_sats_per_byte, qt_backup_tx, to_address, network, is_withdrawal, &InMemorySigner)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn get_partial_sig_request_with_signer(
+    coin: &Coin, 
+    block_height: u32, 
+    initlock: u32, 
+    interval: u32, 
+    fee_rate_sats_per_byte: f64,
+    qt_backup_tx: u32,
+    to_address: String,
+    network: String,
+    is_withdrawal: bool,
+    signer: &dyn Signer) -> core::result::Result<PartialSignatureMsg1, MercuryError>
 {
     let network = utils::get_network(&network)?;
     
@@ -188,25 +204,28 @@ pub fn get_partial_sig_request(
         coin,
         block_height, 
         &tx_out,
-        network)?;
+        network,
+        signer)?;
 
     Ok(session)
 }
 
-pub fn get_musig_session(
+/// Unsigned PSBT of a backup or withdrawal transaction, spending the coin UTXO with the aggregated key.
+/// It is only signed with the blinded musig session, so it is not exported for external signers.
+fn create_unsigned_psbt(
     coin: &Coin,
     block_height: u32, 
     output: &TxOut,
-    network: Network) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+    network: Network) -> core::result::Result<Psbt, MercuryError>
 {
-    let input_pubkey = PublicKey::from_str(&coin.aggregated_pubkey.as_ref().unwrap())?;
+    let input_pubkey = PublicKey::from_str(coin.aggregated_pubkey.as_ref().unwrap())?;
     let input_xonly_pubkey = input_pubkey.x_only_public_key().0;
 
     let outputs = [output.to_owned()].to_vec();
 
     let lock_time = absolute::LockTime::from_height(block_height)?;
 
-    let input_txid = Txid::from_str(&coin.utxo_txid.as_ref().unwrap())?;
+    let input_txid = Txid::from_str(coin.utxo_txid.as_ref().unwrap())?;
     let input_vout = coin.utxo_vout.unwrap();
 
     let tx1 = Transaction {
@@ -225,7 +244,7 @@ pub fn get_musig_session(
 
     let input_amount = coin.amount.unwrap() as u64;
     
-    let input_address = Address::from_str(&coin.aggregated_address.as_ref().unwrap())?.require_network(network)?;
+    let input_address = Address::from_str(coin.aggregated_address.as_ref().unwrap())?.require_network(network)?;
     let input_scriptpubkey = input_address.script_pubkey();
     let mut input = Input {
         witness_utxo: Some(TxOut { value: input_amount, script_pubkey: input_scriptpubkey }),
@@ -235,8 +254,21 @@ pub fn get_musig_session(
     let ty = PsbtSighashType::from_str("SIGHASH_ALL")?;
     input.sighash_type = Some(ty);
     input.tap_internal_key = Some(input_xonly_pubkey.to_owned());
+
     psbt.inputs = vec![input];
 
+    Ok(psbt)
+}
+
+pub fn get_musig_session(
+    coin: &Coin,
+    block_height: u32, 
+    output: &TxOut,
+    network: Network,
+    signer: &dyn Signer) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+{
+    let mut psbt = create_unsigned_psbt(coin, block_height, output, network)?;
+
     let unsigned_tx = psbt.unsigned_tx.clone();
 
     // There must not be more than one input.
@@ -244,7 +276,7 @@ pub fn get_musig_session(
     assert!(psbt.inputs.len() == 1);
 
     let vout = 0; // the vout is always 0 (only one input)
-    let input = psbt.inputs.iter_mut().nth(vout).unwrap();
+    let input = psbt.inputs.get_mut(vout).unwrap();
 
     let hash_ty = input
         .sighash_type
@@ -266,7 +298,8 @@ pub fn get_musig_session(
     let session = calculate_musig_session(
         coin,
         hash,
-        encoded_unsigned_tx)?;
+        encoded_unsigned_tx,
+        signer)?;
 
     Ok(session)
 }
@@ -274,11 +307,12 @@ pub fn get_musig_session(
 pub fn calculate_musig_session(
     coin: &Coin,
     hash: TapSighash,
-    encoded_unsigned_tx: String,) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+    encoded_unsigned_tx: String,
+    signer: &dyn Signer) -> core::result::Result<PartialSignatureMsg1, MercuryError>
 {
     let secp = Secp256k1::new();
 
-    let aggregate_pubkey = PublicKey::from_str(&coin.aggregated_pubkey.as_ref().unwrap())?; 
+    let aggregate_pubkey = PublicKey::from_str(coin.aggregated_pubkey.as_ref().unwrap())?; 
 
     let tap_tweak = TapTweakHash::from_key_and_tweak(aggregate_pubkey.x_only_public_key().0, None);
     let tap_tweak_bytes = tap_tweak.as_byte_array();
@@ -318,19 +352,18 @@ pub fn calculate_musig_session(
         parity_acc,
     );
 
-    let client_seckey = PrivateKey::from_wif(&coin.user_privkey)?.inner;
-
     let client_pubkey = PublicKey::from_str(&coin.user_pubkey)?;
 
-    let client_keypair = KeyPair::from_secret_key(&secp, &client_seckey);
-
     let client_sec_nonce_bytes = hex::decode(coin.secret_nonce.as_ref().unwrap())?;
     let client_sec_nonce_bytes: [u8; 132] = client_sec_nonce_bytes.try_into().unwrap();
     let client_sec_nonce = MusigSecNonce::from_slice(client_sec_nonce_bytes);
 
-    let client_partial_sig = session.blinded_partial_sign_without_keyaggcoeff(&secp, client_sec_nonce, &client_keypair, negate_seckey)?;
+    let client_partial_sig = signer.musig_partial_sign(coin, &session, client_sec_nonce, negate_seckey)?;
 
-    assert!(session.blinded_musig_partial_sig_verify(&secp, &client_partial_sig, &client_pub_nonce, &client_pubkey, &output_pubkey, parity_acc));
+    // the signer may be external, its partial signature is checked before it is sent to the server
+    if !session.blinded_musig_partial_sig_verify(&secp, &client_partial_sig, &client_pub_nonce, &client_pubkey, &output_pubkey, parity_acc) {
+        return Err(MercuryError::InvalidSignature { reason: "the partial signature of the user key does not verify".to_string() });
+    }
 
     let encoded_session = hex::encode(session.serialize());
 
@@ -384,7 +417,7 @@ pub fn create_signature(
     let client_partial_sig_bytes = hex::decode(client_partial_sig_hex)?;
     let client_partial_sig = MusigPartialSignature::from_slice(client_partial_sig_bytes.as_slice())?;
 
-    let session_bytes: [u8; 133] = hex::decode(&session_hex)?.try_into().unwrap();
+    let session_bytes: [u8; 133] = hex::decode(session_hex)?.try_into().unwrap();
     let session = MusigSession::from_slice(session_bytes);
 
     let sig = session.partial_sig_agg(&[client_partial_sig, server_partial_sig]);
@@ -393,7 +426,7 @@ pub fn create_signature(
 
     let x_only_key_tweaked = output_pubkey.x_only_public_key().0;
 
-    if !secp.verify_schnorr(&sig, &msg, &x_only_key_tweaked).is_ok() {
+    if secp.verify_schnorr(&sig, &msg, &x_only_key_tweaked).is_err() {
         return Err(MercuryError::SchnorrSignatureValidationError);
     }
 
@@ -416,7 +449,7 @@ pub fn new_backup_transaction(
     }
 
     let vout = 0;
-    let input = psbt.inputs.iter_mut().nth(vout).unwrap();
+    let input = psbt.inputs.get_mut(vout).unwrap();
 
     let hash_ty = input
         .sighash_type
@@ -429,18 +462,7 @@ pub fn new_backup_transaction(
 
     input.tap_key_sig = Some(final_signature);
 
-    psbt.inputs.iter_mut().for_each(|input| {
-        let mut script_witness: Witness = Witness::new();
-        script_witness.push(input.tap_key_sig.unwrap().to_vec());
-        input.final_script_witness = Some(script_witness);
-
-        // Clear all the data fields as per the spec.
-        input.partial_sigs = BTreeMap::new();
-        input.sighash_type = None;
-        input.redeem_script = None;
-        input.witness_script = None;
-        input.bip32_derivation = BTreeMap::new();
-    });
+    crate::psbt::finalize_key_spend_inputs(&mut psbt.inputs)?;
 
     let signed_tx = psbt.extract_tx();
     
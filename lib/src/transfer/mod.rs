@@ -4,7 +4,7 @@ use bitcoin::{Transaction, secp256k1::PublicKey};
 use secp256k1_zkp::musig::{MusigPubNonce, BlindingFactor};
 use serde::{Deserialize, Serialize};
 
-use crate::wallet::BackupTx;
+use crate::{error::MercuryError, wallet::BackupTx};
 
 pub mod receiver;
 pub mod sender;
@@ -54,7 +54,7 @@ impl SenderBackupTransaction {
     pub fn serialize(&self) -> SerializedBackupTransaction {
         SerializedBackupTransaction {
             tx_n: self.tx_n,
-            tx: hex::encode(&bitcoin::consensus::encode::serialize(&self.tx)),
+            tx: hex::encode(bitcoin::consensus::encode::serialize(&self.tx)),
             client_public_nonce: hex::encode(&self.client_public_nonce),
             server_public_nonce: hex::encode(&self.server_public_nonce),
             client_public_key: self.client_public_key.to_string(),
@@ -66,18 +66,18 @@ impl SenderBackupTransaction {
 }
 
 impl SerializedBackupTransaction {
-    pub fn deserialize(&self) -> ReceiverBackupTransaction {
-        ReceiverBackupTransaction {
+    pub fn deserialize(&self) -> Result<ReceiverBackupTransaction, MercuryError> {
+        Ok(ReceiverBackupTransaction {
             statechain_id: "".to_string(),
             tx_n: self.tx_n,
-            tx: bitcoin::consensus::encode::deserialize(&hex::decode(&self.tx).unwrap()).unwrap(),
-            client_public_nonce: MusigPubNonce::from_slice(hex::decode(&self.client_public_nonce).unwrap().as_slice()).unwrap(),
-            server_public_nonce: MusigPubNonce::from_slice(hex::decode(&self.server_public_nonce).unwrap().as_slice()).unwrap(),
-            client_public_key: PublicKey::from_str(&self.client_public_key).unwrap(),
-            server_public_key: PublicKey::from_str(&self.server_public_key).unwrap(),
-            blinding_factor: BlindingFactor::from_slice(hex::decode(&self.blinding_factor).unwrap().as_slice()).unwrap(),
+            tx: bitcoin::consensus::encode::deserialize(&hex::decode(&self.tx)?)?,
+            client_public_nonce: MusigPubNonce::from_slice(hex::decode(&self.client_public_nonce)?.as_slice())?,
+            server_public_nonce: MusigPubNonce::from_slice(hex::decode(&self.server_public_nonce)?.as_slice())?,
+            client_public_key: PublicKey::from_str(&self.client_public_key)?,
+            server_public_key: PublicKey::from_str(&self.server_public_key)?,
+            blinding_factor: BlindingFactor::from_slice(hex::decode(&self.blinding_factor)?.as_slice())?,
             recipient_address: self.recipient_address.clone(),
-        }
+        })
     }
 }
 
@@ -1,13 +1,16 @@
-use std::str::FromStr;
+use std::{collections::{HashMap, HashSet}, str::FromStr};
 
-use bitcoin::{PrivateKey, Transaction, hashes::{sha256, Hash}, Txid, Address, sighash::{TapSighashType, SighashCache, self}, TxOut, taproot::TapTweakHash};
-use secp256k1_zkp::{PublicKey, schnorr::Signature, Secp256k1, Message, XOnlyPublicKey, musig::{MusigPubNonce, BlindingFactor, blinded_musig_pubkey_xonly_tweak_add, MusigAggNonce, MusigSession}, SecretKey, Scalar, KeyPair};
+use bitcoin::{PrivateKey, Transaction, hashes::{sha256, Hash}, Txid, Address, sighash::{TapSighashType, SighashCache, self}, TxOut, taproot::TapTweakHash, Script};
+use secp256k1_zkp::{PublicKey, schnorr::Signature, Secp256k1, Message, XOnlyPublicKey, musig::{MusigPubNonce, BlindingFactor, blinded_musig_pubkey_xonly_tweak_add, MusigAggNonce, MusigSession}, SecretKey, Scalar};
 use serde::{Serialize, Deserialize};
 
-use crate::{error::MercuryError, utils::get_network, wallet::{BackupTx, Coin, CoinStatus, Wallet}};
+use crate::{error::MercuryError, signer::{CoinKey, InMemorySigner, Signer}, utils::get_network, wallet::{BackupTx, Coin, CoinStatus, Wallet}};
 
 use super::TransferMsg;
 
+/// Maximum number of auth keys the server looks up in a single bulk get_msg_addr request
+pub const MAX_MSG_ADDR_BULK_AUTH_KEYS: usize = 100;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct TransferUnlockRequestPayload { 
@@ -58,7 +61,69 @@ pub struct KeyUpdateResponsePayload {
 pub struct GetMsgAddrResponsePayload {
     pub list_enc_transfer_msg: Vec<String>,
 }
- 
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct AuthChallengeRequestPayload {
+    pub auth_pub_keys: Vec<String>, // the challenge can only be used with proofs of all these keys
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct AuthChallengeResponsePayload {
+    pub challenge: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct AuthKeyProof {
+    pub auth_pub_key: String,
+    pub auth_sig: String, // signed "<auth_pub_key>:<challenge>"
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct GetMsgAddrBulkRequestPayload {
+    pub challenge: String, // issued by /transfer/challenge to every auth key
+
+    pub auth_key_proofs: Vec<AuthKeyProof>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct GetMsgAddrBulkResponsePayload {
+    /// Encrypted transfer messages for every requested auth key (empty list when there is none)
+    pub enc_transfer_msgs_per_auth_key: HashMap<String, Vec<String>>,
+    /// Random hex string that rounds the response size up, hiding the number of messages
+    pub padding: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct TransferNotificationRequestPayload {
+    pub challenge: String, // issued by /transfer/challenge to every auth key
+    pub auth_key_proofs: Vec<AuthKeyProof>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Enum))]
+pub enum TransferNotificationType {
+    /// An encrypted transfer message is available for the auth key
+    TransferMsg,
+    /// All coins of the batch the transfer belongs to have been unlocked
+    BatchUnlocked,
+    /// The server key share of the statecoin has been updated to the receiver
+    KeyUpdated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct TransferNotificationPayload {
+    pub notification_type: TransferNotificationType,
+    pub statechain_id: String,
+    pub auth_pub_key: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct StatechainInfo {
@@ -96,7 +161,7 @@ pub struct NewKeyInfo {
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn duplicate_coin_to_initialized_state(wallet: &Wallet, auth_pubkey: &str) -> Result<Coin, MercuryError> {
 
-    let coin = wallet.coins.iter().find(|coin| coin.auth_pubkey == auth_pubkey.to_string());
+    let coin = wallet.coins.iter().find(|coin| coin.auth_pubkey == *auth_pubkey);
 
     if coin.is_none() {
         return Err(MercuryError::CoinNotFound);
@@ -141,15 +206,32 @@ pub fn decrypt_transfer_msg(encrypted_message: &str, private_key_wif: &str) -> R
 
     let decoded_enc_message = hex::decode(encrypted_message)?;
 
-    let decrypted_msg = ecies::decrypt(client_auth_key.secret_bytes().as_slice(), decoded_enc_message.as_slice()).unwrap();
+    let decrypted_msg = ecies::decrypt(client_auth_key.secret_bytes().as_slice(), decoded_enc_message.as_slice())
+        .map_err(|_| MercuryError::SecpError)?;
+
+    parse_transfer_msg(decrypted_msg)
+}
+
+/// Same as `decrypt_transfer_msg`, decrypting with the auth key of the coin
+pub fn decrypt_transfer_msg_with_signer(encrypted_message: &str, coin: &Coin, signer: &dyn Signer) -> Result<TransferMsg, MercuryError> {
+
+    let decoded_enc_message = hex::decode(encrypted_message)?;
+
+    let decrypted_msg = signer.ecies_decrypt(coin, decoded_enc_message.as_slice())?;
+
+    parse_transfer_msg(decrypted_msg)
+}
+
+fn parse_transfer_msg(decrypted_msg: Vec<u8>) -> Result<TransferMsg, MercuryError> {
 
-    let decrypted_msg_str = String::from_utf8(decrypted_msg).unwrap();
+    let decrypted_msg_str = String::from_utf8(decrypted_msg)?;
 
-    let transfer_msg: TransferMsg = serde_json::from_str(decrypted_msg_str.as_str()).unwrap();
+    let transfer_msg: TransferMsg = serde_json::from_str(decrypted_msg_str.as_str())?;
 
     Ok(transfer_msg)
 }
 
+#[allow(clippy::ptr_arg)]
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn get_tx0_outpoint(backup_transactions: &Vec<BackupTx>) -> Result<TxOutpoint, MercuryError> {
 
@@ -169,8 +251,10 @@ pub fn get_tx0_outpoint(backup_transactions: &Vec<BackupTx>) -> Result<TxOutpoin
         return Err(MercuryError::Tx1HasMoreThanOneInput);
     }
 
-    let tx0_txid = tx1.input[0].previous_output.txid;
-    let tx0_vout = tx1.input[0].previous_output.vout as u32;
+    let tx1_input = tx1.input.first().ok_or(MercuryError::TransactionHasNoInputs)?;
+
+    let tx0_txid = tx1_input.previous_output.txid;
+    let tx0_vout = tx1_input.previous_output.vout as u32;
 
     Ok(TxOutpoint{ txid: tx0_txid.to_string(), vout: tx0_vout })
 }
@@ -199,34 +283,48 @@ pub fn verify_transfer_signature(new_user_pubkey: &str, tx0_outpoint: &TxOutpoin
 
 pub fn validate_tx0_output_pubkey(enclave_public_key: &str, transfer_msg: &TransferMsg, tx0_outpoint: &TxOutpoint, tx0_hex: &str, network: &str) -> Result<bool, MercuryError> {
 
-    let network = get_network(&network)?;
+    let network = get_network(network)?;
 
-    let enclave_public_key = PublicKey::from_str(enclave_public_key).unwrap();
+    let enclave_public_key = PublicKey::from_str(enclave_public_key)?;
     let sender_public_key = PublicKey::from_str(&transfer_msg.user_public_key)?;
 
-    let transfer_aggregate_pubkey = sender_public_key.combine(&enclave_public_key).unwrap();
+    let transfer_aggregate_pubkey = sender_public_key.combine(&enclave_public_key)?;
     let transfer_aggregate_xonly_pubkey = transfer_aggregate_pubkey.x_only_public_key().0;
 
     let secp = Secp256k1::new();
 
     let transfer_aggregate_address = Address::p2tr(&secp, transfer_aggregate_xonly_pubkey, None, network);
 
-    let transfer_aggregate_xonly_pubkey = XOnlyPublicKey::from_slice(transfer_aggregate_address.script_pubkey()[2..].as_bytes()).unwrap();
+    let transfer_aggregate_xonly_pubkey = taproot_output_key(&transfer_aggregate_address.script_pubkey())?;
 
-    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
+    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx0_hex)?)?;
 
-    let tx0_output = tx0.output[tx0_outpoint.vout as usize].clone();
+    let tx0_output = get_output(&tx0, tx0_outpoint.vout)?;
 
-    let tx0_output_xonly_pubkey = XOnlyPublicKey::from_slice(tx0_output.script_pubkey[2..].as_bytes()).unwrap();
+    let tx0_output_xonly_pubkey = taproot_output_key(&tx0_output.script_pubkey)?;
 
     Ok(transfer_aggregate_xonly_pubkey == tx0_output_xonly_pubkey)
 }
 
+fn get_output(tx: &Transaction, vout: u32) -> Result<TxOut, MercuryError> {
+    tx.output.get(vout as usize).cloned().ok_or(MercuryError::OutputNotFound { vout })
+}
+
+/// Returns the x-only public key of a taproot output script
+fn taproot_output_key(script_pubkey: &Script) -> Result<XOnlyPublicKey, MercuryError> {
+
+    if !script_pubkey.is_v1_p2tr() {
+        return Err(MercuryError::InvalidTaprootOutput);
+    }
+
+    Ok(XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..])?)
+}
+
 pub fn verify_latest_backup_tx_pays_to_user_pubkey(transfer_msg: &TransferMsg, client_pubkey_share: &str, network: &str) -> Result<bool, MercuryError> {
 
-    let client_pubkey_share = PublicKey::from_str(&client_pubkey_share)?;
+    let client_pubkey_share = PublicKey::from_str(client_pubkey_share)?;
     
-    let network = get_network(&network)?;
+    let network = get_network(network)?;
 
     let last_bkp_tx = transfer_msg.backup_transactions.last();
 
@@ -238,7 +336,7 @@ pub fn verify_latest_backup_tx_pays_to_user_pubkey(transfer_msg: &TransferMsg, c
 
     let last_tx: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&last_bkp_tx.tx)?)?;
 
-    let output = &last_tx.output[0];
+    let output = get_output(&last_tx, 0)?;
 
     let aggregate_address = Address::p2tr(&Secp256k1::new(), client_pubkey_share.x_only_public_key().0, None, network);
 
@@ -248,19 +346,70 @@ pub fn verify_latest_backup_tx_pays_to_user_pubkey(transfer_msg: &TransferMsg, c
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn get_output_address_from_tx0(tx0_outpoint: &TxOutpoint, tx0_hex: &str, network: &str) -> Result<String, MercuryError> {
 
-    let network = get_network(&network)?;
+    let network = get_network(network)?;
 
-    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
+    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx0_hex)?)?;
 
-    let tx0_output = tx0.output[tx0_outpoint.vout as usize].clone();
+    let tx0_output = get_output(&tx0, tx0_outpoint.vout)?;
 
     let output_script_pubkey = tx0_output.script_pubkey;
 
-    let address = Address::from_script(&output_script_pubkey.as_script(), network)?;
+    let address = Address::from_script(output_script_pubkey.as_script(), network)?;
 
     Ok(address.to_string())
 }
 
+/// Checks that the transfer message carries the complete backup chain signed by the server: one backup per signature,
+/// numbered from 1 in order, all spending the same Tx0 outpoint, with the latest one having the lowest locktime.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_backup_chain(backup_transactions: &Vec<BackupTx>, statechain_info: &StatechainInfoResponsePayload) -> Result<(), MercuryError> {
+
+    if backup_transactions.len() as u32 != statechain_info.num_sigs || statechain_info.statechain_info.len() != backup_transactions.len() {
+        return Err(MercuryError::BackupCountMismatch { backups: backup_transactions.len() as u32, num_sigs: statechain_info.num_sigs });
+    }
+
+    let latest_backup_tx = backup_transactions.last().ok_or(MercuryError::NoBackupTransactionFound)?;
+
+    let mut tx_ns = HashSet::new();
+
+    for backup_tx in backup_transactions {
+        if !tx_ns.insert(backup_tx.tx_n) {
+            return Err(MercuryError::DuplicateBackupTxN { tx_n: backup_tx.tx_n });
+        }
+    }
+
+    let tx0_outpoint = get_tx0_outpoint(backup_transactions)?;
+    let latest_locktime = crate::utils::get_blockheight(latest_backup_tx)?;
+
+    for (index, (backup_tx, info)) in backup_transactions.iter().zip(statechain_info.statechain_info.iter()).enumerate() {
+
+        let expected = index as u32 + 1;
+
+        if backup_tx.tx_n != expected {
+            return Err(MercuryError::NonContiguousBackupTxN { expected, found: backup_tx.tx_n });
+        }
+
+        if info.tx_n != backup_tx.tx_n {
+            return Err(MercuryError::StatechainInfoMismatch { tx_n: backup_tx.tx_n, statechain_tx_n: info.tx_n });
+        }
+
+        let tx: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&backup_tx.tx)?)?;
+        let input = tx.input.first().ok_or(MercuryError::TransactionHasNoInputs)?;
+
+        if input.previous_output.txid.to_string() != tx0_outpoint.txid || input.previous_output.vout != tx0_outpoint.vout {
+            return Err(MercuryError::BackupSpendsDifferentOutpoint { tx_n: backup_tx.tx_n });
+        }
+
+        let locktime = crate::utils::get_blockheight(backup_tx)?;
+
+        if backup_tx.tx_n != latest_backup_tx.tx_n && locktime <= latest_locktime {
+            return Err(MercuryError::LatestBackupLocktimeNotLowest { latest_locktime, tx_n: backup_tx.tx_n, locktime });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn validate_signature_scheme(
     transfer_msg: &TransferMsg, 
     statechain_info: &StatechainInfoResponsePayload, 
@@ -269,43 +418,32 @@ pub fn validate_signature_scheme(
     current_fee_rate_sats_per_byte: f64,
     interval: u32) -> Result<u32, MercuryError> {
 
-    let mut previous_lock_time: Option<u32> = None;
+    verify_backup_chain(&transfer_msg.backup_transactions, statechain_info)?;
 
-    let mut sig_scheme_validation = true;
+    let mut previous_lock_time: Option<u32> = None;
 
     for (index, backup_tx) in transfer_msg.backup_transactions.iter().enumerate() {
 
-        let statechain_info = statechain_info.statechain_info.get(index).unwrap();
+        let validation_error = |reason: String| MercuryError::SignatureSchemeValidationError { tx_n: backup_tx.tx_n, reason };
 
-        let is_signature_valid = verify_transaction_signature(&backup_tx.tx, &tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte);
-        if is_signature_valid.is_err() {
-            println!("{}", is_signature_valid.err().unwrap().to_string());
-            sig_scheme_validation = false;
-            break;
-        }
+        let statechain_info = statechain_info.statechain_info.get(index)
+            .ok_or_else(|| validation_error("no statechain info for the backup transaction".to_string()))?;
 
-        let is_blinded_musig_scheme_valid = verify_blinded_musig_scheme(&backup_tx, &tx0_hex, statechain_info);
-        if is_blinded_musig_scheme_valid.is_err() {
-            println!("{}", is_blinded_musig_scheme_valid.err().unwrap().to_string());
-            sig_scheme_validation = false;
-            break;
-        }
+        verify_backup_tx_signature(Some(backup_tx.tx_n), &backup_tx.tx, tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte)?;
+
+        verify_blinded_musig_scheme(backup_tx, tx0_hex, statechain_info)
+            .map_err(|e| validation_error(e.to_string()))?;
 
-        if previous_lock_time.is_some() {
-            let prev_lock_time = previous_lock_time.unwrap();
-            let current_lock_time = crate::utils::get_blockheight(&backup_tx)?;
-            if (prev_lock_time - current_lock_time) as i32 != interval as i32 {
-                println!("interval is not correct");
-                sig_scheme_validation = false;
-                break;
+        let current_lock_time = crate::utils::get_blockheight(backup_tx)?;
+
+        if let Some(prev_lock_time) = previous_lock_time {
+            let lock_time_interval = prev_lock_time as i64 - current_lock_time as i64;
+            if lock_time_interval != interval as i64 {
+                return Err(validation_error(format!("locktime decreased by {} blocks, expected {}", lock_time_interval, interval)));
             }
         }
 
-        previous_lock_time = Some(crate::utils::get_blockheight(&backup_tx)?);
-    }
-
-    if !sig_scheme_validation {
-        return Err(MercuryError::SignatureSchemeValidationError);
+        previous_lock_time = Some(current_lock_time);
     }
 
     if previous_lock_time.is_none() {
@@ -315,29 +453,28 @@ pub fn validate_signature_scheme(
     Ok(previous_lock_time.unwrap())
 }
 
-#[cfg_attr(feature = "bindings", uniffi::export)]
-pub fn verify_transaction_signature(tx_n_hex: &str, tx0_hex: &str, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+/// Returns the fee rate of the backup transaction and whether its signature is valid for the tx0 output it spends
+fn backup_tx_fee_rate_and_signature(tx_n_hex: &str, tx0_hex: &str) -> Result<(f64, bool), MercuryError> {
 
-    let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx_n_hex)?)?;
+    let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx_n_hex)?)?;
 
-    let witness = tx_n.input[0].witness.clone();
+    let tx_n_input = tx_n.input.first().ok_or(MercuryError::TransactionHasNoInputs)?;
+    let tx_n_output = tx_n.output.first().ok_or(MercuryError::TransactionHasNoOutputs)?;
 
-    let witness_data = witness.nth(0).unwrap();
+    let witness_data = tx_n_input.witness.nth(0).ok_or(MercuryError::EmptyWitness)?;
 
     // the last element is the hash type
-    let signature_data = witness_data.split_last().unwrap().1;
-
-    let signature = Signature::from_slice(signature_data).unwrap();
+    let (sighash_byte, signature_data) = witness_data.split_last().ok_or(MercuryError::EmptyWitnessData)?;
 
-    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
+    let signature = Signature::from_slice(signature_data)?;
 
-    let vout = tx_n.input[0].previous_output.vout as usize;
+    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx0_hex)?)?;
 
-    let tx0_output = tx0.output[vout].clone();
+    let tx0_output = get_output(&tx0, tx_n_input.previous_output.vout)?;
 
-    let xonly_pubkey = XOnlyPublicKey::from_slice(tx0_output.script_pubkey[2..].as_bytes()).unwrap();
+    let xonly_pubkey = taproot_output_key(&tx0_output.script_pubkey)?;
 
-    let sighash_type = TapSighashType::from_consensus_u8(witness_data.last().unwrap().to_owned()).unwrap();
+    let sighash_type = TapSighashType::from_consensus_u8(*sighash_byte)?;
 
     let hash = SighashCache::new(tx_n.clone()).taproot_key_spend_signature_hash(
         0,
@@ -346,49 +483,193 @@ pub fn verify_transaction_signature(tx_n_hex: &str, tx0_hex: &str, fee_rate_tole
             script_pubkey: tx0_output.script_pubkey.clone(),
         }]),
         sighash_type,
-    ).unwrap();
+    )?;
 
     let msg: Message = hash.into();
 
-    let fee = tx0_output.value - tx_n.output[0].value;
+    let fee = tx0_output.value.checked_sub(tx_n_output.value)
+        .ok_or(MercuryError::OutputExceedsInput { input: tx0_output.value, output: tx_n_output.value })?;
     let fee_rate = fee as f64 / tx_n.vsize() as f64;
 
+    let signature_valid = Secp256k1::new().verify_schnorr(&signature, &msg, &xonly_pubkey).is_ok();
+
+    Ok((fee_rate, signature_valid))
+}
+
+fn check_fee_rate(tx_n: Option<u32>, fee_rate: f64, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+
     if (fee_rate + fee_rate_tolerance) < current_fee_rate_sats_per_byte {
-        return Err(MercuryError::FeeTooLow);
+        return Err(MercuryError::FeeTooLow { tx_n, fee_rate, expected_fee_rate: current_fee_rate_sats_per_byte, tolerance: fee_rate_tolerance });
     }
 
     if (fee_rate - fee_rate_tolerance) > current_fee_rate_sats_per_byte {
-        return Err(MercuryError::FeeTooHigh);
+        return Err(MercuryError::FeeTooHigh { tx_n, fee_rate, expected_fee_rate: current_fee_rate_sats_per_byte, tolerance: fee_rate_tolerance });
     }
 
-    if !Secp256k1::new().verify_schnorr(&signature, &msg, &xonly_pubkey).is_ok() {
-        return Err(MercuryError::InvalidSignature);
+    Ok(())
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_transaction_signature(tx_n_hex: &str, tx0_hex: &str, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+    verify_backup_tx_signature(None, tx_n_hex, tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte)
+}
+
+/// The fee errors carry `tx_n` when the transaction is checked as part of a transfer message
+fn verify_backup_tx_signature(tx_n: Option<u32>, tx_n_hex: &str, tx0_hex: &str, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+
+    let (fee_rate, signature_valid) = backup_tx_fee_rate_and_signature(tx_n_hex, tx0_hex)?;
+
+    check_fee_rate(tx_n, fee_rate, fee_rate_tolerance, current_fee_rate_sats_per_byte)?;
+
+    if !signature_valid {
+        let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx_n_hex)?)?;
+        return Err(MercuryError::InvalidSignature { reason: format!("signature of transaction {} does not match the key of its input", tx_n.txid()) });
     }
 
     Ok(())
 
 }
 
+/// Result of the checks of a single backup transaction
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BackupTxReport {
+    pub tx_n: u32,
+    pub locktime: Option<u32>,
+    pub fee_rate: Option<f64>,
+    pub fee_rate_valid: bool,
+    pub output_address: Option<String>,
+    pub signature_valid: bool,
+    pub blinded_musig_valid: bool,
+    /// The locktime is `interval` blocks lower than the previous backup transaction (always true for the first one)
+    pub interval_valid: bool,
+    /// The output pays to the public key of the sender of the transfer message. It is reported for the UI and is not
+    /// part of `BackupChainReport::valid`, as the latest backup transaction pays the receiver.
+    pub pays_sender: bool,
+    pub errors: Vec<String>,
+}
 
-fn get_tx_hash(tx_0: &Transaction, tx_n: &Transaction) -> Result<Message, MercuryError> {
-
-    let witness = tx_n.input[0].witness.clone();
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BackupChainReport {
+    /// The chain is complete and all the checks passed for every backup transaction
+    pub valid: bool,
+    /// Why the chain is incomplete, see `verify_backup_chain`
+    pub chain_error: Option<String>,
+    pub backup_txs: Vec<BackupTxReport>,
+    /// Locktime of the latest backup transaction, None if it cannot be read
+    pub previous_lock_time: Option<u32>,
+}
 
-    if witness.nth(0).is_none() {
-        return Err(MercuryError::EmptyWitness);
+impl BackupTxReport {
+    fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.fee_rate_valid && self.signature_valid && self.blinded_musig_valid && self.interval_valid
     }
+}
+
+/// Runs the same checks as `validate_signature_scheme` on every backup transaction, reporting each result instead of
+/// stopping at the first failure.
+pub fn validate_backup_chain(
+    transfer_msg: &TransferMsg,
+    statechain_info: &StatechainInfoResponsePayload,
+    tx0_hex: &str,
+    fee_rate_tolerance: f64,
+    current_fee_rate_sats_per_byte: f64,
+    interval: u32,
+    network: &str) -> Result<BackupChainReport, MercuryError> {
+
+    let network = get_network(network)?;
+
+    let sender_public_key = PublicKey::from_str(&transfer_msg.user_public_key)?;
+    let sender_script_pubkey = Address::p2tr(&Secp256k1::new(), sender_public_key.x_only_public_key().0, None, network).script_pubkey();
+
+    let mut backup_txs = Vec::new();
+    let mut previous_lock_time: Option<u32> = None;
+
+    for (index, backup_tx) in transfer_msg.backup_transactions.iter().enumerate() {
+
+        let mut report = BackupTxReport {
+            tx_n: backup_tx.tx_n,
+            locktime: None,
+            fee_rate: None,
+            fee_rate_valid: false,
+            output_address: None,
+            signature_valid: false,
+            blinded_musig_valid: false,
+            interval_valid: false,
+            pays_sender: false,
+            errors: Vec::new(),
+        };
+
+        match backup_tx_fee_rate_and_signature(&backup_tx.tx, tx0_hex) {
+            Ok((fee_rate, signature_valid)) => {
+                report.fee_rate = Some(fee_rate);
+                report.signature_valid = signature_valid;
+                match check_fee_rate(None, fee_rate, fee_rate_tolerance, current_fee_rate_sats_per_byte) {
+                    Ok(()) => report.fee_rate_valid = true,
+                    Err(e) => report.errors.push(e.to_string()),
+                }
+            },
+            Err(e) => report.errors.push(e.to_string()),
+        }
+
+        match statechain_info.statechain_info.get(index) {
+            Some(statechain_info) => match verify_blinded_musig_scheme(backup_tx, tx0_hex, statechain_info) {
+                Ok(()) => report.blinded_musig_valid = true,
+                Err(e) => report.errors.push(e.to_string()),
+            },
+            None => report.errors.push("no statechain info for the backup transaction".to_string()),
+        }
+
+        let output = hex::decode(&backup_tx.tx).map_err(MercuryError::from)
+            .and_then(|tx| Ok(bitcoin::consensus::encode::deserialize::<Transaction>(&tx)?))
+            .and_then(|tx| get_output(&tx, 0));
 
-    let witness_data = witness.nth(0).unwrap();
+        match output {
+            Ok(output) => {
+                report.pays_sender = output.script_pubkey == sender_script_pubkey;
+                report.output_address = Address::from_script(&output.script_pubkey, network).ok().map(|address| address.to_string());
+            },
+            Err(e) => report.errors.push(e.to_string()),
+        }
 
-    let vout = tx_n.input[0].previous_output.vout as usize;
+        let lock_time = crate::utils::get_blockheight(backup_tx);
+
+        match &lock_time {
+            Ok(current_lock_time) => {
+                report.locktime = Some(*current_lock_time);
+                report.interval_valid = match previous_lock_time {
+                    Some(prev_lock_time) => prev_lock_time as i64 - *current_lock_time as i64 == interval as i64,
+                    // the interval cannot be checked if the locktime of the previous backup transaction is unknown
+                    None => index == 0,
+                };
+            },
+            Err(e) => report.errors.push(e.to_string()),
+        }
 
-    let tx_0_output = tx_0.output[vout].clone();
+        previous_lock_time = lock_time.ok();
 
-    if witness_data.last().is_none() {
-        return Err(MercuryError::EmptyWitnessData);
+        backup_txs.push(report);
     }
 
-    let sighash_type = TapSighashType::from_consensus_u8(witness_data.last().unwrap().to_owned())?;
+    let chain_error = verify_backup_chain(&transfer_msg.backup_transactions, statechain_info).err().map(|e| e.to_string());
+
+    let valid = chain_error.is_none() && backup_txs.iter().all(BackupTxReport::is_valid);
+
+    Ok(BackupChainReport { valid, chain_error, backup_txs, previous_lock_time })
+}
+
+fn get_tx_hash(tx_0: &Transaction, tx_n: &Transaction) -> Result<Message, MercuryError> {
+
+    let tx_n_input = tx_n.input.first().ok_or(MercuryError::TransactionHasNoInputs)?;
+
+    let witness_data = tx_n_input.witness.nth(0).ok_or(MercuryError::EmptyWitness)?;
+
+    let tx_0_output = get_output(tx_0, tx_n_input.previous_output.vout)?;
+
+    let sighash_byte = witness_data.last().ok_or(MercuryError::EmptyWitnessData)?;
+
+    let sighash_type = TapSighashType::from_consensus_u8(*sighash_byte)?;
 
     let hash = SighashCache::new(tx_n).taproot_key_spend_signature_hash(
         0,
@@ -430,7 +711,7 @@ pub fn verify_blinded_musig_scheme(backup_tx: &BackupTx, tx0_hex: &str, statecha
     
     let aggnonce = MusigAggNonce::new(&secp, &[client_public_nonce, server_public_nonce]);
 
-    let tx_0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
+    let tx_0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx0_hex)?)?;
 
     let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&backup_tx.tx)?)?;
 
@@ -462,23 +743,25 @@ fn validate_t1pub(t1: &[u8; 32], x1_pub: &PublicKey, sender_public_key: &PublicK
     let secret_t1 = SecretKey::from_slice(t1)?;
     let public_t1 = secret_t1.public_key(&Secp256k1::new());
 
-    let result_pubkey = sender_public_key.combine(&x1_pub)?;
+    let result_pubkey = sender_public_key.combine(x1_pub)?;
 
     Ok(result_pubkey == public_t1)
 }
 
-fn calculate_t2(transfer_msg: &TransferMsg, client_seckey_share: &SecretKey,) -> Result<SecretKey, MercuryError> {
+fn calculate_t2(transfer_msg: &TransferMsg, coin: &Coin, signer: &dyn Signer) -> Result<SecretKey, MercuryError> {
 
     let t1 = Scalar::from_be_bytes(transfer_msg.t1)?;
 
-    let negated_seckey = client_seckey_share.negate();
-
-    let t2 = negated_seckey.add_tweak(&t1)?;
+    let t2 = signer.user_key_tweak_add(coin, &t1, true)?;
 
     Ok(t2)
 }
 
 pub fn create_transfer_receiver_request_payload(statechain_info: &StatechainInfoResponsePayload, transfer_msg: &TransferMsg, coin: &Coin) -> Result<TransferReceiverRequestPayload, MercuryError> {
+    create_transfer_receiver_request_payload_with_signer(statechain_info, transfer_msg, coin, &InMemorySigner)
+}
+
+pub fn create_transfer_receiver_request_payload_with_signer(statechain_info: &StatechainInfoResponsePayload, transfer_msg: &TransferMsg, coin: &Coin, signer: &dyn Signer) -> Result<TransferReceiverRequestPayload, MercuryError> {
 
     if statechain_info.x1_pub.is_none() {
         return Err(MercuryError::NoX1Pub);
@@ -490,23 +773,16 @@ pub fn create_transfer_receiver_request_payload(statechain_info: &StatechainInfo
 
     let sender_public_key = PublicKey::from_str(&transfer_msg.user_public_key)?;
 
-    let client_seckey_share = PrivateKey::from_wif(&coin.user_privkey)?.inner;
-
-    let client_auth_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
-
     if !validate_t1pub(&transfer_msg.t1, &x1_pub, &sender_public_key)? {
         return Err(MercuryError::InvalidT1);
     }
 
-    let t2 = calculate_t2(&transfer_msg, &client_seckey_share)?;
+    let t2 = calculate_t2(transfer_msg, coin, signer)?;
 
     let t2_hex = hex::encode(t2.secret_bytes());
 
-    let secp = Secp256k1::new();
-
-    let client_auth_keypair = KeyPair::from_seckey_slice(&secp, client_auth_key.as_ref())?;
     let msg = Message::from_hashed_data::<sha256::Hash>(t2_hex.as_bytes());
-    let auth_sig = secp.sign_schnorr(&msg, &client_auth_keypair);
+    let auth_sig = signer.sign_schnorr(coin, CoinKey::Auth, &msg)?;
 
     let transfer_receiver_request_payload = TransferReceiverRequestPayload {
         statechain_id: transfer_msg.statechain_id.clone(),
@@ -521,24 +797,85 @@ pub fn create_transfer_receiver_request_payload(statechain_info: &StatechainInfo
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn sign_message(message: &str, coin: &Coin) -> Result<String, MercuryError> {
+    sign_message_with_signer(message, coin, &InMemorySigner)
+}
 
-    let client_auth_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
-
-    let secp = Secp256k1::new();
+pub fn sign_message_with_signer(message: &str, coin: &Coin, signer: &dyn Signer) -> Result<String, MercuryError> {
 
-    let client_auth_keypair = KeyPair::from_seckey_slice(&secp, client_auth_key.as_ref())?;
     let hashed_msg = Message::from_hashed_data::<sha256::Hash>(message.to_string().as_bytes());
-    let signed_message = secp.sign_schnorr(&hashed_msg, &client_auth_keypair);
+    let signed_message = signer.sign_schnorr(coin, CoinKey::Auth, &hashed_msg)?;
 
     Ok(signed_message.to_string())
 }
 
+/// Message signed by an auth key to answer a challenge issued by the server
+pub fn auth_challenge_message(auth_pub_key: &str, challenge: &str) -> String {
+    format!("{}:{}", auth_pub_key, challenge)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn sign_auth_challenge(challenge: &str, coin: &Coin) -> Result<String, MercuryError> {
+
+    let message = auth_challenge_message(&coin.auth_pubkey, challenge);
+
+    sign_message(&message, coin)
+}
+
+/// Proves the ownership of the auth key of the coin by signing a challenge issued by the server
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_auth_key_proof(coin: &Coin, challenge: &str) -> Result<AuthKeyProof, MercuryError> {
+
+    let auth_sig = sign_auth_challenge(challenge, coin)?;
+
+    Ok(AuthKeyProof {
+        auth_pub_key: coin.auth_pubkey.clone(),
+        auth_sig,
+    })
+}
+
+/// Creates one proof per unique auth key of the coins
+pub fn create_auth_key_proofs(coins: &Vec<Coin>, challenge: &str) -> Result<Vec<AuthKeyProof>, MercuryError> {
+
+    let mut auth_key_proofs = Vec::<AuthKeyProof>::new();
+
+    for coin in coins {
+        // duplicated coins share the same auth key
+        if auth_key_proofs.iter().any(|proof| proof.auth_pub_key == coin.auth_pubkey) {
+            continue;
+        }
+
+        auth_key_proofs.push(create_auth_key_proof(coin, challenge)?);
+    }
+
+    Ok(auth_key_proofs)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_transfer_notification_request_payload(coins: &Vec<Coin>, challenge: &str) -> Result<TransferNotificationRequestPayload, MercuryError> {
+
+    Ok(TransferNotificationRequestPayload {
+        challenge: challenge.to_string(),
+        auth_key_proofs: create_auth_key_proofs(coins, challenge)?,
+    })
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_get_msg_addr_bulk_request_payload(coins: &Vec<Coin>, challenge: &str) -> Result<GetMsgAddrBulkRequestPayload, MercuryError> {
+
+    Ok(GetMsgAddrBulkRequestPayload {
+        challenge: challenge.to_string(),
+        auth_key_proofs: create_auth_key_proofs(coins, challenge)?,
+    })
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn get_new_key_info(server_public_key_hex: &str, coin: &Coin, statechain_id: &str, tx0_outpoint: &TxOutpoint, tx0_hex: &str, network: &str) -> Result<NewKeyInfo, MercuryError> {
-    
-    let network = get_network(&network)?;
+    get_new_key_info_with_signer(server_public_key_hex, coin, statechain_id, tx0_outpoint, tx0_hex, network, &InMemorySigner)
+}
 
-    let client_auth_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
+pub fn get_new_key_info_with_signer(server_public_key_hex: &str, coin: &Coin, statechain_id: &str, tx0_outpoint: &TxOutpoint, tx0_hex: &str, network: &str, signer: &dyn Signer) -> Result<NewKeyInfo, MercuryError> {
+    
+    let network = get_network(network)?;
 
     let client_pubkey_share = PublicKey::from_str(&coin.user_pubkey)?;
 
@@ -552,13 +889,13 @@ pub fn get_new_key_info(server_public_key_hex: &str, coin: &Coin, statechain_id:
 
     let aggregate_address = Address::p2tr(&secp, aggregated_xonly_pubkey, None, network);
 
-    let xonly_pubkey = XOnlyPublicKey::from_slice(aggregate_address.script_pubkey()[2..].as_bytes()).unwrap();
+    let xonly_pubkey = taproot_output_key(&aggregate_address.script_pubkey())?;
 
-    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
+    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx0_hex)?)?;
 
-    let tx0_output = tx0.output[tx0_outpoint.vout as usize].clone();
+    let tx0_output = get_output(&tx0, tx0_outpoint.vout)?;
 
-    let tx0_output_xonly_pubkey = XOnlyPublicKey::from_slice(tx0_output.script_pubkey[2..].as_bytes()).unwrap();
+    let tx0_output_xonly_pubkey = taproot_output_key(&tx0_output.script_pubkey)?;
 
     if tx0_output_xonly_pubkey != xonly_pubkey {
         return Err(MercuryError::IncorrectAggregatedPublicKey);
@@ -566,9 +903,8 @@ pub fn get_new_key_info(server_public_key_hex: &str, coin: &Coin, statechain_id:
 
     let p2tr_agg_address = Address::p2tr(&secp, aggregated_xonly_pubkey, None, network);
 
-    let client_auth_keypair = KeyPair::from_seckey_slice(&secp, client_auth_key.as_ref())?;
     let msg = Message::from_hashed_data::<sha256::Hash>(statechain_id.to_string().as_bytes());
-    let signed_statechain_id = secp.sign_schnorr(&msg, &client_auth_keypair);
+    let signed_statechain_id = signer.sign_schnorr(coin, CoinKey::Auth, &msg)?;
 
     Ok(NewKeyInfo {
         aggregate_pubkey: aggregate_pubkey.to_string(),
@@ -576,4 +912,182 @@ pub fn get_new_key_info(server_public_key_hex: &str, coin: &Coin, statechain_id:
         signed_statechain_id: signed_statechain_id.to_string(),
         amount: tx0_output.value as u32,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{absolute::LockTime, consensus::encode::serialize_hex, Network, OutPoint, ScriptBuf, Sequence, TxIn, Witness};
+
+    use super::*;
+
+    const SENDER_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const OTHER_PUBKEY: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+    const TX0_TXID: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+
+    fn pays_to(public_key: &str) -> ScriptBuf {
+        let public_key = PublicKey::from_str(public_key).unwrap();
+        Address::p2tr(&Secp256k1::new(), public_key.x_only_public_key().0, None, Network::Regtest).script_pubkey()
+    }
+
+    /// Unsigned backup transaction spending the Tx0 output, the signature checks fail but are reported
+    fn backup_tx(tx_n: u32, lock_time: u32, script_pubkey: ScriptBuf) -> BackupTx {
+
+        let tx = Transaction {
+            version: 2,
+            lock_time: LockTime::from_consensus(lock_time),
+            input: vec![TxIn {
+                previous_output: OutPoint { txid: Txid::from_str(TX0_TXID).unwrap(), vout: 0 },
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ENABLE_LOCKTIME_NO_RBF,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut { value: 9000, script_pubkey }],
+        };
+
+        BackupTx {
+            tx_n,
+            tx: serialize_hex(&tx),
+            client_public_nonce: String::new(),
+            server_public_nonce: String::new(),
+            client_public_key: String::new(),
+            server_public_key: String::new(),
+            blinding_factor: String::new(),
+        }
+    }
+
+    fn transfer_msg(backup_transactions: Vec<BackupTx>) -> TransferMsg {
+        TransferMsg {
+            statechain_id: String::from("statechain"),
+            transfer_signature: String::new(),
+            backup_transactions,
+            t1: [0u8; 32],
+            user_public_key: SENDER_PUBKEY.to_string(),
+        }
+    }
+
+    fn statechain_info(num_sigs: u32) -> StatechainInfoResponsePayload {
+        StatechainInfoResponsePayload {
+            enclave_public_key: String::new(),
+            num_sigs,
+            statechain_info: (1..=num_sigs).map(|tx_n| StatechainInfo {
+                statechain_id: String::from("statechain"),
+                server_pubnonce: String::new(),
+                challenge: String::new(),
+                tx_n,
+            }).collect(),
+            x1_pub: None,
+        }
+    }
+
+    fn report(backup_transactions: Vec<BackupTx>, interval: u32) -> BackupChainReport {
+        let num_sigs = backup_transactions.len() as u32;
+        validate_backup_chain(&transfer_msg(backup_transactions), &statechain_info(num_sigs), "", 1.0, 1.0, interval, "regtest").unwrap()
+    }
+
+    /// Locktimes of 500000000 and above are timestamps, which `get_blockheight` rejects
+    const TIMESTAMP_LOCKTIME: u32 = 1_700_000_000;
+
+    #[test]
+    fn test_report_intervals() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+
+        let report = report(vec![backup_tx(1, 1000, sender.clone()), backup_tx(2, 990, sender.clone()), backup_tx(3, 985, sender)], 10);
+
+        let intervals: Vec<bool> = report.backup_txs.iter().map(|backup_tx| backup_tx.interval_valid).collect();
+        assert_eq!(intervals, vec![true, true, false]);
+        assert_eq!(report.previous_lock_time, Some(985));
+        assert!(!report.valid);
+    }
+
+    #[test]
+    fn test_report_interval_after_unreadable_locktime() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+
+        // 1000 - 990 matches the interval, but the backup transaction in between has no block height
+        let report = report(vec![backup_tx(1, 1000, sender.clone()), backup_tx(2, TIMESTAMP_LOCKTIME, sender.clone()), backup_tx(3, 990, sender)], 10);
+
+        assert!(report.backup_txs[0].interval_valid);
+
+        assert_eq!(report.backup_txs[1].locktime, None);
+        assert!(!report.backup_txs[1].interval_valid);
+        assert!(report.backup_txs[1].errors.contains(&MercuryError::LocktimeNotBlockHeightError.to_string()));
+
+        assert_eq!(report.backup_txs[2].locktime, Some(990));
+        assert!(!report.backup_txs[2].interval_valid);
+
+        assert_eq!(report.previous_lock_time, Some(990));
+        assert!(!report.valid);
+    }
+
+    #[test]
+    fn test_report_unreadable_latest_locktime() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+
+        let report = report(vec![backup_tx(1, 1000, sender.clone()), backup_tx(2, 990, sender.clone()), backup_tx(3, TIMESTAMP_LOCKTIME, sender)], 10);
+
+        assert!(report.backup_txs[1].interval_valid);
+        assert_eq!(report.previous_lock_time, None);
+        assert!(report.chain_error.is_some());
+        assert!(!report.valid);
+    }
+
+    #[test]
+    fn test_verify_backup_chain() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+
+        let backup_transactions = vec![backup_tx(1, 1000, sender.clone()), backup_tx(2, 990, sender.clone()), backup_tx(3, 980, sender)];
+
+        assert!(verify_backup_chain(&backup_transactions, &statechain_info(3)).is_ok());
+    }
+
+    #[test]
+    fn test_verify_backup_chain_gap() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+
+        let backup_transactions = vec![backup_tx(1, 1000, sender.clone()), backup_tx(3, 990, sender)];
+
+        let result = verify_backup_chain(&backup_transactions, &statechain_info(2));
+
+        assert!(matches!(result, Err(MercuryError::NonContiguousBackupTxN { expected: 2, found: 3 })));
+    }
+
+    #[test]
+    fn test_verify_backup_chain_latest_locktime_not_lowest() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+
+        let backup_transactions = vec![backup_tx(1, 1000, sender.clone()), backup_tx(2, 990, sender.clone()), backup_tx(3, 990, sender)];
+
+        let result = verify_backup_chain(&backup_transactions, &statechain_info(3));
+
+        assert!(matches!(result, Err(MercuryError::LatestBackupLocktimeNotLowest { latest_locktime: 990, tx_n: 2, locktime: 990 })));
+
+        let report = report(backup_transactions, 10);
+
+        assert!(report.chain_error.is_some());
+        assert!(!report.backup_txs[2].interval_valid);
+        assert!(!report.valid);
+    }
+
+    #[test]
+    fn test_report_final_output() {
+
+        let sender = pays_to(SENDER_PUBKEY);
+        let other = pays_to(OTHER_PUBKEY);
+
+        let report = report(vec![backup_tx(1, 1000, sender.clone()), backup_tx(2, 990, sender), backup_tx(3, 980, other.clone())], 10);
+
+        let pays_sender: Vec<bool> = report.backup_txs.iter().map(|backup_tx| backup_tx.pays_sender).collect();
+        assert_eq!(pays_sender, vec![true, true, false]);
+
+        let other_address = Address::from_script(&other, Network::Regtest).unwrap().to_string();
+        assert_eq!(report.backup_txs[2].output_address, Some(other_address));
+
+        assert!(report.chain_error.is_none());
+    }
+}
@@ -5,7 +5,7 @@ use secp256k1_zkp::{Secp256k1, Message, Scalar};
 use serde::{Serialize, Deserialize};
 use serde_json::json;
 
-use crate::{decode_transfer_address, error::MercuryError, wallet::{BackupTx, Coin}};
+use crate::{decode_transfer_address, error::MercuryError, signer::{CoinKey, InMemorySigner, Signer}, wallet::{BackupTx, Coin}};
 
 use super::TransferMsg;
 
@@ -15,12 +15,49 @@ pub struct PaymentHashRequestPayload {
     pub statechain_id: String,
     pub auth_sig: String, // signed_statechain_id
     pub batch_id: String,
+    pub expiry_secs: Option<u64>, // requested latch expiry, bounded by the server config
 }
 
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct PaymentHashResponsePayload {
     pub hash: String,
+    pub expires_at: String, // RFC 3339
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct LatchReclaimRequestPayload {
+    pub statechain_id: String,
+    pub auth_sig: String, // signed_statechain_id
+    pub batch_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct ConditionalLatchRequestPayload {
+    pub statechain_id: String,
+    pub auth_sig: String, // signed_statechain_id
+    pub batch_id: String,
+    pub oracle_pub_key: String, // x-only public key of the oracle
+    pub event_id: String, // event announced by the oracle, signed with the outcome
+    pub outcome: String, // outcome that releases the coin to the receiver
+    pub expiry_secs: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct ConditionalLatchResponsePayload {
+    pub expires_at: String, // RFC 3339
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct OracleAttestationRequestPayload {
+    pub statechain_id: String,
+    pub batch_id: String,
+    pub outcome: String,
+    pub signature: String, // oracle schnorr signature of the outcome
 }
 
 #[derive(Serialize, Deserialize)]
@@ -30,6 +67,8 @@ pub struct TransferSenderRequestPayload {
     pub auth_sig: String, // signed_statechain_id
     pub new_user_auth_key: String,
     pub batch_id: Option<String>,
+    #[serde(default)]
+    pub batch_size: Option<u32>, // number of coins of the batch, the batch is not unlocked until all of them are registered
 }
 
 #[derive(Serialize, Deserialize)]
@@ -47,6 +86,20 @@ pub struct TransferUpdateMsgRequestPayload {
     pub enc_transfer_msg: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct TransferCancelRequestPayload {
+    pub statechain_id: String,
+    pub auth_sig: String, // signed_statechain_id
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct TransferBatchCancelRequestPayload {
+    pub batch_id: String,
+    pub cancel_requests: Vec<TransferCancelRequestPayload>, // one per coin of the batch
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct TransferPreimageRequestPayload {
@@ -68,38 +121,58 @@ pub fn create_transfer_signature(recipient_address: &str, input_txid: &str, inpu
 
     // new_user_pubkey: PublicKey, input_txid: &Txid, input_vout: u32, client_seckey: &SecretKey
 
-    let (_, recipient_user_pubkey, _) = decode_transfer_address(recipient_address)?;
+    let msg = transfer_signature_message(recipient_address, input_txid, input_vout)?;
 
-    let input_txid = Txid::from_str(&input_txid)?;
     let client_seckey = PrivateKey::from_wif(client_seckey)?.inner;
 
     let secp = Secp256k1::new();
     let keypair = secp256k1::KeyPair::from_seckey_slice(&secp, client_seckey.as_ref()).unwrap();
 
+    let signature = secp.sign_schnorr(&msg, &keypair);
+
+    Ok(signature.to_string())
+}
+
+/// Same as `create_transfer_signature`, signing with the user key of the coin
+pub fn create_transfer_signature_with_signer(recipient_address: &str, input_txid: &str, input_vout: u32, coin: &Coin, signer: &dyn Signer) ->  Result<String, MercuryError> {
+
+    let msg = transfer_signature_message(recipient_address, input_txid, input_vout)?;
+
+    let signature = signer.sign_schnorr(coin, CoinKey::User, &msg)?;
+
+    Ok(signature.to_string())
+}
+
+fn transfer_signature_message(recipient_address: &str, input_txid: &str, input_vout: u32) -> Result<Message, MercuryError> {
+
+    let (_, recipient_user_pubkey, _) = decode_transfer_address(recipient_address)?;
+
+    let input_txid = Txid::from_str(input_txid)?;
+
     let mut data_to_sign = Vec::<u8>::new();
     data_to_sign.extend_from_slice(&input_txid[..]);
     data_to_sign.extend_from_slice(&input_vout.to_le_bytes());
     data_to_sign.extend_from_slice(&recipient_user_pubkey.serialize()[..]);
 
-    let msg = Message::from_hashed_data::<sha256::Hash>(&data_to_sign);
-    let signature = secp.sign_schnorr(&msg, &keypair);
-
-    Ok(signature.to_string())
+    Ok(Message::from_hashed_data::<sha256::Hash>(&data_to_sign))
 }
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn create_transfer_update_msg(x1: &str, recipient_address: &str, coin: &Coin, transfer_signature: &str, backup_transactions: &Vec<BackupTx>) -> Result<TransferUpdateMsgRequestPayload, MercuryError> {
+    create_transfer_update_msg_with_signer(x1, recipient_address, coin, transfer_signature, backup_transactions, &InMemorySigner)
+}
+
+pub fn create_transfer_update_msg_with_signer(x1: &str, recipient_address: &str, coin: &Coin, transfer_signature: &str, backup_transactions: &Vec<BackupTx>, signer: &dyn Signer) -> Result<TransferUpdateMsgRequestPayload, MercuryError> {
 
     let (_, _, recipient_auth_pubkey) = decode_transfer_address(recipient_address)?;  
 
-    let client_seckey = PrivateKey::from_wif(&coin.user_privkey)?.inner;
     let client_public_key = coin.user_pubkey.to_string();
 
     let x1 = hex::decode(x1)?;
     let x1: [u8; 32] = x1.try_into().unwrap();
     let x1 = Scalar::from_be_bytes(x1)?;
     
-    let t1 = client_seckey.add_tweak(&x1)?;
+    let t1 = signer.user_key_tweak_add(coin, &x1, false)?;
 
     let statechain_id = coin.statechain_id.as_ref().unwrap();
     let signed_statechain_id = coin.signed_statechain_id.as_ref().unwrap();
@@ -127,7 +200,7 @@ pub fn create_transfer_update_msg(x1: &str, recipient_address: &str, coin: &Coin
 
     let encrypted_msg = encrypted_msg.unwrap();
 
-    let encrypted_msg_string = hex::encode(&encrypted_msg);
+    let encrypted_msg_string = hex::encode(encrypted_msg);
 
     let transfer_update_msg_request_payload = TransferUpdateMsgRequestPayload {
         statechain_id: statechain_id.to_string(),
@@ -139,3 +212,42 @@ pub fn create_transfer_update_msg(x1: &str, recipient_address: &str, coin: &Coin
     Ok(transfer_update_msg_request_payload)
 }
  
+
+/// The event id is part of the message so that an attestation cannot be replayed for another event with the same outcome.
+/// It is length prefixed so that no (event id, outcome) pair has the same message as another one.
+fn oracle_attestation_message(event_id: &str, outcome: &str) -> Message {
+    let message = format!("{}:{}:{}", event_id.len(), event_id, outcome);
+    Message::from_hashed_data::<sha256::Hash>(message.as_bytes())
+}
+
+/// Signs the outcome of an event with the oracle key (hex). Used by oracles run locally.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn sign_oracle_attestation(event_id: &str, outcome: &str, oracle_seckey: &str) -> Result<String, MercuryError> {
+
+    let oracle_seckey = hex::decode(oracle_seckey)?;
+
+    let secp = Secp256k1::new();
+    let keypair = secp256k1_zkp::KeyPair::from_seckey_slice(&secp, &oracle_seckey)?;
+
+    let signature = secp.sign_schnorr(&oracle_attestation_message(event_id, outcome), &keypair);
+
+    Ok(signature.to_string())
+}
+
+/// Checks that the oracle (x-only public key) attested the outcome of the event
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_oracle_attestation(oracle_pub_key: &str, event_id: &str, outcome: &str, signature: &str) -> bool {
+
+    let oracle_pub_key = match secp256k1_zkp::XOnlyPublicKey::from_str(oracle_pub_key) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+
+    let signature = match secp256k1_zkp::schnorr::Signature::from_str(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    let secp = Secp256k1::new();
+    secp.verify_schnorr(&signature, &oracle_attestation_message(event_id, outcome), &oracle_pub_key).is_ok()
+}
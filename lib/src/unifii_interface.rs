@@ -5,7 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{decode_transfer_address, transfer::{receiver::{StatechainInfoResponsePayload, TransferReceiverRequestPayload, TxOutpoint}, TransferMsg}, wallet::{BackupTx, Coin, Wallet}, MercuryError};
+use crate::{decode_transfer_address, transfer::{receiver::{BackupChainReport, StatechainInfoResponsePayload, TransferReceiverRequestPayload, TxOutpoint}, TransferMsg}, wallet::{BackupTx, Coin, Wallet}, MercuryError};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
@@ -30,6 +30,16 @@ pub fn get_new_coin(wallet: &Wallet) -> Result<Coin, MercuryError> {
     wallet.get_new_coin()
 }
 
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_new_coin_with_passphrase(wallet: &Wallet, passphrase: String) -> Result<Coin, MercuryError> {
+    wallet.get_new_coin_with_passphrase(&passphrase)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_master_fingerprint(wallet: &Wallet, passphrase: String) -> Result<String, MercuryError> {
+    wallet.get_master_fingerprint(&passphrase)
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn decode_statechain_address(sc_address: String) -> Result<DecodedSCAddress, MercuryError> {
     
@@ -107,5 +117,19 @@ pub fn ffi_validate_signature_scheme(
     interval: u32) -> Result<u32, MercuryError> {
 
     let transfer_msg = ffi_to_transfer_msg(ffi_transfer_msg.clone())?;
-    crate::transfer::receiver::validate_signature_scheme(&transfer_msg, &statechain_info, tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte, interval)
+    crate::transfer::receiver::validate_signature_scheme(&transfer_msg, statechain_info, tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte, interval)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn ffi_validate_backup_chain(
+    ffi_transfer_msg: &FFITransferMsg, 
+    statechain_info: &StatechainInfoResponsePayload, 
+    tx0_hex: &str, 
+    fee_rate_tolerance: f64, 
+    current_fee_rate_sats_per_byte: f64,
+    interval: u32,
+    network: &str) -> Result<BackupChainReport, MercuryError> {
+
+    let transfer_msg = ffi_to_transfer_msg(ffi_transfer_msg.clone())?;
+    crate::transfer::receiver::validate_backup_chain(&transfer_msg, statechain_info, tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte, interval, network)
 }
@@ -76,5 +76,5 @@ pub fn is_enclave_pubkey_part_of_coin(coin: &Coin, enclave_pubkey: &str) -> Resu
 
     let coin_aggregated_pubkey = secp256k1_zkp::PublicKey::from_str(coin_aggregated_pubkey)?;
 
-    return Ok(aggregate_enclave_pubkey == coin_aggregated_pubkey);
+    Ok(aggregate_enclave_pubkey == coin_aggregated_pubkey)
 }
\ No newline at end of file
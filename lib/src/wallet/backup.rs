@@ -0,0 +1,201 @@
+//! Encrypted single file backup of a wallet, with its coins, activities and backup transactions.
+//!
+//! The file is a JSON object with a plain header (`format` and `version`) and the encrypted backup.
+//! The header is authenticated with the ciphertext, so it cannot be changed without failing the decryption.
+
+use std::collections::HashSet;
+
+use serde::{Serialize, Deserialize};
+
+use crate::MercuryError;
+
+use super::{encryption::{decrypt_with_password, encrypt_with_password, EncryptedSecrets}, StatechainBackupTxs, Wallet};
+
+pub const WALLET_BACKUP_FORMAT: &str = "mercury-wallet-backup";
+
+/// Version of the backup file format. Files of other versions are rejected instead of being partially imported.
+pub const WALLET_BACKUP_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct WalletBackup {
+    pub wallet: Wallet,
+    pub backup_txs: Vec<StatechainBackupTxs>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WalletBackupFile {
+    format: String,
+    version: u32,
+    encrypted_backup: EncryptedSecrets,
+}
+
+fn backup_file_header(version: u32) -> Vec<u8> {
+    format!("{}:{}", WALLET_BACKUP_FORMAT, version).into_bytes()
+}
+
+/// Checks that the backup transactions belong to coins of the wallet and that no statecoin appears twice
+fn verify_wallet_backup(wallet_backup: &WalletBackup) -> Result<(), MercuryError> {
+
+    let mut statechain_ids = HashSet::<&str>::new();
+
+    for statechain_backup_txs in &wallet_backup.backup_txs {
+
+        let statechain_id = statechain_backup_txs.statechain_id.as_str();
+
+        if !statechain_ids.insert(statechain_id) {
+            return Err(MercuryError::InvalidWalletBackup { reason: format!("duplicated backup transactions of statecoin {}", statechain_id) });
+        }
+
+        if !wallet_backup.wallet.coins.iter().any(|coin| coin.statechain_id.as_deref() == Some(statechain_id)) {
+            return Err(MercuryError::InvalidWalletBackup { reason: format!("backup transactions of statecoin {} do not belong to any coin", statechain_id) });
+        }
+    }
+
+    Ok(())
+}
+
+/// Encrypts the wallet and its backup transactions with the password, returning the content of the backup file
+#[allow(clippy::ptr_arg)]
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn export_wallet_backup(wallet: &Wallet, backup_txs: &Vec<StatechainBackupTxs>, password: &str) -> Result<String, MercuryError> {
+
+    if wallet.is_locked() {
+        return Err(MercuryError::WalletLocked);
+    }
+
+    let mut wallet_backup = WalletBackup {
+        wallet: wallet.clone(),
+        backup_txs: backup_txs.clone(),
+    };
+
+    // the backup is encrypted with its own password, the imported wallet is encrypted again when it is stored
+    wallet_backup.wallet.encrypted_secrets = None;
+
+    verify_wallet_backup(&wallet_backup)?;
+
+    let plaintext = serde_json::to_vec(&wallet_backup)?;
+
+    let encrypted_backup = encrypt_with_password(&plaintext, password, &backup_file_header(WALLET_BACKUP_VERSION))?;
+
+    let wallet_backup_file = WalletBackupFile {
+        format: WALLET_BACKUP_FORMAT.to_string(),
+        version: WALLET_BACKUP_VERSION,
+        encrypted_backup,
+    };
+
+    Ok(serde_json::to_string(&wallet_backup_file)?)
+}
+
+/// Decrypts and verifies the content of a backup file
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn import_wallet_backup(backup_file: &str, password: &str) -> Result<WalletBackup, MercuryError> {
+
+    let wallet_backup_file: WalletBackupFile = serde_json::from_str(backup_file)
+        .map_err(|_| MercuryError::InvalidWalletBackup { reason: "not a wallet backup file".to_string() })?;
+
+    if wallet_backup_file.format != WALLET_BACKUP_FORMAT {
+        return Err(MercuryError::InvalidWalletBackup { reason: format!("unknown format {}", wallet_backup_file.format) });
+    }
+
+    if wallet_backup_file.version != WALLET_BACKUP_VERSION {
+        return Err(MercuryError::UnsupportedWalletBackupVersion { version: wallet_backup_file.version, supported_version: WALLET_BACKUP_VERSION });
+    }
+
+    let plaintext = decrypt_with_password(&wallet_backup_file.encrypted_backup, password, &backup_file_header(wallet_backup_file.version))?;
+
+    let wallet_backup: WalletBackup = serde_json::from_slice(&plaintext)?;
+
+    verify_wallet_backup(&wallet_backup)?;
+
+    Ok(wallet_backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wallet::{tests::test_wallet, BackupTx};
+
+    use super::*;
+
+    fn test_backup_txs(statechain_id: &str) -> Vec<StatechainBackupTxs> {
+        vec![StatechainBackupTxs {
+            statechain_id: statechain_id.to_string(),
+            backup_txs: vec![BackupTx {
+                tx_n: 1,
+                tx: "tx".to_string(),
+                client_public_nonce: "client_public_nonce".to_string(),
+                server_public_nonce: "server_public_nonce".to_string(),
+                client_public_key: "client_public_key".to_string(),
+                server_public_key: "server_public_key".to_string(),
+                blinding_factor: "blinding_factor".to_string(),
+            }],
+        }]
+    }
+
+    fn test_wallet_with_statecoin() -> Wallet {
+        let mut wallet = test_wallet();
+        wallet.coins[0].statechain_id = Some("statechain_id".to_string());
+        wallet
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+
+        let wallet = test_wallet_with_statecoin();
+
+        let backup_file = export_wallet_backup(&wallet, &test_backup_txs("statechain_id"), "password").unwrap();
+
+        let wallet_backup = import_wallet_backup(&backup_file, "password").unwrap();
+
+        assert_eq!(wallet_backup.wallet.name, wallet.name);
+        assert_eq!(wallet_backup.wallet.mnemonic, wallet.mnemonic);
+        assert_eq!(wallet_backup.wallet.coins[0].user_privkey, wallet.coins[0].user_privkey);
+        assert_eq!(wallet_backup.backup_txs.len(), 1);
+        assert_eq!(wallet_backup.backup_txs[0].statechain_id, "statechain_id");
+        assert_eq!(wallet_backup.backup_txs[0].backup_txs[0].tx, "tx");
+    }
+
+    #[test]
+    fn test_export_does_not_keep_encrypted_secrets() {
+
+        let mut wallet = test_wallet_with_statecoin();
+        wallet.lock("wallet password").unwrap();
+        wallet.unlock("wallet password").unwrap();
+
+        let backup_file = export_wallet_backup(&wallet, &Vec::new(), "password").unwrap();
+
+        let wallet_backup = import_wallet_backup(&backup_file, "password").unwrap();
+
+        assert!(wallet_backup.wallet.encrypted_secrets.is_none());
+        assert_eq!(wallet_backup.wallet.mnemonic, wallet.mnemonic);
+    }
+
+    #[test]
+    fn test_import_with_wrong_password() {
+
+        let backup_file = export_wallet_backup(&test_wallet_with_statecoin(), &Vec::new(), "password").unwrap();
+
+        assert!(matches!(import_wallet_backup(&backup_file, "wrong password"), Err(MercuryError::IncorrectPassword)));
+    }
+
+    #[test]
+    fn test_import_unsupported_version() {
+
+        let backup_file = export_wallet_backup(&test_wallet_with_statecoin(), &Vec::new(), "password").unwrap();
+
+        let mut backup_json: serde_json::Value = serde_json::from_str(&backup_file).unwrap();
+        backup_json["version"] = serde_json::json!(WALLET_BACKUP_VERSION + 1);
+
+        let result = import_wallet_backup(&backup_json.to_string(), "password");
+
+        assert!(matches!(result, Err(MercuryError::UnsupportedWalletBackupVersion { version, .. }) if version == WALLET_BACKUP_VERSION + 1));
+    }
+
+    #[test]
+    fn test_export_rejects_backup_txs_of_unknown_statecoin() {
+
+        let result = export_wallet_backup(&test_wallet_with_statecoin(), &test_backup_txs("another_statechain_id"), "password");
+
+        assert!(matches!(result, Err(MercuryError::InvalidWalletBackup { .. })));
+    }
+}
@@ -1,11 +1,12 @@
-use std::{str::FromStr, collections::BTreeMap};
+use std::str::FromStr;
 
-use crate::{error::MercuryError, utils::get_network};
+use crate::{error::MercuryError, psbt::{finalize_key_spend_inputs, signed_vsize, user_key_origins}, signer::{InMemorySigner, Signer}, utils::get_network};
 
 use super::{BackupTx, Coin};
-use bitcoin::{Transaction, Address, TxOut, Txid, OutPoint, TxIn, ScriptBuf, Witness, absolute, psbt::{Psbt, Input, PsbtSighashType, self}, bip32::{Fingerprint, DerivationPath}, Amount, Network, sighash::{TapSighashType, SighashCache, self, TapSighash}, taproot::{TapLeafHash, self}, secp256k1, key::TapTweak, PrivateKey};
-use secp256k1_zkp::{Secp256k1, SecretKey, PublicKey, XOnlyPublicKey};
+use bitcoin::{Transaction, Address, TxOut, Txid, OutPoint, TxIn, ScriptBuf, Witness, absolute, psbt::{Psbt, Input, PsbtSighashType}, Amount, Network, sighash::{TapSighashType, SighashCache, self}, taproot};
+use secp256k1_zkp::PublicKey;
 
+#[allow(clippy::ptr_arg)]
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn latest_backup_tx_pays_to_user_pubkey(backup_txs: &Vec<BackupTx>, coin: &Coin, network: &str) -> Result<BackupTx, MercuryError> {
 
@@ -22,7 +23,7 @@ pub fn latest_backup_tx_pays_to_user_pubkey(backup_txs: &Vec<BackupTx>, coin: &C
                 return None;
             }
 
-            let output: &TxOut = tx.output.get(0)?;
+            let output: &TxOut = tx.output.first()?;
             if backup_address.script_pubkey() == output.script_pubkey {
                 Some(bkp_tx)
             } else {
@@ -33,19 +34,17 @@ pub fn latest_backup_tx_pays_to_user_pubkey(backup_txs: &Vec<BackupTx>, coin: &C
 
     match backup_tx {
         Some(tx) => {
-            return Ok(tx.clone());
+            Ok(tx.clone())
         },
         None => {
-            return Err(MercuryError::NoBackupTransactionFound);
+            Err(MercuryError::NoBackupTransactionFound)
         }
     }
 }
 
 
-#[cfg_attr(feature = "bindings", uniffi::export)]
-pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_rate_sats_per_byte: f64, network: &str) -> Result<String, MercuryError> {
-
-    let network = get_network(network)?;
+/// Unsigned PSBT spending the backup transaction output to `to_address`, with the fee deducted from the output
+fn create_cpfp_psbt_unsigned(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_rate_sats_per_byte: f64, network: Network) -> Result<Psbt, MercuryError> {
 
     let tx_bytes = hex::decode(&backup_tx.tx)?;
     let tx: Transaction = bitcoin::consensus::deserialize(&tx_bytes)?;
@@ -54,7 +53,7 @@ pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_r
         return Err(MercuryError::UnkownNetwork);
     }
 
-    let output: &TxOut = tx.output.get(0).unwrap();
+    let output: &TxOut = tx.output.first().unwrap();
 
     let backup_address = Address::from_str(coin.backup_address.as_str())?.require_network(network)?;
 
@@ -73,21 +72,35 @@ pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_r
         TxOut { value: input_amount, script_pubkey: to_address.script_pubkey() },
     ];
 
-    let tx = create_transaction(&input_tx_hash, input_vout, &coin, input_amount, &outputs, network)?;
+    let psbt = create_psbt(&input_tx_hash, input_vout, coin, input_amount, &outputs, network)?;
 
-    let absolute_fee: u64 = (tx.vsize() as f64 * fee_rate_sats_per_byte).ceil() as u64;
+    let absolute_fee: u64 = (signed_vsize(&psbt)? as f64 * fee_rate_sats_per_byte).ceil() as u64;
 
     let amount_out = input_amount as i64 - absolute_fee as i64;
 
     if amount_out < 0 {
-        return Err(MercuryError::FeeTooHigh);
+        return Err(MercuryError::FeeExceedsAmount { fee: absolute_fee, amount: input_amount });
     }
 
     let outputs = vec![
         TxOut { value: amount_out as u64, script_pubkey: to_address.script_pubkey() },
     ];
 
-    let tx = create_transaction(&input_tx_hash, input_vout, &coin, input_amount, &outputs, network)?;
+    create_psbt(&input_tx_hash, input_vout, coin, input_amount, &outputs, network)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_rate_sats_per_byte: f64, network: &str) -> Result<String, MercuryError> {
+    create_cpfp_tx_with_signer(backup_tx, coin, to_address, fee_rate_sats_per_byte, network, &InMemorySigner)
+}
+
+pub fn create_cpfp_tx_with_signer(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_rate_sats_per_byte: f64, network: &str, signer: &dyn Signer) -> Result<String, MercuryError> {
+
+    let network = get_network(network)?;
+
+    let psbt = create_cpfp_psbt_unsigned(backup_tx, coin, to_address, fee_rate_sats_per_byte, network)?;
+
+    let tx = sign_psbt(psbt, coin, signer)?;
 
     let tx_bytes = bitcoin::consensus::encode::serialize(&tx);
     let encoded_signed_tx = hex::encode(tx_bytes);
@@ -95,11 +108,20 @@ pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_r
     Ok(encoded_signed_tx)
 }
 
-fn create_transaction(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_amount: u64, outputs: &Vec<TxOut>, network: Network) -> Result<Transaction, MercuryError> {
+/// Exports the unsigned CPFP transaction as a base64 PSBT, to be signed with the user key and imported with `import_signed_psbt`
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_cpfp_psbt(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_rate_sats_per_byte: f64, network: &str) -> Result<String, MercuryError> {
+
+    let network = get_network(network)?;
+
+    let psbt = create_cpfp_psbt_unsigned(backup_tx, coin, to_address, fee_rate_sats_per_byte, network)?;
+
+    Ok(psbt.to_string())
+}
 
-    let secp = Secp256k1::new();
+fn create_psbt(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_amount: u64, outputs: &[TxOut], network: Network) -> Result<Psbt, MercuryError> {
 
-    let input_utxo = OutPoint { txid: input_tx_hash.clone(), vout: input_vout };
+    let input_utxo = OutPoint { txid: *input_tx_hash, vout: input_vout };
     let input = TxIn {
         previous_output: input_utxo,
         script_sig: ScriptBuf::new(),
@@ -111,24 +133,14 @@ fn create_transaction(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_
         version: 2,
         lock_time: absolute::LockTime::ZERO,
         input: [input].to_vec(),
-        output: outputs.clone(),
+        output: outputs.to_vec(),
     };
     let mut psbt = Psbt::from_unsigned_tx(tx1)?;
 
     let input_public_key = PublicKey::from_str(&coin.user_pubkey)?;
     let input_x_only_public_key = input_public_key.x_only_public_key().0;
 
-    let mut origins = BTreeMap::new();
-    origins.insert(
-        input_x_only_public_key,
-        (
-            vec![],
-            (
-                Fingerprint::from_str(&coin.fingerprint).unwrap(),
-                DerivationPath::from_str(&coin.derivation_path).unwrap(),
-            ),
-        ),
-    );
+    let origins = user_key_origins(coin, input_x_only_public_key)?;
 
     let mut psbt_inputs = Vec::<Input>::new();
 
@@ -140,7 +152,7 @@ fn create_transaction(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_
             let amount = Amount::from_sat(input_amount);
             Some(TxOut { value: amount.to_sat(), script_pubkey: input_script_pubkey.clone() })
         },
-        tap_key_origins: origins.clone(),
+        tap_key_origins: origins,
         ..Default::default()
     };
     let ty = PsbtSighashType::from_str("SIGHASH_ALL").unwrap();
@@ -151,14 +163,18 @@ fn create_transaction(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_
 
     psbt.inputs = psbt_inputs;
 
+    Ok(psbt)
+}
+
+fn sign_psbt(mut psbt: Psbt, coin: &Coin, signer: &dyn Signer) -> Result<Transaction, MercuryError> {
+
     // SIGNER
     let unsigned_tx = psbt.unsigned_tx.clone();
 
-    let mut input_txouts = Vec::<TxOut>::new();
-    input_txouts.push(TxOut { value: input_amount, script_pubkey: input_script_pubkey });
+    let input_txouts: Vec<TxOut> = psbt.inputs.iter().filter_map(|input| input.witness_utxo.clone()).collect();
 
     let vout = 0;
-    let input = psbt.inputs.iter_mut().nth(vout).unwrap();
+    let input = psbt.inputs.get_mut(vout).unwrap();
 
     let hash_ty = input
         .sighash_type
@@ -167,63 +183,18 @@ fn create_transaction(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_
 
     let hash = SighashCache::new(&unsigned_tx).taproot_key_spend_signature_hash(
         vout,
-        &sighash::Prevouts::All(&input_txouts.as_slice()),
+        &sighash::Prevouts::All(input_txouts.as_slice()),
         hash_ty,
     )?;
 
-    let secret_key = PrivateKey::from_wif(&coin.user_privkey)?.inner;
+    let sig = signer.sign_taproot_key_spend(coin, &hash.into())?;
 
-    sign_psbt_taproot(
-        &secret_key,
-        input.tap_internal_key.unwrap(),
-        None,
-        input,
-        hash,
-        hash_ty,
-        &secp,
-    );
+    input.tap_key_sig = Some(taproot::Signature { sig, hash_ty });
 
     // FINALIZER
-    psbt.inputs.iter_mut().for_each(|input| {
-        let mut script_witness: Witness = Witness::new();
-        script_witness.push(input.tap_key_sig.unwrap().to_vec());
-        input.final_script_witness = Some(script_witness);
-
-        // Clear all the data fields as per the spec.
-        input.partial_sigs = BTreeMap::new();
-        input.sighash_type = None;
-        input.redeem_script = None;
-        input.witness_script = None;
-        input.bip32_derivation = BTreeMap::new();
-    });
+    finalize_key_spend_inputs(&mut psbt.inputs)?;
 
     let tx = psbt.extract_tx();
 
     Ok(tx)
 }
-
-fn sign_psbt_taproot(
-    secret_key: &SecretKey,
-    pubkey: XOnlyPublicKey,
-    leaf_hash: Option<TapLeafHash>,
-    psbt_input: &mut psbt::Input,
-    hash: TapSighash,
-    hash_ty: TapSighashType,
-    secp: &Secp256k1<secp256k1::All>,
-) {
-    let keypair = secp256k1::KeyPair::from_seckey_slice(secp, secret_key.as_ref()).unwrap();
-    let keypair = match leaf_hash {
-        None => keypair.tap_tweak(secp, psbt_input.tap_merkle_root).to_inner(),
-        Some(_) => keypair, // no tweak for script spend
-    };
-
-    let sig = secp.sign_schnorr(&hash.into(), &keypair);
-
-    let final_signature = taproot::Signature { sig, hash_ty };
-
-    if let Some(lh) = leaf_hash {
-        psbt_input.tap_script_sigs.insert((pubkey, lh), final_signature);
-    } else {
-        psbt_input.tap_key_sig = Some(final_signature);
-    }
-}
\ No newline at end of file
@@ -0,0 +1,330 @@
+//! Password based encryption of the wallet secrets (mnemonic and coin private keys).
+//!
+//! The key is derived from the password with Argon2id and the secrets are encrypted with ChaCha20-Poly1305.
+//! A locked wallet has the secrets removed from its fields and only kept in `encrypted_secrets`.
+//! An unlocked wallet keeps `encrypted_secrets`, so it is still known to be password protected when it is stored again.
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{aead::{Aead, KeyInit, Payload}, ChaCha20Poly1305, Key, Nonce};
+use secp256k1_zkp::rand::{self, Rng};
+use serde::{Serialize, Deserialize};
+
+use crate::MercuryError;
+
+use super::Wallet;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct EncryptedSecrets {
+    /// Argon2id memory cost in KiB
+    pub kdf_memory_kib: u32,
+    pub kdf_iterations: u32,
+    pub kdf_parallelism: u32,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CoinSecrets {
+    user_pubkey: String,
+    user_privkey: String,
+    auth_privkey: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WalletSecrets {
+    mnemonic: String,
+    coins: Vec<CoinSecrets>,
+}
+
+/// Upper bounds of the KDF parameters read from stored data, so a crafted file cannot exhaust the memory or CPU
+const MAX_KDF_MEMORY_KIB: u32 = 1024 * 1024;
+const MAX_KDF_ITERATIONS: u32 = 64;
+const MAX_KDF_PARALLELISM: u32 = 16;
+
+/// Key derived from a password for a salt and KDF parameters.
+/// It can be kept for the session, so the KDF does not run every time the wallet is locked or unlocked.
+#[derive(Clone)]
+pub struct DerivedKey {
+    kdf_memory_kib: u32,
+    kdf_iterations: u32,
+    kdf_parallelism: u32,
+    salt: String,
+    key: Key,
+}
+
+impl DerivedKey {
+
+    /// Derives the key for the salt and KDF parameters of the encrypted secrets, or for a new salt if there are none
+    pub fn derive(password: &str, encrypted_secrets: Option<&EncryptedSecrets>) -> Result<Self, MercuryError> {
+
+        let (memory_kib, iterations, parallelism, salt) = match encrypted_secrets {
+            Some(encrypted_secrets) => (
+                encrypted_secrets.kdf_memory_kib,
+                encrypted_secrets.kdf_iterations,
+                encrypted_secrets.kdf_parallelism,
+                hex::decode(&encrypted_secrets.salt)?,
+            ),
+            None => {
+                let salt: [u8; 16] = rand::thread_rng().gen();
+                (Params::DEFAULT_M_COST, Params::DEFAULT_T_COST, Params::DEFAULT_P_COST, salt.to_vec())
+            },
+        };
+
+        if memory_kib > MAX_KDF_MEMORY_KIB || iterations > MAX_KDF_ITERATIONS || parallelism > MAX_KDF_PARALLELISM {
+            return Err(MercuryError::EncryptionError);
+        }
+
+        let params = Params::new(memory_kib, iterations, parallelism, Some(32)).map_err(|_| MercuryError::EncryptionError)?;
+
+        let mut key = [0u8; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(password.as_bytes(), &salt, &mut key)
+            .map_err(|_| MercuryError::EncryptionError)?;
+
+        Ok(DerivedKey {
+            kdf_memory_kib: memory_kib,
+            kdf_iterations: iterations,
+            kdf_parallelism: parallelism,
+            salt: hex::encode(salt),
+            key: *Key::from_slice(&key),
+        })
+    }
+
+    /// Whether the key was derived for the salt and KDF parameters of the encrypted secrets
+    pub fn matches(&self, encrypted_secrets: &EncryptedSecrets) -> bool {
+        self.salt == encrypted_secrets.salt
+            && self.kdf_memory_kib == encrypted_secrets.kdf_memory_kib
+            && self.kdf_iterations == encrypted_secrets.kdf_iterations
+            && self.kdf_parallelism == encrypted_secrets.kdf_parallelism
+    }
+}
+
+/// Encrypts the plaintext with a new nonce. The associated data is authenticated but not encrypted.
+pub(crate) fn encrypt_with_key(plaintext: &[u8], key: &DerivedKey, associated_data: &[u8]) -> Result<EncryptedSecrets, MercuryError> {
+
+    let nonce: [u8; 12] = rand::thread_rng().gen();
+
+    let ciphertext = ChaCha20Poly1305::new(&key.key)
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad: associated_data })
+        .map_err(|_| MercuryError::EncryptionError)?;
+
+    Ok(EncryptedSecrets {
+        kdf_memory_kib: key.kdf_memory_kib,
+        kdf_iterations: key.kdf_iterations,
+        kdf_parallelism: key.kdf_parallelism,
+        salt: key.salt.clone(),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    })
+}
+
+pub(crate) fn encrypt_with_password(plaintext: &[u8], password: &str, associated_data: &[u8]) -> Result<EncryptedSecrets, MercuryError> {
+    encrypt_with_key(plaintext, &DerivedKey::derive(password, None)?, associated_data)
+}
+
+/// Fails with `IncorrectPassword` if the key is wrong or the ciphertext or associated data were modified
+pub(crate) fn decrypt_with_key(encrypted_secrets: &EncryptedSecrets, key: &DerivedKey, associated_data: &[u8]) -> Result<Vec<u8>, MercuryError> {
+
+    if !key.matches(encrypted_secrets) {
+        return Err(MercuryError::EncryptionError);
+    }
+
+    let nonce = hex::decode(&encrypted_secrets.nonce)?;
+    let ciphertext = hex::decode(&encrypted_secrets.ciphertext)?;
+
+    if nonce.len() != 12 {
+        return Err(MercuryError::EncryptionError);
+    }
+
+    // the authentication tag only verifies with the right key
+    ChaCha20Poly1305::new(&key.key)
+        .decrypt(Nonce::from_slice(&nonce), Payload { msg: ciphertext.as_slice(), aad: associated_data })
+        .map_err(|_| MercuryError::IncorrectPassword)
+}
+
+pub(crate) fn decrypt_with_password(encrypted_secrets: &EncryptedSecrets, password: &str, associated_data: &[u8]) -> Result<Vec<u8>, MercuryError> {
+    decrypt_with_key(encrypted_secrets, &DerivedKey::derive(password, Some(encrypted_secrets))?, associated_data)
+}
+
+impl Wallet {
+
+    /// The secrets are encrypted and removed from the wallet fields
+    pub fn is_locked(&self) -> bool {
+        self.encrypted_secrets.is_some() && self.mnemonic.is_empty()
+    }
+
+    /// Encrypts the secrets with the password and removes them from the wallet fields
+    pub fn lock(&mut self, password: &str) -> Result<(), MercuryError> {
+        self.lock_with_key(&DerivedKey::derive(password, None)?)
+    }
+
+    pub fn lock_with_key(&mut self, key: &DerivedKey) -> Result<(), MercuryError> {
+
+        if self.is_locked() {
+            return Err(MercuryError::WalletLocked);
+        }
+
+        let secrets = WalletSecrets {
+            mnemonic: self.mnemonic.clone(),
+            coins: self.coins.iter().map(|coin| CoinSecrets {
+                user_pubkey: coin.user_pubkey.clone(),
+                user_privkey: coin.user_privkey.clone(),
+                auth_privkey: coin.auth_privkey.clone(),
+            }).collect(),
+        };
+
+        self.encrypted_secrets = Some(encrypt_with_key(&serde_json::to_vec(&secrets)?, key, &[])?);
+
+        self.mnemonic = String::new();
+        for coin in self.coins.iter_mut() {
+            coin.user_privkey = String::new();
+            coin.auth_privkey = String::new();
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts the secrets back into the wallet fields. Fails with `IncorrectPassword` if the password is wrong.
+    /// The encrypted secrets are kept, so the wallet must be locked again before it is stored.
+    pub fn unlock(&mut self, password: &str) -> Result<(), MercuryError> {
+        let key = DerivedKey::derive(password, self.encrypted_secrets.as_ref())?;
+        self.unlock_with_key(&key)
+    }
+
+    pub fn unlock_with_key(&mut self, key: &DerivedKey) -> Result<(), MercuryError> {
+
+        let encrypted_secrets = match &self.encrypted_secrets {
+            Some(encrypted_secrets) if self.is_locked() => encrypted_secrets,
+            _ => return Err(MercuryError::WalletNotLocked),
+        };
+
+        let secrets: WalletSecrets = serde_json::from_slice(&decrypt_with_key(encrypted_secrets, key, &[])?)?;
+
+        self.mnemonic = secrets.mnemonic;
+
+        for coin in self.coins.iter_mut() {
+            // coins are matched by key, duplicated coins share the keys of the original coin
+            if let Some(coin_secrets) = secrets.coins.iter().find(|coin_secrets| coin_secrets.user_pubkey == coin.user_pubkey) {
+                coin.user_privkey = coin_secrets.user_privkey.clone();
+                coin.auth_privkey = coin_secrets.auth_privkey.clone();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn lock_wallet(wallet: &Wallet, password: &str) -> Result<Wallet, MercuryError> {
+    let mut wallet = wallet.clone();
+    wallet.lock(password)?;
+    Ok(wallet)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn unlock_wallet(wallet: &Wallet, password: &str) -> Result<Wallet, MercuryError> {
+    let mut wallet = wallet.clone();
+    wallet.unlock(password)?;
+    Ok(wallet)
+}
+
+/// Re-encrypts the secrets of a locked wallet with a new password
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn change_wallet_password(wallet: &Wallet, old_password: &str, new_password: &str) -> Result<Wallet, MercuryError> {
+    let mut wallet = wallet.clone();
+    wallet.unlock(old_password)?;
+    wallet.lock(new_password)?;
+    Ok(wallet)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn is_wallet_locked(wallet: &Wallet) -> bool {
+    wallet.is_locked()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wallet::tests::test_wallet;
+
+    use super::*;
+
+    #[test]
+    fn test_lock_unlock_round_trip() {
+
+        let wallet = test_wallet();
+
+        let locked_wallet = lock_wallet(&wallet, "password").unwrap();
+
+        assert!(locked_wallet.is_locked());
+        assert!(locked_wallet.mnemonic.is_empty());
+        assert!(locked_wallet.coins[0].user_privkey.is_empty());
+        assert!(locked_wallet.coins[0].auth_privkey.is_empty());
+
+        let unlocked_wallet = unlock_wallet(&locked_wallet, "password").unwrap();
+
+        assert!(!unlocked_wallet.is_locked());
+        assert!(unlocked_wallet.encrypted_secrets.is_some());
+        assert_eq!(unlocked_wallet.mnemonic, wallet.mnemonic);
+        assert_eq!(unlocked_wallet.coins[0].user_privkey, wallet.coins[0].user_privkey);
+        assert_eq!(unlocked_wallet.coins[0].auth_privkey, wallet.coins[0].auth_privkey);
+    }
+
+    #[test]
+    fn test_lock_unlock_with_derived_key() {
+
+        let mut wallet = test_wallet();
+        let mnemonic = wallet.mnemonic.clone();
+
+        let key = DerivedKey::derive("password", None).unwrap();
+
+        wallet.lock_with_key(&key).unwrap();
+        wallet.unlock_with_key(&key).unwrap();
+
+        // the same key locks the wallet again, with a new nonce
+        let nonce = wallet.encrypted_secrets.as_ref().unwrap().nonce.clone();
+        wallet.lock_with_key(&key).unwrap();
+        assert_ne!(wallet.encrypted_secrets.as_ref().unwrap().nonce, nonce);
+
+        let key = DerivedKey::derive("password", wallet.encrypted_secrets.as_ref()).unwrap();
+        wallet.unlock_with_key(&key).unwrap();
+        assert_eq!(wallet.mnemonic, mnemonic);
+
+        // a key derived for another salt is rejected
+        wallet.lock_with_key(&key).unwrap();
+        let other_key = DerivedKey::derive("password", None).unwrap();
+        assert!(matches!(wallet.unlock_with_key(&other_key), Err(MercuryError::EncryptionError)));
+    }
+
+    #[test]
+    fn test_unlock_with_wrong_password() {
+
+        let locked_wallet = lock_wallet(&test_wallet(), "password").unwrap();
+
+        assert!(matches!(unlock_wallet(&locked_wallet, "wrong password"), Err(MercuryError::IncorrectPassword)));
+    }
+
+    #[test]
+    fn test_unlock_tampered_ciphertext() {
+
+        let mut locked_wallet = lock_wallet(&test_wallet(), "password").unwrap();
+
+        let encrypted_secrets = locked_wallet.encrypted_secrets.as_mut().unwrap();
+        let mut ciphertext = hex::decode(&encrypted_secrets.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        encrypted_secrets.ciphertext = hex::encode(ciphertext);
+
+        assert!(matches!(unlock_wallet(&locked_wallet, "password"), Err(MercuryError::IncorrectPassword)));
+    }
+
+    #[test]
+    fn test_unlock_rejects_excessive_kdf_parameters() {
+
+        let mut locked_wallet = lock_wallet(&test_wallet(), "password").unwrap();
+
+        locked_wallet.encrypted_secrets.as_mut().unwrap().kdf_memory_kib = MAX_KDF_MEMORY_KIB + 1;
+
+        assert!(matches!(unlock_wallet(&locked_wallet, "password"), Err(MercuryError::EncryptionError)));
+    }
+}
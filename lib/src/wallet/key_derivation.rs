@@ -6,6 +6,9 @@ use secp256k1_zkp::{SecretKey, PublicKey, ffi::types::AlignedType, Secp256k1};
 
 use crate::{encode_sc_address, error::MercuryError, utils::get_network, wallet::{Coin, CoinStatus, Wallet}};
 
+pub const USER_KEY_DERIVATION_PATH: &str = "m/86h/0h/0h";
+pub const AUTH_KEY_DERIVATION_PATH: &str = "m/89h/0h/0h";
+
 pub struct KeyData {
     pub secret_key: SecretKey,
     pub public_key: PublicKey,
@@ -17,11 +20,26 @@ pub struct KeyData {
 
 impl Wallet {
 
-    fn get_seed(&self) -> Result<[u8; 64], MercuryError> {
-        let seed: [u8; 64] = Mnemonic::from_str(&self.mnemonic)?.to_seed("");
+    fn get_seed(&self, passphrase: &str) -> Result<[u8; 64], MercuryError> {
+        if self.has_passphrase && passphrase.is_empty() {
+            return Err(MercuryError::PassphraseRequired);
+        }
+        let seed: [u8; 64] = Mnemonic::from_str(&self.mnemonic)?.to_seed(passphrase);
         Ok(seed)
     }
 
+    /// Fingerprint of the master key of the seed, stored in the wallet to detect a wrong passphrase
+    pub fn get_master_fingerprint(&self, passphrase: &str) -> Result<String, MercuryError> {
+
+        let seed = self.get_seed(passphrase)?;
+        let network = get_network(&self.network)?;
+
+        let secp = Secp256k1::new();
+        let root = ExtendedPrivKey::new_master(network, &seed)?;
+
+        Ok(root.fingerprint(&secp).to_string())
+    }
+
     pub fn get_next_address_index(&self) -> u32 {
 
         let max_index = self.coins.iter().map(|coin| coin.index).max();
@@ -33,8 +51,12 @@ impl Wallet {
     }
 
     pub fn generate_new_key(&self, derivation_path: &str, change_index: u32, address_index:u32) -> Result<KeyData, MercuryError> {
+        self.generate_new_key_with_passphrase(derivation_path, change_index, address_index, "")
+    }
+
+    pub fn generate_new_key_with_passphrase(&self, derivation_path: &str, change_index: u32, address_index:u32, passphrase: &str) -> Result<KeyData, MercuryError> {
 
-        let seed= self.get_seed()?;
+        let seed= self.get_seed(passphrase)?;
         let network = get_network(&self.network)?;
 
         // we need secp256k1 context for key derivation
@@ -71,26 +93,45 @@ impl Wallet {
     }
 
     pub fn get_new_coin(&self) -> Result<Coin, MercuryError> {
+        self.get_new_coin_with_passphrase("")
+    }
+
+    /// Derives the keys of a new coin from the seed of the mnemonic and BIP39 passphrase
+    pub fn get_new_coin_with_passphrase(&self, passphrase: &str) -> Result<Coin, MercuryError> {
+        self.get_coin_at_index(self.get_next_address_index(), passphrase)
+    }
+
+    /// Derives the keys of the coin at the address index, e.g. to find the coins of a recovered wallet
+    pub fn get_coin_at_index(&self, address_index: u32, passphrase: &str) -> Result<Coin, MercuryError> {
 
         let network = get_network(&self.network)?;
 
-        let derivation_path = "m/86h/0h/0h";
+        let derivation_path = USER_KEY_DERIVATION_PATH;
         let change_index = 0;
-        let address_index = self.get_next_address_index();
 
-        let agg_key_data = self.generate_new_key(derivation_path, change_index, address_index)?;
+        let agg_key_data = self.generate_new_key_with_passphrase(derivation_path, change_index, address_index, passphrase)?;
+
+        // a wrong passphrase derives another root key, which would create coins that are never found again
+        // wallets created before the master fingerprint was stored are checked against their coins
+        let master_fingerprint = self.master_fingerprint.as_ref().or(self.coins.first().map(|coin| &coin.fingerprint));
+
+        if master_fingerprint.is_some_and(|fingerprint| *fingerprint != agg_key_data.fingerprint) {
+            return Err(MercuryError::IncorrectPassphrase);
+        }
 
         let client_secret_key = agg_key_data.secret_key;
         let client_pubkey_share = agg_key_data.public_key;
         let backup_address = Address::p2tr(&Secp256k1::new(), client_pubkey_share.x_only_public_key().0, None, network);
 
-        let derivation_path = "m/89h/0h/0h";
-        let auth_key_data = self.generate_new_key(derivation_path, change_index, address_index)?;
+        let derivation_path = AUTH_KEY_DERIVATION_PATH;
+        let auth_key_data = self.generate_new_key_with_passphrase(derivation_path, change_index, address_index, passphrase)?;
 
-        assert!(auth_key_data.fingerprint == agg_key_data.fingerprint);
-        assert!(auth_key_data.address_index == agg_key_data.address_index);
-        assert!(auth_key_data.change_index == agg_key_data.change_index);
-        assert!(auth_key_data.derivation_path != agg_key_data.derivation_path);
+        if auth_key_data.fingerprint != agg_key_data.fingerprint ||
+            auth_key_data.address_index != agg_key_data.address_index ||
+            auth_key_data.change_index != agg_key_data.change_index ||
+            auth_key_data.derivation_path == agg_key_data.derivation_path {
+            return Err(MercuryError::KeyDerivationMismatch);
+        }
 
         let auth_secret = auth_key_data.secret_key;
 
@@ -136,4 +177,110 @@ impl Wallet {
 
         Ok(coin)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{generate_mnemonic_with_word_count, tests::test_wallet};
+
+    const MNEMONIC_24_WORDS: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+    #[test]
+    fn test_master_fingerprint_24_words_with_passphrase() {
+        let mut wallet = test_wallet();
+        wallet.mnemonic = MNEMONIC_24_WORDS.to_string();
+        wallet.network = "bitcoin".to_string();
+        wallet.has_passphrase = true;
+
+        // BIP39 test vector, master key xprv9s21ZrQH143K32qBagUJAMU2LsHg3ka7jqMcV98Y7gVeVyNStwYS3U7yVVoDZ4btbRNf4h6ibWpY22iRmXq35qgLs79f312g2kj5539ebPM
+        assert_eq!(wallet.get_master_fingerprint("TREZOR").unwrap(), "88bca92b");
+    }
+
+    #[test]
+    fn test_coin_24_words_with_passphrase() {
+        let mut wallet = test_wallet();
+        wallet.mnemonic = MNEMONIC_24_WORDS.to_string();
+        wallet.network = "bitcoin".to_string();
+        wallet.coins.clear();
+        wallet.has_passphrase = true;
+        wallet.master_fingerprint = Some(wallet.get_master_fingerprint("TREZOR").unwrap());
+
+        let coin = wallet.get_coin_at_index(0, "TREZOR").unwrap();
+        assert_eq!(coin.address, "ml1qqp46wp46pdtctzs63yu0jwexrf8asjk2z7dypysu2z0wygslp46ekcrwg3hj0a4n3c2d5p0fqfwrcj9gkmjdkje95h7uey55l6gye37lhgqhrex70");
+        assert_eq!(coin.fingerprint, "88bca92b");
+        assert_eq!(coin.derivation_path, "m/86h/0h/0h/0/0");
+    }
+
+    #[test]
+    fn test_generated_24_word_mnemonic() {
+        let mnemonic = generate_mnemonic_with_word_count(24).unwrap();
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+
+        let mut wallet = test_wallet();
+        wallet.mnemonic = mnemonic;
+        wallet.coins.clear();
+        wallet.master_fingerprint = Some(wallet.get_master_fingerprint("").unwrap());
+
+        let coin = wallet.get_new_coin().unwrap();
+        assert_eq!(coin.index, 0);
+        assert_eq!(Some(&coin.fingerprint), wallet.master_fingerprint.as_ref());
+
+        assert!(matches!(generate_mnemonic_with_word_count(13), Err(MercuryError::InvalidMnemonicWordCount { word_count: 13 })));
+    }
+
+    #[test]
+    fn test_coin_at_hardened_index() {
+        let wallet = test_wallet();
+
+        // only normal (non hardened) address indexes can be derived
+        assert!(wallet.get_coin_at_index(1 << 31, "").is_err());
+    }
+
+    #[test]
+    fn test_passphrase_changes_keys() {
+        let wallet = test_wallet();
+
+        let mut passphrase_wallet = test_wallet();
+        passphrase_wallet.coins.clear();
+        passphrase_wallet.has_passphrase = true;
+        passphrase_wallet.master_fingerprint = Some(passphrase_wallet.get_master_fingerprint("TREZOR").unwrap());
+
+        let coin = passphrase_wallet.get_coin_at_index(0, "TREZOR").unwrap();
+
+        assert_ne!(coin.fingerprint, wallet.coins[0].fingerprint);
+        assert_ne!(coin.user_pubkey, wallet.coins[0].user_pubkey);
+        assert_ne!(coin.auth_pubkey, wallet.coins[0].auth_pubkey);
+    }
+
+    #[test]
+    fn test_passphrase_required() {
+        let mut wallet = test_wallet();
+        wallet.has_passphrase = true;
+
+        assert!(matches!(wallet.get_new_coin(), Err(MercuryError::PassphraseRequired)));
+        assert!(matches!(wallet.get_master_fingerprint(""), Err(MercuryError::PassphraseRequired)));
+    }
+
+    #[test]
+    fn test_wrong_passphrase_on_empty_wallet() {
+        let mut wallet = test_wallet();
+        wallet.coins.clear();
+        wallet.has_passphrase = true;
+        wallet.master_fingerprint = Some(wallet.get_master_fingerprint("TREZOR").unwrap());
+
+        assert!(matches!(wallet.get_new_coin_with_passphrase("trezor"), Err(MercuryError::IncorrectPassphrase)));
+        assert!(wallet.get_new_coin_with_passphrase("TREZOR").is_ok());
+    }
+
+    #[test]
+    fn test_wrong_passphrase_without_master_fingerprint() {
+        // wallets created before the master fingerprint was stored
+        let mut wallet = test_wallet();
+        wallet.master_fingerprint = None;
+
+        assert!(matches!(wallet.get_new_coin_with_passphrase("TREZOR"), Err(MercuryError::IncorrectPassphrase)));
+        assert!(wallet.get_new_coin().is_ok());
+    }
+}
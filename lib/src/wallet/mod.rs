@@ -1,5 +1,7 @@
 pub mod key_derivation;
 pub mod cpfp_tx;
+pub mod encryption;
+pub mod backup;
 
 use std::{fmt, str::FromStr};
 
@@ -25,6 +27,15 @@ pub struct Wallet {
     pub activities: Vec<Activity>,
     pub coins: Vec<Coin>,
     pub settings: Settings,
+    /// Mnemonic and coin private keys of a locked wallet
+    #[serde(default)]
+    pub encrypted_secrets: Option<encryption::EncryptedSecrets>,
+    /// The seed is derived with a BIP39 passphrase, which is not stored and must be given to derive new keys
+    #[serde(default)]
+    pub has_passphrase: bool,
+    /// Fingerprint of the master key, used to detect a wrong BIP39 passphrase
+    #[serde(default)]
+    pub master_fingerprint: Option<String>,
 }
 
 #[allow(non_snake_case)]
@@ -57,6 +68,41 @@ pub struct Token {
     pub confirmed: bool,
     pub spent: bool,
     pub expiry: String,
+    #[serde(default)]
+    pub status: Option<TokenStatus>, // None until the status is fetched from the server
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Enum))]
+#[allow(non_camel_case_types)]
+pub enum TokenStatus {
+    PENDING, // invoice not paid yet
+    PAID, // token available for a deposit
+    EXPIRED, // invoice expired unpaid
+    SPENT, // token used in a deposit
+    REFUNDED, // payment returned to the user
+}
+
+impl fmt::Display for TokenStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::PENDING => "PENDING",
+            Self::PAID => "PAID",
+            Self::EXPIRED => "EXPIRED",
+            Self::SPENT => "SPENT",
+            Self::REFUNDED => "REFUNDED",
+        })
+    }
+}
+
+impl Token {
+    /// Updates the token with the status reported by the server
+    pub fn set_status(&mut self, status: TokenStatus, expiry: &str) {
+        self.confirmed = matches!(status, TokenStatus::PAID | TokenStatus::SPENT);
+        self.spent = status == TokenStatus::SPENT;
+        self.expiry = expiry.to_string();
+        self.status = Some(status);
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -195,8 +241,69 @@ pub fn set_config(wallet: &mut Wallet, config: &ServerConfig) {
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn generate_mnemonic() -> core::result::Result<String, MercuryError> {
+    generate_mnemonic_with_word_count(12)
+}
+
+/// Generates a mnemonic of 12, 15, 18, 21 or 24 words
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn generate_mnemonic_with_word_count(word_count: u32) -> core::result::Result<String, MercuryError> {
+    if ![12, 15, 18, 21, 24].contains(&word_count) {
+        return Err(MercuryError::InvalidMnemonicWordCount { word_count });
+    }
     let mut rng = rand::thread_rng();
-    let entropy = (0..16).map(|_| rng.gen::<u8>()).collect::<Vec<u8>>(); // 16 bytes of entropy for 12 words
+    let entropy_len = (word_count * 4 / 3) as usize; // 16 bytes of entropy for 12 words, 32 bytes for 24 words
+    let entropy = (0..entropy_len).map(|_| rng.gen::<u8>()).collect::<Vec<u8>>();
     let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)?;
     Ok(mnemonic.to_string())
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn test_wallet() -> Wallet {
+
+        let settings = Settings {
+            network: "testnet".to_string(),
+            block_explorerURL: None,
+            torProxyHost: None,
+            torProxyPort: None,
+            torProxyControlPassword: None,
+            torProxyControlPort: None,
+            statechainEntityApi: "http://127.0.0.1:8000".to_string(),
+            torStatechainEntityApi: None,
+            electrumProtocol: "tcp".to_string(),
+            electrumHost: "127.0.0.1".to_string(),
+            electrumPort: "50001".to_string(),
+            electrumType: "electrs".to_string(),
+            notifications: false,
+            tutorials: false,
+        };
+
+        let mut wallet = Wallet {
+            name: "w1".to_string(),
+            mnemonic: "ticket sock try two evidence employ fresh beauty settle general ridge lonely".to_string(),
+            version: "0.1.0".to_string(),
+            state_entity_endpoint: "http://127.0.0.1:8000".to_string(),
+            electrum_endpoint: "tcp://127.0.0.1:50001".to_string(),
+            network: "testnet".to_string(),
+            blockheight: 0,
+            initlock: 10000,
+            interval: 10,
+            tokens: Vec::new(),
+            activities: Vec::new(),
+            coins: Vec::new(),
+            settings,
+            encrypted_secrets: None,
+            has_passphrase: false,
+            master_fingerprint: None,
+        };
+
+        wallet.master_fingerprint = Some(wallet.get_master_fingerprint("").unwrap());
+
+        let coin = wallet.get_new_coin().unwrap();
+        wallet.coins.push(coin);
+
+        wallet
+    }
+}
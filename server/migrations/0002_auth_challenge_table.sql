@@ -0,0 +1,9 @@
+CREATE TABLE public.auth_challenge (
+	id serial4 NOT NULL,
+	auth_public_key bytea NOT NULL,
+	challenge varchar NOT NULL,
+	expires_at TIMESTAMPTZ NOT NULL,
+	created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+	CONSTRAINT auth_challenge_pkey PRIMARY KEY (id),
+	CONSTRAINT auth_challenge_auth_public_key_challenge_key UNIQUE (auth_public_key, challenge)
+);
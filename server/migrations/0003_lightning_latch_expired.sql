@@ -0,0 +1 @@
+ALTER TABLE public.lightning_latch ADD COLUMN expired boolean NOT NULL DEFAULT false;
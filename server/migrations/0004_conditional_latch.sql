@@ -0,0 +1,7 @@
+ALTER TABLE public.lightning_latch ADD COLUMN kind varchar NOT NULL DEFAULT 'lightning';
+ALTER TABLE public.lightning_latch ADD COLUMN oracle_xonly_public_key bytea NULL;
+ALTER TABLE public.lightning_latch ADD COLUMN outcome varchar NULL;
+ALTER TABLE public.lightning_latch ADD COLUMN attested_outcome varchar NULL;
+ALTER TABLE public.lightning_latch ADD COLUMN attestation varchar NULL;
+ALTER TABLE public.lightning_latch ADD COLUMN event_id varchar NULL;
+ALTER TABLE public.lightning_latch ADD CONSTRAINT lightning_latch_oracle_event_id_check CHECK (kind <> 'oracle' OR event_id IS NOT NULL);
@@ -0,0 +1 @@
+ALTER TABLE public.statechain_transfer ADD COLUMN batch_size integer NULL;
@@ -7,7 +7,7 @@ pub async fn get_token_status(pool: &sqlx::PgPool, token_id: &str) -> Option<boo
         "SELECT confirmed, spent \
         FROM public.tokens \
         WHERE token_id = $1")
-        .bind(&token_id)
+        .bind(token_id)
         .fetch_one(pool)
         .await;
 
@@ -23,9 +23,9 @@ pub async fn get_token_status(pool: &sqlx::PgPool, token_id: &str) -> Option<boo
     let confirmed: bool = row.get(0);
     let spent: bool = row.get(1);
     if confirmed && !spent {
-        return Some(true);
+        Some(true)
     } else {
-        return Some(false);
+        Some(false)
     }
 
 }
@@ -52,7 +52,7 @@ pub async fn check_existing_key(pool: &sqlx::PgPool, auth_key: &XOnlyPublicKey)
         "SELECT 1 \
         FROM statechain_data \
         WHERE auth_xonly_public_key = $1")
-        .bind(&auth_key.serialize())
+        .bind(auth_key.serialize())
         .fetch_one(pool)
         .await;
 
@@ -69,8 +69,8 @@ pub async fn insert_new_deposit(pool: &sqlx::PgPool, token_id: &str, auth_key: &
 
     let _ = sqlx::query(query)
         .bind(token_id)
-        .bind(&auth_key.serialize())
-        .bind(&server_public_key.serialize())
+        .bind(auth_key.serialize())
+        .bind(server_public_key.serialize())
         .bind(statechain_id)
         .bind(enclave_index)
         .execute(pool)
@@ -2,6 +2,10 @@ use chrono::{DateTime, Utc};
 use secp256k1_zkp::XOnlyPublicKey;
 use sqlx::Row;
 
+/// Kind of the latch: released by a lightning payment or by an oracle attestation
+pub const LATCH_KIND_LIGHTNING: &str = "lightning";
+pub const LATCH_KIND_ORACLE: &str = "oracle";
+
 pub async fn insert_paymenthash(
     pool: &sqlx::PgPool, 
     statechain_id: &str, 
@@ -10,14 +14,44 @@ pub async fn insert_paymenthash(
     pre_image: &str,
     expires_at: &DateTime<Utc>)  
 {
-    let query = "DELETE FROM lightning_latch WHERE expires_at < now()";
+    insert_latch(pool, statechain_id, sender_auth_key, batch_id, pre_image, expires_at, None).await;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn insert_conditional_latch(
+    pool: &sqlx::PgPool, 
+    statechain_id: &str, 
+    sender_auth_key: &XOnlyPublicKey,
+    batch_id: &str,
+    pre_image: &str,
+    expires_at: &DateTime<Utc>,
+    oracle_pub_key: &XOnlyPublicKey,
+    event_id: &str,
+    outcome: &str)  
+{
+    insert_latch(pool, statechain_id, sender_auth_key, batch_id, pre_image, expires_at, Some((oracle_pub_key, event_id, outcome))).await;
+}
+
+async fn insert_latch(
+    pool: &sqlx::PgPool, 
+    statechain_id: &str, 
+    sender_auth_key: &XOnlyPublicKey,
+    batch_id: &str,
+    pre_image: &str,
+    expires_at: &DateTime<Utc>,
+    oracle_condition: Option<(&XOnlyPublicKey, &str, &str)>)  
+{
+    // expired latches are only removed once they were refunded or their pre-image was released
+    let query = "DELETE FROM lightning_latch WHERE expires_at < now() AND (expired = true OR locked = false)";
 
     let _ = sqlx::query(query)
         .execute(pool)
         .await
         .unwrap();
 
-    let query = "INSERT INTO lightning_latch (statechain_id, sender_auth_xonly_public_key, batch_id, pre_image, expires_at) VALUES ($1, $2, $3, $4, $5)";
+    let kind = if oracle_condition.is_some() { LATCH_KIND_ORACLE } else { LATCH_KIND_LIGHTNING };
+
+    let query = "INSERT INTO lightning_latch (statechain_id, sender_auth_xonly_public_key, batch_id, pre_image, expires_at, kind, oracle_xonly_public_key, event_id, outcome) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)";
 
     let _ = sqlx::query(query)
         .bind(statechain_id)
@@ -25,6 +59,10 @@ pub async fn insert_paymenthash(
         .bind(batch_id)
         .bind(pre_image)
         .bind(expires_at)
+        .bind(kind)
+        .bind(oracle_condition.map(|(oracle_pub_key, _, _)| oracle_pub_key.serialize().to_vec()))
+        .bind(oracle_condition.map(|(_, event_id, _)| event_id))
+        .bind(oracle_condition.map(|(_, _, outcome)| outcome))
         .execute(pool)
         .await
         .unwrap();
@@ -68,10 +106,7 @@ pub async fn get_preimage(pool: &sqlx::PgPool, statechain_id: &str, sender_auth_
         .await
         .unwrap();
 
-    if row.is_none()
-    {
-        return None;
-    }
+    row.as_ref()?;
 
     let row = row.unwrap();
 
@@ -81,9 +116,9 @@ pub async fn get_preimage(pool: &sqlx::PgPool, statechain_id: &str, sender_auth_
 
 }
 
-pub async fn get_preimage_by_batch_id(pool: &sqlx::PgPool, batch_id: &str) -> Option<String> {
+pub async fn get_preimage_by_batch_id(pool: &sqlx::PgPool, batch_id: &str) -> Option<(String, DateTime<Utc>)> {
 
-    let query = "SELECT pre_image FROM \
+    let query = "SELECT pre_image, expires_at FROM \
         lightning_latch \
         WHERE batch_id = $1";
 
@@ -93,14 +128,191 @@ pub async fn get_preimage_by_batch_id(pool: &sqlx::PgPool, batch_id: &str) -> Op
         .await
         .unwrap();
 
-    if row.is_none()
-    {
-        return None;
-    }
+    row.as_ref()?;
 
     let row = row.unwrap();
 
     let pre_image: String = row.get(0);
+    let expires_at: DateTime<Utc> = row.get(1);
 
-    Some(pre_image)
+    Some((pre_image, expires_at))
+}
+
+/// Pre-image of the latch, if it is released by a lightning payment
+pub async fn get_latch_preimage(pool: &sqlx::PgPool, statechain_id: &str, batch_id: &str) -> Option<String> {
+
+    let query = "SELECT pre_image FROM \
+        lightning_latch \
+        WHERE statechain_id = $1 \
+        AND batch_id = $2 \
+        AND kind = $3";
+
+    let row = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(batch_id)
+        .bind(LATCH_KIND_LIGHTNING)
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    match row {
+        Some(row) => {
+            let pre_image: String = row.get(0);
+            Some(pre_image)
+        }
+        None => None
+    }
+}
+
+
+pub async fn get_latch_status(pool: &sqlx::PgPool, statechain_id: &str, sender_auth_key: &XOnlyPublicKey, batch_id: &str) -> Option<(bool, bool, DateTime<Utc>)> {
+
+    let query = "SELECT locked, expired, expires_at FROM \
+        lightning_latch \
+        WHERE statechain_id = $1 \
+        AND sender_auth_xonly_public_key = $2 \
+        AND batch_id = $3";
+
+    let row = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(sender_auth_key.serialize())
+        .bind(batch_id)
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    match row {
+        Some(row) => {
+            let locked: bool = row.get(0);
+            let expired: bool = row.get(1);
+            let expires_at: DateTime<Utc> = row.get(2);
+            Some((locked, expired, expires_at))
+        }
+        None => None
+    }
+}
+
+/// Marks the latch as expired and discards its pending transfer if the transfer message was not sent yet.
+/// Otherwise the receiver holds a backup transaction of the coin, so the transfer is kept locked until the sender
+/// sends the coin again to replace it. Returns true if the pending transfer was discarded.
+pub async fn expire_latch(pool: &sqlx::PgPool, statechain_id: &str, batch_id: &str) -> bool {
+
+    let mut transaction = pool.begin().await.unwrap();
+
+    let query = "UPDATE lightning_latch \
+        SET expired = true, updated_at = NOW() \
+        WHERE statechain_id = $1 \
+        AND batch_id = $2";
+
+    let _ = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(batch_id)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    let query = "DELETE FROM statechain_transfer \
+        WHERE statechain_id = $1 \
+        AND batch_id = $2 \
+        AND key_updated = false \
+        AND encrypted_transfer_msg IS NULL";
+
+    let result = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(batch_id)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    transaction.commit().await.unwrap();
+
+    result.rows_affected() > 0
+}
+
+/// Latches that expired while still locked, i.e. the pre-image was never released
+pub async fn get_unpaid_expired_latches(pool: &sqlx::PgPool) -> Vec<(String, String)> {
+
+    let query = "SELECT statechain_id, batch_id FROM \
+        lightning_latch \
+        WHERE expires_at < NOW() \
+        AND locked = true \
+        AND expired = false";
+
+    let rows = sqlx::query(query)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<(String, String)>::new();
+
+    for row in rows {
+        let statechain_id: String = row.get(0);
+        let batch_id: String = row.get(1);
+        result.push((statechain_id, batch_id));
+    }
+
+    result
+}
+
+pub struct OracleLatch {
+    pub oracle_pub_key: XOnlyPublicKey,
+    pub event_id: String,
+    pub outcome: String,
+    pub attested_outcome: Option<String>,
+    pub expired: bool,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub async fn get_oracle_latch(pool: &sqlx::PgPool, statechain_id: &str, batch_id: &str) -> Option<OracleLatch> {
+
+    let query = "SELECT oracle_xonly_public_key, event_id, outcome, attested_outcome, expired, expires_at FROM \
+        lightning_latch \
+        WHERE statechain_id = $1 \
+        AND batch_id = $2 \
+        AND kind = $3";
+
+    let row = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(batch_id)
+        .bind(LATCH_KIND_ORACLE)
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    match row {
+        Some(row) => {
+            let oracle_pub_key_bytes: Vec<u8> = row.get(0);
+            Some(OracleLatch {
+                oracle_pub_key: XOnlyPublicKey::from_slice(&oracle_pub_key_bytes).unwrap(),
+                event_id: row.get(1),
+                outcome: row.get(2),
+                attested_outcome: row.get(3),
+                expired: row.get(4),
+                expires_at: row.get(5),
+            })
+        }
+        None => None
+    }
+}
+
+/// Records the attestation unless another one was recorded first. Returns false if the latch was already attested or expired.
+pub async fn insert_attestation(pool: &sqlx::PgPool, statechain_id: &str, batch_id: &str, attested_outcome: &str, attestation: &str) -> bool {
+
+    let query = "UPDATE lightning_latch \
+        SET attested_outcome = $1, attestation = $2, updated_at = NOW() \
+        WHERE statechain_id = $3 \
+        AND batch_id = $4 \
+        AND attested_outcome IS NULL \
+        AND expired = false";
+
+    let result = sqlx::query(query)
+        .bind(attested_outcome)
+        .bind(attestation)
+        .bind(statechain_id)
+        .bind(batch_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    result.rows_affected() > 0
 }
@@ -0,0 +1,36 @@
+use secp256k1_zkp::{PublicKey, XOnlyPublicKey};
+use sqlx::Row;
+
+/// Statecoins owned by the auth keys, with the latest transfer message when the key was updated by a transfer
+pub async fn get_statecoins_by_auth_keys(pool: &sqlx::PgPool, auth_keys: &[XOnlyPublicKey]) -> Vec::<(XOnlyPublicKey, String, PublicKey, Option<Vec<u8>>)> {
+
+    let query = "\
+        SELECT sd.auth_xonly_public_key, sd.statechain_id, sd.server_public_key, st.encrypted_transfer_msg \
+        FROM statechain_data sd \
+        LEFT JOIN statechain_transfer st \
+        ON st.statechain_id = sd.statechain_id AND st.key_updated = true \
+        WHERE sd.auth_xonly_public_key = ANY($1) \
+        AND sd.statechain_id IS NOT NULL";
+
+    let serialized_keys: Vec<Vec<u8>> = auth_keys.iter().map(|key| key.serialize().to_vec()).collect();
+
+    let rows = sqlx::query(query)
+        .bind(serialized_keys)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<(XOnlyPublicKey, String, PublicKey, Option<Vec<u8>>)>::new();
+
+    for row in rows {
+        let auth_xonly_public_key_bytes = row.get::<Vec<u8>, _>(0);
+        let auth_xonly_public_key = XOnlyPublicKey::from_slice(&auth_xonly_public_key_bytes).unwrap();
+        let statechain_id: String = row.get(1);
+        let server_public_key_bytes = row.get::<Vec<u8>, _>(2);
+        let server_public_key = PublicKey::from_slice(&server_public_key_bytes).unwrap();
+        let encrypted_transfer_msg: Option<Vec<u8>> = row.get(3);
+        result.push((auth_xonly_public_key, statechain_id, server_public_key, encrypted_transfer_msg));
+    }
+
+    result
+}
@@ -14,10 +14,7 @@ pub async fn get_server_pubnonce_from_null_challenge(pool: &sqlx::PgPool, statec
         .await
         .unwrap();
 
-    if row.is_none()
-    {
-        return None;
-    }
+    row.as_ref()?;
 
     let row = row.unwrap();
 
@@ -46,7 +43,7 @@ pub async fn insert_new_signature_data(pool: &sqlx::PgPool, server_pubnonce: &st
         .unwrap();
 
     let mut new_tx_n = row.get::<i32, _>(0);
-    new_tx_n = new_tx_n + 1;
+    new_tx_n += 1;
 
     let query = "\
         INSERT INTO statechain_signature_data \
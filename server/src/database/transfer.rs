@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use secp256k1_zkp::PublicKey;
 
 use sqlx::Row;
 
@@ -27,10 +28,11 @@ pub async fn get_batch_id_and_time_by_statechain_id(pool: &sqlx::PgPool, statech
     }
 }
 
+/// A batch with a declared size is only unlocked once that many coins are registered in it and all of them are unlocked
 pub async fn is_all_coins_unlocked(pool: &sqlx::PgPool, batch_id: &str) -> bool {
 
     let query = "\
-        SELECT locked, locked2 \
+        SELECT locked, locked2, batch_size \
         FROM statechain_transfer \
         WHERE batch_id = $1";
 
@@ -40,14 +42,47 @@ pub async fn is_all_coins_unlocked(pool: &sqlx::PgPool, batch_id: &str) -> bool
         .await
         .unwrap();
 
+    let registered_coins = rows.len();
+
     for row in rows {
         let locked: bool = row.get(0);
         let locked2: bool = row.get(1);
+        let batch_size: Option<i32> = row.get(2);
 
         if locked || locked2 {
             return false;
         }
+
+        if batch_size.is_some_and(|batch_size| registered_coins < batch_size as usize) {
+            return false;
+        }
     }
 
     true
 }
+
+pub async fn get_batch_transfer_receivers(pool: &sqlx::PgPool, batch_id: &str) -> Vec<(String, PublicKey)> {
+
+    let query = "\
+        SELECT statechain_id, new_user_auth_public_key \
+        FROM statechain_transfer \
+        WHERE batch_id = $1";
+
+    let rows = sqlx::query(query)
+        .bind(batch_id)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<(String, PublicKey)>::new();
+
+    for row in rows {
+        let statechain_id: String = row.get(0);
+        let new_user_auth_public_key_bytes = row.get::<Vec<u8>, _>(1);
+        let new_user_auth_public_key = PublicKey::from_slice(&new_user_auth_public_key_bytes).unwrap();
+
+        result.push((statechain_id, new_user_auth_public_key));
+    }
+
+    result
+}
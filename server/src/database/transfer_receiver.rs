@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use mercurylib::transfer::receiver::StatechainInfo;
 use secp256k1_zkp::{PublicKey, Secp256k1, XOnlyPublicKey, SecretKey};
 
@@ -52,9 +53,7 @@ pub async fn get_enclave_pubkey(pool: &sqlx::PgPool, statechain_id: &str) -> Opt
         .await
         .unwrap();
 
-    if row.is_none() {
-        return None;
-    }
+    row.as_ref()?;
 
     let row = row.unwrap();
 
@@ -76,9 +75,7 @@ pub async fn get_x1pub(pool: &sqlx::PgPool, statechain_id: &str) -> Option<Publi
         .await
         .unwrap();
 
-    if row.is_none() {
-        return None;
-    }
+    row.as_ref()?;
 
     let row = row.unwrap();
 
@@ -113,6 +110,35 @@ pub async fn get_statechain_transfer_messages(pool: &sqlx::PgPool, new_user_auth
     result
 }
 
+pub async fn get_statechain_transfer_messages_for_keys(pool: &sqlx::PgPool, new_user_auth_keys: &[PublicKey]) -> Vec::<(PublicKey, String)> {
+
+    let query = "\
+        SELECT new_user_auth_public_key, encrypted_transfer_msg \
+        FROM statechain_transfer \
+        WHERE new_user_auth_public_key = ANY($1)
+        AND encrypted_transfer_msg IS NOT NULL \
+        ORDER BY updated_at ASC";
+
+    let serialized_keys: Vec<Vec<u8>> = new_user_auth_keys.iter().map(|key| key.serialize().to_vec()).collect();
+
+    let rows = sqlx::query(query)
+        .bind(serialized_keys)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<(PublicKey, String)>::new();
+
+    for row in rows {
+        let new_user_auth_public_key_bytes = row.get::<Vec<u8>, _>(0);
+        let new_user_auth_public_key = PublicKey::from_slice(&new_user_auth_public_key_bytes).unwrap();
+        let encrypted_transfer_msg: Vec<u8> = row.get(1);
+        result.push((new_user_auth_public_key, hex::encode(encrypted_transfer_msg)));
+    }
+
+    result
+}
+
 pub async fn get_auth_pubkey_and_x1(pool: &sqlx::PgPool, statechain_id: &str) -> Option<(PublicKey, Vec<u8>)> {
 
     let query = "\
@@ -171,7 +197,7 @@ pub async fn get_server_public_key(pool: &sqlx::PgPool, statechain_id: &str) ->
 
     let server_public_key_bytes: Vec<u8> = row.get(0);
 
-    if server_public_key_bytes.len() == 0 {
+    if server_public_key_bytes.is_empty() {
         return None;
     }
 
@@ -189,8 +215,8 @@ pub async fn update_statechain(pool: &sqlx::PgPool, auth_key: &XOnlyPublicKey, s
         WHERE statechain_id = $3";
 
     let _ = sqlx::query(query)
-        .bind(&auth_key.serialize())
-        .bind(&server_public_key.serialize())
+        .bind(auth_key.serialize())
+        .bind(server_public_key.serialize())
         .bind(statechain_id)
         .execute(&mut *transaction)
         .await
@@ -209,7 +235,8 @@ pub async fn update_statechain(pool: &sqlx::PgPool, auth_key: &XOnlyPublicKey, s
     transaction.commit().await.unwrap();
 }
 
-pub async fn update_unlock_transfer(pool: &sqlx::PgPool, is_current_owner: bool, statechain_id: &str)  {
+/// Returns false if there is no pending transfer of the statecoin
+pub async fn update_unlock_transfer(pool: &sqlx::PgPool, is_current_owner: bool, statechain_id: &str) -> bool {
 
     let locked_field = if is_current_owner { "locked2" } else { "locked" };
 
@@ -217,12 +244,15 @@ pub async fn update_unlock_transfer(pool: &sqlx::PgPool, is_current_owner: bool,
         SET {} = false, updated_at = NOW() \
         WHERE statechain_id = $1", locked_field);
 
-    let _ = sqlx::query(&query)
+    let result = sqlx::query(&query)
         .bind(statechain_id)
         .execute(pool)
         .await
         .unwrap();
 
+    if result.rows_affected() == 0 {
+        return false;
+    }
 
         let query = "SELECT locked, locked2, batch_id \
             FROM statechain_transfer \
@@ -256,6 +286,81 @@ pub async fn update_unlock_transfer(pool: &sqlx::PgPool, is_current_owner: bool,
         
         }
 
-    
+    true
+}
+
+/// Issues a challenge to the auth keys. Previous challenges of the keys remain valid until they are used or expire.
+pub async fn insert_auth_challenge(pool: &sqlx::PgPool, auth_keys: &[PublicKey], challenge: &str, expires_at: &DateTime<Utc>) {
+
+    let mut transaction = pool.begin().await.unwrap();
+
+    let query = "DELETE FROM auth_challenge WHERE expires_at < NOW()";
+
+    let _ = sqlx::query(query)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    let query = "INSERT INTO auth_challenge (auth_public_key, challenge, expires_at) VALUES ($1, $2, $3)";
+
+    for auth_key in auth_keys {
+        let _ = sqlx::query(query)
+            .bind(auth_key.serialize())
+            .bind(challenge)
+            .bind(expires_at)
+            .execute(&mut *transaction)
+            .await
+            .unwrap();
+    }
+
+    transaction.commit().await.unwrap();
+}
+
+/// Deletes the challenge so it cannot be used again. Returns false if it does not exist or is expired.
+pub async fn consume_auth_challenge(pool: &sqlx::PgPool, auth_key: &PublicKey, challenge: &str) -> bool {
+
+    let query = "\
+        DELETE FROM auth_challenge \
+        WHERE auth_public_key = $1 \
+        AND challenge = $2 \
+        AND expires_at > NOW()";
+
+    let result = sqlx::query(query)
+        .bind(auth_key.serialize())
+        .bind(challenge)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    result.rows_affected() > 0
+}
+
+/// Consumes the challenge of every auth key. Returns false, consuming none of them, if any of the keys has no unexpired challenge.
+pub async fn consume_auth_challenge_for_keys(pool: &sqlx::PgPool, auth_keys: &[PublicKey], challenge: &str) -> bool {
+
+    let serialized_auth_keys = auth_keys.iter().map(|auth_key| auth_key.serialize().to_vec()).collect::<Vec<Vec<u8>>>();
+
+    let mut transaction = pool.begin().await.unwrap();
+
+    let query = "\
+        DELETE FROM auth_challenge \
+        WHERE auth_public_key = ANY($1) \
+        AND challenge = $2 \
+        AND expires_at > NOW()";
+
+    let result = sqlx::query(query)
+        .bind(&serialized_auth_keys)
+        .bind(challenge)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    if result.rows_affected() != auth_keys.len() as u64 {
+        transaction.rollback().await.unwrap();
+        return false;
+    }
+
+    transaction.commit().await.unwrap();
 
+    true
 }
@@ -14,7 +14,7 @@ pub async fn exists_msg_for_same_statechain_id_and_new_user_auth_key(pool: &sqlx
     let serialized_new_user_auth_key = new_user_auth_key.serialize();
 
     let row = sqlx::query(&query)
-        .bind(&serialized_new_user_auth_key)
+        .bind(serialized_new_user_auth_key)
         .bind(statechain_id)
         .bind(batch_id)
         .fetch_one(pool)
@@ -50,11 +50,34 @@ pub async fn get_batch_time_by_batch_id(pool: &sqlx::PgPool, batch_id: &str) ->
 }
 
 
+/// Returns the declared size of the batch and the number of coins registered in it, other than the statecoin
+pub async fn get_batch_size_and_count(pool: &sqlx::PgPool, batch_id: &str, statechain_id: &str) -> (Option<u32>, u32) {
+
+    let query = "\
+        SELECT MAX(batch_size), COUNT(*) \
+        FROM statechain_transfer \
+        WHERE batch_id = $1 \
+        AND statechain_id <> $2";
+
+    let row = sqlx::query(query)
+        .bind(batch_id)
+        .bind(statechain_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+    let batch_size: Option<i32> = row.get(0);
+    let count: i64 = row.get(1);
+
+    (batch_size.map(|batch_size| batch_size as u32), count as u32)
+}
+
 pub async fn insert_new_transfer(
     pool: &sqlx::PgPool, 
     new_user_auth_key: &PublicKey, x1: &[u8; 32], 
     statechain_id: &String, 
-    batch_id: &Option<String>)  
+    batch_id: &Option<String>,
+    batch_size: Option<u32>)  
 {
 
     let mut transaction = pool.begin().await.unwrap();
@@ -70,7 +93,7 @@ pub async fn insert_new_transfer(
     let query2 = if batch_id.is_none() {
         "INSERT INTO statechain_transfer (statechain_id, new_user_auth_public_key, x1, locked, locked2) VALUES ($1, $2, $3, $4, $5)"
     } else {
-        "INSERT INTO statechain_transfer (statechain_id, new_user_auth_public_key, x1, batch_id, batch_time, locked, locked2) VALUES ($1, $2, $3, $4, $5, $6, $7)"
+        "INSERT INTO statechain_transfer (statechain_id, new_user_auth_public_key, x1, batch_id, batch_time, locked, locked2, batch_size) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
     };
 
     let ser_new_user_auth_key = new_user_auth_key.serialize();
@@ -90,14 +113,15 @@ pub async fn insert_new_transfer(
             batch_time = Some(Utc::now());
         }
 
-        let sender_auth_key = crate::endpoints::utils::get_auth_key_by_statechain_id(&pool, &statechain_id).await.unwrap();
+        let sender_auth_key = crate::endpoints::utils::get_auth_key_by_statechain_id(pool, statechain_id).await.unwrap();
         let is_lightning_latch = crate::database::lightning_latch::is_lightning_latch(pool, statechain_id, &sender_auth_key, &batch_id).await;
 
         ps_query = ps_query
             .bind(batch_id)
             .bind(batch_time.unwrap())
             .bind(true)
-            .bind(is_lightning_latch);
+            .bind(is_lightning_latch)
+            .bind(batch_size.map(|batch_size| batch_size as i32));
     } else {
         ps_query = ps_query
             .bind(false)
@@ -124,8 +148,92 @@ pub async fn update_transfer_msg(pool: &sqlx::PgPool, new_user_auth_key: &Public
     let _ = sqlx::query(query)
         .bind(enc_transfer_msg)
         .bind(statechain_id)
-        .bind(&new_user_auth_key.serialize())
+        .bind(new_user_auth_key.serialize())
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+/// Returns (key_updated, transfer_msg_sent, batch_id, batch_time) of the pending transfer
+pub async fn get_pending_transfer(pool: &sqlx::PgPool, statechain_id: &str) -> Option<(bool, bool, Option<String>, Option<DateTime<Utc>>)> {
+
+    let query = "\
+        SELECT key_updated, encrypted_transfer_msg IS NOT NULL, batch_id, batch_time \
+        FROM statechain_transfer \
+        WHERE statechain_id = $1";
+
+    let row = sqlx::query(query)
+        .bind(statechain_id)
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    match row {
+        Some(row) => {
+            let key_updated: bool = row.get(0);
+            let transfer_msg_sent: bool = row.get(1);
+            let batch_id: Option<String> = row.get(2);
+            let batch_time: Option<DateTime<Utc>> = row.get(3);
+            Some((key_updated, transfer_msg_sent, batch_id, batch_time))
+        }
+        None => None
+    }
+}
+
+/// Returns false if there was no pending transfer to delete (already completed by the receiver).
+pub async fn delete_pending_transfer(pool: &sqlx::PgPool, statechain_id: &str) -> bool {
+
+    let query = "\
+        DELETE FROM statechain_transfer \
+        WHERE statechain_id = $1 \
+        AND key_updated = false";
+
+    let result = sqlx::query(query)
+        .bind(statechain_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    result.rows_affected() > 0
+}
+
+pub async fn get_batch_transfers(pool: &sqlx::PgPool, batch_id: &str) -> Vec<(String, bool)> {
+
+    let query = "\
+        SELECT statechain_id, key_updated \
+        FROM statechain_transfer \
+        WHERE batch_id = $1";
+
+    let rows = sqlx::query(query)
+        .bind(batch_id)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<(String, bool)>::new();
+
+    for row in rows {
+        let statechain_id: String = row.get(0);
+        let key_updated: bool = row.get(1);
+        result.push((statechain_id, key_updated));
+    }
+
+    result
+}
+
+/// Deletes all the transfers of the batch at once, unless one of them was already completed by its receiver.
+pub async fn delete_batch_transfers(pool: &sqlx::PgPool, batch_id: &str) -> bool {
+
+    let query = "\
+        DELETE FROM statechain_transfer \
+        WHERE batch_id = $1 \
+        AND NOT EXISTS (SELECT 1 FROM statechain_transfer WHERE batch_id = $1 AND key_updated = true)";
+
+    let result = sqlx::query(query)
+        .bind(batch_id)
         .execute(pool)
         .await
         .unwrap();
+
+    result.rows_affected() > 0
 }
\ No newline at end of file
@@ -12,9 +12,7 @@ pub async fn get_enclave_index_from_database(pool: &sqlx::PgPool, statechain_id:
         .await
         .unwrap();
 
-    if row.is_none() {
-        return None;
-    }
+    row.as_ref()?;
 
     let row = row.unwrap();
 
@@ -7,6 +7,9 @@ use serde::{Serialize, Deserialize};
 use serde_json::{Value, json};
 use crate::{server::StateChainEntity, server_config::Enclave};
 
+/// Expiry of the tokens created without payment on test networks (seconds)
+const MOCK_TOKEN_EXPIRY: i64 = 86400;
+
 #[get("/deposit/get_token")]
 pub async fn get_token(statechain_entity: &State<StateChainEntity>) -> status::Custom<Json<Value>>  {
 
@@ -31,7 +34,7 @@ pub async fn get_token(statechain_entity: &State<StateChainEntity>) -> status::C
 
     let response_body = json!(token);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[get("/tokens/token_init")]
@@ -55,7 +58,8 @@ pub async fn token_init(statechain_entity: &State<StateChainEntity>) -> status::
     let token_id = uuid::Uuid::new_v4().to_string();
     let confirmed = false;
     let spent = false;
-    let expiry = String::from("2024-12-26T17:29:50.013Z");
+    let expires_at = chrono::Utc::now() + chrono::Duration::seconds(MOCK_TOKEN_EXPIRY);
+    let expiry = expires_at.to_rfc3339();
 
     crate::database::deposit::insert_new_token(&statechain_entity.pool, &token_id).await;
 
@@ -67,15 +71,16 @@ pub async fn token_init(statechain_entity: &State<StateChainEntity>) -> status::
         token_id,
         confirmed,
         spent,
-        expiry
+        expiry,
+        status: Some(mercurylib::wallet::TokenStatus::PAID),
     };
 
     let response_body = json!(token);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
-fn get_random_enclave_index(statechain_id: &str, enclaves: &Vec<Enclave>) -> Result<usize, String> {
+fn get_random_enclave_index(statechain_id: &str, enclaves: &[Enclave]) -> Result<usize, String> {
     let index_from_statechain_id = get_enclave_index_from_statechain_id(statechain_id, enclaves.len() as u32);
 
     let selected_enclave = enclaves.get(index_from_statechain_id).unwrap();
@@ -99,7 +104,7 @@ fn get_enclave_index_from_statechain_id(statechain_id: &str, enclave_array_len:
     bytes.copy_from_slice(&hash_bytes[..16]);
     let random_number = u128::from_be_bytes(bytes);
 
-    return (random_number % enclave_array_len as u128) as usize;
+    (random_number % enclave_array_len as u128) as usize
 }
 
 #[post("/deposit/init/pod", format = "json", data = "<deposit_msg1>")]
@@ -114,7 +119,7 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
     let msg = Message::from_hashed_data::<sha256::Hash>(token_id.to_string().as_bytes());
 
     let secp = Secp256k1::new();
-    if !secp.verify_schnorr(&signed_token_id, &msg, &auth_key).is_ok() {
+    if secp.verify_schnorr(&signed_token_id, &msg, &auth_key).is_err() {
 
         let response_body = json!({
             "message": "Signature does not match authentication key."
@@ -134,24 +139,66 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
         return status::Custom(Status::BadRequest, Json(response_body));
     }
 
-    let valid_token =  crate::database::deposit::get_token_status(&statechain_entity.pool, &token_id).await;
+    let blind_signature = deposit_msg1.blind_signature.clone();
 
-    if valid_token.is_none() {
-        let response_body = json!({
-            "error": "Deposit Error",
-            "message": "Token ID not found."
-        });
+    if let Some(blind_signature) = &blind_signature {
+
+        let config = crate::server_config::ServerConfig::load();
+
+        // spent blind tokens are recorded by the token server
+        let blind_token_pubkey = match (&config.blind_token_pubkey, &statechain_entity.token_server) {
+            (Some(blind_token_pubkey), Some(_)) => blind_token_pubkey.clone(),
+            _ => {
+                let response_body = json!({
+                    "error": "Deposit Error",
+                    "message": "Blind tokens are not accepted."
+                });
+
+                return status::Custom(Status::BadRequest, Json(response_body));
+            }
+        };
+
+        if !mercurylib::deposit::blind_token::verify_blind_token(&blind_token_pubkey, &token_id, blind_signature) {
+            let response_body = json!({
+                "error": "Deposit Error",
+                "message": "Invalid blind token signature."
+            });
+
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
+    } else {
+        let valid_token = match &statechain_entity.token_server {
+            Some(token_server) => match token_server.verify_token(&token_id).await {
+                Ok(valid_token) => valid_token,
+                Err(err) => {
+                    let response_body = json!({
+                        "error": "Internal Server Error",
+                        "message": err
+                    });
+
+                    return status::Custom(Status::InternalServerError, Json(response_body));
+                }
+            },
+            None => crate::database::deposit::get_token_status(&statechain_entity.pool, &token_id).await,
+        };
+
+        if valid_token.is_none() {
+            let response_body = json!({
+                "error": "Deposit Error",
+                "message": "Token ID not found."
+            });
     
-        return status::Custom(Status::NotFound, Json(response_body));
-    }
+            return status::Custom(Status::NotFound, Json(response_body));
+        }
 
-    if !valid_token.unwrap() {
-        let response_body = json!({
-            "error": "Deposit Error",
-            "message": "Token unpaid or used."
-        });
+        if !valid_token.unwrap() {
+            let response_body = json!({
+                "error": "Deposit Error",
+                "message": "Token unpaid or used."
+            });
     
-        return status::Custom(Status::Gone, Json(response_body));
+            return status::Custom(Status::Gone, Json(response_body));
+        }
     }
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -169,7 +216,7 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
     let path = "get_public_key";
 
     let client: reqwest::Client = reqwest::Client::new();
-    let request = client.post(&format!("{}/{}", lockbox_endpoint, path));
+    let request = client.post(format!("{}/{}", lockbox_endpoint, path));
 
     let payload = GetPublicKeyRequestPayload {
         statechain_id: statechain_id.clone(),
@@ -177,8 +224,8 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
 
     let value = match request.json(&payload).send().await {
         Ok(response) => {
-            let text = response.text().await.unwrap();
-            text
+            
+            response.text().await.unwrap()
         },
         Err(err) => {
             let response_body = json!({
@@ -195,7 +242,7 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
         server_pubkey: &'r str,
     }
 
-    let response: PublicNonceRequestPayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: PublicNonceRequestPayload = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
 
     let mut server_pubkey_hex = response.server_pubkey.to_string();
 
@@ -205,9 +252,39 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
 
     let server_pubkey = PublicKey::from_str(&server_pubkey_hex).unwrap();
 
-    crate::database::deposit::insert_new_deposit(&statechain_entity.pool, &token_id, &auth_key, &server_pubkey, &statechain_id, enclave_index as i32).await;
+    // the token is spent before the deposit is stored, so it cannot fund two statecoins
+    match &statechain_entity.token_server {
+        Some(token_server) => {
+            let spent = if blind_signature.is_some() {
+                token_server.spend_blind_token(&token_id, &statechain_id).await
+            } else {
+                token_server.spend_token(&token_id, &statechain_id).await
+            };
+
+            match spent {
+                Ok(true) => {},
+                Ok(false) => {
+                    let response_body = json!({
+                        "error": "Deposit Error",
+                        "message": "Token unpaid or used."
+                    });
+
+                    return status::Custom(Status::Gone, Json(response_body));
+                },
+                Err(err) => {
+                    let response_body = json!({
+                        "error": "Internal Server Error",
+                        "message": err
+                    });
+
+                    return status::Custom(Status::InternalServerError, Json(response_body));
+                }
+            }
+        },
+        None => crate::database::deposit::set_token_spent(&statechain_entity.pool, &token_id).await,
+    }
 
-    crate::database::deposit::set_token_spent(&statechain_entity.pool, &token_id).await;
+    crate::database::deposit::insert_new_deposit(&statechain_entity.pool, &token_id, &auth_key, &server_pubkey, &statechain_id, enclave_index as i32).await;
 
     let deposit_msg1_response = mercurylib::deposit::DepositMsg1Response {
         server_pubkey: server_pubkey.to_string(),
@@ -1,7 +1,8 @@
 use std::str::FromStr;
 
 use chrono::Duration;
-use mercurylib::transfer::sender::{PaymentHashRequestPayload, PaymentHashResponsePayload, TransferPreimageRequestPayload, TransferPreimageResponsePayload};
+use mercurylib::transfer::sender::{LatchReclaimRequestPayload, PaymentHashRequestPayload, PaymentHashResponsePayload, TransferPreimageRequestPayload, TransferPreimageResponsePayload};
+use log::info;
 use rand::Rng;
 use rocket::{State, serde::json::Json, response::status, http::Status};
 use secp256k1_zkp::PublicKey;
@@ -11,6 +12,33 @@ use sha2::{Sha256, Digest};
 
 use crate::server::StateChainEntity;
 
+/// Interval in seconds between checks for expired latches
+const LATCH_EXPIRY_CHECK_INTERVAL: u64 = 60;
+
+/// If a hold invoice node is configured and the statecoin is latched in the batch,
+/// checks that the invoice for the latch payment hash has been paid.
+pub async fn validate_latch_payment(statechain_entity: &StateChainEntity, statechain_id: &str, batch_id: &str) -> Result<(), String> {
+
+    let hold_invoice_node = match &statechain_entity.hold_invoice_node {
+        Some(node) => node,
+        None => return Ok(()),
+    };
+
+    let pre_image = crate::database::lightning_latch::get_latch_preimage(&statechain_entity.pool, statechain_id, batch_id).await;
+
+    if pre_image.is_none() {
+        return Ok(());
+    }
+
+    let buffer = hex::decode(pre_image.unwrap()).unwrap();
+
+    let mut hasher = Sha256::new();
+    hasher.update(buffer);
+    let payment_hash = hex::encode(hasher.finalize());
+
+    crate::lightning::verify_hold_invoice(hold_invoice_node.as_ref(), &payment_hash).await
+}
+
 #[get("/transfer/paymenthash/<batch_id>")]
 pub async fn get_paymenthash(statechain_entity: &State<StateChainEntity>, batch_id: &str) -> status::Custom<Json<Value>> {
 
@@ -24,7 +52,7 @@ pub async fn get_paymenthash(statechain_entity: &State<StateChainEntity>, batch_
         return status::Custom(Status::NotFound, Json(response_body));
     }
 
-    let pre_image = pre_image.unwrap();
+    let (pre_image, expires_at) = pre_image.unwrap();
     let buffer = hex::decode(pre_image).unwrap();
 
     let mut hasher = Sha256::new();
@@ -35,11 +63,12 @@ pub async fn get_paymenthash(statechain_entity: &State<StateChainEntity>, batch_
 
     let payment_hash_response_payload = PaymentHashResponsePayload {
         hash: payment_hash,
+        expires_at: expires_at.to_rfc3339(),
     };
 
     let response_body = json!(payment_hash_response_payload);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[post("/transfer/paymenthash", format = "json", data = "<payment_hash_payload>")]
@@ -61,10 +90,18 @@ pub async fn post_paymenthash(statechain_entity: &State<StateChainEntity>, payme
     let sender_auth_key = super::utils::get_auth_key_by_statechain_id(&statechain_entity.pool, &statechain_id).await.unwrap();
 
     let buffer = rand::thread_rng().gen::<[u8; 32]>();
-    let pre_image = hex::encode(buffer.clone());
+    let pre_image = hex::encode(buffer);
+
+    let config = crate::server_config::ServerConfig::load();
+
+    // the requested expiry is clamped to the server limits
+    let expiry_secs = payment_hash_payload.0.expiry_secs
+        .unwrap_or(config.latch_expiry_default)
+        .max(config.latch_expiry_min)
+        .min(config.latch_expiry_max);
 
     let now = chrono::Utc::now();
-    let expiry_time = Duration::seconds(90000); // 25h
+    let expiry_time = Duration::seconds(expiry_secs as i64);
     let expires_at = now + expiry_time;
 
     crate::database::lightning_latch::insert_paymenthash(&statechain_entity.pool, &statechain_id, &sender_auth_key, &batch_id, &pre_image, &expires_at).await;
@@ -77,11 +114,12 @@ pub async fn post_paymenthash(statechain_entity: &State<StateChainEntity>, payme
 
     let payment_hash_response_payload = PaymentHashResponsePayload {
         hash: payment_hash,
+        expires_at: expires_at.to_rfc3339(),
     };
 
     let response_body = json!(payment_hash_response_payload);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
     
 }
 
@@ -119,10 +157,114 @@ pub async fn transfer_preimage(statechain_entity: &State<StateChainEntity>, tran
 
     let pre_image = pre_image.unwrap();
 
+    if let Err(message) = validate_latch_payment(statechain_entity, &statechain_id, &batch_id).await {
+        let response_body = json!({
+            "message": message
+        });
+
+        return status::Custom(Status::PaymentRequired, Json(response_body));
+    }
+
     let response_body = json!(TransferPreimageResponsePayload {
         preimage: pre_image
     });
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
+
+}
+
+
+#[post("/transfer/reclaim", format = "json", data = "<latch_reclaim_request_payload>")]
+pub async fn reclaim_latch(statechain_entity: &State<StateChainEntity>, latch_reclaim_request_payload: Json<LatchReclaimRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let statechain_id = latch_reclaim_request_payload.0.statechain_id.clone();
+    let signed_statechain_id = latch_reclaim_request_payload.0.auth_sig.clone();
+    let batch_id = latch_reclaim_request_payload.0.batch_id.clone();
+
+    let sender_auth_key = match super::utils::get_auth_key_by_statechain_id(&statechain_entity.pool, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(_) => {
+            let response_body = json!({
+                "message": "Statechain not found"
+            });
+
+            return status::Custom(Status::NotFound, Json(response_body));
+        }
+    };
+
+    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+
+        let response_body = json!({
+            "message": "Signature does not match authentication key."
+        });
+
+        return status::Custom(Status::Forbidden, Json(response_body));
+    }
+
+    let latch_status = crate::database::lightning_latch::get_latch_status(&statechain_entity.pool, &statechain_id, &sender_auth_key, &batch_id).await;
+
+    let (locked, expired, expires_at) = match latch_status {
+        Some(latch_status) => latch_status,
+        None => {
+            let response_body = json!({
+                "message": "Lightning latch not found"
+            });
+
+            return status::Custom(Status::NotFound, Json(response_body));
+        }
+    };
+
+    if !expired {
+
+        if !locked {
+            let response_body = json!({
+                "message": "The pre-image has already been released."
+            });
+
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
 
+        if expires_at > chrono::Utc::now() {
+            let response_body = json!({
+                "message": format!("Lightning latch has not expired yet. It expires at {}.", expires_at.to_rfc3339())
+            });
+
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
+
+        // the periodic expiry task has not processed this latch yet
+        crate::database::lightning_latch::expire_latch(&statechain_entity.pool, &statechain_id, &batch_id).await;
+    }
+
+    let pending_transfer = crate::database::transfer_sender::get_pending_transfer(&statechain_entity.pool, &statechain_id).await;
+
+    let transfer_msg_sent = pending_transfer.is_some_and(|(key_updated, transfer_msg_sent, _, _)| !key_updated && transfer_msg_sent);
+
+    let message = if transfer_msg_sent {
+        "Latch expired. The transfer message was already sent: send the coin again to replace the receiver's backup transaction."
+    } else {
+        "Latch expired. Transfer discarded."
+    };
+
+    let response_body = json!({
+        "message": message,
+        "transfer_msg_sent": transfer_msg_sent,
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
 }
+
+/// Periodically expires the latches that were not paid in time
+pub async fn expire_latches(pool: sqlx::PgPool) {
+
+    loop {
+        let expired_latches = crate::database::lightning_latch::get_unpaid_expired_latches(&pool).await;
+
+        for (statechain_id, batch_id) in expired_latches {
+            let transfer_discarded = crate::database::lightning_latch::expire_latch(&pool, &statechain_id, &batch_id).await;
+            info!("Lightning latch expired unpaid. Statechain {} in batch {}. Transfer discarded: {}.", statechain_id, batch_id, transfer_discarded);
+        }
+
+        rocket::tokio::time::sleep(std::time::Duration::from_secs(LATCH_EXPIRY_CHECK_INTERVAL)).await;
+    }
+}
\ No newline at end of file
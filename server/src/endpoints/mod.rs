@@ -7,6 +7,9 @@ pub mod transfer_sender;
 pub mod transfer_receiver;
 pub mod withdraw;
 pub mod lightning_latch;
+pub mod oracle_latch;
+pub mod transfer_notification;
+pub mod recovery;
 
 fn is_batch_expired(batch_time: DateTime<Utc>) -> bool {
 
@@ -18,5 +21,5 @@ fn is_batch_expired(batch_time: DateTime<Utc>) -> bool {
 
     let now = chrono::Utc::now();
 
-    return now > expiration_time
+    now > expiration_time
 }
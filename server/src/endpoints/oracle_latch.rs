@@ -0,0 +1,292 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use mercurylib::transfer::sender::{verify_oracle_attestation, ConditionalLatchRequestPayload, ConditionalLatchResponsePayload, OracleAttestationRequestPayload};
+use log::info;
+use rand::Rng;
+use rocket::{State, serde::json::Json, response::status, http::Status};
+use secp256k1_zkp::XOnlyPublicKey;
+use serde_json::{json, Value};
+
+use crate::{database::lightning_latch::OracleLatch, server::StateChainEntity};
+
+/// Effect of an oracle attestation on a conditional transfer
+#[derive(Debug, PartialEq)]
+pub enum AttestationResult {
+    /// The oracle attested the expected outcome. The coin is released to the receiver.
+    ConditionMet,
+    /// The oracle attested another outcome. The transfer will not complete.
+    ConditionFailed,
+    /// The signature was not made by the oracle of the latch for its event
+    InvalidSignature,
+    /// An attestation was already recorded for the latch
+    AlreadyAttested,
+    /// The latch expired before the attestation was submitted
+    Expired,
+}
+
+pub fn check_attestation(oracle_latch: &OracleLatch, outcome: &str, signature: &str, now: &DateTime<Utc>) -> AttestationResult {
+
+    if oracle_latch.attested_outcome.is_some() {
+        return AttestationResult::AlreadyAttested;
+    }
+
+    if oracle_latch.expired || oracle_latch.expires_at < *now {
+        return AttestationResult::Expired;
+    }
+
+    if !verify_oracle_attestation(&oracle_latch.oracle_pub_key.to_string(), &oracle_latch.event_id, outcome, signature) {
+        return AttestationResult::InvalidSignature;
+    }
+
+    if outcome == oracle_latch.outcome {
+        AttestationResult::ConditionMet
+    } else {
+        AttestationResult::ConditionFailed
+    }
+}
+
+#[post("/transfer/conditional", format = "json", data = "<conditional_latch_payload>")]
+pub async fn post_conditional_latch(statechain_entity: &State<StateChainEntity>, conditional_latch_payload: Json<ConditionalLatchRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let statechain_id = conditional_latch_payload.0.statechain_id.clone();
+    let signed_statechain_id = conditional_latch_payload.0.auth_sig.clone();
+    let batch_id = conditional_latch_payload.0.batch_id.clone();
+    let event_id = conditional_latch_payload.0.event_id.clone();
+    let outcome = conditional_latch_payload.0.outcome.clone();
+
+    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+
+        let response_body = json!({
+            "message": "Signature does not match authentication key."
+        });
+
+        return status::Custom(Status::Forbidden, Json(response_body));
+    }
+
+    let oracle_pub_key = match XOnlyPublicKey::from_str(&conditional_latch_payload.0.oracle_pub_key) {
+        Ok(key) => key,
+        Err(_) => {
+            let response_body = json!({
+                "message": "Invalid oracle public key."
+            });
+
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
+    };
+
+    if event_id.is_empty() {
+        let response_body = json!({
+            "message": "The oracle event id is required."
+        });
+
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let sender_auth_key = super::utils::get_auth_key_by_statechain_id(&statechain_entity.pool, &statechain_id).await.unwrap();
+
+    // the pre-image is not used to release the coin, but keeps the latch compatible with the lightning endpoints
+    let buffer = rand::thread_rng().gen::<[u8; 32]>();
+    let pre_image = hex::encode(buffer);
+
+    let config = crate::server_config::ServerConfig::load();
+
+    let expiry_secs = conditional_latch_payload.0.expiry_secs
+        .unwrap_or(config.latch_expiry_default)
+        .max(config.latch_expiry_min)
+        .min(config.latch_expiry_max);
+
+    let expires_at = chrono::Utc::now() + Duration::seconds(expiry_secs as i64);
+
+    crate::database::lightning_latch::insert_conditional_latch(&statechain_entity.pool, &statechain_id, &sender_auth_key, &batch_id, &pre_image, &expires_at, &oracle_pub_key, &event_id, &outcome).await;
+
+    let response_body = json!(ConditionalLatchResponsePayload {
+        expires_at: expires_at.to_rfc3339(),
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[post("/transfer/attestation", format = "json", data = "<oracle_attestation_payload>")]
+pub async fn post_attestation(statechain_entity: &State<StateChainEntity>, oracle_attestation_payload: Json<OracleAttestationRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let statechain_id = oracle_attestation_payload.0.statechain_id.clone();
+    let batch_id = oracle_attestation_payload.0.batch_id.clone();
+    let outcome = oracle_attestation_payload.0.outcome.clone();
+    let signature = oracle_attestation_payload.0.signature.clone();
+
+    let oracle_latch = crate::database::lightning_latch::get_oracle_latch(&statechain_entity.pool, &statechain_id, &batch_id).await;
+
+    if oracle_latch.is_none() {
+        let response_body = json!({
+            "message": "Conditional transfer not found"
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    let oracle_latch = oracle_latch.unwrap();
+
+    let result = check_attestation(&oracle_latch, &outcome, &signature, &Utc::now());
+
+    let (status, message) = match result {
+        AttestationResult::AlreadyAttested => (Status::BadRequest, "An attestation has already been submitted for this conditional transfer."),
+        AttestationResult::Expired => (Status::BadRequest, "Conditional transfer has expired."),
+        AttestationResult::InvalidSignature => (Status::Forbidden, "Signature does not match the oracle public key and event."),
+        AttestationResult::ConditionMet | AttestationResult::ConditionFailed => (Status::Ok, ""),
+    };
+
+    if status != Status::Ok {
+        let response_body = json!({
+            "message": message
+        });
+
+        return status::Custom(status, Json(response_body));
+    }
+
+    let batch_info = crate::database::transfer::get_batch_id_and_time_by_statechain_id(&statechain_entity.pool, &statechain_id).await;
+
+    if result == AttestationResult::ConditionMet && !batch_info.is_some_and(|(transfer_batch_id, _)| transfer_batch_id == batch_id) {
+        let response_body = json!({
+            "message": "Transfer not found."
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    // concurrent submissions race here: only the first one is recorded
+    if !crate::database::lightning_latch::insert_attestation(&statechain_entity.pool, &statechain_id, &batch_id, &outcome, &signature).await {
+        let response_body = json!({
+            "message": "An attestation has already been submitted for this conditional transfer."
+        });
+
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let message = if result == AttestationResult::ConditionMet {
+        // the attestation replaces the unlock signature of the sender
+        crate::database::transfer_receiver::update_unlock_transfer(&statechain_entity.pool, true, &statechain_id).await;
+        crate::endpoints::transfer_receiver::notify_if_batch_unlocked(statechain_entity, &statechain_id).await;
+        "Condition met. Coin released to the receiver."
+    } else if crate::database::lightning_latch::expire_latch(&statechain_entity.pool, &statechain_id, &batch_id).await {
+        "Condition failed. Transfer discarded."
+    } else {
+        "Condition failed. The transfer will not complete; the sender must send the coin again to replace the receiver's backup transaction."
+    };
+
+    info!("Oracle attested outcome {} for statechain {} in batch {}. {}", outcome, statechain_id, batch_id, message);
+
+    let response_body = json!({
+        "message": message
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[cfg(test)]
+mod tests {
+    use mercurylib::transfer::sender::sign_oracle_attestation;
+    use secp256k1_zkp::{rand::thread_rng, KeyPair, Secp256k1};
+
+    use super::*;
+
+    const EVENT_ID: &str = "match_2026_10_19";
+
+    fn local_oracle() -> (String, XOnlyPublicKey) {
+        let secp = Secp256k1::new();
+        let keypair = KeyPair::new(&secp, &mut thread_rng());
+        (hex::encode(keypair.secret_bytes()), keypair.x_only_public_key().0)
+    }
+
+    fn oracle_latch(oracle_pub_key: XOnlyPublicKey, outcome: &str) -> OracleLatch {
+        OracleLatch {
+            oracle_pub_key,
+            event_id: EVENT_ID.to_string(),
+            outcome: outcome.to_string(),
+            attested_outcome: None,
+            expired: false,
+            expires_at: Utc::now() + Duration::seconds(3600),
+        }
+    }
+
+    #[test]
+    fn attestation_of_expected_outcome_meets_condition() {
+        let (oracle_seckey, oracle_pub_key) = local_oracle();
+        let signature = sign_oracle_attestation(EVENT_ID, "team_a_wins", &oracle_seckey).unwrap();
+
+        let latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::ConditionMet);
+    }
+
+    #[test]
+    fn attestation_of_other_outcome_fails_condition() {
+        let (oracle_seckey, oracle_pub_key) = local_oracle();
+        let signature = sign_oracle_attestation(EVENT_ID, "team_b_wins", &oracle_seckey).unwrap();
+
+        let latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        assert_eq!(check_attestation(&latch, "team_b_wins", &signature, &Utc::now()), AttestationResult::ConditionFailed);
+    }
+
+    #[test]
+    fn forged_attestations_are_rejected() {
+        let (_, oracle_pub_key) = local_oracle();
+        let (other_seckey, _) = local_oracle();
+
+        // signed by a key other than the oracle
+        let signature = sign_oracle_attestation(EVENT_ID, "team_a_wins", &other_seckey).unwrap();
+        let latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::InvalidSignature);
+
+        // signature of the oracle for another outcome
+        let (oracle_seckey, oracle_pub_key) = local_oracle();
+        let signature = sign_oracle_attestation(EVENT_ID, "team_b_wins", &oracle_seckey).unwrap();
+        let latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::InvalidSignature);
+
+        assert_eq!(check_attestation(&latch, "team_a_wins", "not a signature", &Utc::now()), AttestationResult::InvalidSignature);
+    }
+
+    #[test]
+    fn attestations_of_other_events_cannot_be_replayed() {
+        let (oracle_seckey, oracle_pub_key) = local_oracle();
+
+        // the oracle attested the same outcome for a previous event
+        let signature = sign_oracle_attestation("match_2026_10_12", "team_a_wins", &oracle_seckey).unwrap();
+
+        let latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::InvalidSignature);
+
+        // the event id and the outcome cannot be shifted into each other
+        let signature = sign_oracle_attestation("match", "_2026_10_19team_a_wins", &oracle_seckey).unwrap();
+        let mut latch = oracle_latch(oracle_pub_key, "_2026_10_19team_a_wins");
+        latch.event_id = "match_2026_10_19".to_string();
+        latch.outcome = "team_a_wins".to_string();
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::InvalidSignature);
+    }
+
+    #[test]
+    fn attested_latches_are_not_attested_again() {
+        let (oracle_seckey, oracle_pub_key) = local_oracle();
+
+        let mut latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        latch.attested_outcome = Some("team_b_wins".to_string());
+
+        // the valid attestation of the expected outcome does not override the recorded one
+        let signature = sign_oracle_attestation(EVENT_ID, "team_a_wins", &oracle_seckey).unwrap();
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::AlreadyAttested);
+    }
+
+    #[test]
+    fn expired_latches_are_not_attested() {
+        let (oracle_seckey, oracle_pub_key) = local_oracle();
+        let signature = sign_oracle_attestation(EVENT_ID, "team_a_wins", &oracle_seckey).unwrap();
+
+        let mut latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        latch.expired = true;
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::Expired);
+
+        let mut latch = oracle_latch(oracle_pub_key, "team_a_wins");
+        latch.expires_at = Utc::now() - Duration::seconds(1);
+        assert_eq!(check_attestation(&latch, "team_a_wins", &signature, &Utc::now()), AttestationResult::Expired);
+    }
+}
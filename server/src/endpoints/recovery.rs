@@ -0,0 +1,54 @@
+use mercurylib::recovery::{RecoverStatecoinsRequestPayload, MAX_RECOVERY_AUTH_KEYS, RecoverStatecoinsResponsePayload, RecoveredStatecoin};
+use rocket::{State, response::status, serde::json::Json, http::Status};
+use serde_json::{Value, json};
+
+use crate::server::StateChainEntity;
+
+#[post("/recovery/statecoins", format = "json", data = "<recover_statecoins_request_payload>")]
+pub async fn recover_statecoins(statechain_entity: &State<StateChainEntity>, recover_statecoins_request_payload: Json<RecoverStatecoinsRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let challenge = recover_statecoins_request_payload.0.challenge;
+    let auth_key_proofs = recover_statecoins_request_payload.0.auth_key_proofs;
+
+    if auth_key_proofs.len() > MAX_RECOVERY_AUTH_KEYS {
+        let response_body = json!({
+            "message": format!("Too many authentication keys. At most {} keys can be recovered per request.", MAX_RECOVERY_AUTH_KEYS)
+        });
+
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let auth_keys = match crate::endpoints::utils::validate_auth_key_proofs(&statechain_entity.pool, &auth_key_proofs, &challenge).await {
+        Ok(auth_keys) => auth_keys,
+        Err(message) => {
+            let response_body = json!({
+                "message": message
+            });
+
+            return status::Custom(Status::Forbidden, Json(response_body));
+        }
+    };
+
+    // the statechain data stores the x-only auth key
+    let auth_xonly_keys: Vec<_> = auth_keys.iter().map(|auth_key| auth_key.x_only_public_key().0).collect();
+
+    let result = crate::database::recovery::get_statecoins_by_auth_keys(&statechain_entity.pool, &auth_xonly_keys).await;
+
+    let mut statecoins = Vec::<RecoveredStatecoin>::new();
+
+    for (auth_xonly_key, statechain_id, server_public_key, encrypted_transfer_msg) in result {
+
+        let auth_key = auth_keys.iter().find(|auth_key| auth_key.x_only_public_key().0 == auth_xonly_key).unwrap();
+
+        statecoins.push(RecoveredStatecoin {
+            auth_pub_key: auth_key.to_string(),
+            statechain_id,
+            server_pubkey: server_public_key.to_string(),
+            enc_transfer_msg: encrypted_transfer_msg.map(hex::encode),
+        });
+    }
+
+    let response_body = json!(RecoverStatecoinsResponsePayload { statecoins });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
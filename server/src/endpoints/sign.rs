@@ -35,7 +35,7 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
     let path = "get_public_nonce";
 
     let client: reqwest::Client = reqwest::Client::new();
-    let request = client.post(&format!("{}/{}", lockbox_endpoint, path));
+    let request = client.post(format!("{}/{}", lockbox_endpoint, path));
 
     let signed_statechain_id = sign_first_request_payload.0.signed_statechain_id.clone();
 
@@ -65,8 +65,8 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
 
     let value = match request.json(&sign_first_request_payload.0).send().await {
         Ok(response) => {
-            let text = response.text().await.unwrap();
-            text
+            
+            response.text().await.unwrap()
         },
         Err(err) => {
             let response_body = json!({
@@ -78,7 +78,7 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
         },
     };
 
-    let response: mercurylib::transaction::SignFirstResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: mercurylib::transaction::SignFirstResponsePayload = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
 
     let mut server_pubnonce_hex = response.server_pubnonce.clone();
 
@@ -90,7 +90,7 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
 
     let response_body = json!(response);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[post("/sign/second", format = "json", data = "<partial_signature_request_payload>")]
@@ -121,7 +121,7 @@ pub async fn sign_second (statechain_entity: &State<StateChainEntity>, partial_s
     let path = "get_partial_signature";
 
     let client: reqwest::Client = reqwest::Client::new();
-    let request = client.post(&format!("{}/{}", lockbox_endpoint, path));
+    let request = client.post(format!("{}/{}", lockbox_endpoint, path));
 
     let signed_statechain_id = partial_signature_request_payload.0.signed_statechain_id.clone();
 
@@ -147,8 +147,8 @@ pub async fn sign_second (statechain_entity: &State<StateChainEntity>, partial_s
 
     let value = match request.json(&partial_signature_request_payload).send().await {
         Ok(response) => {
-            let text = response.text().await.unwrap();
-            text
+            
+            response.text().await.unwrap()
         },
         Err(err) => {
             let response_body = json!({
@@ -165,10 +165,10 @@ pub async fn sign_second (statechain_entity: &State<StateChainEntity>, partial_s
         partial_sig: &'r str,
     }
 
-    let response: PartialSignatureResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: PartialSignatureResponsePayload = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
 
     let response_body = json!(response);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
    
\ No newline at end of file
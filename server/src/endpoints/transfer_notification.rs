@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use mercurylib::transfer::receiver::{TransferNotificationPayload, TransferNotificationRequestPayload, TransferNotificationType};
+use rocket::{State, Shutdown, serde::json::Json, response::{status, stream::{Event, EventStream}}, http::Status};
+use rocket::tokio::{select, sync::broadcast::error::RecvError};
+use secp256k1_zkp::PublicKey;
+use serde_json::{Value, json};
+
+use crate::server::StateChainEntity;
+
+pub fn notify(statechain_entity: &StateChainEntity, notification_type: TransferNotificationType, statechain_id: &str, auth_pub_key: &PublicKey) {
+
+    let notification = TransferNotificationPayload {
+        notification_type,
+        statechain_id: statechain_id.to_string(),
+        auth_pub_key: auth_pub_key.to_string(),
+    };
+
+    // an error here only means there are no subscribers at the moment
+    let _ = statechain_entity.transfer_notification_sender.send(notification);
+}
+
+#[post("/transfer/notifications", format = "json", data = "<transfer_notification_request_payload>")]
+pub async fn transfer_notifications(statechain_entity: &State<StateChainEntity>, transfer_notification_request_payload: Json<TransferNotificationRequestPayload>, mut shutdown: Shutdown) -> Result<EventStream![], status::Custom<Json<Value>>> {
+
+    let challenge = transfer_notification_request_payload.0.challenge;
+    let auth_key_proofs = transfer_notification_request_payload.0.auth_key_proofs;
+
+    if auth_key_proofs.is_empty() {
+
+        let response_body = json!({
+            "message": "No authentication keys provided."
+        });
+
+        return Err(status::Custom(Status::BadRequest, Json(response_body)));
+    }
+
+    let auth_keys = match crate::endpoints::utils::validate_auth_key_proofs(&statechain_entity.pool, &auth_key_proofs, &challenge).await {
+        Ok(auth_keys) => auth_keys.iter().map(|auth_key| auth_key.to_string()).collect::<HashSet<String>>(),
+        Err(message) => {
+            let response_body = json!({
+                "message": message
+            });
+
+            return Err(status::Custom(Status::Forbidden, Json(response_body)));
+        }
+    };
+
+    let mut receiver = statechain_entity.transfer_notification_sender.subscribe();
+
+    Ok(EventStream! {
+        loop {
+            let notification = select! {
+                notification = receiver.recv() => match notification {
+                    Ok(notification) => notification,
+                    Err(RecvError::Closed) => break,
+                    Err(RecvError::Lagged(_)) => continue,
+                },
+                _ = &mut shutdown => break,
+            };
+
+            if auth_keys.contains(&notification.auth_pub_key) {
+                yield Event::json(&notification);
+            }
+        }
+    })
+}
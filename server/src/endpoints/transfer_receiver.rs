@@ -1,7 +1,8 @@
-use std::str::FromStr;
+use std::{collections::HashMap, net::{IpAddr, Ipv4Addr}, str::FromStr};
 
 use bitcoin::hashes::sha256;
-use mercurylib::transfer::receiver::{GetMsgAddrResponsePayload, StatechainInfoResponsePayload, TransferReceiverError, TransferReceiverErrorResponsePayload, TransferReceiverPostResponsePayload, TransferReceiverRequestPayload, TransferUnlockRequestPayload};
+use mercurylib::transfer::receiver::{auth_challenge_message, AuthChallengeRequestPayload, AuthChallengeResponsePayload, GetMsgAddrBulkRequestPayload, GetMsgAddrBulkResponsePayload, GetMsgAddrResponsePayload, TransferNotificationType, StatechainInfoResponsePayload, TransferReceiverError, TransferReceiverErrorResponsePayload, TransferReceiverPostResponsePayload, TransferReceiverRequestPayload, TransferUnlockRequestPayload, MAX_MSG_ADDR_BULK_AUTH_KEYS};
+use rand::RngCore;
 use rocket::{State, response::status, serde::json::Json, http::Status};
 use secp256k1_zkp::{PublicKey, schnorr::Signature, Message, Secp256k1};
 use serde_json::{Value, json};
@@ -13,7 +14,7 @@ use super::is_batch_expired;
 #[get("/info/statechain/<statechain_id>")]
 pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statechain_id: &str) -> status::Custom<Json<Value>> {
 
-    let enclave_public_key = crate::database::transfer_receiver::get_enclave_pubkey(&statechain_entity.pool, &statechain_id).await;
+    let enclave_public_key = crate::database::transfer_receiver::get_enclave_pubkey(&statechain_entity.pool, statechain_id).await;
 
     if enclave_public_key.is_none() {
         let response_body = json!({
@@ -27,7 +28,7 @@ pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statec
 
     let config = crate::server_config::ServerConfig::load();
 
-    let enclave_index = crate::database::utils::get_enclave_index_from_database(&statechain_entity.pool, &statechain_id).await;
+    let enclave_index = crate::database::utils::get_enclave_index_from_database(&statechain_entity.pool, statechain_id).await;
 
     let enclave_index = match enclave_index {
         Some(index) => index,
@@ -46,12 +47,12 @@ pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statec
     let path = "signature_count";
 
     let client: reqwest::Client = reqwest::Client::new();
-    let request = client.get(&format!("{}/{}/{}", lockbox_endpoint, path, statechain_id));
+    let request = client.get(format!("{}/{}/{}", lockbox_endpoint, path, statechain_id));
 
     let value = match request.send().await {
         Ok(response) => {
-            let text = response.text().await.unwrap();
-            text
+            
+            response.text().await.unwrap()
         },
         Err(err) => {
             let response_body = json!({
@@ -63,12 +64,12 @@ pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statec
         },
     };
 
-    let response: Value = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: Value = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
     let num_sigs = response["sig_count"].as_u64().unwrap();
 
-    let statechain_info = crate::database::transfer_receiver::get_statechain_info(&statechain_entity.pool, &statechain_id).await;
+    let statechain_info = crate::database::transfer_receiver::get_statechain_info(&statechain_entity.pool, statechain_id).await;
 
-    let x1_pubkey = crate::database::transfer_receiver::get_x1pub(&statechain_entity.pool, &statechain_id).await;
+    let x1_pubkey = crate::database::transfer_receiver::get_x1pub(&statechain_entity.pool, statechain_id).await;
 
     let mut x1_pub: Option<String> = None;
 
@@ -85,12 +86,73 @@ pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statec
     
     let response_body = json!(statechain_info_response_payload);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
     
 }
 
-#[get("/transfer/get_msg_addr/<new_auth_key>")]
-pub async fn get_msg_addr(statechain_entity: &State<StateChainEntity>, new_auth_key: &str) -> status::Custom<Json<Value>>  {
+/// Number of seconds an auth challenge can be used after being issued
+const AUTH_CHALLENGE_EXPIRY: i64 = 300;
+
+#[post("/transfer/challenge", format = "json", data = "<auth_challenge_request_payload>")]
+pub async fn auth_challenge(statechain_entity: &State<StateChainEntity>, client_ip: Option<IpAddr>, auth_challenge_request_payload: Json<AuthChallengeRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    // clients whose address is unknown share the same limit
+    let client_ip = client_ip.unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+    if !statechain_entity.auth_challenge_rate_limiter.check(client_ip) {
+        let response_body = json!({
+            "message": "Too many challenge requests. Try again later."
+        });
+
+        return status::Custom(Status::TooManyRequests, Json(response_body));
+    }
+
+    let mut auth_keys = Vec::<PublicKey>::new();
+
+    for auth_pub_key in auth_challenge_request_payload.0.auth_pub_keys.iter() {
+
+        let auth_key = PublicKey::from_str(auth_pub_key);
+
+        if auth_key.is_err() {
+            let response_body = json!({
+                "message": "Invalid authentication public key"
+            });
+
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
+
+        let auth_key = auth_key.unwrap();
+
+        if !auth_keys.contains(&auth_key) {
+            auth_keys.push(auth_key);
+        }
+    }
+
+    if auth_keys.is_empty() {
+        let response_body = json!({
+            "message": "At least one authentication public key is required"
+        });
+
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let mut challenge_bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut challenge_bytes);
+    let challenge = hex::encode(challenge_bytes);
+
+    let expires_at = chrono::Utc::now() + chrono::Duration::seconds(AUTH_CHALLENGE_EXPIRY);
+
+    crate::database::transfer_receiver::insert_auth_challenge(&statechain_entity.pool, &auth_keys, &challenge, &expires_at).await;
+
+    let response_body = json!(AuthChallengeResponsePayload {
+        challenge,
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[get("/transfer/get_msg_addr/<new_auth_key>?<challenge>&<auth_sig>")]
+pub async fn get_msg_addr(statechain_entity: &State<StateChainEntity>, new_auth_key: &str, challenge: Option<&str>, auth_sig: Option<&str>) -> status::Custom<Json<Value>>  {
 
     let new_user_auth_public_key = PublicKey::from_str(new_auth_key);
 
@@ -104,6 +166,39 @@ pub async fn get_msg_addr(statechain_entity: &State<StateChainEntity>, new_auth_
     }
 
     let new_user_auth_public_key = new_user_auth_public_key.unwrap();
+
+    if challenge.is_none() || auth_sig.is_none() {
+        let response_body = json!({
+            "message": "A signed challenge is required. Request one at /transfer/challenge."
+        });
+
+        return status::Custom(Status::Unauthorized, Json(response_body));
+    }
+
+    let challenge = challenge.unwrap();
+    let auth_sig = auth_sig.unwrap();
+
+    let signed_message = Signature::from_str(auth_sig);
+    let message = auth_challenge_message(&new_user_auth_public_key.to_string(), challenge);
+    let msg = Message::from_hashed_data::<sha256::Hash>(message.as_bytes());
+
+    let secp = Secp256k1::new();
+
+    if signed_message.is_err() || secp.verify_schnorr(&signed_message.unwrap(), &msg, &new_user_auth_public_key.x_only_public_key().0).is_err() {
+        let response_body = json!({
+            "message": "Signature does not match authentication key."
+        });
+
+        return status::Custom(Status::Forbidden, Json(response_body));
+    }
+
+    if !crate::database::transfer_receiver::consume_auth_challenge(&statechain_entity.pool, &new_user_auth_public_key, challenge).await {
+        let response_body = json!({
+            "message": "Challenge not found or expired."
+        });
+
+        return status::Custom(Status::Forbidden, Json(response_body));
+    }
     
     let result = crate::database::transfer_receiver::get_statechain_transfer_messages(&statechain_entity.pool, &new_user_auth_public_key).await;
 
@@ -113,7 +208,67 @@ pub async fn get_msg_addr(statechain_entity: &State<StateChainEntity>, new_auth_
 
     let response_body = json!(get_msg_addr_response_payload);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+/// The bulk response size is rounded up to a multiple of this value
+const MSG_ADDR_BULK_PADDING_BLOCK: usize = 16384;
+
+#[post("/transfer/get_msg_addr_bulk", format = "json", data = "<get_msg_addr_bulk_request_payload>")]
+pub async fn get_msg_addr_bulk(statechain_entity: &State<StateChainEntity>, get_msg_addr_bulk_request_payload: Json<GetMsgAddrBulkRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let challenge = get_msg_addr_bulk_request_payload.0.challenge;
+    let auth_key_proofs = get_msg_addr_bulk_request_payload.0.auth_key_proofs;
+
+    if auth_key_proofs.len() > MAX_MSG_ADDR_BULK_AUTH_KEYS {
+        let response_body = json!({
+            "message": format!("Too many authentication keys. At most {} keys can be queried per request.", MAX_MSG_ADDR_BULK_AUTH_KEYS)
+        });
+
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let new_user_auth_keys = match crate::endpoints::utils::validate_auth_key_proofs(&statechain_entity.pool, &auth_key_proofs, &challenge).await {
+        Ok(auth_keys) => auth_keys,
+        Err(message) => {
+            let response_body = json!({
+                "message": message
+            });
+
+            return status::Custom(Status::Forbidden, Json(response_body));
+        }
+    };
+
+    let mut enc_transfer_msgs_per_auth_key: HashMap<String, Vec<String>> = new_user_auth_keys.iter()
+        .map(|auth_key| (auth_key.to_string(), Vec::<String>::new()))
+        .collect();
+
+    let result = crate::database::transfer_receiver::get_statechain_transfer_messages_for_keys(&statechain_entity.pool, &new_user_auth_keys).await;
+
+    for (auth_key, enc_transfer_msg) in result {
+        enc_transfer_msgs_per_auth_key.entry(auth_key.to_string()).or_default().push(enc_transfer_msg);
+    }
+
+    let mut get_msg_addr_bulk_response_payload = GetMsgAddrBulkResponsePayload {
+        enc_transfer_msgs_per_auth_key,
+        padding: String::new(),
+    };
+
+    // the padding is hex encoded, so each byte of it adds exactly one byte to the serialized response
+    let unpadded_len = json!(get_msg_addr_bulk_response_payload).to_string().len();
+    let padding_len = MSG_ADDR_BULK_PADDING_BLOCK - (unpadded_len % MSG_ADDR_BULK_PADDING_BLOCK);
+
+    let mut padding_bytes = vec![0u8; (padding_len + 1) / 2];
+    rand::thread_rng().fill_bytes(&mut padding_bytes);
+
+    let mut padding = hex::encode(padding_bytes);
+    padding.truncate(padding_len);
+
+    get_msg_addr_bulk_response_payload.padding = padding;
+
+    let response_body = json!(get_msg_addr_bulk_response_payload);
+
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[post("/transfer/unlock", format = "json", data = "<transfer_unlock_request_payload>")]
@@ -134,7 +289,16 @@ pub async fn transfer_unlock(statechain_entity: &State<StateChainEntity>, transf
         return status::Custom(Status::Forbidden, Json(response_body));
     }
 
-    crate::database::transfer_receiver::update_unlock_transfer(&statechain_entity.pool, is_current_owner_signature, &statechain_id).await;
+    if !crate::database::transfer_receiver::update_unlock_transfer(&statechain_entity.pool, is_current_owner_signature, &statechain_id).await {
+
+        let response_body = json!({
+            "message": "Transfer not found."
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    notify_if_batch_unlocked(statechain_entity, &statechain_id).await;
 
     let response_body = json!({
         "message": "Success"
@@ -143,6 +307,27 @@ pub async fn transfer_unlock(statechain_entity: &State<StateChainEntity>, transf
     status::Custom(Status::Ok, Json(response_body))
 }
 
+pub async fn notify_if_batch_unlocked(statechain_entity: &State<StateChainEntity>, statechain_id: &str) {
+
+    let batch_info = crate::database::transfer::get_batch_id_and_time_by_statechain_id(&statechain_entity.pool, statechain_id).await;
+
+    if batch_info.is_none() {
+        return;
+    }
+
+    let (batch_id, _) = batch_info.unwrap();
+
+    if !crate::database::transfer::is_all_coins_unlocked(&statechain_entity.pool, &batch_id).await {
+        return;
+    }
+
+    let receivers = crate::database::transfer::get_batch_transfer_receivers(&statechain_entity.pool, &batch_id).await;
+
+    for (batch_statechain_id, new_user_auth_key) in receivers {
+        crate::endpoints::transfer_notification::notify(statechain_entity, TransferNotificationType::BatchUnlocked, &batch_statechain_id, &new_user_auth_key);
+    }
+}
+
 pub enum BatchTransferReceiveValidationResult {
 
     /// The statecoin batch is locked (not expired yet and not all coins are unlocked)
@@ -187,7 +372,7 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
     // TODO: check if the statechain_id is within a batch and if it is, check if the batch is still open or expired.
     // If open, check all coins are unlocked. If not, return 400 error.
     // If expired, return 400 error.
-    let batch_validation_result = validate_batch(&statechain_entity, &transfer_receiver_request_payload.statechain_id).await;
+    let batch_validation_result = validate_batch(statechain_entity, &transfer_receiver_request_payload.statechain_id).await;
 
     match batch_validation_result {
         BatchTransferReceiveValidationResult::StatecoinBatchLockedError(msg) => {
@@ -211,6 +396,18 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
         BatchTransferReceiveValidationResult::Success => {},
     }
 
+    let batch_info = crate::database::transfer::get_batch_id_and_time_by_statechain_id(&statechain_entity.pool, &transfer_receiver_request_payload.statechain_id).await;
+
+    if let Some((batch_id, _)) = batch_info {
+        if let Err(message) = crate::endpoints::lightning_latch::validate_latch_payment(statechain_entity, &transfer_receiver_request_payload.statechain_id, &batch_id).await {
+            let response_body = json!({
+                "message": message
+            });
+
+            return status::Custom(Status::PaymentRequired, Json(response_body));
+        }
+    }
+
     let auth_pubkey_x1 = crate::database::transfer_receiver::get_auth_pubkey_and_x1(&statechain_entity.pool, &transfer_receiver_request_payload.statechain_id).await;
 
     if auth_pubkey_x1.is_none() {
@@ -222,10 +419,10 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
     }
 
     let auth_pubkey_x1 = auth_pubkey_x1.unwrap();
-    let auth_pubkey = auth_pubkey_x1.0;
+    let new_user_auth_key = auth_pubkey_x1.0;
     let x1 = auth_pubkey_x1.1;
 
-    let auth_pubkey = auth_pubkey.x_only_public_key().0;
+    let auth_pubkey = new_user_auth_key.x_only_public_key().0;
 
     let statechain_id = transfer_receiver_request_payload.statechain_id.clone();
     let t2 = transfer_receiver_request_payload.t2.clone();
@@ -236,7 +433,7 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
 
     let secp = Secp256k1::new();
     
-    if !secp.verify_schnorr(&signed_message, &msg, &auth_pubkey).is_ok() {
+    if secp.verify_schnorr(&signed_message, &msg, &auth_pubkey).is_err() {
 
         let response_body = json!({
             "message": "Signature does not match authentication key."
@@ -296,12 +493,12 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
     let path = "keyupdate";
 
     let client: reqwest::Client = reqwest::Client::new();
-    let request = client.post(&format!("{}/{}", lockbox_endpoint, path));
+    let request = client.post(format!("{}/{}", lockbox_endpoint, path));
 
     let value = match request.json(&key_update_response_payload).send().await {
         Ok(response) => {
-            let text = response.text().await.unwrap();
-            text
+            
+            response.text().await.unwrap()
         },
         Err(err) => {
             let response_body = json!({
@@ -313,7 +510,7 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
         },
     };
 
-    let response: TransferReceiverPostResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: TransferReceiverPostResponsePayload = serde_json::from_str(value.as_str()).unwrap_or_else(|_| panic!("failed to parse: {}", value.as_str()));
 
     let mut server_pubkey_hex = response.server_pubkey.clone();
 
@@ -325,6 +522,8 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
 
     crate::database::transfer_receiver::update_statechain(&statechain_entity.pool, &auth_pubkey, &server_pubkey, &statechain_id).await;
 
+    crate::endpoints::transfer_notification::notify(statechain_entity, TransferNotificationType::KeyUpdated, &statechain_id, &new_user_auth_key);
+
     let response_body = json!(TransferReceiverPostResponsePayload {
         server_pubkey: server_pubkey.to_string(),
     });
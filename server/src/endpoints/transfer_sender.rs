@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use mercurylib::transfer::sender::{TransferSenderRequestPayload, TransferSenderResponsePayload, TransferUpdateMsgRequestPayload};
+use mercurylib::transfer::{receiver::TransferNotificationType, sender::{TransferBatchCancelRequestPayload, TransferCancelRequestPayload, TransferSenderRequestPayload, TransferSenderResponsePayload, TransferUpdateMsgRequestPayload}};
 use rocket::{State, serde::json::Json, response::status, http::Status};
 use secp256k1_zkp::{PublicKey, Scalar, SecretKey};
 use serde_json::{Value, json};
@@ -16,15 +16,21 @@ pub enum BatchTransferValidationResult {
     StatecoinBatchLockedError (String),
     /// The batch_id sent by the user is expired
     ExpiredBatchTimeError (String),
+    /// The batch size does not match the one declared by the other coins or the batch is already full
+    InvalidBatchSizeError (String),
     /// Success means there is no batch_id for the statecoin, 
     /// or the batch is complete or expired and the batch_id is different from the new_batch_id (or null)
     Success,
 }
 
-pub async fn validate_batch_transfer(statechain_entity: &State<StateChainEntity>, statechain_id: &str, new_batch_id: &Option<String>) -> BatchTransferValidationResult {
+pub async fn validate_batch_transfer(statechain_entity: &State<StateChainEntity>, statechain_id: &str, new_batch_id: &Option<String>, batch_size: Option<u32>) -> BatchTransferValidationResult {
+
+    if batch_size.is_some() && (new_batch_id.is_none() || batch_size == Some(0)) {
+        return BatchTransferValidationResult::InvalidBatchSizeError("The batch size must be positive and requires a batch id.".to_string());
+    }
 
     // get an extistent batch according to the statecoin, in case the user sent a repeated statecoin
-    let batch_info = crate::database::transfer::get_batch_id_and_time_by_statechain_id(&statechain_entity.pool, &statechain_id).await;
+    let batch_info = crate::database::transfer::get_batch_id_and_time_by_statechain_id(&statechain_entity.pool, statechain_id).await;
 
     if batch_info.is_some() {
 
@@ -42,7 +48,7 @@ pub async fn validate_batch_transfer(statechain_entity: &State<StateChainEntity>
             return BatchTransferValidationResult::StatecoinBatchLockedError("Statecoin batch locked (the batch time has not expired).".to_string())
         } else {
             // the batch time has expired
-            if new_batch_id.is_some() && new_batch_id.as_ref().unwrap().to_string() == batch_id {
+            if new_batch_id.is_some() && *new_batch_id.as_ref().unwrap() == batch_id {
                 // if the new_batch_id is the same should return error
                 return BatchTransferValidationResult::ExpiredBatchTimeError("Batch time has expired. Try a new batch id.".to_string());
             } else {
@@ -64,7 +70,17 @@ pub async fn validate_batch_transfer(statechain_entity: &State<StateChainEntity>
             let batch_time = batch_time.unwrap();
 
             if !is_batch_expired(batch_time) {
-                // the batch time has not expired. It is possible to add a new coin to the batch.
+                // the batch time has not expired. It is possible to add a new coin to the batch if it is not full.
+                let (declared_batch_size, registered_coins) = crate::database::transfer_sender::get_batch_size_and_count(&statechain_entity.pool, new_batch_id, statechain_id).await;
+
+                if declared_batch_size != batch_size {
+                    return BatchTransferValidationResult::InvalidBatchSizeError("The batch size does not match the size declared by the other coins of the batch.".to_string());
+                }
+
+                if batch_size.is_some_and(|batch_size| registered_coins >= batch_size) {
+                    return BatchTransferValidationResult::InvalidBatchSizeError("All the coins of the batch are already registered.".to_string());
+                }
+
                 return BatchTransferValidationResult::Success
             } else {
                 // the batch time has expired. New coins not allowed.
@@ -84,6 +100,7 @@ pub async fn transfer_sender(statechain_entity: &State<StateChainEntity>, transf
     let statechain_id = transfer_sender_request_payload.0.statechain_id.clone();
     let signed_statechain_id = transfer_sender_request_payload.0.auth_sig.clone();
     let batch_id = transfer_sender_request_payload.0.batch_id.clone();
+    let batch_size = transfer_sender_request_payload.0.batch_size;
 
     if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
 
@@ -94,10 +111,10 @@ pub async fn transfer_sender(statechain_entity: &State<StateChainEntity>, transf
         return status::Custom(Status::InternalServerError, Json(response_body));
     }
 
-    let batch_transfer_validation_result = validate_batch_transfer(&statechain_entity, &statechain_id, &batch_id).await;
+    let batch_transfer_validation_result = validate_batch_transfer(statechain_entity, &statechain_id, &batch_id, batch_size).await;
 
     match batch_transfer_validation_result {
-        BatchTransferValidationResult::StatecoinBatchLockedError(message) | BatchTransferValidationResult::ExpiredBatchTimeError(message) => {
+        BatchTransferValidationResult::StatecoinBatchLockedError(message) | BatchTransferValidationResult::ExpiredBatchTimeError(message) | BatchTransferValidationResult::InvalidBatchSizeError(message) => {
             let response_body = json!({
                 "message": message
             });
@@ -131,7 +148,7 @@ pub async fn transfer_sender(statechain_entity: &State<StateChainEntity>, transf
     let s_x1 = Scalar::from(secret_x1);
     let x1 = s_x1.to_be_bytes();
 
-    crate::database::transfer_sender::insert_new_transfer(&statechain_entity.pool, &new_user_auth_key, &x1, &statechain_id, &batch_id).await;
+    crate::database::transfer_sender::insert_new_transfer(&statechain_entity.pool, &new_user_auth_key, &x1, &statechain_id, &batch_id, batch_size).await;
 
     let transfer_sender_response_payload = TransferSenderResponsePayload {
         x1: hex::encode(x1),
@@ -139,7 +156,7 @@ pub async fn transfer_sender(statechain_entity: &State<StateChainEntity>, transf
 
     let response_body = json!(transfer_sender_response_payload);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[post("/transfer/update_msg", format = "json", data = "<transfer_update_msg_request_payload>")]
@@ -164,9 +181,141 @@ pub async fn transfer_update_msg(statechain_entity: &State<StateChainEntity>, tr
 
     crate::database::transfer_sender::update_transfer_msg(&statechain_entity.pool, &new_user_auth_key, &enc_transfer_msg, &statechain_id).await;
 
+    crate::endpoints::transfer_notification::notify(statechain_entity, TransferNotificationType::TransferMsg, &statechain_id, &new_user_auth_key);
+
     let response_body = json!({
         "updated": true,
     });
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[post("/transfer/cancel", format = "json", data = "<transfer_cancel_request_payload>")]
+pub async fn transfer_cancel(statechain_entity: &State<StateChainEntity>, transfer_cancel_request_payload: Json<TransferCancelRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let statechain_id = transfer_cancel_request_payload.0.statechain_id.clone();
+    let signed_statechain_id = transfer_cancel_request_payload.0.auth_sig.clone();
+
+    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+
+        let response_body = json!({
+            "message": "Signature does not match authentication key."
+        });
+    
+        return status::Custom(Status::Forbidden, Json(response_body));
+    }
+
+    let pending_transfer = crate::database::transfer_sender::get_pending_transfer(&statechain_entity.pool, &statechain_id).await;
+
+    if pending_transfer.is_none() {
+
+        let response_body = json!({
+            "message": "No pending transfer found for this statechain_id."
+        });
+    
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    let (key_updated, _, batch_id, batch_time) = pending_transfer.unwrap();
+
+    if key_updated {
+
+        let response_body = json!({
+            "message": "Transfer already completed by the receiver."
+        });
+    
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    // removing a coin from a running batch would let the other coins complete without it
+    if batch_id.is_some() && batch_time.is_some() && !is_batch_expired(batch_time.unwrap()) {
+
+        let response_body = json!({
+            "message": "Statecoin batch locked (the batch time has not expired)."
+        });
+    
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    if !crate::database::transfer_sender::delete_pending_transfer(&statechain_entity.pool, &statechain_id).await {
+
+        let response_body = json!({
+            "message": "Transfer already completed by the receiver."
+        });
+    
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let response_body = json!({
+        "message": "Transfer cancelled."
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[post("/transfer/cancel_batch", format = "json", data = "<transfer_batch_cancel_request_payload>")]
+pub async fn transfer_cancel_batch(statechain_entity: &State<StateChainEntity>, transfer_batch_cancel_request_payload: Json<TransferBatchCancelRequestPayload>) -> status::Custom<Json<Value>>  {
+
+    let batch_id = transfer_batch_cancel_request_payload.0.batch_id.clone();
+    let cancel_requests = transfer_batch_cancel_request_payload.0.cancel_requests;
+
+    for cancel_request in cancel_requests.iter() {
+
+        if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &cancel_request.auth_sig, &cancel_request.statechain_id).await {
+
+            let response_body = json!({
+                "message": format!("Signature does not match authentication key for statechain_id {}.", cancel_request.statechain_id)
+            });
+        
+            return status::Custom(Status::Forbidden, Json(response_body));
+        }
+    }
+
+    let batch_transfers = crate::database::transfer_sender::get_batch_transfers(&statechain_entity.pool, &batch_id).await;
+
+    if batch_transfers.is_empty() {
+
+        let response_body = json!({
+            "message": "No pending transfers found for this batch_id."
+        });
+    
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    for (statechain_id, key_updated) in batch_transfers.iter() {
+
+        // the whole batch is cancelled at once, so the owner of every coin must agree
+        if !cancel_requests.iter().any(|cancel_request| &cancel_request.statechain_id == statechain_id) {
+
+            let response_body = json!({
+                "message": format!("Missing cancel request for statechain_id {} of the batch.", statechain_id)
+            });
+        
+            return status::Custom(Status::Forbidden, Json(response_body));
+        }
+
+        if *key_updated {
+
+            let response_body = json!({
+                "message": format!("Transfer of statechain_id {} already completed by the receiver.", statechain_id)
+            });
+        
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
+    }
+
+    if !crate::database::transfer_sender::delete_batch_transfers(&statechain_entity.pool, &batch_id).await {
+
+        let response_body = json!({
+            "message": "Batch transfer already completed by a receiver."
+        });
+    
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    let response_body = json!({
+        "message": "Batch transfer cancelled."
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use bitcoin::hashes::sha256;
+use mercurylib::transfer::receiver::{auth_challenge_message, AuthKeyProof};
 use rocket::{State, response::status, http::Status, serde::json::Json};
 use secp256k1_zkp::{schnorr::Signature, Message, Secp256k1, XOnlyPublicKey};
 use serde_json::{json, Value};
@@ -15,7 +16,7 @@ pub async fn get_auth_key_by_statechain_id(pool: &sqlx::PgPool, statechain_id: &
         "SELECT auth_xonly_public_key \
         FROM public.statechain_data \
         WHERE statechain_id = $1")
-        .bind(&statechain_id)
+        .bind(statechain_id)
         .fetch_one(pool)
         .await;
 
@@ -23,12 +24,12 @@ pub async fn get_auth_key_by_statechain_id(pool: &sqlx::PgPool, statechain_id: &
         Ok(row) => {
             let public_key_bytes = row.get::<Option<Vec<u8>>, _>("auth_xonly_public_key");
             let pk = XOnlyPublicKey::from_slice(&public_key_bytes.unwrap()).unwrap();
-            return Ok(pk);
+            Ok(pk)
         },
         Err(err) => {
-            return Err(err);
+            Err(err)
         }
-    };
+    }
 
 }
 
@@ -54,6 +55,45 @@ pub async fn validate_signature(pool: &sqlx::PgPool, signed_message_hex: &str, s
     secp.verify_schnorr(&signed_message, &msg, &auth_key).is_ok()
 }
 
+fn validate_auth_key_proof(auth_key_proof: &AuthKeyProof, challenge: &str) -> Option<PublicKey> {
+
+    let auth_key = PublicKey::from_str(&auth_key_proof.auth_pub_key).ok()?;
+    let signed_message = Signature::from_str(&auth_key_proof.auth_sig).ok()?;
+
+    let message = auth_challenge_message(&auth_key_proof.auth_pub_key, challenge);
+    let msg = Message::from_hashed_data::<sha256::Hash>(message.as_bytes());
+
+    let secp = Secp256k1::new();
+
+    if secp.verify_schnorr(&signed_message, &msg, &auth_key.x_only_public_key().0).is_ok() {
+        Some(auth_key)
+    } else {
+        None
+    }
+}
+
+/// Checks that every auth key signed the challenge and consumes the challenge, which must have been issued to all of them.
+/// Returns the auth keys, or the reason the proofs were rejected.
+pub async fn validate_auth_key_proofs(pool: &sqlx::PgPool, auth_key_proofs: &[AuthKeyProof], challenge: &str) -> Result<Vec<PublicKey>, String> {
+
+    let mut auth_keys = Vec::<PublicKey>::new();
+
+    for auth_key_proof in auth_key_proofs.iter() {
+
+        match validate_auth_key_proof(auth_key_proof, challenge) {
+            Some(auth_key) if auth_keys.contains(&auth_key) => continue,
+            Some(auth_key) => auth_keys.push(auth_key),
+            None => return Err(format!("Signature does not match authentication key {}.", auth_key_proof.auth_pub_key)),
+        }
+    }
+
+    if !crate::database::transfer_receiver::consume_auth_challenge_for_keys(pool, &auth_keys, challenge).await {
+        return Err("Challenge not found or expired.".to_string());
+    }
+
+    Ok(auth_keys)
+}
+
 #[get("/info/config")]
 pub async fn info_config() -> status::Custom<Json<Value>> {
 
@@ -67,7 +107,7 @@ pub async fn info_config() -> status::Custom<Json<Value>> {
 
     let response_body = json!(server_config);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[get("/info/keylist")]
@@ -124,6 +164,6 @@ pub async fn info_keylist(statechain_entity: &State<StateChainEntity>) -> status
 
     let response_body = json!(key_list_response_payload);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 
 }
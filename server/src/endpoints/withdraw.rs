@@ -64,7 +64,7 @@ pub async fn withdraw_complete(statechain_entity: &State<StateChainEntity>, dele
     let path = "delete_statechain";
 
     let client: reqwest::Client = reqwest::Client::new();
-    let request = client.delete(&format!("{}/{}/{}", lockbox_endpoint, path, statechain_id));
+    let request = client.delete(format!("{}/{}/{}", lockbox_endpoint, path, statechain_id));
 
     let response = request.send().await;
 
@@ -84,6 +84,6 @@ pub async fn withdraw_complete(statechain_entity: &State<StateChainEntity>, dele
         "message": "Statechain deleted.",
     });
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 
 }
\ No newline at end of file
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+
+use super::{HoldInvoiceNode, HoldInvoiceState};
+
+/// LND node accessed through its REST API
+pub struct LndNode {
+    url: String,
+    /// Hex encoded macaroon with invoice read permission
+    macaroon: String,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct LndInvoice {
+    state: String,
+}
+
+impl LndNode {
+    pub fn new(url: &str, macaroon: &str, tls_cert_path: Option<&str>) -> Result<Self, String> {
+
+        let mut builder = reqwest::Client::builder();
+
+        // LND uses a self-signed certificate by default
+        if let Some(tls_cert_path) = tls_cert_path {
+            let pem = std::fs::read(tls_cert_path).map_err(|e| e.to_string())?;
+            let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| e.to_string())?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let client = builder.build().map_err(|e| e.to_string())?;
+
+        Ok(LndNode {
+            url: url.trim_end_matches('/').to_string(),
+            macaroon: macaroon.to_string(),
+            client,
+        })
+    }
+}
+
+fn parse_invoice_state(state: &str) -> Result<HoldInvoiceState, String> {
+    match state {
+        "OPEN" => Ok(HoldInvoiceState::Open),
+        "ACCEPTED" => Ok(HoldInvoiceState::Accepted),
+        "SETTLED" => Ok(HoldInvoiceState::Settled),
+        "CANCELED" => Ok(HoldInvoiceState::Canceled),
+        _ => Err(format!("Unknown invoice state {}", state)),
+    }
+}
+
+#[rocket::async_trait]
+impl HoldInvoiceNode for LndNode {
+    async fn lookup_invoice(&self, payment_hash: &str) -> Result<Option<HoldInvoiceState>, String> {
+
+        let response = self.client
+            .get(&format!("{}/v1/invoice/{}", self.url, payment_hash))
+            .header("Grpc-Metadata-macaroon", &self.macaroon)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("LND lookup invoice failed. status: {}, error: {}", status, text));
+        }
+
+        let invoice: LndInvoice = response.json().await.map_err(|e| e.to_string())?;
+
+        Ok(Some(parse_invoice_state(&invoice.state)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_lnd_invoice_states() {
+        let invoice: LndInvoice = serde_json::from_str(r#"{"memo":"","r_hash":"","state":"ACCEPTED"}"#).unwrap();
+        assert_eq!(parse_invoice_state(&invoice.state), Ok(HoldInvoiceState::Accepted));
+        assert_eq!(parse_invoice_state("OPEN"), Ok(HoldInvoiceState::Open));
+        assert!(parse_invoice_state("UNKNOWN").is_err());
+    }
+}
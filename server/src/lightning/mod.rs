@@ -0,0 +1,86 @@
+pub mod lnd;
+
+/// State of a hold invoice as reported by the lightning node
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoldInvoiceState {
+    /// Invoice created, no HTLC received yet
+    Open,
+    /// The HTLC has been received and is held until the preimage is revealed
+    Accepted,
+    Settled,
+    Canceled,
+}
+
+/// Lightning node able to report the state of the hold invoices it holds
+#[rocket::async_trait]
+pub trait HoldInvoiceNode: Send + Sync {
+    /// Returns None if the node does not know any invoice for the payment hash (hex)
+    async fn lookup_invoice(&self, payment_hash: &str) -> Result<Option<HoldInvoiceState>, String>;
+}
+
+/// Checks that an invoice for the payment hash exists and its payment has been accepted (or already settled)
+pub async fn verify_hold_invoice(node: &dyn HoldInvoiceNode, payment_hash: &str) -> Result<(), String> {
+
+    match node.lookup_invoice(payment_hash).await? {
+        Some(HoldInvoiceState::Accepted) | Some(HoldInvoiceState::Settled) => Ok(()),
+        Some(state) => Err(format!("Invoice for payment hash {} is not paid. Current state: {:?}", payment_hash, state)),
+        None => Err(format!("No invoice found for payment hash {}", payment_hash)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    struct MockNode {
+        invoices: HashMap<String, HoldInvoiceState>,
+    }
+
+    #[rocket::async_trait]
+    impl HoldInvoiceNode for MockNode {
+        async fn lookup_invoice(&self, payment_hash: &str) -> Result<Option<HoldInvoiceState>, String> {
+            Ok(self.invoices.get(payment_hash).cloned())
+        }
+    }
+
+    struct UnreachableNode;
+
+    #[rocket::async_trait]
+    impl HoldInvoiceNode for UnreachableNode {
+        async fn lookup_invoice(&self, _payment_hash: &str) -> Result<Option<HoldInvoiceState>, String> {
+            Err("connection refused".to_string())
+        }
+    }
+
+    fn mock_node() -> MockNode {
+        let mut invoices = HashMap::new();
+        invoices.insert("open".to_string(), HoldInvoiceState::Open);
+        invoices.insert("accepted".to_string(), HoldInvoiceState::Accepted);
+        invoices.insert("settled".to_string(), HoldInvoiceState::Settled);
+        invoices.insert("canceled".to_string(), HoldInvoiceState::Canceled);
+        MockNode { invoices }
+    }
+
+    #[rocket::async_test]
+    async fn accepted_and_settled_invoices_are_valid() {
+        let node = mock_node();
+        assert!(verify_hold_invoice(&node, "accepted").await.is_ok());
+        assert!(verify_hold_invoice(&node, "settled").await.is_ok());
+    }
+
+    #[rocket::async_test]
+    async fn unpaid_or_missing_invoices_are_rejected() {
+        let node = mock_node();
+        assert!(verify_hold_invoice(&node, "open").await.is_err());
+        assert!(verify_hold_invoice(&node, "canceled").await.is_err());
+        assert!(verify_hold_invoice(&node, "unknown").await.is_err());
+    }
+
+    #[rocket::async_test]
+    async fn node_errors_are_propagated() {
+        let result = verify_hold_invoice(&UnreachableNode, "accepted").await;
+        assert_eq!(result, Err("connection refused".to_string()));
+    }
+}
@@ -2,6 +2,9 @@ mod endpoints;
 mod server_config;
 mod server;
 mod database;
+mod lightning;
+mod token_server;
+mod rate_limiter;
 
 #[macro_use] extern crate rocket;
 
@@ -48,6 +51,8 @@ async fn main() {
         .await
         .unwrap();
 
+    rocket::tokio::spawn(endpoints::lightning_latch::expire_latches(statechain_entity.pool.clone()));
+
     let _ = rocket::build()
         .mount("/", routes![
             endpoints::deposit::post_deposit,
@@ -58,13 +63,22 @@ async fn main() {
             endpoints::lightning_latch::get_paymenthash,
             endpoints::lightning_latch::post_paymenthash,
             endpoints::lightning_latch::transfer_preimage,
+            endpoints::lightning_latch::reclaim_latch,
+            endpoints::oracle_latch::post_conditional_latch,
+            endpoints::oracle_latch::post_attestation,
             endpoints::transfer_sender::transfer_sender,
             endpoints::transfer_sender::transfer_update_msg,
+            endpoints::transfer_sender::transfer_cancel,
+            endpoints::transfer_sender::transfer_cancel_batch,
+            endpoints::transfer_receiver::auth_challenge,
             endpoints::transfer_receiver::get_msg_addr,
+            endpoints::transfer_receiver::get_msg_addr_bulk,
             endpoints::transfer_receiver::statechain_info,
             endpoints::transfer_receiver::transfer_unlock,
             endpoints::transfer_receiver::transfer_receiver,
+            endpoints::transfer_notification::transfer_notifications,
             endpoints::withdraw::withdraw_complete,
+            endpoints::recovery::recover_statecoins,
             utils::info_config,
             utils::info_keylist,
             all_options,
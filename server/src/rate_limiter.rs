@@ -0,0 +1,72 @@
+use std::{collections::HashMap, net::IpAddr, sync::Mutex, time::{Duration, Instant}};
+
+/// Limits the number of requests of each client in a fixed time window
+pub struct RateLimiter {
+    max_requests: u32,
+    window: Duration,
+    /// Start of the current window and number of requests in it, per client
+    requests: Mutex<HashMap<IpAddr, (Instant, u32)>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        RateLimiter {
+            max_requests,
+            window,
+            requests: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Counts a request of the client. Returns false if the client exceeded the limit of the current window.
+    pub fn check(&self, client: IpAddr) -> bool {
+        self.check_at(client, Instant::now())
+    }
+
+    fn check_at(&self, client: IpAddr, now: Instant) -> bool {
+
+        let mut requests = self.requests.lock().unwrap();
+
+        // clients whose window ended start again from zero
+        requests.retain(|_, (window_start, _)| now.duration_since(*window_start) < self.window);
+
+        let (_, count) = requests.entry(client).or_insert((now, 0));
+
+        if *count >= self.max_requests {
+            return false;
+        }
+
+        *count += 1;
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+
+    #[test]
+    fn requests_above_the_limit_are_rejected_until_the_window_ends() {
+        let rate_limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let start = Instant::now();
+
+        assert!(rate_limiter.check_at(client, start));
+        assert!(rate_limiter.check_at(client, start + Duration::from_secs(1)));
+        assert!(!rate_limiter.check_at(client, start + Duration::from_secs(2)));
+
+        assert!(rate_limiter.check_at(client, start + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn clients_are_limited_separately() {
+        let rate_limiter = RateLimiter::new(1, Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert!(rate_limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), start));
+        assert!(!rate_limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), start));
+        assert!(rate_limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)), start));
+    }
+}
@@ -1,11 +1,20 @@
 use std::time::Duration;
 
+use mercurylib::transfer::receiver::TransferNotificationPayload;
+use rocket::tokio::sync::broadcast;
 use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
 
-use crate::server_config::ServerConfig;
+use crate::{lightning::{lnd::LndNode, HoldInvoiceNode}, rate_limiter::RateLimiter, server_config::ServerConfig, token_server::TokenServerClient};
 
 pub struct StateChainEntity {
     pub pool: Pool<Postgres>,
+    pub transfer_notification_sender: broadcast::Sender<TransferNotificationPayload>,
+    /// Used to verify the lightning latch payments. None if no node is configured.
+    pub hold_invoice_node: Option<Box<dyn HoldInvoiceNode>>,
+    /// Used to verify and spend the deposit tokens. None if the local tokens table is used.
+    pub token_server: Option<TokenServerClient>,
+    /// Limits the auth challenges issued to each client, since they are requested without authentication
+    pub auth_challenge_rate_limiter: RateLimiter,
 }
 
 impl StateChainEntity {
@@ -21,8 +30,29 @@ impl StateChainEntity {
             .await
             .unwrap();
 
+        let (transfer_notification_sender, _) = broadcast::channel(1024);
+
+        let hold_invoice_node: Option<Box<dyn HoldInvoiceNode>> = match (&config.lnd_url, &config.lnd_macaroon) {
+            (Some(lnd_url), Some(lnd_macaroon)) => {
+                let lnd_node = LndNode::new(lnd_url, lnd_macaroon, config.lnd_tls_cert.as_deref()).unwrap();
+                Some(Box::new(lnd_node))
+            },
+            _ => None,
+        };
+
+        let token_server = match (&config.token_server_url, &config.token_server_key) {
+            (Some(token_server_url), Some(token_server_key)) => Some(TokenServerClient::new(token_server_url, token_server_key)),
+            _ => None,
+        };
+
+        let auth_challenge_rate_limiter = RateLimiter::new(config.auth_challenge_rate_limit, Duration::from_secs(60));
+
         StateChainEntity {
             pool,
+            transfer_notification_sender,
+            hold_invoice_node,
+            token_server,
+            auth_challenge_rate_limiter,
         }
     }
 }
\ No newline at end of file
@@ -23,6 +23,27 @@ pub struct ServerConfig {
     pub batch_timeout: u32,
     /// Enclave server list
     pub enclaves: Vec<Enclave>,
+    /// Lightning latch expiry used when the sender does not request one (seconds)
+    pub latch_expiry_default: u64,
+    /// Minimum lightning latch expiry a sender can request (seconds)
+    pub latch_expiry_min: u64,
+    /// Maximum lightning latch expiry a sender can request (seconds)
+    pub latch_expiry_max: u64,
+    /// LND REST API url used to verify lightning latch hold invoices (optional)
+    pub lnd_url: Option<String>,
+    /// Hex encoded LND macaroon
+    pub lnd_macaroon: Option<String>,
+    /// Path to the LND TLS certificate
+    pub lnd_tls_cert: Option<String>,
+    /// Token server URL. Deposit tokens are verified and spent through it if set,
+    /// otherwise the local tokens table (mock tokens on test networks) is used.
+    pub token_server_url: Option<String>,
+    /// Key used to authenticate with the token server
+    pub token_server_key: Option<String>,
+    /// X-only public key of the token server blind signing key. Blind tokens are rejected if not set.
+    pub blind_token_pubkey: Option<String>,
+    /// Maximum number of auth challenges a client can request per minute
+    pub auth_challenge_rate_limit: u32,
 }
 
 impl Default for ServerConfig {
@@ -43,6 +64,16 @@ impl Default for ServerConfig {
                     allow_deposit: false,
                 }
             ],
+            latch_expiry_default: 90000,
+            latch_expiry_min: 600,
+            latch_expiry_max: 90000,
+            lnd_url: None,
+            lnd_macaroon: None,
+            lnd_tls_cert: None,
+            token_server_url: None,
+            token_server_key: None,
+            blind_token_pubkey: None,
+            auth_challenge_rate_limit: 60,
         }
     }
 }
@@ -56,21 +87,22 @@ impl From<ConfigRs> for ServerConfig {
             connection_string: config.get::<String>("connection_string").unwrap_or_else(|_| String::new()),
             batch_timeout: config.get::<u32>("batch_timeout").unwrap_or(0),
             enclaves: config.get::<Vec<Enclave>>("enclaves").unwrap_or_else(|_| Vec::new()),
+            latch_expiry_default: config.get::<u64>("latch_expiry_default").unwrap_or(0),
+            latch_expiry_min: config.get::<u64>("latch_expiry_min").unwrap_or(0),
+            latch_expiry_max: config.get::<u64>("latch_expiry_max").unwrap_or(0),
+            lnd_url: config.get::<String>("lnd_url").ok(),
+            lnd_macaroon: config.get::<String>("lnd_macaroon").ok(),
+            lnd_tls_cert: config.get::<String>("lnd_tls_cert").ok(),
+            token_server_url: config.get::<String>("token_server_url").ok(),
+            token_server_key: config.get::<String>("token_server_key").ok(),
+            blind_token_pubkey: config.get::<String>("blind_token_pubkey").ok(),
+            auth_challenge_rate_limit: config.get::<u32>("auth_challenge_rate_limit").unwrap_or(0),
         }
     }
 }
 
 impl ServerConfig {
     pub fn load() -> Self {
-        let mut conf_rs = ConfigRs::default();
-        let _ = conf_rs
-            // First merge struct default config
-            .merge(ConfigRs::try_from(&ServerConfig::default()).unwrap());
-        // Override with settings in file Settings.toml if exists
-        conf_rs.merge(File::with_name("Settings").required(false));
-        // Override with settings in file Rocket.toml if exists
-        conf_rs.merge(File::with_name("Rocket").required(false));
-
         let settings = ConfigRs::builder()
             .add_source(File::with_name("Settings"))
             .build()
@@ -81,14 +113,20 @@ impl ServerConfig {
             env::var(env_var).unwrap_or_else(|_| settings.get_string(key).unwrap())
         };
 
+        let get_env_or_config_optional = |key: &str, env_var: &str| -> Option<String> {
+            env::var(env_var).ok().or_else(|| settings.get_string(key).ok())
+        };
+
+        let default = ServerConfig::default();
+
         let get_env_or_config_enclave = |key: &str, env_var: &str| -> Vec<Enclave> {
 
             let env_enclaves = env::var(env_var);
 
             
-            if env_enclaves.is_ok() {
+            if let Ok(env_enclaves) = env_enclaves {
 
-                return serde_json::from_str::<Vec<Enclave>>(&env_enclaves.unwrap()).unwrap();
+                return serde_json::from_str::<Vec<Enclave>>(&env_enclaves).unwrap();
             }
 
             settings.get::<Vec<Enclave>>(key).unwrap()
@@ -101,6 +139,16 @@ impl ServerConfig {
             connection_string: get_env_or_config("connection_string", "CONNECTION_STRING"),
             batch_timeout: get_env_or_config("batch_timeout", "BATCH_TIMEOUT").parse::<u32>().unwrap(),
             enclaves: get_env_or_config_enclave("enclaves", "ENCLAVES"),
+            latch_expiry_default: get_env_or_config_optional("latch_expiry_default", "LATCH_EXPIRY_DEFAULT").map_or(default.latch_expiry_default, |v| v.parse::<u64>().unwrap()),
+            latch_expiry_min: get_env_or_config_optional("latch_expiry_min", "LATCH_EXPIRY_MIN").map_or(default.latch_expiry_min, |v| v.parse::<u64>().unwrap()),
+            latch_expiry_max: get_env_or_config_optional("latch_expiry_max", "LATCH_EXPIRY_MAX").map_or(default.latch_expiry_max, |v| v.parse::<u64>().unwrap()),
+            lnd_url: get_env_or_config_optional("lnd_url", "LND_URL"),
+            lnd_macaroon: get_env_or_config_optional("lnd_macaroon", "LND_MACAROON"),
+            lnd_tls_cert: get_env_or_config_optional("lnd_tls_cert", "LND_TLS_CERT"),
+            token_server_url: get_env_or_config_optional("token_server_url", "TOKEN_SERVER_URL"),
+            token_server_key: get_env_or_config_optional("token_server_key", "TOKEN_SERVER_KEY"),
+            blind_token_pubkey: get_env_or_config_optional("blind_token_pubkey", "BLIND_TOKEN_PUBKEY"),
+            auth_challenge_rate_limit: get_env_or_config_optional("auth_challenge_rate_limit", "AUTH_CHALLENGE_RATE_LIMIT").map_or(default.auth_challenge_rate_limit, |v| v.parse::<u32>().unwrap()),
         }
     }
 }
@@ -0,0 +1,92 @@
+use serde::{Serialize, Deserialize};
+
+/// Client of the token server, which owns the tokens table
+pub struct TokenServerClient {
+    url: String,
+    /// Sent in the `X-Service-Key` header to authenticate with the token server
+    service_key: String,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct TokenVerifyResponse {
+    confirmed: bool,
+}
+
+#[derive(Serialize)]
+struct TokenSpendRequest<'r> {
+    token_id: &'r str,
+    statechain_id: &'r str,
+}
+
+impl TokenServerClient {
+    pub fn new(url: &str, service_key: &str) -> Self {
+        TokenServerClient {
+            url: url.trim_end_matches('/').to_string(),
+            service_key: service_key.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Returns None if the token does not exist and false if it is unpaid, spent or refunded
+    pub async fn verify_token(&self, token_id: &str) -> Result<Option<bool>, String> {
+
+        let response = self.client
+            .get(&format!("{}/token/token_verify/{}", self.url, token_id))
+            .header("X-Service-Key", &self.service_key)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("Token verification failed. status: {}, error: {}", status, text));
+        }
+
+        let token_verify_response: TokenVerifyResponse = response.json().await.map_err(|e| e.to_string())?;
+
+        Ok(Some(token_verify_response.confirmed))
+    }
+
+    /// Spends the token for the statecoin. Spending it again for the same statecoin also returns true,
+    /// so the request can be safely retried.
+    pub async fn spend_token(&self, token_id: &str, statechain_id: &str) -> Result<bool, String> {
+        self.spend("token/token_spend", token_id, statechain_id).await
+    }
+
+    /// Records the blind token as spent by the statecoin, once its signature was verified.
+    /// Spending it again for the same statecoin also returns true.
+    pub async fn spend_blind_token(&self, token_id: &str, statechain_id: &str) -> Result<bool, String> {
+        self.spend("token/blind_spend", token_id, statechain_id).await
+    }
+
+    async fn spend(&self, path: &str, token_id: &str, statechain_id: &str) -> Result<bool, String> {
+
+        let payload = TokenSpendRequest {
+            token_id,
+            statechain_id,
+        };
+
+        let response = self.client
+            .post(&format!("{}/{}", self.url, path))
+            .header("X-Service-Key", &self.service_key)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => Ok(true),
+            reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE => Ok(false),
+            status => {
+                let text = response.text().await.unwrap_or_default();
+                Err(format!("Token spend failed. status: {}, error: {}", status, text))
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS public.tokens (
+	id serial4 NOT NULL,
+	token_id varchar NULL UNIQUE,
+	invoice varchar NULL,
+	onchain_address varchar NULL,
+	processor_id varchar NULL,
+	confirmed boolean DEFAULT false,
+	spent boolean DEFAULT false,
+	onchain_status varchar NULL,
+	onchain_amount bigint NULL,
+	expires_at TIMESTAMPTZ NULL,
+	refunded boolean NOT NULL DEFAULT false,
+	refund_txid varchar NULL,
+	statechain_id varchar NULL
+);
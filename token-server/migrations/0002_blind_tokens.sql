@@ -0,0 +1,3 @@
+ALTER TABLE public.tokens ADD COLUMN blind_secret_nonce varchar NULL;
+ALTER TABLE public.tokens ADD COLUMN blind_public_nonce varchar NULL;
+ALTER TABLE public.tokens ADD COLUMN blind_challenge varchar NULL;
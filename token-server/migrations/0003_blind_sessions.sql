@@ -0,0 +1 @@
+ALTER TABLE public.tokens ADD COLUMN blind_nonce_created_at timestamptz NULL;
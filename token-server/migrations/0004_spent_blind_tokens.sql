@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS public.blind_tokens (
+	token_id varchar NOT NULL,
+	statechain_id varchar NOT NULL,
+	created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+	CONSTRAINT blind_tokens_pkey PRIMARY KEY (token_id)
+);
@@ -0,0 +1,216 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use rocket::tokio::sync::OnceCell;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{AddressPayment, ChainBackend};
+
+/// Watch-only descriptor wallet holding the token addresses
+const WATCH_WALLET: &str = "token_server_watch";
+
+/// Maximum number of transactions listed per address
+const MAX_ADDRESS_TRANSACTIONS: u64 = 1000;
+
+/// Bitcoin Core JSON-RPC. The token addresses are imported as `addr()` descriptors in a watch-only wallet,
+/// so their payments are tracked by the node instead of being searched in the UTXO set.
+pub struct Bitcoind {
+    url: String,
+    user: Option<String>,
+    password: Option<String>,
+    client: reqwest::Client,
+    wallet_loaded: OnceCell<()>,
+    /// Addresses imported since the server started. The wallet keeps them across restarts.
+    imported_addresses: Mutex<HashSet<String>>,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct DescriptorInfo {
+    descriptor: String,
+}
+
+#[derive(Deserialize)]
+struct ImportDescriptorResult {
+    success: bool,
+    error: Option<Value>,
+}
+
+#[derive(Deserialize)]
+struct WalletTransaction {
+    address: Option<String>,
+    category: String,
+    /// Amount in BTC
+    amount: f64,
+    confirmations: i64,
+    txid: String,
+}
+
+/// Wallet already loaded
+const RPC_WALLET_ALREADY_LOADED: i64 = -35;
+/// Wallet not found
+const RPC_WALLET_NOT_FOUND: i64 = -18;
+
+impl Bitcoind {
+    pub fn new(url: &str, user: Option<&str>, password: Option<&str>) -> Self {
+        Bitcoind {
+            url: url.trim_end_matches('/').to_string(),
+            user: user.map(|user| user.to_string()),
+            password: password.map(|password| password.to_string()),
+            client: reqwest::Client::new(),
+            wallet_loaded: OnceCell::new(),
+            imported_addresses: Mutex::new(HashSet::new()),
+        }
+    }
+
+    async fn call<T: serde::de::DeserializeOwned>(&self, path: &str, method: &str, params: Value) -> Result<Result<T, RpcError>, String> {
+
+        let body = json!({
+            "jsonrpc": "1.0",
+            "id": "token-server",
+            "method": method,
+            "params": params,
+        });
+
+        let mut request = self.client.post(format!("{}{}", self.url, path)).json(&body);
+
+        if let Some(user) = &self.user {
+            request = request.basic_auth(user, self.password.as_ref());
+        }
+
+        // bitcoind returns RPC errors with an HTTP error status, so the body is parsed whatever the status
+        let response: RpcResponse<T> = request
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if let Some(error) = response.error {
+            return Ok(Err(error));
+        }
+
+        response.result.map(Ok).ok_or(format!("bitcoind {} returned no result", method))
+    }
+
+    async fn node_call<T: serde::de::DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, String> {
+        self.call("", method, params).await?
+            .map_err(|error| format!("bitcoind {} failed: {}", method, error.message))
+    }
+
+    async fn wallet_call<T: serde::de::DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, String> {
+        self.call(&format!("/wallet/{}", WATCH_WALLET), method, params).await?
+            .map_err(|error| format!("bitcoind {} failed: {}", method, error.message))
+    }
+
+    /// Loads the watch-only wallet, creating it the first time
+    async fn load_wallet(&self) -> Result<(), String> {
+
+        match self.call::<Value>("", "loadwallet", json!([WATCH_WALLET])).await? {
+            Ok(_) => return Ok(()),
+            Err(error) if error.code == RPC_WALLET_ALREADY_LOADED => return Ok(()),
+            Err(error) if error.code == RPC_WALLET_NOT_FOUND => {},
+            Err(error) => return Err(format!("bitcoind loadwallet failed: {}", error.message)),
+        }
+
+        // disable_private_keys, blank, passphrase, avoid_reuse, descriptors
+        let _: Value = self.node_call("createwallet", json!([WATCH_WALLET, true, true, "", false, true])).await?;
+
+        Ok(())
+    }
+
+    /// Imports the address in the watch-only wallet. Payments made before the import are found by the rescan
+    /// of the recent blocks, which covers the addresses of new tokens.
+    async fn watch_address(&self, address: &str) -> Result<(), String> {
+
+        if self.imported_addresses.lock().unwrap().contains(address) {
+            return Ok(());
+        }
+
+        let descriptor_info: DescriptorInfo = self.node_call("getdescriptorinfo", json!([format!("addr({})", address)])).await?;
+
+        let request = json!([[{
+            "desc": descriptor_info.descriptor,
+            "timestamp": "now",
+            "label": address,
+        }]]);
+
+        let results: Vec<ImportDescriptorResult> = self.wallet_call("importdescriptors", request).await?;
+
+        if let Some(result) = results.iter().find(|result| !result.success) {
+            return Err(format!("bitcoind importdescriptors failed: {}", result.error.clone().unwrap_or_default()));
+        }
+
+        self.imported_addresses.lock().unwrap().insert(address.to_string());
+
+        Ok(())
+    }
+}
+
+/// Payments received by the address, from the transactions of its label in the watch-only wallet
+fn received_payments(transactions: Vec<WalletTransaction>, address: &str) -> Vec<AddressPayment> {
+
+    transactions.into_iter()
+        .filter(|transaction| transaction.category == "receive" && transaction.address.as_deref() == Some(address))
+        // conflicted transactions have negative confirmations
+        .filter(|transaction| transaction.confirmations >= 0)
+        .map(|transaction| AddressPayment {
+            txid: transaction.txid,
+            amount_sats: (transaction.amount * 100_000_000.0).round() as u64,
+            confirmations: transaction.confirmations as u64,
+        })
+        .collect()
+}
+
+#[rocket::async_trait]
+impl ChainBackend for Bitcoind {
+    async fn address_payments(&self, address: &str) -> Result<Vec<AddressPayment>, String> {
+
+        self.wallet_loaded.get_or_try_init(|| self.load_wallet()).await?;
+
+        self.watch_address(address).await?;
+
+        // label, count, skip, include_watchonly
+        let transactions: Vec<WalletTransaction> = self.wallet_call("listtransactions", json!([address, MAX_ADDRESS_TRANSACTIONS, 0, true])).await?;
+
+        Ok(received_payments(transactions, address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080";
+
+    #[test]
+    fn test_received_payments() {
+
+        let transactions: Vec<WalletTransaction> = serde_json::from_value(json!([
+            { "address": ADDRESS, "category": "receive", "amount": 0.0001, "confirmations": 3, "txid": "aa" },
+            { "address": ADDRESS, "category": "receive", "amount": 0.00005, "confirmations": 0, "txid": "bb" },
+            { "address": ADDRESS, "category": "receive", "amount": 0.0001, "confirmations": -1, "txid": "cc" },
+            { "address": ADDRESS, "category": "send", "amount": -0.0001, "confirmations": 5, "txid": "dd" },
+            { "address": "bcrt1qother", "category": "receive", "amount": 0.0001, "confirmations": 5, "txid": "ee" },
+        ])).unwrap();
+
+        let payments = received_payments(transactions, ADDRESS);
+
+        assert_eq!(payments, vec![
+            AddressPayment { txid: String::from("aa"), amount_sats: 10000, confirmations: 3 },
+            AddressPayment { txid: String::from("bb"), amount_sats: 5000, confirmations: 0 },
+        ]);
+    }
+}
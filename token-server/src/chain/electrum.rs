@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use electrum_client::{bitcoin::{Address, Script, Transaction}, ElectrumApi};
+
+use super::{AddressPayment, ChainBackend};
+
+/// Electrum server. The client is blocking, so requests run on the blocking thread pool.
+pub struct Electrum {
+    url: String,
+}
+
+impl Electrum {
+    pub fn new(url: &str) -> Self {
+        Electrum {
+            url: url.to_string(),
+        }
+    }
+}
+
+/// Amount the transaction pays to the script and its confirmations. Electrum reports mempool transactions
+/// with height 0, or -1 if they spend unconfirmed outputs.
+fn received_payment(transaction: &Transaction, height: i32, script: &Script, tip_height: u64) -> Option<AddressPayment> {
+
+    let amount_sats: u64 = transaction.output.iter()
+        .filter(|output| output.script_pubkey.as_script() == script)
+        .map(|output| output.value)
+        .sum();
+
+    if amount_sats == 0 {
+        return None;
+    }
+
+    let confirmations = if height <= 0 { 0 } else { (tip_height + 1).saturating_sub(height as u64) };
+
+    Some(AddressPayment {
+        txid: transaction.txid().to_string(),
+        amount_sats,
+        confirmations,
+    })
+}
+
+/// Payments are read from the address history, so they are still counted once their outputs are spent
+fn get_address_payments(url: &str, address: &str) -> Result<Vec<AddressPayment>, String> {
+
+    let address = Address::from_str(address).map_err(|e| e.to_string())?.assume_checked();
+    let script = address.script_pubkey();
+
+    let client = electrum_client::Client::new(url).map_err(|e| e.to_string())?;
+
+    let tip_height = client.block_headers_subscribe().map_err(|e| e.to_string())?.height as u64;
+
+    let history = client.script_get_history(&script).map_err(|e| e.to_string())?;
+
+    let mut payments = Vec::new();
+
+    for entry in history {
+        let transaction = client.transaction_get(&entry.tx_hash).map_err(|e| e.to_string())?;
+
+        if let Some(payment) = received_payment(&transaction, entry.height, &script, tip_height) {
+            payments.push(payment);
+        }
+    }
+
+    Ok(payments)
+}
+
+#[rocket::async_trait]
+impl ChainBackend for Electrum {
+    async fn address_payments(&self, address: &str) -> Result<Vec<AddressPayment>, String> {
+
+        let url = self.url.clone();
+        let address = address.to_string();
+
+        rocket::tokio::task::spawn_blocking(move || get_address_payments(&url, &address))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use electrum_client::bitcoin::{absolute::LockTime, OutPoint, TxIn, TxOut};
+
+    use super::*;
+
+    fn transaction_paying(outputs: Vec<(&Address, u64)>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn { previous_output: OutPoint::null(), ..Default::default() }],
+            output: outputs.into_iter().map(|(address, value)| TxOut { value, script_pubkey: address.script_pubkey() }).collect(),
+        }
+    }
+
+    fn address(address: &str) -> Address {
+        Address::from_str(address).unwrap().assume_checked()
+    }
+
+    #[test]
+    fn test_received_payment() {
+
+        let token_address = address("bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080");
+        let change_address = address("bcrt1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qzf4jry");
+
+        let transaction = transaction_paying(vec![(&token_address, 6000), (&change_address, 50000), (&token_address, 4000)]);
+        let script = token_address.script_pubkey();
+
+        let payment = received_payment(&transaction, 100, &script, 102).unwrap();
+        assert_eq!(payment.amount_sats, 10000);
+        assert_eq!(payment.confirmations, 3);
+        assert_eq!(payment.txid, transaction.txid().to_string());
+
+        assert_eq!(received_payment(&transaction, 0, &script, 102).unwrap().confirmations, 0);
+        assert_eq!(received_payment(&transaction, -1, &script, 102).unwrap().confirmations, 0);
+
+        // a spend from the address pays nothing to it
+        let spend = transaction_paying(vec![(&change_address, 9000)]);
+        assert!(received_payment(&spend, 100, &script, 102).is_none());
+    }
+}
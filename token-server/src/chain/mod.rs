@@ -0,0 +1,145 @@
+pub mod bitcoind;
+pub mod electrum;
+
+use crate::server_config::ServerConfig;
+
+/// Output paying to a watched address
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressPayment {
+    pub txid: String,
+    pub amount_sats: u64,
+    /// 0 if the transaction is still in the mempool
+    pub confirmations: u64,
+}
+
+/// Source of the on-chain payments received by the token addresses
+#[rocket::async_trait]
+pub trait ChainBackend: Send + Sync {
+    async fn address_payments(&self, address: &str) -> Result<Vec<AddressPayment>, String>;
+}
+
+/// Builds the chain backend selected by the `chain_backend` setting, if any
+pub fn from_config(config: &ServerConfig) -> Result<Option<Box<dyn ChainBackend>>, String> {
+
+    let chain_backend = match &config.chain_backend {
+        Some(chain_backend) => chain_backend,
+        None => return Ok(None),
+    };
+
+    let backend: Box<dyn ChainBackend> = match chain_backend.as_str() {
+        "electrum" => {
+            let electrum_url = config.electrum_url.as_ref().ok_or("electrum_url must be set for the electrum chain backend")?;
+            Box::new(electrum::Electrum::new(electrum_url))
+        },
+        "bitcoind" => {
+            let bitcoind_url = config.bitcoind_url.as_ref().ok_or("bitcoind_url must be set for the bitcoind chain backend")?;
+            Box::new(bitcoind::Bitcoind::new(bitcoind_url, config.bitcoind_user.as_deref(), config.bitcoind_password.as_deref()))
+        },
+        chain_backend => return Err(format!("Unknown chain backend {}", chain_backend)),
+    };
+
+    Ok(Some(backend))
+}
+
+/// Result of comparing the payments received by a token address with the token fee
+#[derive(Debug, Clone, PartialEq)]
+pub enum OnchainPaymentStatus {
+    Unpaid,
+    /// Payments were received but do not have enough confirmations yet
+    Unconfirmed,
+    Underpaid,
+    Paid,
+    Overpaid,
+}
+
+impl OnchainPaymentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnchainPaymentStatus::Unpaid => "unpaid",
+            OnchainPaymentStatus::Unconfirmed => "unconfirmed",
+            OnchainPaymentStatus::Underpaid => "underpaid",
+            OnchainPaymentStatus::Paid => "paid",
+            OnchainPaymentStatus::Overpaid => "overpaid",
+        }
+    }
+
+    /// Whether the token can be confirmed
+    pub fn is_paid(&self) -> bool {
+        matches!(self, OnchainPaymentStatus::Paid | OnchainPaymentStatus::Overpaid)
+    }
+}
+
+/// Returns the payment status and the amount received with at least `min_confirmations`
+pub fn onchain_payment_status(payments: &[AddressPayment], fee_sats: u64, min_confirmations: u64) -> (OnchainPaymentStatus, u64) {
+
+    let confirmed_amount: u64 = payments.iter()
+        .filter(|payment| payment.confirmations >= min_confirmations)
+        .map(|payment| payment.amount_sats)
+        .sum();
+
+    let status = if payments.is_empty() {
+        OnchainPaymentStatus::Unpaid
+    } else if confirmed_amount == 0 {
+        OnchainPaymentStatus::Unconfirmed
+    } else if confirmed_amount < fee_sats {
+        OnchainPaymentStatus::Underpaid
+    } else if confirmed_amount == fee_sats {
+        OnchainPaymentStatus::Paid
+    } else {
+        OnchainPaymentStatus::Overpaid
+    };
+
+    (status, confirmed_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment(amount_sats: u64, confirmations: u64) -> AddressPayment {
+        AddressPayment {
+            txid: format!("{}-{}", amount_sats, confirmations),
+            amount_sats,
+            confirmations,
+        }
+    }
+
+    #[test]
+    fn test_unpaid_and_unconfirmed() {
+        assert_eq!(onchain_payment_status(&[], 10000, 3), (OnchainPaymentStatus::Unpaid, 0));
+        assert_eq!(onchain_payment_status(&[payment(10000, 0)], 10000, 3), (OnchainPaymentStatus::Unconfirmed, 0));
+        assert_eq!(onchain_payment_status(&[payment(10000, 2)], 10000, 3), (OnchainPaymentStatus::Unconfirmed, 0));
+    }
+
+    #[test]
+    fn test_paid_with_min_confirmations() {
+        let (status, amount) = onchain_payment_status(&[payment(10000, 3)], 10000, 3);
+
+        assert_eq!(status, OnchainPaymentStatus::Paid);
+        assert_eq!(amount, 10000);
+        assert!(status.is_paid());
+    }
+
+    #[test]
+    fn test_only_confirmed_payments_are_counted() {
+        let payments = [payment(6000, 5), payment(4000, 1)];
+
+        let (status, amount) = onchain_payment_status(&payments, 10000, 3);
+        assert_eq!(status, OnchainPaymentStatus::Underpaid);
+        assert_eq!(amount, 6000);
+        assert!(!status.is_paid());
+
+        // the second payment confirms
+        let payments = [payment(6000, 7), payment(4000, 3)];
+        assert_eq!(onchain_payment_status(&payments, 10000, 3), (OnchainPaymentStatus::Paid, 10000));
+    }
+
+    #[test]
+    fn test_overpaid() {
+        let (status, amount) = onchain_payment_status(&[payment(12000, 4)], 10000, 3);
+
+        assert_eq!(status, OnchainPaymentStatus::Overpaid);
+        assert_eq!(amount, 12000);
+        assert!(status.is_paid());
+    }
+}
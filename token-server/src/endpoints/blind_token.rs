@@ -0,0 +1,290 @@
+use mercurylib::{deposit::blind_token::{BlindNonceResponsePayload, BlindSignRequestPayload, BlindSignResponsePayload}, wallet::TokenStatus};
+use rocket::{serde::json::Json, response::status, State, http::Status};
+use serde_json::{Value, json};
+use sqlx::Row;
+
+use crate::{endpoints::token::{get_token_lifecycle, ServiceKey, TokenSpendRequest}, server::TokenServer};
+
+fn blind_tokens_disabled() -> status::Custom<Json<Value>> {
+    let response_body = json!({
+        "message": "Blind tokens are not enabled."
+    });
+
+    status::Custom(Status::NotFound, Json(response_body))
+}
+
+#[get("/token/blind_pubkey")]
+pub async fn blind_pubkey(token_server: &State<TokenServer>) -> status::Custom<Json<Value>> {
+
+    let blind_signing_key = match &token_server.config.blind_signing_key {
+        Some(blind_signing_key) => blind_signing_key,
+        None => return blind_tokens_disabled(),
+    };
+
+    let public_key = match mercurylib::deposit::blind_token::blind_signer_pubkey(blind_signing_key) {
+        Ok(public_key) => public_key,
+        Err(_) => {
+            let response_body = json!({
+                "message": "Invalid blind signing key."
+            });
+
+            return status::Custom(Status::InternalServerError, Json(response_body));
+        }
+    };
+
+    let response_body = json!({
+        "public_key": public_key
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+/// Returns the nonce the paid token will be blind signed with. The same nonce is returned until the token is signed
+/// or the session times out. Only one session is open at a time: concurrent sessions would let a client combine
+/// the signatures of many nonces into a signature it was not given (ROS attack).
+#[post("/token/blind_nonce/<token_id>")]
+pub async fn blind_nonce(token_server: &State<TokenServer>, token_id: String) -> status::Custom<Json<Value>> {
+
+    if token_server.config.blind_signing_key.is_none() {
+        return blind_tokens_disabled();
+    }
+
+    let token = get_token_lifecycle(&token_server.pool, &token_id).await;
+
+    if token.is_none() {
+        let response_body = json!({
+            "message": "Token not found"
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    let (_, token_status, _) = token.unwrap();
+
+    if token_status != TokenStatus::PAID {
+        let response_body = json!({
+            "message": format!("Only paid tokens can be blind signed. Current status: {:?}", token_status)
+        });
+
+        return status::Custom(Status::Gone, Json(response_body));
+    }
+
+    let public_nonce = match open_blind_session(&token_server.pool, &token_id, token_server.config.blind_session_timeout).await {
+        Some(public_nonce) => public_nonce,
+        None => {
+            let response_body = json!({
+                "message": "Another blind signature is in progress, retry later."
+            });
+
+            return status::Custom(Status::Conflict, Json(response_body));
+        }
+    };
+
+    let response_body = json!(BlindNonceResponsePayload {
+        public_nonce,
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+/// Signs the blinded challenge and spends the token. Only one challenge is ever signed per token,
+/// so the request can be repeated with the same challenge but not with a different one.
+#[post("/token/blind_sign", format = "json", data = "<blind_sign_request>")]
+pub async fn blind_sign(token_server: &State<TokenServer>, blind_sign_request: Json<BlindSignRequestPayload>) -> status::Custom<Json<Value>> {
+
+    let blind_signing_key = match &token_server.config.blind_signing_key {
+        Some(blind_signing_key) => blind_signing_key,
+        None => return blind_tokens_disabled(),
+    };
+
+    let blind_sign_request = blind_sign_request.0;
+
+    let blind_session_timeout = token_server.config.blind_session_timeout;
+
+    let secret_nonce = match get_blind_nonce(&token_server.pool, &blind_sign_request.token_id, blind_session_timeout).await {
+        Some((secret_nonce, _)) => secret_nonce,
+        None => {
+            let response_body = json!({
+                "message": "No open blind signing session for the token."
+            });
+
+            return status::Custom(Status::NotFound, Json(response_body));
+        }
+    };
+
+    // the signature is only returned once the challenge is recorded, so the nonce is never used for another challenge
+    let blind_signature = match mercurylib::deposit::blind_token::blind_sign(blind_signing_key, &secret_nonce, &blind_sign_request.challenge) {
+        Ok(blind_signature) => blind_signature,
+        Err(_) => {
+            let response_body = json!({
+                "message": "Invalid challenge."
+            });
+
+            return status::Custom(Status::BadRequest, Json(response_body));
+        }
+    };
+
+    if !set_token_blind_signed(&token_server.pool, &blind_sign_request.token_id, &blind_sign_request.challenge, &secret_nonce, blind_session_timeout).await {
+        let response_body = json!({
+            "message": "Token unpaid, refunded, spent, already signed for another challenge or the session timed out."
+        });
+
+        return status::Custom(Status::Gone, Json(response_body));
+    }
+
+    let response_body = json!(BlindSignResponsePayload {
+        blind_signature,
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+/// Records the blind token as spent by the statecoin, after the mercury server verified its signature.
+/// Spending it again for the same statecoin succeeds, so the request can be retried.
+#[post("/token/blind_spend", format = "json", data = "<token_spend_request>")]
+pub async fn blind_spend(token_server: &State<TokenServer>, _service_key: ServiceKey, token_spend_request: Json<TokenSpendRequest>) -> status::Custom<Json<Value>> {
+
+    let token_spend_request = token_spend_request.0;
+
+    if !set_blind_token_spent(&token_server.pool, &token_spend_request.token_id, &token_spend_request.statechain_id).await {
+        let response_body = json!({
+            "message": "Blind token spent by another statecoin."
+        });
+
+        return status::Custom(Status::Gone, Json(response_body));
+    }
+
+    let response_body = json!({
+        "message": "Blind token spent."
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+/// Key of the advisory lock serializing the opening of blind signing sessions
+const BLIND_SESSION_LOCK: i64 = 0x626c696e64;
+
+/// Returns the public nonce of the open session of the token, opening one if no other token has a session.
+/// Sessions without a challenge after the timeout are closed first. Returns None if another session is open.
+pub async fn open_blind_session(pool: &sqlx::PgPool, token_id: &str, timeout: u64) -> Option<String> {
+
+    let mut transaction = pool.begin().await.unwrap();
+
+    sqlx::query("SELECT pg_advisory_xact_lock($1)")
+        .bind(BLIND_SESSION_LOCK)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    let query = "UPDATE tokens \
+        SET blind_secret_nonce = NULL, blind_public_nonce = NULL, blind_nonce_created_at = NULL \
+        WHERE blind_secret_nonce IS NOT NULL \
+        AND blind_challenge IS NULL \
+        AND blind_nonce_created_at < NOW() - $1 * INTERVAL '1 second'";
+
+    let _ = sqlx::query(query)
+        .bind(timeout as i64)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    let open_session = sqlx::query(
+        "SELECT token_id, blind_public_nonce \
+        FROM public.tokens \
+        WHERE blind_secret_nonce IS NOT NULL \
+        AND blind_challenge IS NULL")
+        .fetch_optional(&mut *transaction)
+        .await
+        .unwrap();
+
+    if let Some(row) = open_session {
+        let open_token_id: String = row.get(0);
+        let public_nonce: String = row.get(1);
+
+        transaction.commit().await.unwrap();
+
+        return if open_token_id == token_id { Some(public_nonce) } else { None };
+    }
+
+    let (secret_nonce, public_nonce) = mercurylib::deposit::blind_token::generate_blind_nonce();
+
+    let query = "UPDATE tokens \
+        SET blind_secret_nonce = $1, blind_public_nonce = $2, blind_nonce_created_at = NOW() \
+        WHERE token_id = $3";
+
+    let _ = sqlx::query(query)
+        .bind(&secret_nonce)
+        .bind(&public_nonce)
+        .bind(token_id)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    transaction.commit().await.unwrap();
+
+    Some(public_nonce)
+}
+
+/// Returns the secret and public nonce of the token, if its session is open or its challenge was signed
+pub async fn get_blind_nonce(pool: &sqlx::PgPool, token_id: &str, timeout: u64) -> Option<(String, String)> {
+
+    let row = sqlx::query(
+        "SELECT blind_secret_nonce, blind_public_nonce \
+        FROM public.tokens \
+        WHERE token_id = $1 \
+        AND (blind_challenge IS NOT NULL OR blind_nonce_created_at >= NOW() - $2 * INTERVAL '1 second')")
+        .bind(token_id)
+        .bind(timeout as i64)
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    let row = row?;
+
+    let secret_nonce: Option<String> = row.get(0);
+    let public_nonce: Option<String> = row.get(1);
+
+    secret_nonce.zip(public_nonce)
+}
+
+/// Records the signed challenge and marks the token as spent. Returns false if the token is unpaid, refunded,
+/// spent, was signed for another challenge or its session with this nonce timed out.
+pub async fn set_token_blind_signed(pool: &sqlx::PgPool, token_id: &str, challenge: &str, secret_nonce: &str, timeout: u64) -> bool {
+
+    let query = "UPDATE tokens \
+        SET spent = true, blind_challenge = $1 \
+        WHERE token_id = $2 \
+        AND confirmed = true \
+        AND refunded = false \
+        AND blind_secret_nonce = $3 \
+        AND (spent = false OR blind_challenge = $1) \
+        AND (blind_challenge IS NOT NULL OR blind_nonce_created_at >= NOW() - $4 * INTERVAL '1 second')";
+
+    let result = sqlx::query(query)
+        .bind(challenge)
+        .bind(token_id)
+        .bind(secret_nonce)
+        .bind(timeout as i64)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    result.rows_affected() == 1
+}
+
+/// Records the blind token as spent by the statecoin. Returns false if it was spent by another statecoin.
+pub async fn set_blind_token_spent(pool: &sqlx::PgPool, token_id: &str, statechain_id: &str) -> bool {
+
+    let query = "INSERT INTO blind_tokens (token_id, statechain_id) VALUES ($1, $2) \
+        ON CONFLICT (token_id) DO UPDATE SET statechain_id = blind_tokens.statechain_id \
+        WHERE blind_tokens.statechain_id = $2";
+
+    let result = sqlx::query(query)
+        .bind(token_id)
+        .bind(statechain_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    result.rows_affected() == 1
+}
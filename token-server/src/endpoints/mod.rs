@@ -1 +1,2 @@
+pub mod blind_token;
 pub mod token;
\ No newline at end of file
@@ -1,87 +1,12 @@
-use std::str::FromStr;
-
-use rocket::{serde::json::Json, response::status, State, http::Status};
+use chrono::{DateTime, Utc};
+use log::error;
+use rocket::{serde::json::Json, response::status, State, http::Status, request::{FromRequest, Outcome, Request}};
 use serde::{Serialize, Deserialize};
 use serde_json::{Value, json};
+use mercurylib::{deposit::TokenStatusResponsePayload, wallet::TokenStatus};
 use sqlx::Row;
 
-use crate::server::TokenServer;
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct Invoice{
-    pub id: String,
-    pub pr: String,
-    pub checkoutUrl: String,
-    pub onChainAddr: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct ReqInvoice{
-    pub title: String,
-    pub description: String,
-    pub amount: String,
-    pub unit: String,
-    pub redirectAfterPaid: String,
-    pub email: String,
-    pub emailLanguage: String,
-    pub onChain: bool,
-    pub delay: u64,
-    pub extra: Extra,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct Extra{
-    pub tag: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct RTLInvoice{
-    pub id: String,
-    pub pr: String,
-    pub checkoutUrl: String,
-    pub onChainAddr: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct RTLData{
-    pub label: String,
-    pub bolt11: String,
-    pub payment_hash: String,
-    pub msatoshi: u64,
-    pub amount_msat: String,
-    pub status: String,
-    pub description: String,
-    pub expires_at: u64
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct RTLQuery{
-    pub createdAt: u64,
-    pub delay: u64,
-    pub pr: String,
-    pub amount: u64,
-    pub btcAmount: String,
-    pub unit: String,
-    pub isPaid: bool,
-    pub updatePrice: bool,
-    pub isHodled: bool,
-    pub isInit: bool,
-    pub isFixedSatPrice: bool,
-    pub deleteExpiredInvoice: bool,
-    pub isExpired: bool,
-    pub paymentMethod: Option<String>,
-    pub paidAt: Option<String>,
-    pub title: String,
-    pub hash: String,
-    pub fiatAmount: f64,
-    pub fiatUnit: String,
-    pub onChainAddr: String,
-    pub minConfirmations: u64,
-    pub confirmations: u64,
-    pub txId: Option<String>,
-    pub isPending: bool,
-    pub extra: Extra,
-}
+use crate::{chain::{onchain_payment_status, ChainBackend}, processor::PaymentStatus, server::TokenServer};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PODInfo {
@@ -90,12 +15,15 @@ pub struct PODInfo {
     pub lightning_invoice: String,
     pub btc_payment_address: String,
     pub processor_id: String,
+    pub expiry: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PODStatus {
     pub confirmed: bool,
     pub expiry: u64,
+    /// Status of the on-chain payment (unpaid, unconfirmed, underpaid, paid or overpaid), if a chain backend watches it
+    pub onchain_status: Option<String>,
 }
 
 
@@ -104,43 +32,71 @@ pub async fn token_init(token_server: &State<TokenServer>) -> status::Custom<Jso
 
     let token_id = uuid::Uuid::new_v4().to_string();   
 
-    let invoice: Invoice = get_lightning_invoice(token_server, token_id.clone()).await;
+    let fee_sats = token_server.config.fee.parse::<u64>().unwrap();
+
+    let invoice = match token_server.processor.create_invoice(&token_id, fee_sats).await {
+        Ok(invoice) => invoice,
+        Err(err) => {
+            let response_body = json!({
+                "message": format!("Payment processor error: {}", err)
+            });
+
+            return status::Custom(Status::InternalServerError, Json(response_body));
+        }
+    };
+
+    // the token expires with its invoice
+    let expires_at = DateTime::<Utc>::from_timestamp(invoice.expiry as i64, 0).unwrap();
+
     let pod_info = PODInfo {
         token_id: token_id.clone(),
         fee: token_server.config.fee.clone(),
         lightning_invoice: invoice.pr.clone(),
-        btc_payment_address: invoice.onChainAddr.clone(),
+        btc_payment_address: invoice.onchain_address.clone(),
         processor_id: invoice.id.clone(),
+        expiry: expires_at.to_rfc3339(),
     };
 
-    insert_new_token(&token_server.pool, &token_id, &invoice.pr.clone(), &invoice.onChainAddr, &invoice.id).await;
+    insert_new_token(&token_server.pool, &token_id, &invoice.pr, &invoice.onchain_address, &invoice.id, &expires_at).await;
 
     let response_body = json!(pod_info);
 
-    return status::Custom(Status::Ok, Json(response_body));
+    status::Custom(Status::Ok, Json(response_body))
 }
 
 #[get("/token/token_verify/<token_id>")]
 pub async fn token_verify(token_server: &State<TokenServer>, token_id: String) -> status::Custom<Json<Value>> {
 
     let row = sqlx::query(
-        "SELECT processor_id, confirmed, spent \
+        "SELECT processor_id, confirmed, spent, onchain_status, refunded \
         FROM public.tokens \
         WHERE token_id = $1")
         .bind(&token_id)
-        .fetch_one(&token_server.pool)
-        .await;
+        .fetch_optional(&token_server.pool)
+        .await
+        .unwrap();
+
+    if row.is_none() {
+        let response_body = json!({
+            "message": "Token not found"
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
 
     let row = row.unwrap();
 
     let processor_id: String = row.get(0);
     let confirmed: bool = row.get(1);
     let spent: bool = row.get(2);
+    let onchain_status: Option<String> = row.get(3);
+    let refunded: bool = row.get(4);
 
-    if spent {
+    if spent || refunded {
         let pod_status = PODStatus {
             confirmed: false,
-            expiry: 0 as u64,
+            expiry: 0_u64,
+            onchain_status: onchain_status.clone(),
         };
         let response_body = json!(pod_status);
         return status::Custom(Status::Ok, Json(response_body));            
@@ -149,34 +105,270 @@ pub async fn token_verify(token_server: &State<TokenServer>, token_id: String) -
     if confirmed {
         let pod_status = PODStatus {
             confirmed: true,
-            expiry: 0 as u64,
+            expiry: 0_u64,
+            onchain_status: onchain_status.clone(),
         };
         let response_body = json!(pod_status);
-        return status::Custom(Status::Ok, Json(response_body));            
+        status::Custom(Status::Ok, Json(response_body))
     } else {
-        let expiry = query_lightning_payment(token_server, &processor_id).await;
-        if expiry == 0 {
-            set_token_confirmed(&token_server.pool, &token_id).await;
-            let pod_status = PODStatus {
-                confirmed: true,
-                expiry: expiry,
-            };
-            let response_body = json!(pod_status);
-            return status::Custom(Status::Ok, Json(response_body));  
-        } else {
-            let pod_status = PODStatus {
-                confirmed: false,
-                expiry: expiry,
-            };
-            let response_body = json!(pod_status);
-            return status::Custom(Status::Ok, Json(response_body));
+        let payment_status = match token_server.processor.query_payment(&processor_id).await {
+            Ok(payment_status) => payment_status,
+            Err(err) => {
+                let response_body = json!({
+                    "message": format!("Payment processor error: {}", err)
+                });
+
+                return status::Custom(Status::InternalServerError, Json(response_body));
+            }
+        };
+
+        match payment_status {
+            PaymentStatus::Paid => {
+                set_token_confirmed(&token_server.pool, &token_id).await;
+                let pod_status = PODStatus {
+                    confirmed: true,
+                    expiry: 0,
+                    onchain_status: onchain_status.clone(),
+                };
+                let response_body = json!(pod_status);
+                status::Custom(Status::Ok, Json(response_body))
+            },
+            PaymentStatus::Pending { expiry } => {
+                let pod_status = PODStatus {
+                    confirmed: false,
+                    expiry,
+                    onchain_status: onchain_status.clone(),
+                };
+                let response_body = json!(pod_status);
+                status::Custom(Status::Ok, Json(response_body))
+            }
         }
     }
 }
 
-pub async fn insert_new_token(pool: &sqlx::PgPool, token_id: &str, invoice: &str, onchain_address: &str, processor_id: &str)  {
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TokenRefundRequest {
+    /// Transaction returning the payment, if it was refunded on-chain
+    pub refund_txid: Option<String>,
+}
+
+/// Request guard for the admin endpoints. The `X-Admin-Key` header must match the `admin_key` setting.
+pub struct AdminKey;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AdminKey {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+
+        let token_server = request.rocket().state::<TokenServer>().unwrap();
+
+        match (&token_server.config.admin_key, request.headers().get_one("X-Admin-Key")) {
+            (Some(admin_key), Some(key)) if admin_key == key => Outcome::Success(AdminKey),
+            _ => Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TokenSpendRequest {
+    pub token_id: String,
+    /// Statecoin created with the token. Spending the token again for the same statecoin succeeds.
+    pub statechain_id: String,
+}
+
+/// Request guard for the endpoints called by the mercury server. The `X-Service-Key` header must match the `service_key` setting.
+pub struct ServiceKey;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ServiceKey {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+
+        let token_server = request.rocket().state::<TokenServer>().unwrap();
+
+        match (&token_server.config.service_key, request.headers().get_one("X-Service-Key")) {
+            (Some(service_key), Some(key)) if service_key == key => Outcome::Success(ServiceKey),
+            _ => Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+#[get("/token/status/<token_id>")]
+pub async fn token_status(token_server: &State<TokenServer>, token_id: String) -> status::Custom<Json<Value>> {
+
+    let token = get_token_lifecycle(&token_server.pool, &token_id).await;
+
+    if token.is_none() {
+        let response_body = json!({
+            "message": "Token not found"
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    let (processor_id, mut token_status, expires_at) = token.unwrap();
+
+    // pending tokens are checked with the processor, in case the payment was not seen yet
+    if token_status == TokenStatus::PENDING {
+        match token_server.processor.query_payment(&processor_id).await {
+            Ok(PaymentStatus::Paid) => {
+                set_token_confirmed(&token_server.pool, &token_id).await;
+                token_status = TokenStatus::PAID;
+            },
+            Ok(PaymentStatus::Pending { .. }) => {},
+            Err(err) => {
+                let response_body = json!({
+                    "message": format!("Payment processor error: {}", err)
+                });
+
+                return status::Custom(Status::InternalServerError, Json(response_body));
+            }
+        }
+    }
+
+    let response_body = json!(TokenStatusResponsePayload {
+        token_id,
+        status: token_status,
+        expiry: expires_at.map(|expires_at| expires_at.to_rfc3339()).unwrap_or_default(),
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[post("/token/refund/<token_id>", format = "json", data = "<token_refund_request>")]
+pub async fn token_refund(token_server: &State<TokenServer>, _admin_key: AdminKey, token_id: String, token_refund_request: Json<TokenRefundRequest>) -> status::Custom<Json<Value>> {
+
+    let token = get_token_lifecycle(&token_server.pool, &token_id).await;
+
+    if token.is_none() {
+        let response_body = json!({
+            "message": "Token not found"
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    let (_, token_status, _) = token.unwrap();
+
+    if token_status == TokenStatus::SPENT || token_status == TokenStatus::REFUNDED {
+        let response_body = json!({
+            "message": format!("Token cannot be refunded. Current status: {:?}", token_status)
+        });
+
+        return status::Custom(Status::BadRequest, Json(response_body));
+    }
+
+    set_token_refunded(&token_server.pool, &token_id, token_refund_request.0.refund_txid.as_deref()).await;
+
+    let response_body = json!({
+        "message": "Token refunded."
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
+
+#[post("/token/token_spend", format = "json", data = "<token_spend_request>")]
+pub async fn token_spend(token_server: &State<TokenServer>, _service_key: ServiceKey, token_spend_request: Json<TokenSpendRequest>) -> status::Custom<Json<Value>> {
+
+    let token_spend_request = token_spend_request.0;
 
-    let query = "INSERT INTO tokens (token_id, invoice, onchain_address, processor_id, confirmed, spent) VALUES ($1, $2, $3, $4, $5, $6)";
+    if set_token_spent(&token_server.pool, &token_spend_request.token_id, &token_spend_request.statechain_id).await {
+        let response_body = json!({
+            "message": "Token spent."
+        });
+
+        return status::Custom(Status::Ok, Json(response_body));
+    }
+
+    if get_token_lifecycle(&token_server.pool, &token_spend_request.token_id).await.is_none() {
+        let response_body = json!({
+            "message": "Token not found"
+        });
+
+        return status::Custom(Status::NotFound, Json(response_body));
+    }
+
+    let response_body = json!({
+        "message": "Token unpaid, refunded or spent by another statecoin."
+    });
+
+    status::Custom(Status::Gone, Json(response_body))
+}
+
+/// Marks a paid token as spent by the statecoin. Returns false if the token is unpaid, refunded or was spent by another statecoin.
+pub async fn set_token_spent(pool: &sqlx::PgPool, token_id: &str, statechain_id: &str) -> bool {
+
+    let query = "UPDATE tokens \
+        SET spent = true, statechain_id = $1 \
+        WHERE token_id = $2 \
+        AND confirmed = true \
+        AND refunded = false \
+        AND (spent = false OR statechain_id = $1)";
+
+    let result = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(token_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    result.rows_affected() == 1
+}
+
+/// Returns the processor id, status and expiry of the token
+pub async fn get_token_lifecycle(pool: &sqlx::PgPool, token_id: &str) -> Option<(String, TokenStatus, Option<DateTime<Utc>>)> {
+
+    let row = sqlx::query(
+        "SELECT processor_id, confirmed, spent, refunded, expires_at \
+        FROM public.tokens \
+        WHERE token_id = $1")
+        .bind(token_id)
+        .fetch_optional(pool)
+        .await
+        .unwrap();
+
+    let row = row?;
+
+    let processor_id: String = row.get(0);
+    let confirmed: bool = row.get(1);
+    let spent: bool = row.get(2);
+    let refunded: bool = row.get(3);
+    let expires_at: Option<DateTime<Utc>> = row.get(4);
+
+    let token_status = if spent {
+        TokenStatus::SPENT
+    } else if refunded {
+        TokenStatus::REFUNDED
+    } else if confirmed {
+        TokenStatus::PAID
+    } else if expires_at.is_some_and(|expires_at| expires_at < Utc::now()) {
+        TokenStatus::EXPIRED
+    } else {
+        TokenStatus::PENDING
+    };
+
+    Some((processor_id, token_status, expires_at))
+}
+
+pub async fn set_token_refunded(pool: &sqlx::PgPool, token_id: &str, refund_txid: Option<&str>)  {
+
+    let query = "UPDATE tokens \
+        SET refunded = true, refund_txid = $1 \
+        WHERE token_id = $2";
+
+    let _ = sqlx::query(query)
+        .bind(refund_txid)
+        .bind(token_id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+pub async fn insert_new_token(pool: &sqlx::PgPool, token_id: &str, invoice: &str, onchain_address: &str, processor_id: &str, expires_at: &DateTime<Utc>)  {
+
+    let query = "INSERT INTO tokens (token_id, invoice, onchain_address, processor_id, confirmed, spent, expires_at) VALUES ($1, $2, $3, $4, $5, $6, $7)";
 
     let _ = sqlx::query(query)
         .bind(token_id)
@@ -185,82 +377,96 @@ pub async fn insert_new_token(pool: &sqlx::PgPool, token_id: &str, invoice: &str
         .bind(processor_id)
         .bind(false)
         .bind(false)
+        .bind(expires_at)
         .execute(pool)
         .await
         .unwrap();
 }
 
 
-pub async fn get_lightning_invoice(token_server: &State<TokenServer>, token_id: String) -> Invoice {
+pub async fn set_token_confirmed(pool: &sqlx::PgPool, token_id: &str)  {
 
-    let processor_url = token_server.config.processor_url.clone();
-    let api_key = token_server.config.api_key.clone();
-    let path: &str = "checkout";
-    let extra: Extra = Extra {
-        tag: "invoice-web".to_string(),
-    };
-    let inv_request: ReqInvoice = ReqInvoice {
-        title: token_id.clone().to_string(),
-        description: "".to_string(),
-        amount: token_server.config.fee.clone(),
-        unit: token_server.config.unit.clone(),
-        redirectAfterPaid: "".to_string(),
-        email: "".to_string(),
-        emailLanguage: "en".to_string(),
-        onChain: true,
-        delay: token_server.config.delay.clone(),
-        extra: extra,
-    };
+    let mut transaction = pool.begin().await.unwrap();
 
-    let client: reqwest::Client = reqwest::Client::new();
-    let request = client.post(&format!("{}/{}", processor_url, path));
-    
-    let value = request.header("Api-Key", api_key).header("encodingtype","hex").json(&inv_request).send().await.unwrap().text().await.unwrap(); 
+    let query = "UPDATE tokens \
+        SET confirmed = true \
+        WHERE token_id = $1";
 
-    let ret_invoice: RTLInvoice = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let _ = sqlx::query(query)
+        .bind(token_id)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
 
-    let invoice = Invoice {
-        id: ret_invoice.id,
-        pr: ret_invoice.pr,
-        checkoutUrl: ret_invoice.checkoutUrl,
-        onChainAddr: ret_invoice.onChainAddr,
-    };
-    return invoice;
+    transaction.commit().await.unwrap();
 }
 
-pub async fn query_lightning_payment(token_server: &State<TokenServer>, processor_id: &String) -> u64 {
+/// Interval in seconds between checks of the on-chain token payments
+const ONCHAIN_CHECK_INTERVAL: u64 = 60;
 
-    let processor_url = token_server.config.processor_url.clone();
-    let api_key = token_server.config.api_key.clone();
-    let path: String = "checkout/".to_string() + processor_id;
+/// Periodically checks the payments received by the on-chain addresses of the unconfirmed tokens.
+/// Tokens are confirmed once the fee is paid with enough confirmations. Underpayments and overpayments are recorded in the token status.
+pub async fn watch_onchain_payments(pool: sqlx::PgPool, chain_backend: Box<dyn ChainBackend>, fee_sats: u64, min_confirmations: u64) {
 
-    let client: reqwest::Client = reqwest::Client::new();
-    let request = client.get(&format!("{}/{}", processor_url, path));
+    loop {
+        let tokens = get_unconfirmed_onchain_tokens(&pool).await;
 
-    let value = request.header("Api-Key", api_key).header("encodingtype","hex").send().await.unwrap().text().await.unwrap();
+        for (token_id, onchain_address) in tokens {
 
-    let ret_invoice: RTLQuery = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+            let payments = match chain_backend.address_payments(&onchain_address).await {
+                Ok(payments) => payments,
+                Err(err) => {
+                    error!("Failed to get payments of address {}: {}", onchain_address, err);
+                    continue;
+                }
+            };
 
-    if ret_invoice.isPaid {
-        return 0;
-    } else {
-        return ret_invoice.createdAt + ret_invoice.delay;
+            let (status, amount) = onchain_payment_status(&payments, fee_sats, min_confirmations);
+
+            update_onchain_status(&pool, &token_id, status.as_str(), amount, status.is_paid()).await;
+        }
+
+        rocket::tokio::time::sleep(std::time::Duration::from_secs(ONCHAIN_CHECK_INTERVAL)).await;
     }
 }
 
-pub async fn set_token_confirmed(pool: &sqlx::PgPool, token_id: &str)  {
+pub async fn get_unconfirmed_onchain_tokens(pool: &sqlx::PgPool) -> Vec<(String, String)> {
 
-    let mut transaction = pool.begin().await.unwrap();
+    let query = "SELECT token_id, onchain_address \
+        FROM tokens \
+        WHERE confirmed = false \
+        AND spent = false \
+        AND onchain_address IS NOT NULL \
+        AND onchain_address <> ''";
+
+    let rows = sqlx::query(query)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<(String, String)>::new();
+
+    for row in rows {
+        let token_id: String = row.get(0);
+        let onchain_address: String = row.get(1);
+        result.push((token_id, onchain_address));
+    }
+
+    result
+}
+
+pub async fn update_onchain_status(pool: &sqlx::PgPool, token_id: &str, onchain_status: &str, onchain_amount: u64, confirmed: bool)  {
 
     let query = "UPDATE tokens \
-        SET confirmed = true \
-        WHERE token_id = $1";
+        SET onchain_status = $1, onchain_amount = $2, confirmed = confirmed OR $3 \
+        WHERE token_id = $4";
 
     let _ = sqlx::query(query)
+        .bind(onchain_status)
+        .bind(onchain_amount as i64)
+        .bind(confirmed)
         .bind(token_id)
-        .execute(&mut *transaction)
+        .execute(pool)
         .await
         .unwrap();
-
-    transaction.commit().await.unwrap();
 }
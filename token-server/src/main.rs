@@ -1,4 +1,6 @@
+mod chain;
 mod endpoints;
+mod processor;
 mod server_config;
 mod server;
 
@@ -27,14 +29,34 @@ fn not_found(req: &Request) -> Value {
 #[rocket::main]
 async fn main() {
 
+    env_logger::init();
+
     server_config::ServerConfig::load();
 
     let token_server = TokenServer::new().await;
 
+    sqlx::migrate!("./migrations")
+        .run(&token_server.pool)
+        .await
+        .unwrap();
+
+    if let Some(chain_backend) = chain::from_config(&token_server.config).unwrap() {
+        let fee_sats = token_server.config.fee.parse::<u64>().unwrap();
+        rocket::tokio::spawn(endpoints::token::watch_onchain_payments(token_server.pool.clone(), chain_backend, fee_sats, token_server.config.min_confirmations));
+    }
+
     let _ = rocket::build()
         .mount("/", routes![
             endpoints::token::token_init,
             endpoints::token::token_verify,
+            endpoints::token::token_status,
+            endpoints::token::token_refund,
+            endpoints::token::token_spend,
+            endpoints::blind_token::blind_pubkey,
+            endpoints::blind_token::blind_nonce,
+            endpoints::blind_token::blind_sign,
+            endpoints::blind_token::blind_spend,
+            all_options,
         ])
         .register("/", catchers![
             not_found,
@@ -0,0 +1,179 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{PaymentProcessor, PaymentStatus, ProcessorInvoice};
+
+/// BTCPay Server Greenfield API
+pub struct BtcPay {
+    url: String,
+    api_key: String,
+    store_id: String,
+    delay: u64,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BtcPayInvoice {
+    id: String,
+    status: String,
+    expiration_time: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BtcPayPaymentMethod {
+    payment_method: String,
+    destination: String,
+}
+
+impl BtcPay {
+    pub fn new(url: &str, api_key: &str, store_id: &str, delay: u64) -> Self {
+        BtcPay {
+            url: url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            store_id: store_id.to_string(),
+            delay,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+
+        let response = self.client
+            .get(&format!("{}/api/v1/stores/{}/{}", self.url, self.store_id, path))
+            .header("Authorization", format!("token {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("BTCPay request failed. status: {}, error: {}", status, text));
+        }
+
+        response.json().await.map_err(|e| e.to_string())
+    }
+}
+
+/// Returns the lightning payment request and the on-chain address of the invoice.
+/// The payment method ids depend on the BTCPay Server version.
+fn payment_destinations(payment_methods: Vec<BtcPayPaymentMethod>) -> (String, String) {
+
+    let mut pr = String::new();
+    let mut onchain_address = String::new();
+
+    for payment_method in payment_methods {
+        match payment_method.payment_method.as_str() {
+            "BTC" | "BTC-CHAIN" => onchain_address = payment_method.destination,
+            "BTC-LightningNetwork" | "BTC-LN" => pr = payment_method.destination,
+            _ => {},
+        }
+    }
+
+    (pr, onchain_address)
+}
+
+/// Only settled invoices are paid. Processing invoices are paid but not confirmed yet.
+fn payment_status(invoice: &BtcPayInvoice) -> PaymentStatus {
+    if invoice.status == "Settled" {
+        PaymentStatus::Paid
+    } else {
+        PaymentStatus::Pending { expiry: invoice.expiration_time }
+    }
+}
+
+#[rocket::async_trait]
+impl PaymentProcessor for BtcPay {
+    async fn create_invoice(&self, token_id: &str, fee_sats: u64) -> Result<ProcessorInvoice, String> {
+
+        let body = json!({
+            "amount": fee_sats.to_string(),
+            "currency": "SATS",
+            "metadata": { "orderId": token_id },
+            "checkout": { "expirationMinutes": (self.delay / 60).max(1) },
+        });
+
+        let response = self.client
+            .post(&format!("{}/api/v1/stores/{}/invoices", self.url, self.store_id))
+            .header("Authorization", format!("token {}", self.api_key))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("BTCPay create invoice failed. status: {}, error: {}", status, text));
+        }
+
+        let invoice: BtcPayInvoice = response.json().await.map_err(|e| e.to_string())?;
+
+        let payment_methods: Vec<BtcPayPaymentMethod> = self.get(&format!("invoices/{}/payment-methods", invoice.id)).await?;
+
+        let (pr, onchain_address) = payment_destinations(payment_methods);
+
+        Ok(ProcessorInvoice {
+            id: invoice.id,
+            pr,
+            onchain_address,
+            expiry: invoice.expiration_time,
+        })
+    }
+
+    async fn query_payment(&self, processor_id: &str) -> Result<PaymentStatus, String> {
+
+        let invoice: BtcPayInvoice = self.get(&format!("invoices/{}", processor_id)).await?;
+
+        Ok(payment_status(&invoice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invoice(status: &str) -> BtcPayInvoice {
+        serde_json::from_value(json!({
+            "id": "HkRqRv5Jq5rRvgzrUXMsrL",
+            "storeId": "9CiNzKoANXxmk5ayZngSXrHTiVvvgCrwrpFQd4m2K776",
+            "amount": "10000",
+            "currency": "SATS",
+            "status": status,
+            "additionalStatus": "None",
+            "createdTime": 1700000000,
+            "expirationTime": 1700003600,
+            "metadata": { "orderId": "token" },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_payment_status() {
+        assert_eq!(payment_status(&invoice("Settled")), PaymentStatus::Paid);
+        assert_eq!(payment_status(&invoice("New")), PaymentStatus::Pending { expiry: 1700003600 });
+        assert_eq!(payment_status(&invoice("Processing")), PaymentStatus::Pending { expiry: 1700003600 });
+        assert_eq!(payment_status(&invoice("Expired")), PaymentStatus::Pending { expiry: 1700003600 });
+    }
+
+    #[test]
+    fn test_payment_destinations() {
+
+        let payment_methods: Vec<BtcPayPaymentMethod> = serde_json::from_value(json!([
+            { "paymentMethod": "BTC-CHAIN", "destination": "bcrt1qaddress", "amount": "0.0001", "activated": true },
+            { "paymentMethod": "BTC-LN", "destination": "lnbcrt100u1invoice", "amount": "0.0001", "activated": true },
+            { "paymentMethod": "LTC-CHAIN", "destination": "ltc1qaddress", "amount": "0.1", "activated": true },
+        ])).unwrap();
+
+        assert_eq!(payment_destinations(payment_methods), (String::from("lnbcrt100u1invoice"), String::from("bcrt1qaddress")));
+
+        // ids of BTCPay Server 1.x
+        let payment_methods: Vec<BtcPayPaymentMethod> = serde_json::from_value(json!([
+            { "paymentMethod": "BTC", "destination": "bcrt1qaddress" },
+            { "paymentMethod": "BTC-LightningNetwork", "destination": "lnbcrt100u1invoice" },
+        ])).unwrap();
+
+        assert_eq!(payment_destinations(payment_methods), (String::from("lnbcrt100u1invoice"), String::from("bcrt1qaddress")));
+    }
+}
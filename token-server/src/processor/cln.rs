@@ -0,0 +1,151 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{PaymentProcessor, PaymentStatus, ProcessorInvoice};
+
+/// Core Lightning node accessed through clnrest. Only lightning payments are accepted.
+pub struct Cln {
+    url: String,
+    /// Rune with invoice and listinvoices permissions
+    rune: String,
+    delay: u64,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct ClnInvoiceResponse {
+    bolt11: String,
+    expires_at: u64,
+}
+
+#[derive(Deserialize)]
+struct ClnListInvoicesResponse {
+    invoices: Vec<ClnInvoice>,
+}
+
+#[derive(Deserialize)]
+struct ClnInvoice {
+    status: String,
+    expires_at: u64,
+}
+
+impl Cln {
+    pub fn new(url: &str, rune: &str, tls_cert_path: Option<&str>, delay: u64) -> Result<Self, String> {
+        Ok(Cln {
+            url: url.trim_end_matches('/').to_string(),
+            rune: rune.to_string(),
+            delay,
+            client: super::node_client(tls_cert_path)?,
+        })
+    }
+
+    async fn call<T: serde::de::DeserializeOwned>(&self, method: &str, body: serde_json::Value) -> Result<T, String> {
+
+        let response = self.client
+            .post(&format!("{}/v1/{}", self.url, method))
+            .header("Rune", &self.rune)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("CLN {} failed. status: {}, error: {}", method, status, text));
+        }
+
+        response.json().await.map_err(|e| e.to_string())
+    }
+}
+
+/// Status of the invoice with the label, which is unique
+fn payment_status(response: ClnListInvoicesResponse, label: &str) -> Result<PaymentStatus, String> {
+
+    let invoice = response.invoices.into_iter().next().ok_or(format!("No invoice found with label {}", label))?;
+
+    if invoice.status == "paid" {
+        Ok(PaymentStatus::Paid)
+    } else {
+        Ok(PaymentStatus::Pending { expiry: invoice.expires_at })
+    }
+}
+
+#[rocket::async_trait]
+impl PaymentProcessor for Cln {
+    async fn create_invoice(&self, token_id: &str, fee_sats: u64) -> Result<ProcessorInvoice, String> {
+
+        // the token id is used as the invoice label, which CLN requires to be unique
+        let body = json!({
+            "amount_msat": fee_sats * 1000,
+            "label": token_id,
+            "description": token_id,
+            "expiry": self.delay,
+        });
+
+        let invoice: ClnInvoiceResponse = self.call("invoice", body).await?;
+
+        Ok(ProcessorInvoice {
+            id: token_id.to_string(),
+            pr: invoice.bolt11,
+            onchain_address: String::new(),
+            expiry: invoice.expires_at,
+        })
+    }
+
+    async fn query_payment(&self, processor_id: &str) -> Result<PaymentStatus, String> {
+
+        let response: ClnListInvoicesResponse = self.call("listinvoices", json!({ "label": processor_id })).await?;
+
+        payment_status(response, processor_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_invoices(status: &str) -> ClnListInvoicesResponse {
+        serde_json::from_value(json!({
+            "invoices": [{
+                "label": "token",
+                "bolt11": "lnbcrt100u1invoice",
+                "payment_hash": "95dccb9fc20604dc73e3f555addf1546668d1a1f4e4a3c2665425d9a69733cf1",
+                "amount_msat": 10000000,
+                "status": status,
+                "description": "token",
+                "expires_at": 1700003600,
+                "created_index": 1,
+            }]
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_payment_status() {
+        assert_eq!(payment_status(list_invoices("paid"), "token"), Ok(PaymentStatus::Paid));
+        assert_eq!(payment_status(list_invoices("unpaid"), "token"), Ok(PaymentStatus::Pending { expiry: 1700003600 }));
+        assert_eq!(payment_status(list_invoices("expired"), "token"), Ok(PaymentStatus::Pending { expiry: 1700003600 }));
+    }
+
+    #[test]
+    fn test_unknown_label_is_an_error() {
+        let response: ClnListInvoicesResponse = serde_json::from_value(json!({ "invoices": [] })).unwrap();
+
+        assert!(payment_status(response, "token").is_err());
+    }
+
+    #[test]
+    fn test_invoice_response() {
+
+        let invoice: ClnInvoiceResponse = serde_json::from_value(json!({
+            "payment_hash": "95dccb9fc20604dc73e3f555addf1546668d1a1f4e4a3c2665425d9a69733cf1",
+            "expires_at": 1700003600,
+            "bolt11": "lnbcrt100u1invoice",
+            "payment_secret": "189979",
+            "created_index": 1,
+        })).unwrap();
+
+        assert_eq!(invoice.bolt11, "lnbcrt100u1invoice");
+        assert_eq!(invoice.expires_at, 1700003600);
+    }
+}
@@ -0,0 +1,96 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use super::{PaymentProcessor, PaymentStatus, ProcessorInvoice};
+
+/// In-memory processor for tests and local development.
+/// If `auto_pay` is set, invoices are reported as paid as soon as they are created.
+pub struct FakeProcessor {
+    auto_pay: bool,
+    /// Paid status and expiry of the invoices, by processor id
+    invoices: Mutex<HashMap<String, (bool, u64)>>,
+}
+
+/// Expiry of the fake invoices (seconds)
+const FAKE_INVOICE_EXPIRY: u64 = 3600;
+
+impl FakeProcessor {
+    pub fn new(auto_pay: bool) -> Self {
+        FakeProcessor {
+            auto_pay,
+            invoices: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Marks the invoice as paid. Returns false if the invoice does not exist.
+    #[allow(dead_code)]
+    pub fn pay(&self, processor_id: &str) -> bool {
+        match self.invoices.lock().unwrap().get_mut(processor_id) {
+            Some((paid, _)) => {
+                *paid = true;
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl PaymentProcessor for FakeProcessor {
+    async fn create_invoice(&self, token_id: &str, fee_sats: u64) -> Result<ProcessorInvoice, String> {
+
+        let expiry = chrono::Utc::now().timestamp() as u64 + FAKE_INVOICE_EXPIRY;
+
+        let id = uuid::Uuid::new_v4().to_string();
+
+        self.invoices.lock().unwrap().insert(id.clone(), (self.auto_pay, expiry));
+
+        Ok(ProcessorInvoice {
+            pr: format!("lnfake{}{}", fee_sats, token_id),
+            onchain_address: String::new(),
+            id,
+            expiry,
+        })
+    }
+
+    async fn query_payment(&self, processor_id: &str) -> Result<PaymentStatus, String> {
+
+        match self.invoices.lock().unwrap().get(processor_id) {
+            Some((true, _)) => Ok(PaymentStatus::Paid),
+            Some((false, expiry)) => Ok(PaymentStatus::Pending { expiry: *expiry }),
+            None => Err(format!("Unknown invoice {}", processor_id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rocket::async_test]
+    async fn invoices_are_pending_until_paid() {
+        let processor = FakeProcessor::new(false);
+
+        let invoice = processor.create_invoice("token", 10000).await.unwrap();
+
+        assert!(matches!(processor.query_payment(&invoice.id).await, Ok(PaymentStatus::Pending { .. })));
+        assert!(processor.pay(&invoice.id));
+        assert_eq!(processor.query_payment(&invoice.id).await, Ok(PaymentStatus::Paid));
+    }
+
+    #[rocket::async_test]
+    async fn auto_pay_invoices_are_paid_on_creation() {
+        let processor = FakeProcessor::new(true);
+
+        let invoice = processor.create_invoice("token", 10000).await.unwrap();
+
+        assert_eq!(processor.query_payment(&invoice.id).await, Ok(PaymentStatus::Paid));
+    }
+
+    #[rocket::async_test]
+    async fn unknown_invoices_are_errors() {
+        let processor = FakeProcessor::new(true);
+
+        assert!(!processor.pay("unknown"));
+        assert!(processor.query_payment("unknown").await.is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{PaymentProcessor, PaymentStatus, ProcessorInvoice};
+
+/// LND node accessed through its REST API. Only lightning payments are accepted.
+pub struct Lnd {
+    url: String,
+    /// Hex encoded macaroon with invoice permissions
+    macaroon: String,
+    delay: u64,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct LndAddInvoiceResponse {
+    r_hash: String,
+    payment_request: String,
+}
+
+#[derive(Deserialize)]
+struct LndInvoice {
+    state: String,
+    creation_date: String,
+    expiry: String,
+}
+
+impl Lnd {
+    pub fn new(url: &str, macaroon: &str, tls_cert_path: Option<&str>, delay: u64) -> Result<Self, String> {
+        Ok(Lnd {
+            url: url.trim_end_matches('/').to_string(),
+            macaroon: macaroon.to_string(),
+            delay,
+            client: super::node_client(tls_cert_path)?,
+        })
+    }
+}
+
+/// LND returns the payment hash in base64, but looks invoices up by the hex encoded hash
+fn processor_id(r_hash: &str) -> Result<String, String> {
+    let r_hash = STANDARD.decode(r_hash).map_err(|e| e.to_string())?;
+    Ok(hex::encode(r_hash))
+}
+
+/// LND encodes the 64 bit integers of the invoice as strings
+fn payment_status(invoice: &LndInvoice) -> Result<PaymentStatus, String> {
+
+    if invoice.state == "SETTLED" {
+        return Ok(PaymentStatus::Paid);
+    }
+
+    let creation_date = invoice.creation_date.parse::<u64>().map_err(|e| e.to_string())?;
+    let expiry = invoice.expiry.parse::<u64>().map_err(|e| e.to_string())?;
+
+    Ok(PaymentStatus::Pending { expiry: creation_date + expiry })
+}
+
+#[rocket::async_trait]
+impl PaymentProcessor for Lnd {
+    async fn create_invoice(&self, token_id: &str, fee_sats: u64) -> Result<ProcessorInvoice, String> {
+
+        let body = json!({
+            "memo": token_id,
+            "value": fee_sats.to_string(),
+            "expiry": self.delay.to_string(),
+        });
+
+        let response = self.client
+            .post(&format!("{}/v1/invoices", self.url))
+            .header("Grpc-Metadata-macaroon", &self.macaroon)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("LND add invoice failed. status: {}, error: {}", status, text));
+        }
+
+        let invoice: LndAddInvoiceResponse = response.json().await.map_err(|e| e.to_string())?;
+
+        Ok(ProcessorInvoice {
+            id: processor_id(&invoice.r_hash)?,
+            pr: invoice.payment_request,
+            onchain_address: String::new(),
+            expiry: chrono::Utc::now().timestamp() as u64 + self.delay,
+        })
+    }
+
+    async fn query_payment(&self, processor_id: &str) -> Result<PaymentStatus, String> {
+
+        let response = self.client
+            .get(&format!("{}/v1/invoice/{}", self.url, processor_id))
+            .header("Grpc-Metadata-macaroon", &self.macaroon)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("LND lookup invoice failed. status: {}, error: {}", status, text));
+        }
+
+        let invoice: LndInvoice = response.json().await.map_err(|e| e.to_string())?;
+
+        payment_status(&invoice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invoice(state: &str) -> LndInvoice {
+        serde_json::from_value(json!({
+            "memo": "token",
+            "r_hash": "ldzLn8IGBNxz4/VVrd8VRmaNGh9OSjwmZUJdmmlzPPE=",
+            "value": "10000",
+            "settled": state == "SETTLED",
+            "creation_date": "1700000000",
+            "settle_date": "0",
+            "payment_request": "lnbcrt100u1invoice",
+            "expiry": "3600",
+            "state": state,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_payment_status() {
+        assert_eq!(payment_status(&invoice("SETTLED")), Ok(PaymentStatus::Paid));
+        assert_eq!(payment_status(&invoice("OPEN")), Ok(PaymentStatus::Pending { expiry: 1700003600 }));
+        assert_eq!(payment_status(&invoice("CANCELED")), Ok(PaymentStatus::Pending { expiry: 1700003600 }));
+    }
+
+    #[test]
+    fn test_invalid_expiry_is_an_error() {
+        let mut invoice = invoice("OPEN");
+        invoice.expiry = String::from("never");
+
+        assert!(payment_status(&invoice).is_err());
+    }
+
+    #[test]
+    fn test_processor_id_is_the_hex_payment_hash() {
+
+        let response: LndAddInvoiceResponse = serde_json::from_value(json!({
+            "r_hash": "ldzLn8IGBNxz4/VVrd8VRmaNGh9OSjwmZUJdmmlzPPE=",
+            "payment_request": "lnbcrt100u1invoice",
+            "add_index": "1",
+            "payment_addr": "GJl5CyDT0Ug3Rbx5qwFu6d8+9ZMkoPm6CAr4Q6DStjY=",
+        })).unwrap();
+
+        assert_eq!(processor_id(&response.r_hash).unwrap(), "95dccb9fc20604dc73e3f555addf1546668d1a1f4e4a3c2665425d9a69733cf1");
+        assert!(processor_id("not base64!").is_err());
+    }
+}
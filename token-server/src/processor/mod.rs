@@ -0,0 +1,69 @@
+pub mod btcpay;
+pub mod cln;
+#[cfg(any(test, feature = "fake-processor"))]
+pub mod fake;
+pub mod lnd;
+pub mod swiss_bitcoin_pay;
+
+use crate::server_config::ServerConfig;
+
+/// Invoice created by the payment processor for a token
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessorInvoice {
+    /// Identifier used to query the payment status
+    pub id: String,
+    /// BOLT11 payment request
+    pub pr: String,
+    /// On-chain payment address. Empty if the processor only accepts lightning payments.
+    pub onchain_address: String,
+    /// Unix timestamp after which the invoice can no longer be paid
+    pub expiry: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentStatus {
+    Paid,
+    /// Not paid yet. The invoice expires at the given unix timestamp.
+    Pending { expiry: u64 },
+}
+
+/// Backend that creates the token invoices and reports their payment
+#[rocket::async_trait]
+pub trait PaymentProcessor: Send + Sync {
+    async fn create_invoice(&self, token_id: &str, fee_sats: u64) -> Result<ProcessorInvoice, String>;
+    async fn query_payment(&self, processor_id: &str) -> Result<PaymentStatus, String>;
+}
+
+/// Builds the payment processor selected by the `processor` setting
+pub fn from_config(config: &ServerConfig) -> Result<Box<dyn PaymentProcessor>, String> {
+
+    let processor: Box<dyn PaymentProcessor> = match config.processor.as_str() {
+        "swiss_bitcoin_pay" => Box::new(swiss_bitcoin_pay::SwissBitcoinPay::new(&config.processor_url, &config.api_key, &config.unit, config.delay)),
+        "btcpay" => {
+            let store_id = config.btcpay_store_id.as_ref().ok_or("btcpay_store_id must be set for the btcpay processor")?;
+            Box::new(btcpay::BtcPay::new(&config.processor_url, &config.api_key, store_id, config.delay))
+        },
+        "lnd" => Box::new(lnd::Lnd::new(&config.processor_url, &config.api_key, config.processor_tls_cert.as_deref(), config.delay)?),
+        "cln" => Box::new(cln::Cln::new(&config.processor_url, &config.api_key, config.processor_tls_cert.as_deref(), config.delay)?),
+        // confirms every payment, so it is only built with the fake-processor feature
+        #[cfg(any(test, feature = "fake-processor"))]
+        "fake" => Box::new(fake::FakeProcessor::new(true)),
+        processor => return Err(format!("Unknown payment processor {}", processor)),
+    };
+
+    Ok(processor)
+}
+
+/// Builds a reqwest client trusting the node certificate, which is usually self-signed
+fn node_client(tls_cert_path: Option<&str>) -> Result<reqwest::Client, String> {
+
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(tls_cert_path) = tls_cert_path {
+        let pem = std::fs::read(tls_cert_path).map_err(|e| e.to_string())?;
+        let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| e.to_string())?;
+        builder = builder.add_root_certificate(cert);
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
@@ -0,0 +1,236 @@
+#![allow(non_snake_case)]
+
+use serde::{Serialize, Deserialize};
+
+use super::{PaymentProcessor, PaymentStatus, ProcessorInvoice};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReqInvoice{
+    pub title: String,
+    pub description: String,
+    pub amount: String,
+    pub unit: String,
+    pub redirectAfterPaid: String,
+    pub email: String,
+    pub emailLanguage: String,
+    pub onChain: bool,
+    pub delay: u64,
+    pub extra: Extra,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Extra{
+    pub tag: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RTLInvoice{
+    pub id: String,
+    pub pr: String,
+    pub checkoutUrl: String,
+    pub onChainAddr: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RTLData{
+    pub label: String,
+    pub bolt11: String,
+    pub payment_hash: String,
+    pub msatoshi: u64,
+    pub amount_msat: String,
+    pub status: String,
+    pub description: String,
+    pub expires_at: u64
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RTLQuery{
+    pub createdAt: u64,
+    pub delay: u64,
+    pub pr: String,
+    pub amount: u64,
+    pub btcAmount: String,
+    pub unit: String,
+    pub isPaid: bool,
+    pub updatePrice: bool,
+    pub isHodled: bool,
+    pub isInit: bool,
+    pub isFixedSatPrice: bool,
+    pub deleteExpiredInvoice: bool,
+    pub isExpired: bool,
+    pub paymentMethod: Option<String>,
+    pub paidAt: Option<String>,
+    pub title: String,
+    pub hash: String,
+    pub fiatAmount: f64,
+    pub fiatUnit: String,
+    pub onChainAddr: String,
+    pub minConfirmations: u64,
+    pub confirmations: u64,
+    pub txId: Option<String>,
+    pub isPending: bool,
+    pub extra: Extra,
+}
+
+/// Swiss Bitcoin Pay checkout API
+pub struct SwissBitcoinPay {
+    processor_url: String,
+    api_key: String,
+    unit: String,
+    delay: u64,
+    client: reqwest::Client,
+}
+
+impl SwissBitcoinPay {
+    pub fn new(processor_url: &str, api_key: &str, unit: &str, delay: u64) -> Self {
+        SwissBitcoinPay {
+            processor_url: processor_url.to_string(),
+            api_key: api_key.to_string(),
+            unit: unit.to_string(),
+            delay,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+/// The API returns errors as JSON bodies, which are reported as they are
+fn parse_response<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_str(value).map_err(|_| format!("failed to parse: {}", value))
+}
+
+/// Checkouts expire `delay` seconds after their creation
+fn payment_status(query: &RTLQuery) -> PaymentStatus {
+    if query.isPaid {
+        PaymentStatus::Paid
+    } else {
+        PaymentStatus::Pending { expiry: query.createdAt + query.delay }
+    }
+}
+
+#[rocket::async_trait]
+impl PaymentProcessor for SwissBitcoinPay {
+    async fn create_invoice(&self, token_id: &str, fee_sats: u64) -> Result<ProcessorInvoice, String> {
+
+        let path: &str = "checkout";
+        let extra: Extra = Extra {
+            tag: "invoice-web".to_string(),
+        };
+        let inv_request: ReqInvoice = ReqInvoice {
+            title: token_id.to_string(),
+            description: "".to_string(),
+            amount: fee_sats.to_string(),
+            unit: self.unit.clone(),
+            redirectAfterPaid: "".to_string(),
+            email: "".to_string(),
+            emailLanguage: "en".to_string(),
+            onChain: true,
+            delay: self.delay,
+            extra,
+        };
+
+        let request = self.client.post(format!("{}/{}", self.processor_url, path));
+
+        let value = request.header("Api-Key", &self.api_key).header("encodingtype","hex").json(&inv_request).send().await
+            .map_err(|e| e.to_string())?
+            .text().await
+            .map_err(|e| e.to_string())?;
+
+        let ret_invoice: RTLInvoice = parse_response(&value)?;
+
+        Ok(ProcessorInvoice {
+            id: ret_invoice.id,
+            pr: ret_invoice.pr,
+            onchain_address: ret_invoice.onChainAddr,
+            expiry: chrono::Utc::now().timestamp() as u64 + self.delay,
+        })
+    }
+
+    async fn query_payment(&self, processor_id: &str) -> Result<PaymentStatus, String> {
+
+        let path: String = "checkout/".to_string() + processor_id;
+
+        let request = self.client.get(format!("{}/{}", self.processor_url, path));
+
+        let value = request.header("Api-Key", &self.api_key).header("encodingtype","hex").send().await
+            .map_err(|e| e.to_string())?
+            .text().await
+            .map_err(|e| e.to_string())?;
+
+        let ret_invoice: RTLQuery = parse_response(&value)?;
+
+        Ok(payment_status(&ret_invoice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn checkout(is_paid: bool) -> String {
+        json!({
+            "createdAt": 1700000000,
+            "delay": 3600,
+            "pr": "lnbc100u1invoice",
+            "amount": 10000,
+            "btcAmount": "0.0001",
+            "unit": "BTC",
+            "isPaid": is_paid,
+            "updatePrice": false,
+            "isHodled": false,
+            "isInit": true,
+            "isFixedSatPrice": true,
+            "deleteExpiredInvoice": false,
+            "isExpired": false,
+            "paymentMethod": if is_paid { Some("lightning") } else { None },
+            "paidAt": if is_paid { Some("2023-11-14T22:30:00.000Z") } else { None },
+            "title": "token",
+            "hash": "95dccb9fc20604dc73e3f555addf1546668d1a1f4e4a3c2665425d9a69733cf1",
+            "fiatAmount": 3.5,
+            "fiatUnit": "USD",
+            "onChainAddr": "bc1qaddress",
+            "minConfirmations": 1,
+            "confirmations": 0,
+            "txId": null,
+            "isPending": false,
+            "extra": { "tag": "invoice-web" },
+        }).to_string()
+    }
+
+    #[test]
+    fn test_payment_status() {
+
+        let query: RTLQuery = parse_response(&checkout(true)).unwrap();
+        assert_eq!(payment_status(&query), PaymentStatus::Paid);
+
+        let query: RTLQuery = parse_response(&checkout(false)).unwrap();
+        assert_eq!(payment_status(&query), PaymentStatus::Pending { expiry: 1700003600 });
+    }
+
+    #[test]
+    fn test_create_invoice_response() {
+
+        let value = json!({
+            "id": "aW52b2ljZQ",
+            "pr": "lnbc100u1invoice",
+            "checkoutUrl": "https://checkout.swiss-bitcoin-pay.ch/aW52b2ljZQ",
+            "onChainAddr": "bc1qaddress",
+        }).to_string();
+
+        let invoice: RTLInvoice = parse_response(&value).unwrap();
+
+        assert_eq!(invoice.id, "aW52b2ljZQ");
+        assert_eq!(invoice.onChainAddr, "bc1qaddress");
+    }
+
+    #[test]
+    fn test_error_response_is_reported() {
+
+        let value = json!({ "message": "Invalid API key" }).to_string();
+
+        let error = parse_response::<RTLInvoice>(&value).unwrap_err();
+
+        assert!(error.contains("Invalid API key"));
+    }
+}
@@ -1,10 +1,11 @@
 use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
 
-use crate::server_config::ServerConfig;
+use crate::{processor::PaymentProcessor, server_config::ServerConfig};
 
 pub struct TokenServer {
     pub config: ServerConfig,
     pub pool: Pool<Postgres>,
+    pub processor: Box<dyn PaymentProcessor>,
 }
 
 impl TokenServer {
@@ -19,9 +20,12 @@ impl TokenServer {
             .await
             .unwrap();
 
+        let processor = crate::processor::from_config(&config).unwrap();
+
         TokenServer {
             config,
             pool,
+            processor,
         }
     }
 }
\ No newline at end of file
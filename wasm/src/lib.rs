@@ -2,11 +2,9 @@
 
 mod utils;
 
-use mercurylib::{decode_transfer_address, deposit::DepositMsg1Response, transfer::{receiver::{create_transfer_receiver_request_payload, decrypt_transfer_msg, get_new_key_info, StatechainInfo, StatechainInfoResponsePayload, TxOutpoint}, sender::create_transfer_signature, TransferMsg}, utils::ServerConfig, wallet::{Activity, BackupTx, Coin, Settings, Token, Wallet}};
+use mercurylib::{decode_transfer_address, error::MercuryError, deposit::{blind_token::BlindedToken, DepositMsg1Response, TokenStatusResponsePayload}, transfer::{receiver::{create_transfer_receiver_request_payload, BackupChainReport, decrypt_transfer_msg, get_new_key_info, StatechainInfo, StatechainInfoResponsePayload, TxOutpoint}, sender::create_transfer_signature, TransferMsg}, utils::ServerConfig, wallet::{Activity, BackupTx, Coin, Settings, Token, TokenStatus, Wallet}};
 use wasm_bindgen::prelude::*;
 use serde::{Serialize, Deserialize};
-use bip39::Mnemonic;
-use rand::Rng;
 
 #[wasm_bindgen]
 extern "C" {
@@ -79,6 +77,18 @@ pub fn confirmToken(token_id: String, wallet_json: JsValue) -> JsValue {
     serde_wasm_bindgen::to_value(&wallet).unwrap()
 }
 
+#[wasm_bindgen]
+pub fn updateTokenStatus(token_status_json: JsValue, wallet_json: JsValue) -> JsValue {
+    let mut wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    let token_status: TokenStatusResponsePayload = serde_wasm_bindgen::from_value(token_status_json).unwrap();
+    for token in wallet.tokens.iter_mut() {
+        if token.token_id == token_status.token_id {
+            token.set_status(token_status.status.clone(), &token_status.expiry);
+        }
+    }
+    serde_wasm_bindgen::to_value(&wallet).unwrap()
+}
+
 #[wasm_bindgen]
 pub fn getTokens(wallet_json: JsValue) -> JsValue {
     let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
@@ -96,22 +106,21 @@ pub fn getBalance(wallet_json: JsValue) -> u32 {
 }
 
 #[wasm_bindgen]
-pub fn getSCAddress(wallet_json: JsValue, index: u32, network: String) -> String {
-    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
-    let address = mercurylib::get_sc_address(&wallet.mnemonic, index, &network).unwrap();
+pub fn getSCAddress(wallet_json: JsValue, index: u32, network: String, passphrase: Option<String>) -> String {
+    let mut wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    wallet.network = network;
+    // fails if the wallet has a passphrase and none is given
+    let address = mercurylib::get_sc_address_with_passphrase(&wallet, &passphrase.unwrap_or_default(), index).unwrap();
     address.to_string()
 }
 
 #[wasm_bindgen]
-pub fn generateMnemonic() -> String {
-    let mut rng = rand::thread_rng();
-    let entropy = (0..16).map(|_| rng.gen::<u8>()).collect::<Vec<u8>>(); // 16 bytes of entropy for 12 words
-    let mnemonic = Mnemonic::from_entropy(&entropy).unwrap();
-    mnemonic.to_string()
+pub fn generateMnemonic(word_count: Option<u32>) -> String {
+    mercurylib::wallet::generate_mnemonic_with_word_count(word_count.unwrap_or(12)).unwrap()
 }
 
 #[wasm_bindgen]
-pub fn fromMnemonic(name: String, mnemonic: String) -> JsValue {
+pub fn fromMnemonic(name: String, mnemonic: String, passphrase: Option<String>) -> JsValue {
 
     let settings = Settings {
         network: String::from("signet"),
@@ -130,7 +139,9 @@ pub fn fromMnemonic(name: String, mnemonic: String) -> JsValue {
         tutorials: false
     };
 
-    let wallet = Wallet {
+    let passphrase = passphrase.unwrap_or_default();
+
+    let mut wallet = Wallet {
         name,
         mnemonic,
         version: String::from("0.1.0"),
@@ -143,8 +154,12 @@ pub fn fromMnemonic(name: String, mnemonic: String) -> JsValue {
         tokens: Vec::new(),
         activities: Vec::new(),
         coins: Vec::new(),
-        settings
+        settings,
+        encrypted_secrets: None,
+        has_passphrase: !passphrase.is_empty(),
+        master_fingerprint: None
     };
+    wallet.master_fingerprint = Some(wallet.get_master_fingerprint(&passphrase).unwrap());
     serde_wasm_bindgen::to_value(&wallet).unwrap()
 }
 
@@ -161,19 +176,58 @@ pub fn getCoins(wallet_json: JsValue) -> JsValue {
 }
 
 #[wasm_bindgen]
-pub fn getNewCoin(wallet_json: JsValue) -> JsValue {
+pub fn getNewCoin(wallet_json: JsValue, passphrase: Option<String>) -> JsValue {
     let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
-    let coin = wallet.get_new_coin().unwrap();
+    let coin = wallet.get_new_coin_with_passphrase(&passphrase.unwrap_or_default()).unwrap();
     serde_wasm_bindgen::to_value(&coin).unwrap()
 }
 
 #[wasm_bindgen]
-pub fn createDepositMsg1(coin_json: JsValue, token_id: String) -> JsValue {
+pub fn deriveRecoveryCoins(wallet_json: JsValue, start_index: u32, count: u32, passphrase: Option<String>) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    let coins = mercurylib::recovery::derive_recovery_coins(&wallet, start_index, count, passphrase.unwrap_or_default()).unwrap();
+    serde_wasm_bindgen::to_value(&coins).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn createRecoverStatecoinsRequestPayload(coins_json: JsValue, challenge: String) -> JsValue {
+    let coins: Vec<Coin> = serde_wasm_bindgen::from_value(coins_json).unwrap();
+    let payload = mercurylib::recovery::create_recover_statecoins_request_payload(&coins, &challenge).unwrap();
+    serde_wasm_bindgen::to_value(&payload).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn recoverCoin(coin_json: JsValue, recovered_statecoin_json: JsValue, network: String) -> JsValue {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
+    let recovered_statecoin: mercurylib::recovery::RecoveredStatecoin = serde_wasm_bindgen::from_value(recovered_statecoin_json).unwrap();
+    match mercurylib::recovery::recover_coin(&coin, &recovered_statecoin, network) {
+        Ok(coin) => serde_wasm_bindgen::to_value(&coin).unwrap(),
+        Err(_) => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub fn createDepositMsg1(coin_json: JsValue, token_id: String, blind_signature: Option<String>) -> JsValue {
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
-    let deposit_msg_1 = mercurylib::deposit::create_deposit_msg1(&coin, &token_id).unwrap();
+    let deposit_msg_1 = match blind_signature {
+        Some(blind_signature) => mercurylib::deposit::create_blind_deposit_msg1(&coin, &token_id, &blind_signature).unwrap(),
+        None => mercurylib::deposit::create_deposit_msg1(&coin, &token_id).unwrap(),
+    };
     serde_wasm_bindgen::to_value(&deposit_msg_1).unwrap()
 }
 
+#[wasm_bindgen]
+pub fn blindToken(signer_pubkey: String, public_nonce: String) -> JsValue {
+    let blinded_token = mercurylib::deposit::blind_token::blind_token(&signer_pubkey, &public_nonce).unwrap();
+    serde_wasm_bindgen::to_value(&blinded_token).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn unblindTokenSignature(blinded_token_json: JsValue, blind_signature: String, signer_pubkey: String) -> String {
+    let blinded_token: BlindedToken = serde_wasm_bindgen::from_value(blinded_token_json).unwrap();
+    mercurylib::deposit::blind_token::unblind_token_signature(&blinded_token, &blind_signature, &signer_pubkey).unwrap()
+}
+
 #[wasm_bindgen]
 pub fn handleDepositMsg1Response(coin_json: JsValue, deposit_msg_1_response_json: JsValue) -> JsValue {
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
@@ -199,11 +253,12 @@ pub fn createAndCommitNonces(coin_json: JsValue) -> JsValue {
 #[wasm_bindgen]
 pub fn getUserBackupAddress(coin_json: JsValue, network: String) -> String {
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
-    let backup_address = mercurylib::transaction::get_user_backup_address(&coin, network).unwrap();
-    backup_address
+    
+    mercurylib::transaction::get_user_backup_address(&coin, network).unwrap()
 }
 
 #[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn getPartialSigRequest(
     coin_json: JsValue, 
     block_height: u32, 
@@ -239,15 +294,15 @@ pub fn createSignature(
     session_hex: String,
     output_pubkey_hex: String) -> String
 {
-    let signature = mercurylib::transaction::create_signature(msg, client_partial_sig_hex, server_partial_sig_hex, session_hex, output_pubkey_hex).unwrap();
-    signature
+    
+    mercurylib::transaction::create_signature(msg, client_partial_sig_hex, server_partial_sig_hex, session_hex, output_pubkey_hex).unwrap()
 }
 
 #[wasm_bindgen]
 pub fn newBackupTransaction(encoded_unsigned_tx: String, signature_hex: String) -> String
 {
-    let backup_tx = mercurylib::transaction::new_backup_transaction(encoded_unsigned_tx, signature_hex).unwrap();
-    backup_tx
+    
+    mercurylib::transaction::new_backup_transaction(encoded_unsigned_tx, signature_hex).unwrap()
 }
 
 #[wasm_bindgen]
@@ -255,15 +310,82 @@ pub fn createCpfpTx(backup_tx_json: JsValue, coin_json: JsValue, to_address: Str
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
     let backup_tx: BackupTx = serde_wasm_bindgen::from_value(backup_tx_json).unwrap();
 
-    let backup_tx = mercurylib::wallet::cpfp_tx::create_cpfp_tx(&backup_tx, &coin, &to_address, fee_rate_sats_per_byte as f64, &network).unwrap();
-    backup_tx
+    
+    mercurylib::wallet::cpfp_tx::create_cpfp_tx(&backup_tx, &coin, &to_address, fee_rate_sats_per_byte as f64, &network).unwrap()
     // "".to_string()
 }
 
+#[wasm_bindgen]
+pub fn createCpfpPsbt(backup_tx_json: JsValue, coin_json: JsValue, to_address: String, fee_rate_sats_per_byte: f32, network: String) -> String {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
+    let backup_tx: BackupTx = serde_wasm_bindgen::from_value(backup_tx_json).unwrap();
+
+    mercurylib::wallet::cpfp_tx::create_cpfp_psbt(&backup_tx, &coin, &to_address, fee_rate_sats_per_byte as f64, &network).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn importSignedPsbt(psbt_base64: String) -> JsValue {
+    match mercurylib::psbt::import_signed_psbt(&psbt_base64) {
+        Ok(signed_tx) => JsValue::from_str(&signed_tx),
+        Err(_) => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub fn lockWallet(wallet_json: JsValue, password: String) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    match mercurylib::wallet::encryption::lock_wallet(&wallet, &password) {
+        Ok(wallet) => serde_wasm_bindgen::to_value(&wallet).unwrap(),
+        Err(_) => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub fn unlockWallet(wallet_json: JsValue, password: String) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    match mercurylib::wallet::encryption::unlock_wallet(&wallet, &password) {
+        Ok(wallet) => serde_wasm_bindgen::to_value(&wallet).unwrap(),
+        Err(_) => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub fn changeWalletPassword(wallet_json: JsValue, old_password: String, new_password: String) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    match mercurylib::wallet::encryption::change_wallet_password(&wallet, &old_password, &new_password) {
+        Ok(wallet) => serde_wasm_bindgen::to_value(&wallet).unwrap(),
+        Err(_) => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub fn isWalletLocked(wallet_json: JsValue) -> bool {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    wallet.is_locked()
+}
+
+#[wasm_bindgen]
+pub fn exportWalletBackup(wallet_json: JsValue, backup_txs_json: JsValue, password: String) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    let backup_txs: Vec<mercurylib::wallet::StatechainBackupTxs> = serde_wasm_bindgen::from_value(backup_txs_json).unwrap();
+    match mercurylib::wallet::backup::export_wallet_backup(&wallet, &backup_txs, &password) {
+        Ok(backup_file) => JsValue::from_str(&backup_file),
+        Err(_) => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub fn importWalletBackup(backup_file: String, password: String) -> JsValue {
+    match mercurylib::wallet::backup::import_wallet_backup(&backup_file, &password) {
+        Ok(wallet_backup) => serde_wasm_bindgen::to_value(&wallet_backup).unwrap(),
+        Err(_) => JsValue::NULL,
+    }
+}
+
 #[wasm_bindgen]
 pub fn createTransferSignature(recipient_address: String, input_txid: String, input_vout: u32, client_seckey: String) -> String {
-    let signature = create_transfer_signature(&recipient_address, &input_txid, input_vout, &client_seckey).unwrap();
-    signature
+    
+    create_transfer_signature(&recipient_address, &input_txid, input_vout, &client_seckey).unwrap()
 }
 
 #[wasm_bindgen]
@@ -314,30 +436,30 @@ pub fn getTx0Outpoint(backup_transactions: JsValue) -> JsValue {
 pub fn verifyTransferSignature(new_user_pubkey: String, tx0_outpoint: JsValue, transfer_msg: JsValue) -> bool {
     let tx0_outpoint: TxOutpoint = serde_wasm_bindgen::from_value(tx0_outpoint).unwrap();
     let transfer_msg: TransferMsg = serde_wasm_bindgen::from_value(transfer_msg).unwrap();
-    let result = mercurylib::transfer::receiver::verify_transfer_signature(&new_user_pubkey, &tx0_outpoint, &transfer_msg).unwrap();
-    result
+    
+    mercurylib::transfer::receiver::verify_transfer_signature(&new_user_pubkey, &tx0_outpoint, &transfer_msg).unwrap()
 }
 
 #[wasm_bindgen]
 pub fn validateTx0OutputPubkey(enclave_public_key: String, transfer_msg: JsValue, tx0_outpoint: JsValue, tx0_hex: String, network: String) -> bool {
     let tx0_outpoint: TxOutpoint = serde_wasm_bindgen::from_value(tx0_outpoint).unwrap();
     let transfer_msg: TransferMsg = serde_wasm_bindgen::from_value(transfer_msg).unwrap();
-    let result = mercurylib::transfer::receiver::validate_tx0_output_pubkey(&enclave_public_key, &transfer_msg, &tx0_outpoint, &tx0_hex, &network).unwrap();
-    result
+    
+    mercurylib::transfer::receiver::validate_tx0_output_pubkey(&enclave_public_key, &transfer_msg, &tx0_outpoint, &tx0_hex, &network).unwrap()
 }
 
 #[wasm_bindgen]
 pub fn verifyLatestBackupTxPaysToUserPubkey(transfer_msg: JsValue, client_pubkey_share: String, network: String) -> bool {
     let transfer_msg: TransferMsg = serde_wasm_bindgen::from_value(transfer_msg).unwrap();
-    let result = mercurylib::transfer::receiver::verify_latest_backup_tx_pays_to_user_pubkey(&transfer_msg, &client_pubkey_share, &network).unwrap();
-    result
+    
+    mercurylib::transfer::receiver::verify_latest_backup_tx_pays_to_user_pubkey(&transfer_msg, &client_pubkey_share, &network).unwrap()
 }
 
 #[wasm_bindgen]
 pub fn getOutputAddressFromTx0(tx0_outpoint: JsValue, tx0_hex: String, network: String) -> String {
     let tx0_outpoint: TxOutpoint = serde_wasm_bindgen::from_value(tx0_outpoint).unwrap();
-    let output_address = mercurylib::transfer::receiver::get_output_address_from_tx0(&tx0_outpoint, &tx0_hex, &network).unwrap();
-    output_address
+    
+    mercurylib::transfer::receiver::get_output_address_from_tx0(&tx0_outpoint, &tx0_hex, &network).unwrap()
 }
 
 //TODO: remove this function
@@ -345,19 +467,22 @@ pub fn getOutputAddressFromTx0(tx0_outpoint: JsValue, tx0_hex: String, network:
 pub fn verifyTransactionSignature(tx_n_hex: String, tx0_hex: String, fee_rate_tolerance: f32, current_fee_rate_sats_per_byte: f32) -> JsValue {
     let result = mercurylib::transfer::receiver::verify_transaction_signature(&tx_n_hex, &tx0_hex, fee_rate_tolerance as f64, current_fee_rate_sats_per_byte as f64);
 
-    #[derive(Serialize, Deserialize)]
+    #[derive(Serialize)]
     struct ValidationResult {
         result: bool,
         msg: Option<String>,
+        error: Option<MercuryError>,
     }
 
     let mut validation_result = ValidationResult {
         result: result.is_ok(),
-        msg: None
+        msg: None,
+        error: None,
     };
 
-    if result.is_err() {
-        validation_result.msg = Some(result.err().unwrap().to_string());
+    if let Err(err) = result {
+        validation_result.msg = Some(err.to_string());
+        validation_result.error = Some(err);
     }
 
     serde_wasm_bindgen::to_value(&validation_result).unwrap()
@@ -370,19 +495,22 @@ pub fn verifyBlindedMusigScheme(backup_tx: JsValue, tx0_hex: String, statechain_
     let statechain_info: StatechainInfo = serde_wasm_bindgen::from_value(statechain_info).unwrap();
 
     let result = mercurylib::transfer::receiver::verify_blinded_musig_scheme(&backup_tx, &tx0_hex, &statechain_info);
-    #[derive(Serialize, Deserialize)]
+    #[derive(Serialize)]
     struct ValidationResult {
         result: bool,
         msg: Option<String>,
+        error: Option<MercuryError>,
     }
 
     let mut validation_result = ValidationResult {
         result: result.is_ok(),
-        msg: None
+        msg: None,
+        error: None,
     };
 
-    if result.is_err() {
-        validation_result.msg = Some(result.err().unwrap().to_string());
+    if let Err(err) = result {
+        validation_result.msg = Some(err.to_string());
+        validation_result.error = Some(err);
     }
 
     serde_wasm_bindgen::to_value(&validation_result).unwrap()
@@ -391,8 +519,8 @@ pub fn verifyBlindedMusigScheme(backup_tx: JsValue, tx0_hex: String, statechain_
 #[wasm_bindgen]
 pub fn getBlockheight(backup_tx: JsValue) -> u32 {
     let backup_tx: BackupTx = serde_wasm_bindgen::from_value(backup_tx).unwrap();
-    let blockheight = mercurylib::utils::get_blockheight(&backup_tx).unwrap();
-    blockheight
+    
+    mercurylib::utils::get_blockheight(&backup_tx).unwrap()
 }
 
 #[wasm_bindgen]
@@ -418,8 +546,8 @@ pub fn getNewKeyInfo(server_public_key_hex: String, coin: JsValue, statechain_id
 
 #[wasm_bindgen]
 pub fn validateAddress(address: String, network: String) -> bool {
-    let result = mercurylib::validate_address(&address, &network).unwrap();
-    result
+    
+    mercurylib::validate_address(&address, &network).unwrap()
 }
 
 #[wasm_bindgen]
@@ -427,9 +555,19 @@ pub fn signMessage(statechain_id: String, coin: JsValue) -> String {
 
     let coin: Coin = serde_wasm_bindgen::from_value(coin).unwrap();
 
-    let signature = mercurylib::transfer::receiver::sign_message(&statechain_id, &coin).unwrap();
+    
 
-    signature
+    mercurylib::transfer::receiver::sign_message(&statechain_id, &coin).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn signAuthChallenge(challenge: String, coin: JsValue) -> String {
+
+    let coin: Coin = serde_wasm_bindgen::from_value(coin).unwrap();
+
+    
+
+    mercurylib::transfer::receiver::sign_auth_challenge(&challenge, &coin).unwrap()
 }
 
 #[wasm_bindgen]
@@ -448,10 +586,9 @@ pub fn latestBackuptxPaysToUserpubkey(backup_transactions: JsValue, coin: JsValu
 
     let backup_transaction = mercurylib::wallet::cpfp_tx::latest_backup_tx_pays_to_user_pubkey(&backup_transactions, &coin, &network);
 
-    if backup_transaction.is_err() {
-        return JsValue::NULL;
-    } else {
-        serde_wasm_bindgen::to_value(&backup_transaction.unwrap()).unwrap()
+    match backup_transaction {
+        Ok(backup_transaction) => serde_wasm_bindgen::to_value(&backup_transaction).unwrap(),
+        Err(_) => JsValue::NULL,
     }
 }
 
@@ -460,10 +597,9 @@ pub fn duplicateCoinToInitializedState(walletJson: JsValue, authPubkey: String)
     let wallet: Wallet = serde_wasm_bindgen::from_value(walletJson).unwrap();
     let new_coin = mercurylib::transfer::receiver::duplicate_coin_to_initialized_state(&wallet, &authPubkey);
     
-    if new_coin.is_err() {
-        return JsValue::NULL;
-    } else {
-        serde_wasm_bindgen::to_value(&new_coin.unwrap()).unwrap()
+    match new_coin {
+        Ok(new_coin) => serde_wasm_bindgen::to_value(&new_coin).unwrap(),
+        Err(_) => JsValue::NULL,
     }
 }
 
@@ -475,27 +611,71 @@ pub fn validateSignatureScheme(transfer_msg: JsValue, statechain_info: JsValue,
 
     let result = mercurylib::transfer::receiver::validate_signature_scheme(&transfer_msg, &statechain_info, &tx0_hex, fee_rate_tolerance as f64, current_fee_rate_sats_per_byte as f64, interval);
 
-    #[derive(Serialize, Deserialize)]
+    #[derive(Serialize)]
     struct ValidationResult {
         result: bool,
         msg: Option<String>,
+        error: Option<MercuryError>,
         previousLockTime: u32,
     }
 
-    if result.is_err() {
-        let validation_result = ValidationResult {
-            result: false,
-            msg: Some(result.err().unwrap().to_string()),
-            previousLockTime: 0
-        };
-        return serde_wasm_bindgen::to_value(&validation_result).unwrap();
-    } else {
-        let validation_result = ValidationResult {
-            result: true,
-            msg: None,
-            previousLockTime: result.unwrap()
-        };
-        return serde_wasm_bindgen::to_value(&validation_result).unwrap();
+    match result {
+        Err(err) => {
+            let validation_result = ValidationResult {
+                result: false,
+                msg: Some(err.to_string()),
+                error: Some(err),
+                previousLockTime: 0
+            };
+            serde_wasm_bindgen::to_value(&validation_result).unwrap()
+        },
+        Ok(previous_lock_time) => {
+            let validation_result = ValidationResult {
+                result: true,
+                msg: None,
+                error: None,
+                previousLockTime: previous_lock_time
+            };
+            serde_wasm_bindgen::to_value(&validation_result).unwrap()
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn validateBackupChain(transfer_msg: JsValue, statechain_info: JsValue, tx0_hex: String, fee_rate_tolerance: f32, current_fee_rate_sats_per_byte: f32, interval: u32, network: String) -> JsValue {
+
+    let statechain_info: StatechainInfoResponsePayload = serde_wasm_bindgen::from_value(statechain_info).unwrap();
+    let transfer_msg: TransferMsg = serde_wasm_bindgen::from_value(transfer_msg).unwrap();
+
+    let result = mercurylib::transfer::receiver::validate_backup_chain(&transfer_msg, &statechain_info, &tx0_hex, fee_rate_tolerance as f64, current_fee_rate_sats_per_byte as f64, interval, &network);
+
+    #[derive(Serialize)]
+    struct ValidationResult {
+        result: bool,
+        msg: Option<String>,
+        error: Option<MercuryError>,
+        report: Option<BackupChainReport>,
+    }
+
+    match result {
+        Err(err) => {
+            let validation_result = ValidationResult {
+                result: false,
+                msg: Some(err.to_string()),
+                error: Some(err),
+                report: None
+            };
+            serde_wasm_bindgen::to_value(&validation_result).unwrap()
+        },
+        Ok(report) => {
+            let validation_result = ValidationResult {
+                result: report.valid,
+                msg: None,
+                error: None,
+                report: Some(report)
+            };
+            serde_wasm_bindgen::to_value(&validation_result).unwrap()
+        }
     }
 }
 
@@ -510,7 +690,8 @@ pub fn getMockWallet() -> JsValue {
             token_id: String::from("e08aee00-cfed-4ab7-b304-38d61405720e"),
             confirmed: true,
             spent: false,
-            expiry: "2023-11-07T12:34:56.789Z".to_string()
+            expiry: "2023-11-07T12:34:56.789Z".to_string(),
+            status: Some(TokenStatus::PAID)
         },
         Token {
             btc_payment_address: String::from("bc1..."),
@@ -520,7 +701,8 @@ pub fn getMockWallet() -> JsValue {
             token_id: String::from("aed9a34c-5666-4d83-940f-9b74e16b8672"),
             confirmed: true,
             spent: false,
-            expiry: "2023-11-07T12:34:56.789Z".to_string()
+            expiry: "2023-11-07T12:34:56.789Z".to_string(),
+            status: Some(TokenStatus::PAID)
         }
     ];
 
@@ -575,7 +757,10 @@ pub fn getMockWallet() -> JsValue {
         tokens,
         activities: activity,
         coins: Vec::new(), // coins
-        settings
+        settings,
+        encrypted_secrets: None,
+        has_passphrase: false,
+        master_fingerprint: None
     };
     serde_wasm_bindgen::to_value(&wallet).unwrap()
 }
\ No newline at end of file